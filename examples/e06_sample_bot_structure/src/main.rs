@@ -10,7 +10,6 @@
 #![allow(deprecated)] // We recommend migrating to poise, instead of using the standard command framework.
 mod commands;
 
-use std::collections::HashSet;
 use std::env;
 use std::sync::Arc;
 
@@ -69,14 +68,7 @@ async fn main() {
 
     // We will fetch your bot's owners and id
     let (owners, _bot_id) = match http.get_current_application_info().await {
-        Ok(info) => {
-            let mut owners = HashSet::new();
-            if let Some(owner) = &info.owner {
-                owners.insert(owner.id);
-            }
-
-            (owners, info.id)
-        },
+        Ok(info) => (info.owner_ids(), info.id),
         Err(why) => panic!("Could not access application info: {:?}", why),
     };
 
@@ -119,7 +119,7 @@ async fn challenge(ctx: &Context, msg: &Message, _: Args) -> CommandResult {
         .author_id(msg.author.id);
 
     if let Some(reaction) = collector.await {
-        let _ = if reaction.emoji.as_data() == "1️⃣" {
+        let _ = if reaction.reaction().emoji.as_data() == "1️⃣" {
             score += 1;
             msg.reply(ctx, "That's correct!").await
         } else {
@@ -0,0 +1,142 @@
+//! Logging in to a user account with its email address (or phone number) and password.
+//!
+//! [`login`] either returns the token directly, or an [`MfaTicket`] if the account has
+//! multi-factor authentication enabled, which is exchanged for the token with a code from an
+//! authenticator app via [`MfaTicket::totp`].
+//!
+//! ```rust,no_run
+//! use serenity::auth::{login, LoginResult};
+//! use serenity::http::Http;
+//!
+//! # async fn run() -> Result<(), serenity::Error> {
+//! # fn read_code() -> String { unimplemented!() }
+//! let http = Http::new("");
+//! let token = match login(&http, "user@example.com", "hunter2").await? {
+//!     LoginResult::Token(token) => token,
+//!     LoginResult::MfaRequired(ticket) => ticket.totp(&http, &read_code()).await?,
+//! };
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Discord may require a captcha to be solved before logging in, in which case [`login`] fails
+//! with an [`Error::Captcha`]. See [`Error::captcha_solution`] for how to retry the request.
+
+use crate::http::{Http, LightMethod, Request, Route};
+use crate::internal::prelude::*;
+use crate::json::{json, to_vec};
+use crate::model::id::UserId;
+
+/// The result of a [`login`].
+#[derive(Clone, Debug)]
+pub enum LoginResult {
+    /// The login succeeded, with the token of the account.
+    Token(String),
+    /// The account has multi-factor authentication enabled, so a code must be provided to finish
+    /// the login.
+    MfaRequired(MfaTicket),
+}
+
+/// The ticket of a login which requires a multi-factor authentication code, received from
+/// [`login`].
+#[derive(Clone, Debug, Deserialize)]
+#[non_exhaustive]
+pub struct MfaTicket {
+    /// The Id of the user logging in.
+    pub user_id: UserId,
+    /// The ticket identifying the login.
+    pub ticket: String,
+    /// Whether a code from an authenticator app can be used.
+    #[serde(default)]
+    pub totp: bool,
+    /// Whether a code sent via SMS can be used.
+    #[serde(default)]
+    pub sms: bool,
+    /// Whether a backup code can be used.
+    #[serde(default)]
+    pub backup: bool,
+}
+
+impl MfaTicket {
+    /// Finishes the login with a code from an authenticator app, returning the token of the
+    /// account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the code is invalid or the ticket expired.
+    pub async fn totp(&self, http: impl AsRef<Http>, code: &str) -> Result<String> {
+        let body = to_vec(&json!({
+            "code": code,
+            "ticket": self.ticket,
+        }))?;
+        let request = Request::new(Route::AuthMfaTotp, LightMethod::Post).body(Some(body));
+
+        let response: TokenResponse = http.as_ref().fire(request).await?;
+        Ok(response.token)
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    token: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LoginResponse {
+    Token(TokenResponse),
+    Mfa(MfaTicket),
+}
+
+/// Logs in to a user account with its email address or phone number and its password.
+///
+/// The given [`Http`] doesn't need a token, e.g. one created with `Http::new("")`.
+///
+/// # Errors
+///
+/// Returns an [`Error::Http`] if the credentials are invalid, or an [`Error::Captcha`] if a
+/// captcha must be solved first.
+pub async fn login(http: impl AsRef<Http>, login: &str, password: &str) -> Result<LoginResult> {
+    let body = to_vec(&json!({
+        "login": login,
+        "password": password,
+        "undelete": false,
+    }))?;
+    let request = Request::new(Route::AuthLogin, LightMethod::Post).body(Some(body));
+
+    Ok(match http.as_ref().fire(request).await? {
+        LoginResponse::Token(response) => LoginResult::Token(response.token),
+        LoginResponse::Mfa(ticket) => LoginResult::MfaRequired(ticket),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::from_value;
+
+    #[test]
+    fn login_response() {
+        let response: LoginResponse = from_value(json!({
+            "user_id": "80351110224678912",
+            "token": "token",
+            "user_settings": {"locale": "en-US", "theme": "dark"},
+        }))
+        .unwrap();
+        assert!(matches!(response, LoginResponse::Token(r) if r.token == "token"));
+
+        let response: LoginResponse = from_value(json!({
+            "user_id": "80351110224678912",
+            "mfa": true,
+            "sms": false,
+            "ticket": "ticket",
+            "backup": true,
+            "totp": true,
+            "webauthn": null,
+        }))
+        .unwrap();
+        let LoginResponse::Mfa(ticket) = response else { panic!("expected an MFA ticket") };
+        assert_eq!(ticket.user_id, UserId::new(80351110224678912));
+        assert!(ticket.totp && !ticket.sms);
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to begin a prune of the inactive members of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#begin-guild-prune)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct BeginGuildPrune<'a> {
+    days: u8,
+    compute_prune_count: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    include_roles: Vec<RoleId>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> BeginGuildPrune<'a> {
+    /// Creates a new builder which prunes members who have been inactive for the given number of
+    /// days.
+    ///
+    /// **Note**: The number of days must be between 1 and 30.
+    pub fn new(days: u8) -> Self {
+        Self {
+            days,
+            compute_prune_count: true,
+            include_roles: Vec::new(),
+            audit_log_reason: None,
+        }
+    }
+
+    /// Sets the number of days of inactivity, replacing the current value as set in
+    /// [`Self::new`].
+    pub fn days(mut self, days: u8) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Whether to return the number of pruned members. Defaults to `true`.
+    ///
+    /// Discord discourages computing the count for large guilds.
+    pub fn compute_prune_count(mut self, compute_prune_count: bool) -> Self {
+        self.compute_prune_count = compute_prune_count;
+        self
+    }
+
+    /// Also prunes inactive members with any of the given roles. By default, only members
+    /// without roles are pruned.
+    pub fn include_roles(mut self, roles: impl IntoIterator<Item = impl Into<RoleId>>) -> Self {
+        self.include_roles = roles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for BeginGuildPrune<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = Option<GuildPrune>;
+
+    /// Begins the prune. Returns the number of pruned members, unless
+    /// [`Self::compute_prune_count`] was set to `false`.
+    ///
+    /// **Note**: Requires the [Kick Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        #[cfg(feature = "cache")]
+        crate::utils::user_has_guild_perms(
+            &cache_http,
+            ctx,
+            Permissions::KICK_MEMBERS | Permissions::MANAGE_GUILD,
+        )?;
+
+        cache_http.http().begin_guild_prune(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn begin_guild_prune() {
+        let builder = BeginGuildPrune::new(7).compute_prune_count(false).audit_log_reason("spam");
+        assert_eq!(to_value(&builder).unwrap(), json!({"days": 7, "compute_prune_count": false}));
+
+        let builder = builder.include_roles([RoleId::new(1), RoleId::new(2)]);
+        assert_eq!(to_value(&builder).unwrap()["include_roles"], json!(["1", "2"]));
+    }
+}
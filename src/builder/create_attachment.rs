@@ -25,6 +25,12 @@ pub struct CreateAttachment {
     pub(crate) id: u64, // Placeholder ID will be filled in when sending the request
     pub filename: String,
     pub description: Option<String>,
+    /// The length of the audio, for voice messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    /// The base64 encoded waveform of the audio, for voice messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<String>,
 
     #[serde(skip)]
     pub data: Vec<u8>,
@@ -37,10 +43,43 @@ impl CreateAttachment {
             data: data.into(),
             filename: filename.into(),
             description: None,
+            duration_secs: None,
+            waveform: None,
             id: 0,
         }
     }
 
+    /// Builds a voice message attachment from OGG Opus encoded audio, its length in seconds and
+    /// its waveform.
+    ///
+    /// The waveform consists of up to 256 amplitude samples of the audio, one byte each. The
+    /// message must be sent with [`MessageFlags::IS_VOICE_MESSAGE`] and this as its only
+    /// attachment, see [`CreateMessage::flags`].
+    ///
+    /// **Note**: Only user accounts can send voice messages.
+    ///
+    /// [`MessageFlags::IS_VOICE_MESSAGE`]: crate::model::channel::MessageFlags::IS_VOICE_MESSAGE
+    /// [`CreateMessage::flags`]: super::CreateMessage::flags
+    pub fn voice_message(
+        audio: impl Into<Vec<u8>>,
+        duration_secs: f64,
+        waveform: &[u8],
+    ) -> CreateAttachment {
+        use base64::Engine;
+
+        CreateAttachment {
+            duration_secs: Some(duration_secs),
+            waveform: Some(base64::prelude::BASE64_STANDARD.encode(waveform)),
+            ..CreateAttachment::bytes(audio, "voice-message.ogg")
+        }
+    }
+
+    /// Whether this is a voice message attachment, see [`Self::voice_message`].
+    #[must_use]
+    pub fn is_voice_message(&self) -> bool {
+        self.duration_secs.is_some() && self.waveform.is_some()
+    }
+
     /// Builds an [`CreateAttachment`] by reading a local file.
     ///
     /// # Errors
@@ -277,6 +316,21 @@ impl EditAttachments {
         files
     }
 
+    /// Returns the new attachments and the amount of kept existing attachments.
+    #[cfg(feature = "http")]
+    pub(crate) fn new_and_existing(&self) -> (Vec<&CreateAttachment>, usize) {
+        let new: Vec<_> = self
+            .new_and_existing_attachments
+            .iter()
+            .filter_map(|a| match a {
+                NewOrExisting::New(a) => Some(a),
+                NewOrExisting::Existing(_) => None,
+            })
+            .collect();
+        let existing = self.new_and_existing_attachments.len() - new.len();
+        (new, existing)
+    }
+
     #[cfg(feature = "cache")]
     pub(crate) fn is_empty(&self) -> bool {
         self.new_and_existing_attachments.is_empty()
@@ -46,6 +46,8 @@ pub struct CreateChannel<'a> {
     available_tags: Vec<ForumTag>,
     #[serde(skip_serializing_if = "Option::is_none")]
     default_sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_forum_layout: Option<ForumLayoutType>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -73,6 +75,7 @@ impl<'a> CreateChannel<'a> {
             default_reaction_emoji: None,
             available_tags: Vec::new(),
             default_sort_order: None,
+            default_forum_layout: None,
         }
     }
 
@@ -231,7 +234,7 @@ impl<'a> CreateChannel<'a> {
 
     /// Emoji to show in the add reaction button on a thread in a forum
     ///
-    /// Only for [`ChannelType::Forum`]
+    /// Only for [`ChannelType::Forum`], [`ChannelType::Media`]
     pub fn default_reaction_emoji(mut self, default_reaction_emoji: ForumEmoji) -> Self {
         self.default_reaction_emoji = Some(default_reaction_emoji);
         self
@@ -239,7 +242,7 @@ impl<'a> CreateChannel<'a> {
 
     /// Set of tags that can be used in a forum channel
     ///
-    /// Only for [`ChannelType::Forum`]
+    /// Only for [`ChannelType::Forum`], [`ChannelType::Media`]
     pub fn available_tags(mut self, available_tags: impl IntoIterator<Item = ForumTag>) -> Self {
         self.available_tags = available_tags.into_iter().collect();
         self
@@ -247,11 +250,19 @@ impl<'a> CreateChannel<'a> {
 
     /// The default sort order type used to order posts in forum channels
     ///
-    /// Only for [`ChannelType::Forum`]
+    /// Only for [`ChannelType::Forum`], [`ChannelType::Media`]
     pub fn default_sort_order(mut self, default_sort_order: SortOrder) -> Self {
         self.default_sort_order = Some(default_sort_order);
         self
     }
+
+    /// The default forum layout type used to display posts in forum channels
+    ///
+    /// Only for [`ChannelType::Forum`]
+    pub fn default_forum_layout(mut self, default_forum_layout: ForumLayoutType) -> Self {
+        self.default_forum_layout = Some(default_forum_layout);
+        self
+    }
 }
 
 #[cfg(feature = "http")]
@@ -320,6 +320,8 @@ pub struct CreateCommand {
     #[serde(skip_serializing_if = "Option::is_none")]
     contexts: Option<Vec<InteractionContext>>,
     nsfw: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    handler: Option<EntryPointHandlerType>,
 }
 
 impl CreateCommand {
@@ -342,6 +344,7 @@ impl CreateCommand {
 
             options: Vec::new(),
             nsfw: false,
+            handler: None,
         }
     }
 
@@ -463,6 +466,13 @@ impl CreateCommand {
         self.nsfw = nsfw;
         self
     }
+
+    /// Specifies how the command is handled. Only valid for [`CommandType::PrimaryEntryPoint`]
+    /// commands, for which it is required.
+    pub fn handler(mut self, handler: EntryPointHandlerType) -> Self {
+        self.handler = Some(handler);
+        self
+    }
 }
 
 #[cfg(feature = "http")]
@@ -31,6 +31,68 @@ impl serde::Serialize for CreateActionRow {
     }
 }
 
+impl CreateActionRow {
+    pub(crate) fn buttons_mut(&mut self) -> Option<&mut Vec<CreateButton>> {
+        match self {
+            Self::Buttons(buttons) => Some(buttons),
+            _ => None,
+        }
+    }
+}
+
+/// A builder for a top-level component of a message.
+///
+/// All components except action rows require the [`MessageFlags::IS_COMPONENTS_V2`] flag, which
+/// is set by [`CreateMessage::components_v2`] and [`EditMessage::components_v2`].
+///
+/// [`CreateMessage::components_v2`]: super::CreateMessage::components_v2
+/// [`EditMessage::components_v2`]: super::EditMessage::components_v2
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#component-object-component-types).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+#[must_use]
+pub enum CreateComponent {
+    ActionRow(CreateActionRow),
+    Section(CreateSection),
+    TextDisplay(CreateTextDisplay),
+    MediaGallery(CreateMediaGallery),
+    File(CreateFileComponent),
+    Separator(CreateSeparator),
+    Container(CreateContainer),
+}
+
+impl CreateComponent {
+    pub(crate) fn buttons_mut(&mut self) -> Option<&mut Vec<CreateButton>> {
+        match self {
+            Self::ActionRow(row) => row.buttons_mut(),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_for_create_component {
+    ($($variant:ident($builder:ident)),*) => {
+        $(
+            impl From<$builder> for CreateComponent {
+                fn from(builder: $builder) -> Self {
+                    Self::$variant(builder)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_create_component!(
+    ActionRow(CreateActionRow),
+    Section(CreateSection),
+    TextDisplay(CreateTextDisplay),
+    MediaGallery(CreateMediaGallery),
+    File(CreateFileComponent),
+    Separator(CreateSeparator),
+    Container(CreateContainer)
+);
+
 /// A builder for creating a button component in a message
 #[derive(Clone, Debug, Serialize, PartialEq)]
 #[must_use]
@@ -400,3 +462,352 @@ impl CreateInputText {
         self
     }
 }
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+struct CreateUnfurledMediaItem {
+    url: String,
+}
+
+/// A builder for a component which shows text next to a button or thumbnail.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#section).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateSection {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    components: Vec<CreateTextDisplay>,
+    accessory: CreateSectionAccessory,
+}
+
+impl CreateSection {
+    /// Creates a section with the given text displays and accessory.
+    ///
+    /// **Note**: A section must have between one and three text displays.
+    pub fn new(components: Vec<CreateTextDisplay>, accessory: CreateSectionAccessory) -> Self {
+        Self {
+            kind: ComponentType::Section,
+            components,
+            accessory,
+        }
+    }
+
+    /// Sets the text displays of the section. Replaces the current value as set in
+    /// [`Self::new`].
+    pub fn components(mut self, components: Vec<CreateTextDisplay>) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Sets the accessory of the section. Replaces the current value as set in [`Self::new`].
+    pub fn accessory(mut self, accessory: CreateSectionAccessory) -> Self {
+        self.accessory = accessory;
+        self
+    }
+}
+
+/// A builder for the component shown next to the text of a [`CreateSection`].
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+#[must_use]
+pub enum CreateSectionAccessory {
+    Button(CreateButton),
+    Thumbnail(CreateThumbnail),
+}
+
+/// A builder for a component which shows markdown text.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#text-display).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateTextDisplay {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    content: String,
+}
+
+impl CreateTextDisplay {
+    /// Creates a text display with the given markdown text.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            kind: ComponentType::TextDisplay,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the text of the text display. Replaces the current value as set in [`Self::new`].
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+}
+
+/// A builder for a small image shown as the accessory of a [`CreateSection`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#thumbnail).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateThumbnail {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    media: CreateUnfurledMediaItem,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler: Option<bool>,
+}
+
+impl CreateThumbnail {
+    /// Creates a thumbnail showing the image at the given URL, which may also be an
+    /// `attachment://<filename>` reference.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            kind: ComponentType::Thumbnail,
+            media: CreateUnfurledMediaItem {
+                url: url.into(),
+            },
+            description: None,
+            spoiler: None,
+        }
+    }
+
+    /// Sets the alt text of the image.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the image is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = Some(spoiler);
+        self
+    }
+}
+
+/// A builder for a component which shows a grid of images and videos.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateMediaGallery {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    items: Vec<CreateMediaGalleryItem>,
+}
+
+impl CreateMediaGallery {
+    /// Creates a media gallery with the given items.
+    ///
+    /// **Note**: A media gallery must have between one and ten items.
+    pub fn new(items: Vec<CreateMediaGalleryItem>) -> Self {
+        Self {
+            kind: ComponentType::MediaGallery,
+            items,
+        }
+    }
+
+    /// Sets the items of the gallery. Replaces the current value as set in [`Self::new`].
+    pub fn items(mut self, items: Vec<CreateMediaGalleryItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Adds an item to the gallery.
+    pub fn add_item(mut self, item: CreateMediaGalleryItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// A builder for an image or video of a [`CreateMediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery-media-gallery-item-structure).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateMediaGalleryItem {
+    media: CreateUnfurledMediaItem,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler: Option<bool>,
+}
+
+impl CreateMediaGalleryItem {
+    /// Creates an item showing the media at the given URL, which may also be an
+    /// `attachment://<filename>` reference.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            media: CreateUnfurledMediaItem {
+                url: url.into(),
+            },
+            description: None,
+            spoiler: None,
+        }
+    }
+
+    /// Sets the alt text of the item.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the item is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = Some(spoiler);
+        self
+    }
+}
+
+/// A builder for a component which shows an attached file.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#file).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateFileComponent {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    file: CreateUnfurledMediaItem,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler: Option<bool>,
+}
+
+impl CreateFileComponent {
+    /// Creates a file component showing the attachment with the given filename, which must be
+    /// uploaded with the same message.
+    pub fn new(filename: impl AsRef<str>) -> Self {
+        Self {
+            kind: ComponentType::File,
+            file: CreateUnfurledMediaItem {
+                url: format!("attachment://{}", filename.as_ref()),
+            },
+            spoiler: None,
+        }
+    }
+
+    /// Whether the file is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = Some(spoiler);
+        self
+    }
+}
+
+/// A builder for a component which adds vertical padding between other components.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#separator).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateSeparator {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    divider: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spacing: Option<SeparatorSpacing>,
+}
+
+impl CreateSeparator {
+    /// Creates a separator, with or without a visual divider line.
+    pub fn new(divider: bool) -> Self {
+        Self {
+            kind: ComponentType::Separator,
+            divider,
+            spacing: None,
+        }
+    }
+
+    /// Sets the amount of padding. Defaults to [`SeparatorSpacing::Small`].
+    pub fn spacing(mut self, spacing: SeparatorSpacing) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+}
+
+/// A builder for a component which groups other components in a box.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#container).
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[must_use]
+pub struct CreateContainer {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    components: Vec<CreateComponent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accent_color: Option<Colour>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler: Option<bool>,
+}
+
+impl CreateContainer {
+    /// Creates a container with the given components.
+    ///
+    /// **Note**: Containers can't be nested.
+    pub fn new(components: Vec<CreateComponent>) -> Self {
+        Self {
+            kind: ComponentType::Container,
+            components,
+            accent_color: None,
+            spoiler: None,
+        }
+    }
+
+    /// Sets the components of the container. Replaces the current value as set in
+    /// [`Self::new`].
+    pub fn components(mut self, components: Vec<CreateComponent>) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Adds a component to the container.
+    pub fn add_component(mut self, component: impl Into<CreateComponent>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    /// Sets the color of the bar on the left side of the container.
+    pub fn accent_color(mut self, colour: impl Into<Colour>) -> Self {
+        self.accent_color = Some(colour.into());
+        self
+    }
+
+    /// Whether the container is blurred out as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = Some(spoiler);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn layout_components() {
+        let section = CreateSection::new(
+            vec![CreateTextDisplay::new("**Hello**")],
+            CreateSectionAccessory::Thumbnail(CreateThumbnail::new("attachment://avatar.png")),
+        );
+        let container =
+            CreateContainer::new(vec![section.into(), CreateSeparator::new(true).into()])
+                .accent_color(0xff0000)
+                .add_component(CreateFileComponent::new("notes.txt"));
+
+        assert_eq!(
+            to_value(CreateComponent::from(container)).unwrap(),
+            json!({
+                "type": 17,
+                "components": [{
+                    "type": 9,
+                    "components": [{"type": 10, "content": "**Hello**"}],
+                    "accessory": {"type": 11, "media": {"url": "attachment://avatar.png"}},
+                }, {
+                    "type": 14,
+                    "divider": true,
+                }, {
+                    "type": 13,
+                    "file": {"url": "attachment://notes.txt"},
+                }],
+                "accent_color": 0xff0000,
+            }),
+        );
+    }
+}
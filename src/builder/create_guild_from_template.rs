@@ -0,0 +1,76 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::CreateAttachment;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+
+/// A builder to create a new guild from a [`GuildTemplate`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template)
+///
+/// [`GuildTemplate`]: crate::model::guild::GuildTemplate
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateGuildFromTemplate {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+}
+
+impl CreateGuildFromTemplate {
+    /// Creates a builder with the given guild name, leaving the icon empty.
+    ///
+    /// **Note**: Must be between 2 and 100 characters long.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the name of the new guild, replacing the current value as set in [`Self::new`].
+    ///
+    /// **Note**: Must be between 2 and 100 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the icon of the new guild.
+    pub fn icon(mut self, icon: &CreateAttachment) -> Self {
+        self.icon = Some(icon.to_base64());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateGuildFromTemplate {
+    type Context<'ctx> = &'ctx str;
+    type Built = PartialGuild;
+
+    /// Creates a new guild from the template with the given code.
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`] will be received
+    /// over a [`Shard`].
+    ///
+    /// **Note**: This endpoint is usually only available for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if there is no template with the given code, or if the current user
+    /// cannot create a guild.
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().create_guild_from_template(ctx, &self).await
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to invoke an application command of another application as the current user, the
+/// same way the official client does when a user sends a slash command.
+///
+/// Commands which can be used in a channel can be found with
+/// [`Http::search_application_commands`].
+///
+/// **Note**: Only works for user accounts.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::builder::{Builder, CreateInteraction};
+/// # use serenity::http::Http;
+/// # use serenity::model::prelude::*;
+/// #
+/// # #[cfg(feature = "http")]
+/// # async fn run(http: &Http, channel_id: ChannelId, session_id: String) -> Result<(), Box<dyn std::error::Error>> {
+/// let search = http.search_application_commands(channel_id, Some("ping"), Some(1)).await?;
+/// if let Some(command) = search.application_commands.first() {
+///     let option = CommandDataOption::new("text", CommandDataOptionValue::String("hi".into()));
+///     CreateInteraction::new(command, channel_id, session_id)
+///         .add_option(option)
+///         .execute(http, ())
+///         .await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Http::search_application_commands`]: crate::http::Http::search_application_commands
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateInteraction {
+    #[serde(rename = "type")]
+    kind: InteractionType,
+    application_id: ApplicationId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    session_id: String,
+    data: CreateInteractionData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct CreateInteractionData {
+    version: CommandVersionId,
+    id: CommandId,
+    name: String,
+    #[serde(rename = "type")]
+    kind: CommandType,
+    options: Vec<CommandDataOption>,
+    attachments: Vec<()>,
+}
+
+impl CreateInteraction {
+    /// Creates a builder invoking the given command in the given channel.
+    ///
+    /// The session Id is the one of the current gateway connection, found in
+    /// [`Ready::session_id`]. For guild channels, [`Self::guild_id`] must also be set unless the
+    /// command is specific to the guild.
+    pub fn new(command: &Command, channel_id: ChannelId, session_id: impl Into<String>) -> Self {
+        Self {
+            kind: InteractionType::Command,
+            application_id: command.application_id,
+            guild_id: command.guild_id,
+            channel_id,
+            session_id: session_id.into(),
+            data: CreateInteractionData {
+                version: command.version,
+                id: command.id,
+                name: command.name.clone(),
+                kind: command.kind,
+                options: Vec::new(),
+                attachments: Vec::new(),
+            },
+            nonce: None,
+        }
+    }
+
+    /// Sets the guild the channel belongs to.
+    pub fn guild_id(mut self, guild_id: impl Into<GuildId>) -> Self {
+        self.guild_id = Some(guild_id.into());
+        self
+    }
+
+    /// Adds an option passed to the command. For subcommands, pass a
+    /// [`CommandDataOptionValue::SubCommand`] containing the subcommand's options.
+    pub fn add_option(mut self, option: CommandDataOption) -> Self {
+        self.data.options.push(option);
+        self
+    }
+
+    /// Sets the options passed to the command, replacing the current list.
+    pub fn options(mut self, options: Vec<CommandDataOption>) -> Self {
+        self.data.options = options;
+        self
+    }
+
+    /// Sets a nonce, which can be used to correlate the interaction with the resulting events.
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateInteraction {
+    type Context<'ctx> = ();
+    type Built = ();
+
+    /// Invokes the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the command can't be used in the channel or the options are
+    /// invalid.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        _ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().create_interaction(&self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn payload() {
+        let command: Command = from_value(json!({
+            "id": "1",
+            "type": 1,
+            "application_id": "2",
+            "name": "ping",
+            "description": "",
+            "version": "3",
+        }))
+        .unwrap();
+
+        let option = CommandDataOption::new("text", CommandDataOptionValue::String("hi".into()));
+        let builder = CreateInteraction::new(&command, ChannelId::new(4), "session")
+            .guild_id(GuildId::new(5))
+            .add_option(option);
+
+        assert_eq!(
+            to_value(&builder).unwrap(),
+            json!({
+                "type": 2,
+                "application_id": "2",
+                "guild_id": "5",
+                "channel_id": "4",
+                "session_id": "session",
+                "data": {
+                    "version": "3",
+                    "id": "1",
+                    "name": "ping",
+                    "type": 1,
+                    "options": [{"name": "text", "type": 3, "value": "hi"}],
+                    "attachments": [],
+                },
+            })
+        );
+    }
+}
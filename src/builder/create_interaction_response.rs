@@ -64,6 +64,12 @@ pub enum CreateInteractionResponse {
     ///
     /// Corresponds to Discord's `PREMIUM_REQUIRED'.
     PremiumRequired,
+    /// Only valid for command and component interactions of applications with Activities enabled.
+    ///
+    /// Launches the application's Activity for the invoking user.
+    ///
+    /// Corresponds to Discord's `LAUNCH_ACTIVITY`.
+    LaunchActivity,
 }
 
 impl serde::Serialize for CreateInteractionResponse {
@@ -81,6 +87,7 @@ impl serde::Serialize for CreateInteractionResponse {
                 Self::Autocomplete(_) => 8,
                 Self::Modal(_) => 9,
                 Self::PremiumRequired => 10,
+                Self::LaunchActivity => 12,
             },
             "data": match self {
                 Self::Pong => json::NULL,
@@ -90,7 +97,7 @@ impl serde::Serialize for CreateInteractionResponse {
                 Self::UpdateMessage(x) => json::to_value(x).map_err(S::Error::custom)?,
                 Self::Autocomplete(x) => json::to_value(x).map_err(S::Error::custom)?,
                 Self::Modal(x) => json::to_value(x).map_err(S::Error::custom)?,
-                Self::PremiumRequired => json::NULL,
+                Self::PremiumRequired | Self::LaunchActivity => json::NULL,
             }
         })
         .serialize(serializer)
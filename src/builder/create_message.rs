@@ -5,6 +5,7 @@ use super::{
     CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     CreatePoll,
     EditAttachments,
@@ -65,7 +66,7 @@ pub struct CreateMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Vec<CreateActionRow>>,
+    components: Option<Vec<CreateComponent>>,
     sticker_ids: Vec<StickerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<MessageFlags>,
@@ -103,6 +104,43 @@ impl CreateMessage {
         Ok(())
     }
 
+    #[cfg(feature = "http")]
+    fn check_voice_message(&self) -> Result<()> {
+        if !self.flags.is_some_and(|f| f.contains(MessageFlags::IS_VOICE_MESSAGE)) {
+            return Ok(());
+        }
+
+        let (new, existing) = self.attachments.new_and_existing();
+        let valid = matches!(new.as_slice(), [attachment] if attachment.is_voice_message())
+            && existing == 0
+            && self.content.as_ref().map_or(true, String::is_empty)
+            && self.embeds.is_empty()
+            && self.sticker_ids.is_empty()
+            && self.poll.is_none();
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::InvalidVoiceMessage))
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn check_components_v2(&self) -> StdResult<(), ModelError> {
+        if !self.flags.is_some_and(|f| f.contains(MessageFlags::IS_COMPONENTS_V2)) {
+            return Ok(());
+        }
+
+        let valid = self.content.as_ref().map_or(true, String::is_empty)
+            && self.embeds.is_empty()
+            && self.sticker_ids.is_empty()
+            && self.poll.is_none();
+        if valid {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidComponentsV2)
+        }
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -216,12 +254,29 @@ impl CreateMessage {
 
     /// Sets the components of this message.
     pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
-        self.components = Some(components);
+        self.components = Some(components.into_iter().map(Into::into).collect());
         self
     }
     super::button_and_select_menu_convenience_methods!(self.components);
 
+    /// Sets the layout components of this message, such as containers, sections and text
+    /// displays, and sets the [`MessageFlags::IS_COMPONENTS_V2`] flag.
+    ///
+    /// **Note**: Messages with layout components can't have content, embeds, stickers or a poll.
+    /// Use [`CreateTextDisplay`] for text instead.
+    ///
+    /// [`CreateTextDisplay`]: super::CreateTextDisplay
+    pub fn components_v2(mut self, components: Vec<CreateComponent>) -> Self {
+        self.components = Some(components);
+        self.flags =
+            Some(self.flags.unwrap_or_else(MessageFlags::empty) | MessageFlags::IS_COMPONENTS_V2);
+        self
+    }
+
     /// Sets the flags for the message.
+    ///
+    /// **Note**: This replaces the [`MessageFlags::IS_COMPONENTS_V2`] flag set by
+    /// [`Self::components_v2`], so call it first.
     pub fn flags(mut self, flags: MessageFlags) -> Self {
         self.flags = Some(flags);
         self
@@ -316,7 +371,11 @@ impl Builder for CreateMessage {
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::MessageTooLong`] if the message contents are over the above limits.
+    /// Returns a [`ModelError::MessageTooLong`] if the message contents are over the above limits,
+    /// or a [`ModelError::InvalidVoiceMessage`] if [`MessageFlags::IS_VOICE_MESSAGE`] is set but
+    /// the message isn't a valid voice message. Returns a [`ModelError::InvalidComponentsV2`] if
+    /// [`MessageFlags::IS_COMPONENTS_V2`] is set and the message has content, embeds, stickers or
+    /// a poll.
     ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
     /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
@@ -340,6 +399,8 @@ impl Builder for CreateMessage {
         }
 
         self.check_length()?;
+        self.check_voice_message()?;
+        self.check_components_v2().map_err(Error::Model)?;
 
         let http = cache_http.http();
 
@@ -364,3 +425,52 @@ impl Builder for CreateMessage {
         Ok(message)
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+    use crate::builder::CreateTextDisplay;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn voice_message() {
+        let voice = CreateAttachment::voice_message(vec![0; 4], 1.5, &[0, 128, 255]);
+        let builder =
+            CreateMessage::new().flags(MessageFlags::IS_VOICE_MESSAGE).add_file(voice.clone());
+        assert!(builder.check_voice_message().is_ok());
+        assert_eq!(
+            to_value(&builder.attachments).unwrap(),
+            json!([{
+                "id": 0,
+                "filename": "voice-message.ogg",
+                "description": null,
+                "duration_secs": 1.5,
+                "waveform": "AID/",
+            }]),
+        );
+
+        let builder = builder.content("hello");
+        assert!(builder.check_voice_message().is_err());
+
+        let builder = CreateMessage::new()
+            .flags(MessageFlags::IS_VOICE_MESSAGE)
+            .add_file(CreateAttachment::bytes(vec![0; 4], "audio.ogg"));
+        assert!(builder.check_voice_message().is_err());
+    }
+
+    #[test]
+    fn components_v2() {
+        let text = CreateTextDisplay::new("hello");
+        let builder = CreateMessage::new()
+            .flags(MessageFlags::SUPPRESS_NOTIFICATIONS)
+            .components_v2(vec![text.into()]);
+        assert!(builder.check_components_v2().is_ok());
+        assert_eq!(
+            to_value(&builder).unwrap()["flags"],
+            json!((MessageFlags::SUPPRESS_NOTIFICATIONS | MessageFlags::IS_COMPONENTS_V2).bits()),
+        );
+
+        let builder = builder.content("hello");
+        assert!(matches!(builder.check_components_v2(), Err(ModelError::InvalidComponentsV2)));
+    }
+}
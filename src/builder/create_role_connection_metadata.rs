@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::model::prelude::*;
+
+/// A builder for a role connection metadata record of an application, to be registered with
+/// [`ApplicationRoleConnectionMetadata::set_records`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object).
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateRoleConnectionMetadata {
+    #[serde(rename = "type")]
+    kind: ApplicationRoleConnectionMetadataType,
+    key: String,
+    name: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    name_localizations: HashMap<String, String>,
+    description: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    description_localizations: HashMap<String, String>,
+}
+
+impl CreateRoleConnectionMetadata {
+    /// Creates a builder with the given type, key, name and description.
+    ///
+    /// **Note**: The key must be between 1 and 50 characters long, and only consist of `a-z`,
+    /// `0-9` or `_` characters. The name must be between 1 and 100 characters long, and the
+    /// description between 1 and 200 characters long.
+    pub fn new(
+        kind: ApplicationRoleConnectionMetadataType,
+        key: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            key: key.into(),
+            name: name.into(),
+            name_localizations: HashMap::new(),
+            description: description.into(),
+            description_localizations: HashMap::new(),
+        }
+    }
+
+    /// Specifies a localized name of the metadata record.
+    pub fn name_localized(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations.insert(locale.into(), name.into());
+        self
+    }
+
+    /// Specifies a localized description of the metadata record.
+    pub fn description_localized(
+        mut self,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.description_localizations.insert(locale.into(), description.into());
+        self
+    }
+}
@@ -25,6 +25,8 @@ pub struct CreateScheduledEvent<'a> {
     entity_type: ScheduledEventType,
     #[serde(skip_serializing_if = "Option::is_none")]
     image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<ScheduledEventRecurrenceRule>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -48,6 +50,7 @@ impl<'a> CreateScheduledEvent<'a> {
             description: None,
             entity_metadata: None,
             scheduled_end_time: None,
+            recurrence_rule: None,
 
             // Set the privacy level to `GUILD_ONLY`. As this is the only possible value of this
             // field, it's onlyu used at event creation, and we don't even parse it into the
@@ -115,6 +118,14 @@ impl<'a> CreateScheduledEvent<'a> {
         self
     }
 
+    /// Makes the scheduled event recur according to the given rule.
+    ///
+    /// **Note**: Discord only supports some rules, see [`ScheduledEventRecurrenceRule`].
+    pub fn recurrence_rule(mut self, rule: ScheduledEventRecurrenceRule) -> Self {
+        self.recurrence_rule = Some(rule.into_request());
+        self
+    }
+
     /// Sets the request's audit log reason.
     pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
         self.audit_log_reason = Some(reason);
@@ -135,7 +146,9 @@ impl<'a> Builder for CreateScheduledEvent<'a> {
     /// # Errors
     ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
-    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    /// lacks permission. Returns a [`ModelError::InvalidRecurrenceRule`] if the recurrence rule
+    /// isn't supported by Discord. Otherwise returns [`Error::Http`], as well as if invalid data is
+    /// given.
     ///
     /// [Create Events]: Permissions::CREATE_EVENTS
     async fn execute(
@@ -146,6 +159,10 @@ impl<'a> Builder for CreateScheduledEvent<'a> {
         #[cfg(feature = "cache")]
         crate::utils::user_has_guild_perms(&cache_http, ctx, Permissions::CREATE_EVENTS)?;
 
+        if let Some(rule) = &self.recurrence_rule {
+            rule.check().map_err(Error::Model)?;
+        }
+
         cache_http.http().create_scheduled_event(ctx, &self, self.audit_log_reason).await
     }
 }
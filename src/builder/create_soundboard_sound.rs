@@ -0,0 +1,147 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::CreateAttachment;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to create a soundboard sound in a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#create-guild-soundboard-sound)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateSoundboardSound<'a> {
+    name: String,
+    sound: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<EmojiId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<String>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> CreateSoundboardSound<'a> {
+    /// Creates a new builder with the given name and audio file. Both are required.
+    ///
+    /// **Note**: The name must be between 2 and 32 characters long. The sound must be an MP3 or
+    /// OGG file of at most 512 KB and 5.2 seconds; files ending in `.ogg` are sent as OGG, all
+    /// others as MP3.
+    pub fn new(name: impl Into<String>, sound: &CreateAttachment) -> Self {
+        Self {
+            name: name.into(),
+            sound: sound_data_uri(sound),
+            volume: None,
+            emoji_id: None,
+            emoji_name: None,
+            audit_log_reason: None,
+        }
+    }
+
+    /// Sets the name of the sound, replacing the current value as set in [`Self::new`].
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the audio file of the sound, replacing the current value as set in [`Self::new`].
+    pub fn sound(mut self, sound: &CreateAttachment) -> Self {
+        self.sound = sound_data_uri(sound);
+        self
+    }
+
+    /// Sets the volume of the sound, from 0 to 1. Defaults to 1.
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Sets the emoji shown for the sound.
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        match emoji.into() {
+            ReactionType::Custom {
+                id, ..
+            } => {
+                self.emoji_id = Some(id);
+                self.emoji_name = None;
+            },
+            ReactionType::Unicode(name) => {
+                self.emoji_id = None;
+                self.emoji_name = Some(name);
+            },
+        }
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for CreateSoundboardSound<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = SoundboardSound;
+
+    /// Creates a new soundboard sound in the guild with the data set, if any.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        #[cfg(feature = "cache")]
+        crate::utils::user_has_guild_perms(
+            &cache_http,
+            ctx,
+            Permissions::CREATE_GUILD_EXPRESSIONS,
+        )?;
+
+        cache_http.http().create_guild_soundboard_sound(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// Discord expects the audio as a data URI, and only accepts MP3 and OGG files.
+fn sound_data_uri(sound: &CreateAttachment) -> String {
+    use base64::Engine;
+
+    let mime = if sound.filename.to_ascii_lowercase().ends_with(".ogg") {
+        "audio/ogg"
+    } else {
+        "audio/mpeg"
+    };
+    let encoded = base64::prelude::BASE64_STANDARD.encode(&sound.data);
+    format!("data:{mime};base64,{encoded}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sound_mime_type() {
+        let ogg = CreateAttachment::bytes(vec![1, 2, 3], "quack.OGG");
+        assert_eq!(sound_data_uri(&ogg), "data:audio/ogg;base64,AQID");
+
+        let mp3 = CreateAttachment::bytes(vec![1, 2, 3], "quack.mp3");
+        assert_eq!(sound_data_uri(&mp3), "data:audio/mpeg;base64,AQID");
+    }
+}
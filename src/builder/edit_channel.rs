@@ -255,13 +255,25 @@ impl<'a> EditChannel<'a> {
         self
     }
 
-    /// Channel flags combined as a bitfield. Currently only [`ChannelFlags::REQUIRE_TAG`] is
-    /// supported.
+    /// Channel flags combined as a bitfield. Only [`ChannelFlags::REQUIRE_TAG`] and
+    /// [`ChannelFlags::HIDE_MEDIA_DOWNLOAD_OPTIONS`] are supported.
     pub fn flags(mut self, flags: ChannelFlags) -> Self {
         self.flags = Some(flags);
         self
     }
 
+    /// Whether a tag must be applied to new posts in a forum or media channel.
+    ///
+    /// As Discord replaces all flags of the channel at once, this keeps the other flags of
+    /// `current_flags`, i.e. [`GuildChannel::flags`], or of the flags set by [`Self::flags`] if
+    /// called before.
+    pub fn require_tag(mut self, current_flags: ChannelFlags, require_tag: bool) -> Self {
+        let mut flags = self.flags.unwrap_or(current_flags);
+        flags.set(ChannelFlags::REQUIRE_TAG, require_tag);
+        self.flags = Some(flags);
+        self
+    }
+
     /// The emoji to show in the add reaction button on a thread in a forum channel
     pub fn default_reaction_emoji(mut self, default_reaction_emoji: Option<ForumEmoji>) -> Self {
         self.default_reaction_emoji = Some(default_reaction_emoji);
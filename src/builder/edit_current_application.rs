@@ -0,0 +1,168 @@
+#[cfg(feature = "http")]
+use super::Builder;
+use super::CreateAttachment;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the current application.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application#edit-current-application)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditCurrentApplication {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cover_image: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interactions_endpoint_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role_connections_verification_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_install_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    install_params: Option<InstallParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<ApplicationFlags>,
+}
+
+impl EditCurrentApplication {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the description of the application.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the tags describing the content and functionality of the application.
+    ///
+    /// **Note**: At most 5 tags of up to 20 characters each are allowed.
+    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the icon of the application.
+    pub fn icon(mut self, icon: &CreateAttachment) -> Self {
+        self.icon = Some(Some(icon.to_base64()));
+        self
+    }
+
+    /// Deletes the icon of the application.
+    pub fn delete_icon(mut self) -> Self {
+        self.icon = Some(None);
+        self
+    }
+
+    /// Sets the default rich presence invite cover image of the application.
+    pub fn cover_image(mut self, cover_image: &CreateAttachment) -> Self {
+        self.cover_image = Some(Some(cover_image.to_base64()));
+        self
+    }
+
+    /// Deletes the default rich presence invite cover image of the application.
+    pub fn delete_cover_image(mut self) -> Self {
+        self.cover_image = Some(None);
+        self
+    }
+
+    /// Sets the URL which interactions are sent to instead of the gateway.
+    ///
+    /// **Note**: Discord validates the URL by sending it a ping interaction before saving it.
+    pub fn interactions_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.interactions_endpoint_url = Some(url.into());
+        self
+    }
+
+    /// Sets the URL users are sent to in order to verify their role connection.
+    pub fn role_connections_verification_url(mut self, url: impl Into<String>) -> Self {
+        self.role_connections_verification_url = Some(url.into());
+        self
+    }
+
+    /// Sets the custom authorization URL of the application, replacing the in-app link built
+    /// from [`Self::install_params`].
+    pub fn custom_install_url(mut self, url: impl Into<String>) -> Self {
+        self.custom_install_url = Some(url.into());
+        self
+    }
+
+    /// Sets the scopes and permissions of the application's default in-app authorization link.
+    pub fn install_params(mut self, scopes: Vec<Scope>, permissions: Permissions) -> Self {
+        self.install_params = Some(InstallParams {
+            scopes,
+            permissions,
+        });
+        self
+    }
+
+    /// Sets the flags of the application.
+    ///
+    /// **Note**: Only the [`GATEWAY_PRESENCE_LIMITED`], [`GATEWAY_GUILD_MEMBERS_LIMITED`] and
+    /// [`GATEWAY_MESSAGE_CONTENT_LIMITED`] flags can be changed.
+    ///
+    /// [`GATEWAY_PRESENCE_LIMITED`]: ApplicationFlags::GATEWAY_PRESENCE_LIMITED
+    /// [`GATEWAY_GUILD_MEMBERS_LIMITED`]: ApplicationFlags::GATEWAY_GUILD_MEMBERS_LIMITED
+    /// [`GATEWAY_MESSAGE_CONTENT_LIMITED`]: ApplicationFlags::GATEWAY_MESSAGE_CONTENT_LIMITED
+    pub fn flags(mut self, flags: ApplicationFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditCurrentApplication {
+    type Context<'ctx> = ();
+    type Built = CurrentApplicationInfo;
+
+    /// Edits the current application with the fields set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if invalid data is given, or if the current user is not an
+    /// application.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        _ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_current_application(&self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn edit_current_application() {
+        let builder = EditCurrentApplication::new()
+            .description("A bot")
+            .tags(["utility"])
+            .delete_icon()
+            .install_params(vec![Scope::Bot], Permissions::SEND_MESSAGES);
+
+        assert_eq!(
+            to_value(&builder).unwrap(),
+            json!({
+                "description": "A bot",
+                "tags": ["utility"],
+                "icon": null,
+                "install_params": {"scopes": ["bot"], "permissions": "2048"},
+            }),
+        );
+    }
+}
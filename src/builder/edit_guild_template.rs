@@ -0,0 +1,73 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::prelude::*;
+
+/// A builder to edit the name or description of a [`GuildTemplate`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#modify-guild-template)
+///
+/// [`GuildTemplate`]: crate::model::guild::GuildTemplate
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditGuildTemplate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+}
+
+impl EditGuildTemplate {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the template.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the template. Pass [`None`] to remove it.
+    ///
+    /// **Note**: Must be at most 120 characters long.
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditGuildTemplate {
+    type Context<'ctx> = (GuildId, &'ctx str);
+    type Built = GuildTemplate;
+
+    /// Edits the template with the given code of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        #[cfg(feature = "cache")]
+        crate::utils::user_has_guild_perms(&cache_http, ctx.0, Permissions::MANAGE_GUILD)?;
+
+        cache_http.http().edit_guild_template(ctx.0, ctx.1, &self).await
+    }
+}
@@ -41,12 +41,17 @@ impl<'a> EditGuildWelcomeScreen<'a> {
         self
     }
 
+    /// Adds a channel linked in the welcome screen.
+    ///
+    /// **Note**: There can only be up to 5 channels.
     pub fn add_welcome_channel(mut self, channel: CreateGuildWelcomeChannel) -> Self {
         self.welcome_channels.push(channel);
         self
     }
 
     /// Channels linked in the welcome screen and their display options
+    ///
+    /// **Note**: There can only be up to 5 channels.
     pub fn set_welcome_channels(mut self, channels: Vec<CreateGuildWelcomeChannel>) -> Self {
         self.welcome_channels = channels;
         self
@@ -91,10 +96,11 @@ impl<'a> Builder for EditGuildWelcomeScreen<'a> {
 pub struct CreateGuildWelcomeChannel(GuildWelcomeChannel);
 
 impl CreateGuildWelcomeChannel {
-    pub fn new(channel_id: ChannelId, description: String) -> Self {
+    /// Creates a builder for the given channel and description, without an emoji.
+    pub fn new(channel_id: impl Into<ChannelId>, description: impl Into<String>) -> Self {
         Self(GuildWelcomeChannel {
-            channel_id,
-            description,
+            channel_id: channel_id.into(),
+            description: description.into(),
             emoji: None,
         })
     }
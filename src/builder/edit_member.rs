@@ -136,11 +136,33 @@ impl<'a> EditMember<'a> {
         self
     }
 
+    /// Sets the flags of the member.
+    ///
+    /// **Note**: Only [`GuildMemberFlags::BYPASSES_VERIFICATION`] can be edited, all other flags
+    /// are ignored. Requires the [Manage Guild] or [Manage Roles] permission, or all of the
+    /// [Moderate Members], [Kick Members] and [Ban Members] permissions.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    /// [Moderate Members]: Permissions::MODERATE_MEMBERS
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Ban Members]: Permissions::BAN_MEMBERS
     pub fn flags(mut self, flags: GuildMemberFlags) -> Self {
         self.flags = Some(flags);
         self
     }
 
+    /// Whether the member is exempt from the verification requirements of the guild. Replaces the
+    /// value set by [`Self::flags`].
+    ///
+    /// **Note**: Requires the same permissions as [`Self::flags`].
+    pub fn bypasses_verification(mut self, bypasses_verification: bool) -> Self {
+        let mut flags = self.flags.unwrap_or_default();
+        flags.set(GuildMemberFlags::BYPASSES_VERIFICATION, bypasses_verification);
+        self.flags = Some(flags);
+        self
+    }
+
     /// Sets the request's audit log reason.
     pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
         self.audit_log_reason = Some(reason);
@@ -4,6 +4,7 @@ use super::{
     CreateActionRow,
     CreateAllowedMentions,
     CreateAttachment,
+    CreateComponent,
     CreateEmbed,
     EditAttachments,
 };
@@ -47,7 +48,7 @@ pub struct EditMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<CreateAllowedMentions>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    components: Option<Vec<CreateActionRow>>,
+    components: Option<Vec<CreateComponent>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     attachments: Option<EditAttachments>,
 }
@@ -76,6 +77,21 @@ impl EditMessage {
         Ok(())
     }
 
+    #[cfg(feature = "http")]
+    fn check_components_v2(&self) -> StdResult<(), ModelError> {
+        if !self.flags.is_some_and(|f| f.contains(MessageFlags::IS_COMPONENTS_V2)) {
+            return Ok(());
+        }
+
+        let valid = self.content.as_ref().map_or(true, String::is_empty)
+            && self.embeds.as_ref().map_or(true, Vec::is_empty);
+        if valid {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidComponentsV2)
+        }
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -170,12 +186,27 @@ impl EditMessage {
 
     /// Sets the components of this message.
     pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
-        self.components = Some(components);
+        self.components = Some(components.into_iter().map(Into::into).collect());
         self
     }
     super::button_and_select_menu_convenience_methods!(self.components);
 
+    /// Sets the layout components of this message, such as containers, sections and text
+    /// displays, and sets the [`MessageFlags::IS_COMPONENTS_V2`] flag.
+    ///
+    /// **Note**: The flag can't be removed once set. Messages with layout components can't have
+    /// content or embeds, so editing a message to use them requires clearing both.
+    pub fn components_v2(mut self, components: Vec<CreateComponent>) -> Self {
+        self.components = Some(components);
+        self.flags =
+            Some(self.flags.unwrap_or_else(MessageFlags::empty) | MessageFlags::IS_COMPONENTS_V2);
+        self
+    }
+
     /// Sets the flags for the message.
+    ///
+    /// **Note**: This replaces the [`MessageFlags::IS_COMPONENTS_V2`] flag set by
+    /// [`Self::components_v2`], so call it first.
     pub fn flags(mut self, flags: MessageFlags) -> Self {
         self.flags = Some(flags);
         self
@@ -241,7 +272,9 @@ impl Builder for EditMessage {
     ///
     /// # Errors
     ///
-    /// Returns a [`ModelError::MessageTooLong`] if the message contents are over the above limits.
+    /// Returns a [`ModelError::MessageTooLong`] if the message contents are over the above limits,
+    /// or a [`ModelError::InvalidComponentsV2`] if [`MessageFlags::IS_COMPONENTS_V2`] is set and
+    /// the message has content or embeds.
     ///
     /// Returns [`Error::Http`] if the user lacks permission, as well as if invalid data is given.
     ///
@@ -253,6 +286,7 @@ impl Builder for EditMessage {
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
         self.check_length()?;
+        self.check_components_v2().map_err(Error::Model)?;
 
         #[cfg(feature = "cache")]
         if let Some(user_id) = ctx.2 {
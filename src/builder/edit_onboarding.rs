@@ -0,0 +1,347 @@
+#[cfg(feature = "http")]
+use std::collections::HashSet;
+
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// The minimum number of default channels required to enable onboarding.
+#[cfg(feature = "http")]
+const MIN_DEFAULT_CHANNELS: usize = 7;
+
+/// A builder to edit the onboarding of a guild.
+///
+/// The onboarding is replaced as a whole, so all prompts to keep must be given again.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#modify-guild-onboarding)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditOnboarding<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompts: Option<Vec<CreateOnboardingPrompt>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_channel_ids: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<OnboardingMode>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditOnboarding<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prompts shown during onboarding and in the customize community tab.
+    pub fn prompts(mut self, prompts: Vec<CreateOnboardingPrompt>) -> Self {
+        self.prompts = Some(prompts);
+        self
+    }
+
+    /// Adds a prompt shown during onboarding and in the customize community tab.
+    pub fn add_prompt(mut self, prompt: CreateOnboardingPrompt) -> Self {
+        self.prompts.get_or_insert_with(Vec::new).push(prompt);
+        self
+    }
+
+    /// Sets the channels which members get opted into automatically.
+    ///
+    /// **Note**: At least 7 channels are required to enable onboarding. See [`Self::mode`] for
+    /// which channels are counted.
+    pub fn default_channels(
+        mut self,
+        channels: impl IntoIterator<Item = impl Into<ChannelId>>,
+    ) -> Self {
+        self.default_channel_ids = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether onboarding is enabled in the guild.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the criteria used to check if onboarding is set up correctly.
+    ///
+    /// With [`OnboardingMode::Default`], only the default channels are counted. With
+    /// [`OnboardingMode::Advanced`], the channels of prompt options are counted as well.
+    pub fn mode(mut self, mode: OnboardingMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+
+    #[cfg(feature = "http")]
+    fn check_default_channels(&self) -> StdResult<(), ModelError> {
+        let Some(default_channel_ids) = &self.default_channel_ids else {
+            return Ok(());
+        };
+        if self.enabled != Some(true) {
+            return Ok(());
+        }
+
+        let mut channels: HashSet<ChannelId> = default_channel_ids.iter().copied().collect();
+        if self.mode == Some(OnboardingMode::Advanced) {
+            let prompts = self.prompts.iter().flatten();
+            let options = prompts.flat_map(|prompt| &prompt.options);
+            channels.extend(options.flat_map(|option| option.channel_ids.iter().copied()));
+        }
+
+        if channels.len() < MIN_DEFAULT_CHANNELS {
+            return Err(ModelError::OnboardingDefaultChannelAmount(channels.len()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditOnboarding<'_> {
+    type Context<'ctx> = GuildId;
+    type Built = Onboarding;
+
+    /// Edits the guild's onboarding.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::OnboardingDefaultChannelAmount`] if onboarding is enabled with
+    /// fewer than 7 default channels. Otherwise returns [`Error::Http`] if the current user lacks
+    /// permission, or if invalid data is given.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        self.check_default_channels().map_err(Error::Model)?;
+
+        cache_http.http().edit_guild_onboarding(ctx, &self, self.audit_log_reason).await
+    }
+}
+
+/// A builder for an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPrompt {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<OnboardingPromptId>,
+    #[serde(rename = "type")]
+    kind: OnboardingPromptType,
+    options: Vec<CreateOnboardingPromptOption>,
+    title: String,
+    single_select: bool,
+    required: bool,
+    in_onboarding: bool,
+}
+
+impl CreateOnboardingPrompt {
+    /// Creates a builder for a new prompt with the given type and title, shown during onboarding.
+    pub fn new(kind: OnboardingPromptType, title: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            kind,
+            options: Vec::new(),
+            title: title.into(),
+            single_select: false,
+            required: false,
+            in_onboarding: true,
+        }
+    }
+
+    /// Sets the Id of an existing prompt, so that it is updated rather than replaced.
+    pub fn id(mut self, id: OnboardingPromptId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the options which can be chosen.
+    pub fn options(mut self, options: Vec<CreateOnboardingPromptOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Adds an option which can be chosen.
+    pub fn add_option(mut self, option: CreateOnboardingPromptOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Whether members are limited to choosing one option.
+    pub fn single_select(mut self, single_select: bool) -> Self {
+        self.single_select = single_select;
+        self
+    }
+
+    /// Whether the prompt must be answered to complete onboarding.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Whether the prompt is shown during onboarding, as opposed to only in the customize
+    /// community tab.
+    pub fn in_onboarding(mut self, in_onboarding: bool) -> Self {
+        self.in_onboarding = in_onboarding;
+        self
+    }
+}
+
+impl From<OnboardingPrompt> for CreateOnboardingPrompt {
+    fn from(prompt: OnboardingPrompt) -> Self {
+        Self {
+            id: Some(prompt.id),
+            kind: prompt.kind,
+            options: prompt.options.into_iter().map(Into::into).collect(),
+            title: prompt.title,
+            single_select: prompt.single_select,
+            required: prompt.required,
+            in_onboarding: prompt.in_onboarding,
+        }
+    }
+}
+
+/// A builder for an [`OnboardingPromptOption`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure)
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct CreateOnboardingPromptOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<OnboardingPromptOptionId>,
+    channel_ids: Vec<ChannelId>,
+    role_ids: Vec<RoleId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<EmojiId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_animated: Option<bool>,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl CreateOnboardingPromptOption {
+    /// Creates a builder for a new option with the given title.
+    ///
+    /// **Note**: An option must add the member to at least one channel or role.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            channel_ids: Vec::new(),
+            role_ids: Vec::new(),
+            emoji_id: None,
+            emoji_name: None,
+            emoji_animated: None,
+            title: title.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the Id of an existing option, so that it is updated rather than replaced.
+    pub fn id(mut self, id: OnboardingPromptOptionId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the channels a member is added to when choosing the option.
+    pub fn channels(mut self, channels: impl IntoIterator<Item = impl Into<ChannelId>>) -> Self {
+        self.channel_ids = channels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the roles a member is given when choosing the option.
+    pub fn roles(mut self, roles: impl IntoIterator<Item = impl Into<RoleId>>) -> Self {
+        self.role_ids = roles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the emoji shown next to the option.
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        match emoji.into() {
+            ReactionType::Custom {
+                animated,
+                id,
+                name,
+            } => {
+                self.emoji_id = Some(id);
+                self.emoji_name = name;
+                self.emoji_animated = Some(animated);
+            },
+            ReactionType::Unicode(name) => {
+                self.emoji_id = None;
+                self.emoji_name = Some(name);
+                self.emoji_animated = None;
+            },
+        }
+        self
+    }
+
+    /// Sets the description of the option.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl From<OnboardingPromptOption> for CreateOnboardingPromptOption {
+    fn from(option: OnboardingPromptOption) -> Self {
+        let builder = Self {
+            id: Some(option.id),
+            channel_ids: option.channel_ids,
+            role_ids: option.role_ids,
+            emoji_id: None,
+            emoji_name: None,
+            emoji_animated: None,
+            title: option.title,
+            description: option.description,
+        };
+        match option.emoji {
+            Some(emoji) => builder.emoji(emoji),
+            None => builder,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_channel_amount() {
+        let builder = EditOnboarding::new().enabled(true).default_channels([1_u64, 2, 3, 4, 5, 6]);
+        assert!(matches!(
+            builder.check_default_channels(),
+            Err(ModelError::OnboardingDefaultChannelAmount(6))
+        ));
+
+        let prompt = CreateOnboardingPrompt::new(OnboardingPromptType::MultipleChoice, "Topics")
+            .add_option(CreateOnboardingPromptOption::new("Rust").channels([6_u64, 7]));
+        let builder = builder.add_prompt(prompt);
+        assert!(builder.check_default_channels().is_err());
+        assert!(builder.mode(OnboardingMode::Advanced).check_default_channels().is_ok());
+
+        let builder = EditOnboarding::new().enabled(false).default_channels([1_u64]);
+        assert!(builder.check_default_channels().is_ok());
+    }
+}
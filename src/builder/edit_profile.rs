@@ -6,7 +6,8 @@ use crate::http::CacheHttp;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
 #[cfg(feature = "http")]
-use crate::model::user::CurrentUser;
+use crate::json::json;
+use crate::model::prelude::*;
 
 /// A builder to edit the current user's settings, to be used in conjunction with
 /// [`CurrentUser::edit`].
@@ -18,9 +19,25 @@ pub struct EditProfile {
     #[serde(skip_serializing_if = "Option::is_none")]
     username: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_name: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     avatar: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    avatar_decoration_id: Option<Option<SkuId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar_decoration_sku_id: Option<Option<SkuId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     banner: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "accent_color")]
+    accent_colour: Option<Option<Colour>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bio: Option<String>,
+    // Only accepted by the profile endpoint.
+    #[serde(skip)]
+    pronouns: Option<String>,
 }
 
 impl EditProfile {
@@ -62,11 +79,27 @@ impl EditProfile {
     /// When modifying the username, if another user has the same _new_ username and current
     /// discriminator, a new unique discriminator will be assigned. If there are no available
     /// discriminators with the requested username, an error will occur.
+    ///
+    /// **Note**: For user accounts, the current password must be provided via [`Self::password`].
     pub fn username(mut self, username: impl Into<String>) -> Self {
         self.username = Some(username.into());
         self
     }
 
+    /// Sets the current password of the account, which user accounts must provide to change their
+    /// username.
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Sets the display name of the current user, shown instead of the username. Pass [`None`]
+    /// to remove it.
+    pub fn global_name(mut self, global_name: Option<String>) -> Self {
+        self.global_name = Some(global_name);
+        self
+    }
+
     /// Sets the banner of the current user.
     pub fn banner(mut self, banner: &CreateAttachment) -> Self {
         self.banner = Some(Some(banner.to_base64()));
@@ -78,6 +111,44 @@ impl EditProfile {
         self.banner = Some(None);
         self
     }
+
+    /// Sets the avatar decoration of the current user, given the Id of the decoration and of the
+    /// SKU it was obtained from.
+    ///
+    /// **Note**: Only works for user accounts owning the decoration.
+    pub fn avatar_decoration(mut self, decoration_id: SkuId, sku_id: SkuId) -> Self {
+        self.avatar_decoration_id = Some(Some(decoration_id));
+        self.avatar_decoration_sku_id = Some(Some(sku_id));
+        self
+    }
+
+    /// Removes the current user's avatar decoration.
+    pub fn delete_avatar_decoration(mut self) -> Self {
+        self.avatar_decoration_id = Some(None);
+        self.avatar_decoration_sku_id = Some(None);
+        self
+    }
+
+    /// Sets the accent colour of the current user, shown as their banner if they don't have one.
+    /// Pass [`None`] to remove it.
+    pub fn accent_colour(mut self, accent_colour: Option<Colour>) -> Self {
+        self.accent_colour = Some(accent_colour);
+        self
+    }
+
+    /// Sets the "About Me" section of the current user. Pass an empty string to remove it.
+    pub fn bio(mut self, bio: impl Into<String>) -> Self {
+        self.bio = Some(bio.into());
+        self
+    }
+
+    /// Sets the pronouns of the current user. Pass an empty string to remove them.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub fn pronouns(mut self, pronouns: impl Into<String>) -> Self {
+        self.pronouns = Some(pronouns.into());
+        self
+    }
 }
 
 #[cfg(feature = "http")]
@@ -88,6 +159,8 @@ impl Builder for EditProfile {
 
     /// Edit the current user's profile with the fields set.
     ///
+    /// If pronouns are set, this performs a second request to the profile endpoint first.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if an invalid value is set. May also return an [`Error::Json`]
@@ -97,6 +170,41 @@ impl Builder for EditProfile {
         cache_http: impl CacheHttp,
         _ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
-        cache_http.http().edit_profile(&self).await
+        let http = cache_http.http();
+        if let Some(pronouns) = &self.pronouns {
+            http.edit_user_profile(&json!({
+                "pronouns": pronouns,
+            }))
+            .await?;
+        }
+
+        http.edit_profile(&self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn profile_fields() {
+        let builder = EditProfile::new()
+            .global_name(None)
+            .accent_colour(Some(Colour::RED))
+            .bio("hi")
+            .pronouns("they/them")
+            .delete_avatar_decoration();
+
+        assert_eq!(
+            to_value(&builder).unwrap(),
+            json!({
+                "global_name": null,
+                "avatar_decoration_id": null,
+                "avatar_decoration_sku_id": null,
+                "accent_color": 0xE74C3C,
+                "bio": "hi",
+            }),
+        );
     }
 }
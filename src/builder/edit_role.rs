@@ -129,14 +129,21 @@ impl<'a> EditRole<'a> {
         self
     }
 
-    /// Set the role icon to a unicode emoji.
+    /// Set the role icon to a unicode emoji, removing any custom icon image. Pass [`None`] to
+    /// remove the icon.
+    ///
+    /// **Note**: Requires the guild to have the `ROLE_ICONS` feature.
     pub fn unicode_emoji(mut self, unicode_emoji: Option<String>) -> Self {
         self.unicode_emoji = Some(unicode_emoji);
         self.icon = Some(None);
         self
     }
 
-    /// Set the role icon to a custom image.
+    /// Set the role icon to a custom image, removing any unicode emoji icon. Pass [`None`] to
+    /// remove the icon.
+    ///
+    /// **Note**: Requires the guild to have the `ROLE_ICONS` feature. The image must be a PNG,
+    /// JPEG or GIF of at most 256 KB.
     pub fn icon(mut self, icon: Option<&CreateAttachment>) -> Self {
         self.icon = Some(icon.map(CreateAttachment::to_base64));
         self.unicode_emoji = Some(None);
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(any(feature = "http", doc))]
+use crate::model::prelude::*;
+
+/// A builder to update the [`ApplicationRoleConnection`] of the current user.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#update-current-user-application-role-connection).
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditRoleConnection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl EditRoleConnection {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the vanity name of the platform the application connects to.
+    ///
+    /// **Note**: Must be at most 50 characters long.
+    pub fn platform_name(mut self, platform_name: impl Into<String>) -> Self {
+        self.platform_name = Some(platform_name.into());
+        self
+    }
+
+    /// Sets the username of the user on the platform.
+    ///
+    /// **Note**: Must be at most 100 characters long.
+    pub fn platform_username(mut self, platform_username: impl Into<String>) -> Self {
+        self.platform_username = Some(platform_username.into());
+        self
+    }
+
+    /// Sets the value of the user for a metadata key of the application. Integers and booleans
+    /// are given as strings, e.g. `"42"` or `"1"`, and dates as ISO8601 timestamps.
+    ///
+    /// **Note**: Once any value is set, the values of all keys not set are removed.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditRoleConnection {
+    type Context<'ctx> = ApplicationId;
+    type Built = ApplicationRoleConnection;
+
+    /// Updates the role connection of the current user for the given application.
+    ///
+    /// **Note**: Requires an OAuth2 access token with the [`Scope::RoleConnectionsWrite`] scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the token lacks the required scope, or if invalid data is given.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_user_application_role_connection(ctx, &self).await
+    }
+}
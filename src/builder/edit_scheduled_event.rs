@@ -31,6 +31,8 @@ pub struct EditScheduledEvent<'a> {
     status: Option<ScheduledEventStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Option<ScheduledEventRecurrenceRule>>,
 
     #[serde(skip)]
     audit_log_reason: Option<&'a str>,
@@ -159,6 +161,14 @@ impl<'a> EditScheduledEvent<'a> {
         self
     }
 
+    /// Sets the rule by which the scheduled event recurs. Pass [`None`] to stop it from recurring.
+    ///
+    /// **Note**: Discord only supports some rules, see [`ScheduledEventRecurrenceRule`].
+    pub fn recurrence_rule(mut self, rule: Option<ScheduledEventRecurrenceRule>) -> Self {
+        self.recurrence_rule = Some(rule.map(ScheduledEventRecurrenceRule::into_request));
+        self
+    }
+
     /// Sets the request's audit log reason.
     pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
         self.audit_log_reason = Some(reason);
@@ -180,7 +190,9 @@ impl<'a> Builder for EditScheduledEvent<'a> {
     /// # Errors
     ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
-    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    /// lacks permission. Returns a [`ModelError::InvalidRecurrenceRule`] if the recurrence rule
+    /// isn't supported by Discord. Otherwise returns [`Error::Http`], as well as if invalid data is
+    /// given.
     ///
     /// [Create Events]: Permissions::CREATE_EVENTS
     /// [Manage Events]: Permissions::MANAGE_EVENTS
@@ -189,6 +201,10 @@ impl<'a> Builder for EditScheduledEvent<'a> {
         cache_http: impl CacheHttp,
         ctx: Self::Context<'_>,
     ) -> Result<Self::Built> {
+        if let Some(Some(rule)) = &self.recurrence_rule {
+            rule.check().map_err(Error::Model)?;
+        }
+
         cache_http.http().edit_scheduled_event(ctx.0, ctx.1, &self, self.audit_log_reason).await
     }
 }
@@ -0,0 +1,97 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit a soundboard sound of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#modify-guild-soundboard-sound)
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditSoundboardSound<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<Option<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_id: Option<Option<EmojiId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji_name: Option<Option<String>>,
+
+    #[serde(skip)]
+    audit_log_reason: Option<&'a str>,
+}
+
+impl<'a> EditSoundboardSound<'a> {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the sound.
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the volume of the sound, from 0 to 1. Pass [`None`] to reset it to 1.
+    pub fn volume(mut self, volume: Option<f64>) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Sets the emoji shown for the sound. Pass [`None`] to remove it.
+    pub fn emoji(mut self, emoji: Option<ReactionType>) -> Self {
+        let (emoji_id, emoji_name) = match emoji {
+            Some(ReactionType::Custom {
+                id, ..
+            }) => (Some(id), None),
+            Some(ReactionType::Unicode(name)) => (None, Some(name)),
+            None => (None, None),
+        };
+        self.emoji_id = Some(emoji_id);
+        self.emoji_name = Some(emoji_name);
+        self
+    }
+
+    /// Sets the request's audit log reason.
+    pub fn audit_log_reason(mut self, reason: &'a str) -> Self {
+        self.audit_log_reason = Some(reason);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditSoundboardSound<'_> {
+    type Context<'ctx> = (GuildId, SoundId);
+    type Built = SoundboardSound;
+
+    /// Edits the soundboard sound.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http
+            .http()
+            .edit_guild_soundboard_sound(ctx.0, ctx.1, &self, self.audit_log_reason)
+            .await
+    }
+}
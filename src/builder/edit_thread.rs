@@ -89,6 +89,17 @@ impl<'a> EditThread<'a> {
         self
     }
 
+    /// Whether the forum post is pinned to the top of its forum channel. Replaces the value set by
+    /// [`Self::flags`].
+    ///
+    /// **Note**: Only one post can be pinned per forum channel.
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        let mut flags = self.flags.unwrap_or_default();
+        flags.set(ChannelFlags::PINNED, pinned);
+        self.flags = Some(flags);
+        self
+    }
+
     /// If this is a forum post, edits the assigned tags of this forum post.
     pub fn applied_tags(mut self, applied_tags: impl IntoIterator<Item = ForumTagId>) -> Self {
         self.applied_tags = Some(applied_tags.into_iter().collect());
@@ -0,0 +1,142 @@
+#[cfg(feature = "http")]
+use super::Builder;
+#[cfg(feature = "http")]
+use crate::http::CacheHttp;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A builder to edit the settings of the current user account. Only the settings which are set
+/// are changed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::builder::{Builder, EditUserSettings};
+/// # use serenity::http::Http;
+/// # use serenity::model::user::{CustomStatus, Theme};
+/// #
+/// # #[cfg(feature = "http")]
+/// # async fn run(http: &Http) -> Result<(), Box<dyn std::error::Error>> {
+/// let builder = EditUserSettings::new()
+///     .theme(Theme::Light)
+///     .custom_status(CustomStatus::new("Eating pizza").emoji('🍕'));
+/// let settings = builder.execute(http, ()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+#[must_use]
+pub struct EditUserSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme: Option<Theme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<OnlineStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_status: Option<Option<CustomStatus>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_guilds_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restricted_guilds: Option<Vec<GuildId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_positions: Option<Vec<GuildId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_folders: Option<Vec<GuildFolder>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    developer_mode: Option<bool>,
+}
+
+impl EditUserSettings {
+    /// Equivalent to [`Self::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the theme of the client.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the language of the client, e.g. `"en-US"`.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Sets the status shown to other users.
+    pub fn status(mut self, status: OnlineStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the custom status shown below the user's name.
+    pub fn custom_status(mut self, custom_status: CustomStatus) -> Self {
+        self.custom_status = Some(Some(custom_status));
+        self
+    }
+
+    /// Clears the custom status.
+    pub fn clear_custom_status(mut self) -> Self {
+        self.custom_status = Some(None);
+        self
+    }
+
+    /// Sets whether members of newly joined guilds are restricted from sending direct messages.
+    pub fn default_guilds_restricted(mut self, restricted: bool) -> Self {
+        self.default_guilds_restricted = Some(restricted);
+        self
+    }
+
+    /// Sets the guilds whose members are restricted from sending direct messages.
+    pub fn restricted_guilds(mut self, guild_ids: impl IntoIterator<Item = GuildId>) -> Self {
+        self.restricted_guilds = Some(guild_ids.into_iter().collect());
+        self
+    }
+
+    /// Sets the order of the guilds in the guild list.
+    pub fn guild_positions(mut self, guild_ids: impl IntoIterator<Item = GuildId>) -> Self {
+        self.guild_positions = Some(guild_ids.into_iter().collect());
+        self
+    }
+
+    /// Sets the folders of the guild list, in order, to reorganise it.
+    ///
+    /// Every guild of the user should be part of a folder, using [`GuildFolder::single`] for
+    /// guilds outside of a folder.
+    pub fn guild_folders(mut self, folders: impl IntoIterator<Item = GuildFolder>) -> Self {
+        self.guild_folders = Some(folders.into_iter().collect());
+        self
+    }
+
+    /// Sets whether developer mode, which allows to copy Ids, is enabled.
+    pub fn developer_mode(mut self, enabled: bool) -> Self {
+        self.developer_mode = Some(enabled);
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+#[async_trait::async_trait]
+impl Builder for EditUserSettings {
+    type Context<'ctx> = ();
+    type Built = UserSettings;
+
+    /// Edits the current user's settings with the fields set.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if an invalid value is set. May also return an [`Error::Json`]
+    /// if there is an error in deserializing the API response.
+    async fn execute(
+        self,
+        cache_http: impl CacheHttp,
+        _ctx: Self::Context<'_>,
+    ) -> Result<Self::Built> {
+        cache_http.http().edit_user_settings(&self).await
+    }
+}
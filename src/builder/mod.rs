@@ -37,6 +37,7 @@ pub(crate) fn check_overflow(len: usize, max: usize) -> StdResult<(), usize> {
 }
 
 mod add_member;
+mod begin_guild_prune;
 mod bot_auth_parameters;
 mod create_allowed_mentions;
 mod create_attachment;
@@ -47,30 +48,41 @@ mod create_components;
 mod create_embed;
 mod create_forum_post;
 mod create_forum_tag;
+mod create_guild_from_template;
+mod create_guild_template;
+mod create_interaction;
 mod create_interaction_response;
 mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
 pub mod create_poll;
+mod create_role_connection_metadata;
 mod create_scheduled_event;
+mod create_soundboard_sound;
 mod create_stage_instance;
 mod create_sticker;
 mod create_thread;
 mod create_webhook;
 mod edit_automod_rule;
 mod edit_channel;
+mod edit_current_application;
 mod edit_guild;
+mod edit_guild_template;
 mod edit_guild_welcome_screen;
 mod edit_guild_widget;
 mod edit_interaction_response;
 mod edit_member;
 mod edit_message;
+mod edit_onboarding;
 mod edit_profile;
 mod edit_role;
+mod edit_role_connection;
 mod edit_scheduled_event;
+mod edit_soundboard_sound;
 mod edit_stage_instance;
 mod edit_sticker;
 mod edit_thread;
+mod edit_user_settings;
 mod edit_voice_state;
 mod edit_webhook;
 mod edit_webhook_message;
@@ -78,6 +90,7 @@ mod execute_webhook;
 mod get_messages;
 
 pub use add_member::*;
+pub use begin_guild_prune::*;
 pub use bot_auth_parameters::*;
 pub use create_allowed_mentions::*;
 pub use create_attachment::*;
@@ -88,30 +101,41 @@ pub use create_components::*;
 pub use create_embed::*;
 pub use create_forum_post::*;
 pub use create_forum_tag::*;
+pub use create_guild_from_template::*;
+pub use create_guild_template::*;
+pub use create_interaction::*;
 pub use create_interaction_response::*;
 pub use create_interaction_response_followup::*;
 pub use create_invite::*;
 pub use create_message::*;
 pub use create_poll::{CreatePoll, CreatePollAnswer};
+pub use create_role_connection_metadata::*;
 pub use create_scheduled_event::*;
+pub use create_soundboard_sound::*;
 pub use create_stage_instance::*;
 pub use create_sticker::*;
 pub use create_thread::*;
 pub use create_webhook::*;
 pub use edit_automod_rule::*;
 pub use edit_channel::*;
+pub use edit_current_application::*;
 pub use edit_guild::*;
+pub use edit_guild_template::*;
 pub use edit_guild_welcome_screen::*;
 pub use edit_guild_widget::*;
 pub use edit_interaction_response::*;
 pub use edit_member::*;
 pub use edit_message::*;
+pub use edit_onboarding::*;
 pub use edit_profile::*;
 pub use edit_role::*;
+pub use edit_role_connection::*;
 pub use edit_scheduled_event::*;
+pub use edit_soundboard_sound::*;
 pub use edit_stage_instance::*;
 pub use edit_sticker::*;
 pub use edit_thread::*;
+pub use edit_user_settings::*;
 pub use edit_voice_state::*;
 pub use edit_webhook::*;
 pub use edit_webhook_message::*;
@@ -126,13 +150,13 @@ macro_rules! button_and_select_menu_convenience_methods {
         /// automatically.
         pub fn button(mut $self, button: super::CreateButton) -> Self {
             let rows = $self$(.$components_path)+.get_or_insert_with(Vec::new);
-            let row_with_space_left = rows.last_mut().and_then(|row| match row {
-                super::CreateActionRow::Buttons(buttons) if buttons.len() < 5 => Some(buttons),
-                _ => None,
-            });
+            let row_with_space_left = rows
+                .last_mut()
+                .and_then(|row| row.buttons_mut())
+                .filter(|buttons| buttons.len() < 5);
             match row_with_space_left {
                 Some(row) => row.push(button),
-                None => rows.push(super::CreateActionRow::Buttons(vec![button])),
+                None => rows.push(super::CreateActionRow::Buttons(vec![button]).into()),
             }
             $self
         }
@@ -143,7 +167,7 @@ macro_rules! button_and_select_menu_convenience_methods {
         pub fn select_menu(mut $self, select_menu: super::CreateSelectMenu) -> Self {
             $self$(.$components_path)+
                 .get_or_insert_with(Vec::new)
-                .push(super::CreateActionRow::SelectMenu(select_menu));
+                .push(super::CreateActionRow::SelectMenu(select_menu).into());
             $self
         }
     };
@@ -1,7 +1,7 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use super::{Cache, CacheUpdate};
-use crate::model::channel::{GuildChannel, Message};
+use super::{unread, Cache, CacheEntity, CacheUpdate, CachedInvite, ChannelUnread};
+use crate::model::channel::{GuildChannel, Message, PollAnswerCount, StageInstance};
 use crate::model::event::{
     ChannelCreateEvent,
     ChannelDeleteEvent,
@@ -17,23 +17,43 @@ use crate::model::event::{
     GuildRoleCreateEvent,
     GuildRoleDeleteEvent,
     GuildRoleUpdateEvent,
+    GuildScheduledEventCreateEvent,
+    GuildScheduledEventDeleteEvent,
+    GuildScheduledEventUpdateEvent,
     GuildStickersUpdateEvent,
     GuildUpdateEvent,
+    InviteCreateEvent,
+    InviteDeleteEvent,
+    MessageAckEvent,
     MessageCreateEvent,
+    MessageDeleteBulkEvent,
+    MessageDeleteEvent,
+    MessagePollVoteAddEvent,
+    MessagePollVoteRemoveEvent,
     MessageUpdateEvent,
     PresenceUpdateEvent,
     ReadyEvent,
+    RelationshipAddEvent,
+    RelationshipRemoveEvent,
+    RelationshipUpdateEvent,
+    StageInstanceCreateEvent,
+    StageInstanceDeleteEvent,
+    StageInstanceUpdateEvent,
     ThreadCreateEvent,
     ThreadDeleteEvent,
+    ThreadListSyncEvent,
+    ThreadMemberUpdateEvent,
+    ThreadMembersUpdateEvent,
     ThreadUpdateEvent,
     UserUpdateEvent,
     VoiceChannelStatusUpdateEvent,
     VoiceStateUpdateEvent,
 };
-use crate::model::gateway::ShardInfo;
-use crate::model::guild::{Guild, GuildMemberFlags, Member, Role};
-use crate::model::id::ShardId;
-use crate::model::user::{CurrentUser, OnlineStatus};
+use crate::model::gateway::{Presence, ShardInfo};
+use crate::model::guild::{Emoji, Guild, GuildMemberFlags, Member, Role, ScheduledEvent};
+use crate::model::id::{AnswerId, ChannelId, EmojiId, MessageId, ShardId, StickerId, UserId};
+use crate::model::sticker::Sticker;
+use crate::model::user::{CurrentUser, OnlineStatus, Relationship};
 use crate::model::voice::VoiceState;
 
 impl CacheUpdate for ChannelCreateEvent {
@@ -46,6 +66,7 @@ impl CacheUpdate for ChannelCreateEvent {
             .and_then(|mut g| g.channels.insert(self.channel.id, self.channel.clone()));
 
         cache.channels.insert(self.channel.id, self.channel.guild_id);
+        cache.update_hooks.notify(CacheEntity::Channel, old_channel.as_ref(), Some(&self.channel));
         old_channel
     }
 }
@@ -57,10 +78,26 @@ impl CacheUpdate for ChannelDeleteEvent {
         let (channel_id, guild_id) = (self.channel.id, self.channel.guild_id);
 
         cache.channels.remove(&channel_id);
-        cache.guilds.get_mut(&guild_id).map(|mut g| g.channels.remove(&channel_id));
+        let (old_channel, old_threads) = cache
+            .guilds
+            .get_mut(&guild_id)
+            .map(|mut g| {
+                let (old_threads, threads) = std::mem::take(&mut g.threads)
+                    .into_iter()
+                    .partition(|thread| thread.parent_id == Some(channel_id));
+                g.threads = threads;
+                (g.channels.remove(&channel_id), old_threads)
+            })
+            .unwrap_or_default();
+        cache.update_hooks.notify(CacheEntity::Channel, old_channel.as_ref(), None);
+        for thread in &old_threads {
+            cache.update_hooks.notify(CacheEntity::Channel, Some(thread), None);
+        }
 
         // Remove the cached messages for the channel.
-        cache.messages.remove(&channel_id).map(|(_, messages)| messages.into_values().collect())
+        let (_, messages) = cache.messages.remove(&channel_id)?;
+        cache.remove_message_revisions(messages.keys());
+        Some(messages.into_values().collect())
     }
 }
 
@@ -70,10 +107,12 @@ impl CacheUpdate for ChannelUpdateEvent {
     fn update(&mut self, cache: &Cache) -> Option<GuildChannel> {
         cache.channels.insert(self.channel.id, self.channel.guild_id);
 
-        cache
+        let old_channel = cache
             .guilds
             .get_mut(&self.channel.guild_id)
-            .and_then(|mut g| g.channels.insert(self.channel.id, self.channel.clone()))
+            .and_then(|mut g| g.channels.insert(self.channel.id, self.channel.clone()));
+        cache.update_hooks.notify(CacheEntity::Channel, old_channel.as_ref(), Some(&self.channel));
+        old_channel
     }
 }
 
@@ -99,6 +138,7 @@ impl CacheUpdate for GuildCreateEvent {
     fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.remove(&self.guild.id);
         let mut guild = self.guild.clone();
+        cache.strip_guild(&mut guild);
 
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user);
@@ -107,11 +147,19 @@ impl CacheUpdate for GuildCreateEvent {
             }
         }
 
-        cache.guilds.insert(self.guild.id, guild);
+        let old_guild = cache.guilds.insert(self.guild.id, guild);
+        if cache.guilds.is_enabled() {
+            cache.update_hooks.notify(CacheEntity::Guild, old_guild.as_ref(), Some(&self.guild));
+        }
         for channel_id in self.guild.channels.keys() {
             cache.channels.insert(*channel_id, self.guild.id);
         }
 
+        cache.track_members(self.guild.id, self.guild.members.keys().copied());
+        if cache.settings().cache_presences {
+            cache.track_presences(self.guild.id, self.guild.presences.keys().copied());
+        }
+
         None
     }
 }
@@ -122,37 +170,28 @@ impl CacheUpdate for GuildDeleteEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         if self.guild.unavailable {
             cache.unavailable_guilds.insert(self.guild.id, ());
-            cache.guilds.remove(&self.guild.id);
+            let old_guild = cache.guilds.remove(&self.guild.id).map(|(_, guild)| guild);
+            cache.update_hooks.notify(CacheEntity::Guild, old_guild.as_ref(), None);
 
             return None;
         }
 
-        match cache.guilds.remove(&self.guild.id) {
-            Some(guild) => {
-                for channel_id in guild.1.channels.keys() {
-                    // Remove the channel from the cache.
-                    cache.channels.remove(channel_id);
-
-                    // Remove the channel's cached messages.
-                    cache.messages.remove(channel_id);
-                }
-
-                Some(guild.1)
-            },
-            None => None,
-        }
+        let old_guild = cache.clear_guild(self.guild.id);
+        cache.update_hooks.notify(CacheEntity::Guild, old_guild.as_ref(), None);
+        old_guild
     }
 }
 
 impl CacheUpdate for GuildEmojisUpdateEvent {
-    type Output = ();
+    type Output = HashMap<EmojiId, Emoji>;
 
-    fn update(&mut self, cache: &Cache) -> Option<()> {
-        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
-            guild.emojis.clone_from(&self.emojis);
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_emojis {
+            return None;
         }
 
-        None
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        Some(std::mem::replace(&mut guild.emojis, self.emojis.clone()))
     }
 }
 
@@ -166,9 +205,14 @@ impl CacheUpdate for GuildMemberAddEvent {
             self.member.user = u.clone();
         }
 
-        if let Some(mut guild) = cache.guilds.get_mut(&self.member.guild_id) {
+        let old_member = cache.guilds.get_mut(&self.member.guild_id).map(|mut guild| {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+            guild.members.insert(user_id, self.member.clone())
+        });
+        cache.track_members(self.member.guild_id, [user_id]);
+
+        if let Some(old_member) = old_member {
+            cache.update_hooks.notify(CacheEntity::Member, old_member.as_ref(), Some(&self.member));
         }
 
         None
@@ -179,12 +223,16 @@ impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
-            guild.member_count -= 1;
-            return guild.members.remove(&self.user.id);
-        }
+        cache.members_lru.remove(&(self.guild_id, self.user.id));
+        #[cfg(feature = "temp_cache")]
+        cache.temp_members.invalidate(&(self.guild_id, self.user.id));
 
-        None
+        let old_member = cache.guilds.get_mut(&self.guild_id).and_then(|mut guild| {
+            guild.member_count -= 1;
+            guild.members.remove(&self.user.id)
+        });
+        cache.update_hooks.notify(CacheEntity::Member, old_member.as_ref(), None);
+        old_member
     }
 }
 
@@ -193,8 +241,12 @@ impl CacheUpdate for GuildMemberUpdateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         cache.update_user_entry(&self.user);
+        cache.track_members(self.guild_id, [self.user.id]);
+        #[cfg(feature = "temp_cache")]
+        cache.temp_members.invalidate(&(self.guild_id, self.user.id));
 
-        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
+        let mut new_member = None;
+        let item = if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
 
@@ -209,6 +261,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 member.avatar.clone_from(&self.avatar);
                 member.communication_disabled_until.clone_from(&self.communication_disabled_until);
                 member.unusual_dm_activity_until.clone_from(&self.unusual_dm_activity_until);
+                member.avatar_decoration_data.clone_from(&self.avatar_decoration_data);
 
                 item
             } else {
@@ -231,17 +284,26 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                     communication_disabled_until: self.communication_disabled_until,
                     flags: GuildMemberFlags::default(),
                     unusual_dm_activity_until: self.unusual_dm_activity_until,
+                    avatar_decoration_data: self.avatar_decoration_data.clone(),
                 });
             }
 
+            if cache.update_hooks.is_active() {
+                new_member = guild.members.get(&self.user.id).cloned();
+            }
+
             item
         } else {
             None
-        }
+        };
+
+        cache.update_hooks.notify(CacheEntity::Member, item.as_ref(), new_member.as_ref());
+        item
     }
 }
 
 impl CacheUpdate for GuildMembersChunkEvent {
+    /// Returned once the last chunk of the request was received.
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
@@ -249,11 +311,30 @@ impl CacheUpdate for GuildMembersChunkEvent {
             cache.update_user_entry(&member.user);
         }
 
-        if let Some(mut g) = cache.guilds.get_mut(&self.guild_id) {
-            g.members.extend(self.members.clone());
+        let old_members: Option<Vec<_>> = cache.guilds.get_mut(&self.guild_id).map(|mut g| {
+            self.members
+                .iter()
+                .map(|(user_id, member)| (g.members.insert(*user_id, member.clone()), member))
+                .collect()
+        });
+        cache.track_members(self.guild_id, self.members.keys().copied());
+
+        for (old_member, member) in old_members.into_iter().flatten() {
+            cache.update_hooks.notify(CacheEntity::Member, old_member.as_ref(), Some(member));
         }
 
-        None
+        let key = (self.guild_id, self.nonce.clone());
+        let received = {
+            let mut received = cache.member_chunks.entry(key.clone()).or_default();
+            *received += 1;
+            *received
+        };
+        if received < self.chunk_count {
+            return None;
+        }
+
+        cache.member_chunks.remove(&key);
+        Some(())
     }
 }
 
@@ -261,11 +342,15 @@ impl CacheUpdate for GuildRoleCreateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
-        cache
+        let old_role = cache
             .guilds
             .get_mut(&self.role.guild_id)
             .map(|mut g| g.roles.insert(self.role.id, self.role.clone()));
 
+        if let Some(old_role) = old_role {
+            cache.update_hooks.notify(CacheEntity::Role, old_role.as_ref(), Some(&self.role));
+        }
+
         None
     }
 }
@@ -274,7 +359,10 @@ impl CacheUpdate for GuildRoleDeleteEvent {
     type Output = Role;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        cache.guilds.get_mut(&self.guild_id).and_then(|mut g| g.roles.remove(&self.role_id))
+        let old_role =
+            cache.guilds.get_mut(&self.guild_id).and_then(|mut g| g.roles.remove(&self.role_id));
+        cache.update_hooks.notify(CacheEntity::Role, old_role.as_ref(), None);
+        old_role
     }
 }
 
@@ -282,25 +370,28 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     type Output = Role;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(mut guild) = cache.guilds.get_mut(&self.role.guild_id) {
-            if let Some(role) = guild.roles.get_mut(&self.role.id) {
-                return Some(std::mem::replace(role, self.role.clone()));
-            }
-        }
+        let old_role = cache.guilds.get_mut(&self.role.guild_id).and_then(|mut guild| {
+            let role = guild.roles.get_mut(&self.role.id)?;
+            Some(std::mem::replace(role, self.role.clone()))
+        });
 
-        None
+        if old_role.is_some() {
+            cache.update_hooks.notify(CacheEntity::Role, old_role.as_ref(), Some(&self.role));
+        }
+        old_role
     }
 }
 
 impl CacheUpdate for GuildStickersUpdateEvent {
-    type Output = ();
+    type Output = HashMap<StickerId, Sticker>;
 
-    fn update(&mut self, cache: &Cache) -> Option<()> {
-        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
-            guild.stickers.clone_from(&self.stickers);
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_stickers {
+            return None;
         }
 
-        None
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        Some(std::mem::replace(&mut guild.stickers, self.stickers.clone()))
     }
 }
 
@@ -308,7 +399,10 @@ impl CacheUpdate for GuildUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        let mut changed = None;
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild.id) {
+            let old_guild = cache.update_hooks.is_active().then(|| guild.clone());
+
             guild.afk_metadata.clone_from(&self.guild.afk_metadata);
             guild.banner.clone_from(&self.guild.banner);
             guild.discovery_splash.clone_from(&self.guild.discovery_splash);
@@ -335,17 +429,58 @@ impl CacheUpdate for GuildUpdateEvent {
             guild.verification_level = self.guild.verification_level;
             guild.widget_channel_id = self.guild.widget_channel_id;
             guild.widget_enabled = self.guild.widget_enabled;
+
+            changed = old_guild.map(|old_guild| (old_guild, guild.clone()));
+        }
+
+        if let Some((old_guild, new_guild)) = changed {
+            cache.update_hooks.notify(CacheEntity::Guild, Some(&old_guild), Some(&new_guild));
         }
 
         None
     }
 }
 
+impl CacheUpdate for InviteCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let guild_id = self.guild_id?;
+        if let Some(invites) = &cache.invites.0 {
+            invites
+                .entry(guild_id)
+                .or_default()
+                .insert(self.code.clone(), CachedInvite::from(&*self));
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for InviteDeleteEvent {
+    type Output = CachedInvite;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.invites.get_mut(&self.guild_id?)?.remove(&self.code)
+    }
+}
+
+impl CacheUpdate for MessageAckEvent {
+    /// The unread state of the channel before it was acknowledged.
+    type Output = ChannelUnread;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        unread::ack(cache, self.channel_id, self.message_id, self.mention_count.unwrap_or(0))
+    }
+}
+
 impl CacheUpdate for MessageCreateEvent {
     /// The oldest message, if the channel's message cache was already full.
     type Output = Message;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        unread::record_message(cache, &self.message);
+
         // Update the relevant channel object with the new latest message if this message is newer
         let guild = self.message.guild_id.and_then(|g_id| cache.guilds.get_mut(&g_id));
 
@@ -380,9 +515,18 @@ impl CacheUpdate for MessageCreateEvent {
             }
         }
 
+        let mut message = self.message.clone();
+        cache.strip_message(&mut message);
+
         queue.push_back(self.message.id);
-        messages.insert(self.message.id, self.message.clone());
+        messages.insert(self.message.id, message);
+        drop((messages, queue));
+
+        if let Some(removed_msg) = &removed_msg {
+            cache.remove_message_revisions([&removed_msg.id]);
+        }
 
+        cache.update_hooks.notify(CacheEntity::Message, None, Some(&self.message));
         removed_msg
     }
 }
@@ -402,6 +546,104 @@ fn update_channel_last_message_id(message: &Message, channel: &mut GuildChannel,
     }
 }
 
+impl CacheUpdate for MessageDeleteEvent {
+    type Output = Message;
+
+    fn update(&mut self, cache: &Cache) -> Option<Message> {
+        remove_messages(cache, self.channel_id, &[self.message_id]).pop()
+    }
+}
+
+impl CacheUpdate for MessageDeleteBulkEvent {
+    type Output = Vec<Message>;
+
+    fn update(&mut self, cache: &Cache) -> Option<Vec<Message>> {
+        Some(remove_messages(cache, self.channel_id, &self.ids))
+    }
+}
+
+/// Removes the given messages of a channel from the cache, returning the ones that were cached.
+fn remove_messages(cache: &Cache, channel_id: ChannelId, ids: &[MessageId]) -> Vec<Message> {
+    #[cfg(feature = "temp_cache")]
+    for id in ids {
+        cache.temp_messages.invalidate(id);
+    }
+
+    let removed: Vec<_> = match cache.messages.get_mut(&channel_id) {
+        Some(mut messages) => ids.iter().filter_map(|id| messages.remove(id)).collect(),
+        None => return Vec::new(),
+    };
+    if let Some(mut queue) = cache.message_queue.get_mut(&channel_id) {
+        queue.retain(|id| !ids.contains(id));
+    }
+    cache.remove_message_revisions(removed.iter().map(|message| &message.id));
+
+    for message in &removed {
+        cache.update_hooks.notify(CacheEntity::Message, Some(message), None);
+    }
+    removed
+}
+
+impl CacheUpdate for MessagePollVoteAddEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        update_poll_count(cache, self.channel_id, self.message_id, self.answer_id, self.user_id, 1);
+        None
+    }
+}
+
+impl CacheUpdate for MessagePollVoteRemoveEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        update_poll_count(
+            cache,
+            self.channel_id,
+            self.message_id,
+            self.answer_id,
+            self.user_id,
+            -1,
+        );
+        None
+    }
+}
+
+/// Applies a vote to the results of a cached poll, if Discord provided any for it.
+fn update_poll_count(
+    cache: &Cache,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    answer_id: AnswerId,
+    user_id: UserId,
+    change: i64,
+) -> Option<()> {
+    let is_current_user = cache.user.read().id == user_id;
+
+    let mut messages = cache.messages.get_mut(&channel_id)?;
+    let results = messages.get_mut(&message_id)?.poll.as_mut()?.results.as_mut()?;
+    if results.is_finalized {
+        return None;
+    }
+
+    let counts = &mut results.answer_counts;
+    if !counts.iter().any(|count| count.id == answer_id) {
+        counts.push(PollAnswerCount {
+            id: answer_id,
+            count: 0,
+            me_voted: false,
+        });
+    }
+
+    let answer_count = counts.iter_mut().find(|count| count.id == answer_id)?;
+    answer_count.count = answer_count.count.saturating_add_signed(change);
+    if is_current_user {
+        answer_count.me_voted = change > 0;
+    }
+
+    Some(())
+}
+
 impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
 
@@ -411,15 +653,32 @@ impl CacheUpdate for MessageUpdateEvent {
         let old_message = message.clone();
 
         self.apply_to_message(message);
+        cache.strip_message(message);
+
+        let new_message = cache.update_hooks.is_active().then(|| message.clone());
+        drop(messages);
+
+        let max_revisions = cache.settings().max_message_revisions;
+        if max_revisions > 0 {
+            let mut revisions = cache.message_revisions.entry(self.id).or_default();
+            while revisions.len() >= max_revisions {
+                revisions.pop_front();
+            }
+            revisions.push_back(old_message.clone());
+        }
+
+        if let Some(new_message) = new_message {
+            cache.update_hooks.notify(CacheEntity::Message, Some(&old_message), Some(&new_message));
+        }
 
         Some(old_message)
     }
 }
 
 impl CacheUpdate for PresenceUpdateEvent {
-    type Output = ();
+    type Output = Presence;
 
-    fn update(&mut self, cache: &Cache) -> Option<()> {
+    fn update(&mut self, cache: &Cache) -> Option<Presence> {
         if let Some(user) = self.presence.user.to_user() {
             cache.update_user_entry(&user);
         }
@@ -428,18 +687,27 @@ impl CacheUpdate for PresenceUpdateEvent {
             self.presence.user.update_with_user(&user);
         }
 
+        let mut old = None;
         if let Some(guild_id) = self.presence.guild_id {
+            let cache_presences = cache.settings().cache_presences;
+            let mut old_presence = None;
+            let mut new_member = None;
+            let mut member_inserted = false;
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user.id);
-                } else {
-                    guild.presences.insert(self.presence.user.id, self.presence.clone());
+                if cache_presences {
+                    old_presence = Some(if self.presence.status == OnlineStatus::Offline {
+                        guild.presences.remove(&self.presence.user.id)
+                    } else {
+                        guild.presences.insert(self.presence.user.id, self.presence.clone())
+                    });
                 }
 
                 // Create a partial member instance out of the presence update data.
                 if let Some(user) = self.presence.user.to_user() {
-                    guild.members.entry(self.presence.user.id).or_insert_with(|| Member {
+                    let user_id = self.presence.user.id;
+                    member_inserted = !guild.members.contains_key(&user_id);
+                    let member = guild.members.entry(user_id).or_insert_with(|| Member {
                         deaf: false,
                         guild_id,
                         joined_at: None,
@@ -454,12 +722,38 @@ impl CacheUpdate for PresenceUpdateEvent {
                         communication_disabled_until: None,
                         flags: GuildMemberFlags::default(),
                         unusual_dm_activity_until: None,
+                        avatar_decoration_data: None,
                     });
+                    if member_inserted && cache.update_hooks.is_active() {
+                        new_member = Some(member.clone());
+                    }
                 }
             }
+
+            let user_id = self.presence.user.id;
+            if member_inserted {
+                cache.track_members(guild_id, [user_id]);
+            }
+            if self.presence.status == OnlineStatus::Offline {
+                cache.presences_lru.remove(&(guild_id, user_id));
+            } else if cache_presences {
+                cache.track_presences(guild_id, [user_id]);
+            }
+
+            if let Some(old_presence) = old_presence {
+                let new_presence =
+                    (self.presence.status != OnlineStatus::Offline).then_some(&self.presence);
+                cache.update_hooks.notify(
+                    CacheEntity::Presence,
+                    old_presence.as_ref(),
+                    new_presence,
+                );
+                old = old_presence;
+            }
+            cache.update_hooks.notify(CacheEntity::Member, None, new_member.as_ref());
         }
 
-        None
+        old
     }
 }
 
@@ -492,6 +786,8 @@ impl CacheUpdate for ReadyEvent {
         if !guilds_to_remove.is_empty() {
             for guild in guilds_to_remove {
                 cache.guilds.remove(&guild);
+                cache.members_lru.remove_where(|(g, _)| *g == guild);
+                cache.presences_lru.remove_where(|(g, _)| *g == guild);
             }
         }
 
@@ -502,6 +798,138 @@ impl CacheUpdate for ReadyEvent {
         }
         *cache.user.write() = ready.user;
 
+        unread::load_read_states(cache, &ready.read_state);
+        *cache.sessions.write() = ready.sessions;
+
+        cache.relationships.clear();
+        for relationship in ready.relationships {
+            cache.relationships.insert(relationship.id, relationship);
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for RelationshipAddEvent {
+    type Output = Relationship;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let old_relationship =
+            cache.relationships.insert(self.relationship.id, self.relationship.clone());
+        if cache.relationships.is_enabled() {
+            cache.update_hooks.notify(
+                CacheEntity::Relationship,
+                old_relationship.as_ref(),
+                Some(&self.relationship),
+            );
+        }
+        old_relationship
+    }
+}
+
+impl CacheUpdate for RelationshipRemoveEvent {
+    type Output = Relationship;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let old_relationship = cache.relationships.remove(&self.relationship.id).map(|(_, r)| r);
+        cache.update_hooks.notify(CacheEntity::Relationship, old_relationship.as_ref(), None);
+        old_relationship
+    }
+}
+
+impl CacheUpdate for RelationshipUpdateEvent {
+    type Output = Relationship;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        // Updates don't carry the user, so keep the one received when the relationship was added.
+        if let Some(old) = cache.relationships.get(&self.relationship.id) {
+            if self.relationship.user.is_none() {
+                self.relationship.user.clone_from(&old.user);
+            }
+            if self.relationship.since.is_none() {
+                self.relationship.since = old.since;
+            }
+        }
+
+        let old_relationship =
+            cache.relationships.insert(self.relationship.id, self.relationship.clone());
+        if cache.relationships.is_enabled() {
+            cache.update_hooks.notify(
+                CacheEntity::Relationship,
+                old_relationship.as_ref(),
+                Some(&self.relationship),
+            );
+        }
+        old_relationship
+    }
+}
+
+impl CacheUpdate for StageInstanceCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        upsert(&mut guild.stage_instances, self.stage_instance.clone(), |e| e.id);
+
+        None
+    }
+}
+
+impl CacheUpdate for StageInstanceUpdateEvent {
+    type Output = StageInstance;
+
+    fn update(&mut self, cache: &Cache) -> Option<StageInstance> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        upsert(&mut guild.stage_instances, self.stage_instance.clone(), |e| e.id)
+    }
+}
+
+impl CacheUpdate for StageInstanceDeleteEvent {
+    type Output = StageInstance;
+
+    fn update(&mut self, cache: &Cache) -> Option<StageInstance> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        let i = guild.stage_instances.iter().position(|e| e.id == self.stage_instance.id)?;
+        Some(guild.stage_instances.remove(i))
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventCreateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        upsert(&mut guild.scheduled_events, self.event.clone(), |e| e.id);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUpdateEvent {
+    type Output = ScheduledEvent;
+
+    fn update(&mut self, cache: &Cache) -> Option<ScheduledEvent> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        upsert(&mut guild.scheduled_events, self.event.clone(), |e| e.id)
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventDeleteEvent {
+    type Output = ScheduledEvent;
+
+    fn update(&mut self, cache: &Cache) -> Option<ScheduledEvent> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        let i = guild.scheduled_events.iter().position(|e| e.id == self.event.id)?;
+        Some(guild.scheduled_events.remove(i))
+    }
+}
+
+/// Replaces the item with the same Id as `value`, returning it, or pushes `value` otherwise.
+fn upsert<T, K: PartialEq>(items: &mut Vec<T>, value: T, id: impl Fn(&T) -> K) -> Option<T> {
+    if let Some(i) = items.iter().position(|e| id(e) == id(&value)) {
+        Some(std::mem::replace(&mut items[i], value))
+    } else {
+        items.push(value);
         None
     }
 }
@@ -512,14 +940,17 @@ impl CacheUpdate for ThreadCreateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
-        cache.guilds.get_mut(&guild_id).and_then(|mut g| {
+        let old_thread = cache.guilds.get_mut(&guild_id).map(|mut g| {
             if let Some(i) = g.threads.iter().position(|e| e.id == thread_id) {
                 Some(std::mem::replace(&mut g.threads[i], self.thread.clone()))
             } else {
                 g.threads.push(self.thread.clone());
                 None
             }
-        })
+        })?;
+
+        cache.update_hooks.notify(CacheEntity::Channel, old_thread.as_ref(), Some(&self.thread));
+        old_thread
     }
 }
 
@@ -529,14 +960,17 @@ impl CacheUpdate for ThreadUpdateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
-        cache.guilds.get_mut(&guild_id).and_then(|mut g| {
+        let old_thread = cache.guilds.get_mut(&guild_id).map(|mut g| {
             if let Some(i) = g.threads.iter().position(|e| e.id == thread_id) {
                 Some(std::mem::replace(&mut g.threads[i], self.thread.clone()))
             } else {
                 g.threads.push(self.thread.clone());
                 None
             }
-        })
+        })?;
+
+        cache.update_hooks.notify(CacheEntity::Channel, old_thread.as_ref(), Some(&self.thread));
+        old_thread
     }
 }
 
@@ -546,9 +980,98 @@ impl CacheUpdate for ThreadDeleteEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
-        cache.guilds.get_mut(&guild_id).and_then(|mut g| {
+        let old_thread = cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             g.threads.iter().position(|e| e.id == thread_id).map(|i| g.threads.remove(i))
-        })
+        });
+        cache.update_hooks.notify(CacheEntity::Channel, old_thread.as_ref(), None);
+        old_thread
+    }
+}
+
+impl CacheUpdate for ThreadListSyncEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let is_synced = |thread: &GuildChannel| match &self.channel_ids {
+            Some(channel_ids) => thread.parent_id.is_some_and(|id| channel_ids.contains(&id)),
+            None => true,
+        };
+        let is_archived =
+            |thread: &GuildChannel| thread.thread_metadata.is_some_and(|m| m.archived);
+
+        for thread in &mut self.threads {
+            if let Some(member) = self.members.iter().find(|member| member.id == thread.id) {
+                thread.member = Some(member.inner.clone());
+            }
+        }
+
+        let (removed, replaced) = {
+            let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+
+            // Active threads of the synced channels which are missing from the sync were deleted
+            // or became inaccessible. Known archived threads are kept.
+            let (removed, kept) =
+                std::mem::take(&mut guild.threads).into_iter().partition::<Vec<_>, _>(|thread| {
+                    is_synced(thread)
+                        && !is_archived(thread)
+                        && !self.threads.iter().any(|synced| synced.id == thread.id)
+                });
+            guild.threads = kept;
+
+            let mut replaced = Vec::with_capacity(self.threads.len());
+            for thread in &self.threads {
+                if let Some(i) = guild.threads.iter().position(|e| e.id == thread.id) {
+                    replaced.push(Some(std::mem::replace(&mut guild.threads[i], thread.clone())));
+                } else {
+                    guild.threads.push(thread.clone());
+                    replaced.push(None);
+                }
+            }
+
+            (removed, replaced)
+        };
+
+        for thread in &removed {
+            cache.update_hooks.notify(CacheEntity::Channel, Some(thread), None);
+        }
+        for (old, new) in replaced.iter().zip(&self.threads) {
+            cache.update_hooks.notify(CacheEntity::Channel, old.as_ref(), Some(new));
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ThreadMemberUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let guild_id = self.member.guild_id?;
+        let mut guild = cache.guilds.get_mut(&guild_id)?;
+        let thread = guild.threads.iter_mut().find(|thread| thread.id == self.member.id)?;
+        thread.member = Some(self.member.inner.clone());
+
+        None
+    }
+}
+
+impl CacheUpdate for ThreadMembersUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let current_user_id = cache.user.read().id;
+
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let thread = guild.threads.iter_mut().find(|thread| thread.id == self.id)?;
+        thread.member_count = Some(self.member_count.clamp(0, u8::MAX.into()) as u8);
+
+        if let Some(member) = self.added_members.iter().find(|m| m.user_id == current_user_id) {
+            thread.member = Some(member.inner.clone());
+        } else if self.removed_member_ids.contains(&current_user_id) {
+            thread.member = None;
+        }
+
+        None
     }
 }
 
@@ -556,8 +1079,13 @@ impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let mut user = cache.user.write();
-        Some(std::mem::replace(&mut user, self.current_user.clone()))
+        let old_user = std::mem::replace(&mut *cache.user.write(), self.current_user.clone());
+        cache.update_hooks.notify(
+            CacheEntity::CurrentUser,
+            Some(&old_user),
+            Some(&self.current_user),
+        );
+        Some(old_user)
     }
 }
 
@@ -566,12 +1094,19 @@ impl CacheUpdate for VoiceStateUpdateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
         if let Some(guild_id) = self.voice_state.guild_id {
-            if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
+            if let Some(member) = &self.voice_state.member {
+                cache.track_members(guild_id, [member.user.id]);
+            }
+
+            let mut old_member = None;
+            let old_state = if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 if let Some(member) = &self.voice_state.member {
-                    guild.members.insert(member.user.id, member.clone());
+                    old_member = Some(guild.members.insert(member.user.id, member.clone()));
                 }
 
-                if self.voice_state.channel_id.is_some() {
+                if !cache.settings().cache_voice_states {
+                    None
+                } else if self.voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
                     guild.voice_states.insert(self.voice_state.user_id, self.voice_state.clone())
                 } else {
@@ -580,7 +1115,12 @@ impl CacheUpdate for VoiceStateUpdateEvent {
                 }
             } else {
                 None
+            };
+
+            if let (Some(old_member), Some(member)) = (old_member, &self.voice_state.member) {
+                cache.update_hooks.notify(CacheEntity::Member, old_member.as_ref(), Some(member));
             }
+            old_state
         } else {
             None
         }
@@ -0,0 +1,165 @@
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use super::CacheMirror;
+use crate::model::prelude::*;
+
+/// A hook which is notified whenever the cache inserts, updates or removes an entity.
+///
+/// This allows mirroring the cached state elsewhere, e.g. into a database, without handling every
+/// gateway event. Register it with [`Cache::add_update_hook`].
+///
+/// Hooks are called synchronously from the shard receiving the event, after the change was
+/// applied, so they should return quickly. They may read the cache, but must not block on it
+/// being mutated.
+///
+/// Only the entities listed in [`CacheEntity`] are reported. Bulk replacements, such as the
+/// guilds removed on [`Ready`] or [`Cache::restore_from`], and evictions caused by the cache
+/// [`Settings`] limits are not.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::cache::{Cache, CacheEntity, CacheEntityKind, CacheUpdateHook};
+///
+/// struct Mirror;
+///
+/// impl CacheUpdateHook for Mirror {
+///     fn on_update(
+///         &self,
+///         kind: CacheEntityKind,
+///         old: Option<CacheEntity<'_>>,
+///         new: Option<CacheEntity<'_>>,
+///     ) {
+///         if let (CacheEntityKind::Member, Some(CacheEntity::Member(member))) = (kind, new) {
+///             println!("{} was stored (previously cached: {})", member.user.name, old.is_some());
+///         }
+///     }
+/// }
+///
+/// let cache = Cache::new();
+/// cache.add_update_hook(Mirror);
+/// ```
+///
+/// [`Cache::add_update_hook`]: super::Cache::add_update_hook
+/// [`Cache::restore_from`]: super::Cache::restore_from
+/// [`Settings`]: super::Settings
+pub trait CacheUpdateHook: Send + Sync {
+    /// Called after an entity of the given kind was changed.
+    ///
+    /// `old` is [`None`] if the entity was newly inserted, and `new` is [`None`] if it was
+    /// removed.
+    fn on_update(
+        &self,
+        kind: CacheEntityKind,
+        old: Option<CacheEntity<'_>>,
+        new: Option<CacheEntity<'_>>,
+    );
+}
+
+/// The kind of entity passed to a [`CacheUpdateHook`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CacheEntityKind {
+    Guild,
+    Channel,
+    Role,
+    Member,
+    User,
+    CurrentUser,
+    Presence,
+    Message,
+    Relationship,
+}
+
+/// A reference to an entity passed to a [`CacheUpdateHook`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum CacheEntity<'a> {
+    Guild(&'a Guild),
+    /// A guild channel or thread.
+    Channel(&'a GuildChannel),
+    Role(&'a Role),
+    Member(&'a Member),
+    User(&'a User),
+    CurrentUser(&'a CurrentUser),
+    Presence(&'a Presence),
+    Message(&'a Message),
+    Relationship(&'a Relationship),
+}
+
+impl CacheEntity<'_> {
+    /// Returns the kind of the entity.
+    #[must_use]
+    pub fn kind(&self) -> CacheEntityKind {
+        match self {
+            Self::Guild(_) => CacheEntityKind::Guild,
+            Self::Channel(_) => CacheEntityKind::Channel,
+            Self::Role(_) => CacheEntityKind::Role,
+            Self::Member(_) => CacheEntityKind::Member,
+            Self::User(_) => CacheEntityKind::User,
+            Self::CurrentUser(_) => CacheEntityKind::CurrentUser,
+            Self::Presence(_) => CacheEntityKind::Presence,
+            Self::Message(_) => CacheEntityKind::Message,
+            Self::Relationship(_) => CacheEntityKind::Relationship,
+        }
+    }
+}
+
+/// The registered [`CacheUpdateHook`]s and [`CacheMirror`] of a cache.
+#[derive(Default)]
+pub(crate) struct UpdateHooks {
+    hooks: RwLock<Vec<Arc<dyn CacheUpdateHook>>>,
+    mirror: RwLock<Option<Arc<dyn CacheMirror>>>,
+}
+
+impl UpdateHooks {
+    pub fn add(&self, hook: Arc<dyn CacheUpdateHook>) {
+        self.hooks.write().push(hook);
+    }
+
+    pub fn mirror(&self) -> Option<Arc<dyn CacheMirror>> {
+        self.mirror.read().clone()
+    }
+
+    pub fn set_mirror(&self, mirror: Arc<dyn CacheMirror>) {
+        *self.mirror.write() = Some(mirror);
+    }
+
+    /// Whether any hooks or a mirror are registered, used to skip cloning old values otherwise.
+    pub fn is_active(&self) -> bool {
+        !self.hooks.read().is_empty() || self.mirror.read().is_some()
+    }
+
+    pub fn notify<'a, T>(
+        &self,
+        entity: impl Fn(&'a T) -> CacheEntity<'a>,
+        old: Option<&'a T>,
+        new: Option<&'a T>,
+    ) {
+        let hooks = self.hooks.read().clone();
+        let mirror = self.mirror();
+        if let Some(kind) = old.or(new).map(|value| entity(value).kind()) {
+            for hook in hooks {
+                hook.on_update(kind, old.map(&entity), new.map(&entity));
+            }
+            if let Some(mirror) = mirror {
+                mirror.on_update(kind, old.map(&entity), new.map(&entity));
+            }
+        }
+    }
+}
+
+impl fmt::Debug for UpdateHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdateHooks")
+            .field("len", &self.hooks.read().len())
+            .field("has_mirror", &self.mirror.read().is_some())
+            .finish()
+    }
+}
+
+#[cfg(feature = "typesize")]
+impl typesize::TypeSize for UpdateHooks {}
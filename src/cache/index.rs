@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+
+use super::wrappers::BuildHasher;
+use super::{CacheEntity, CacheEntityKind, CacheUpdateHook};
+use crate::model::prelude::*;
+
+/// Indexes of the names of cached guilds, channels and members, enabled via
+/// [`Settings::index_names`].
+///
+/// The indexes are kept up to date by a clone registered as a [`CacheUpdateHook`]. Entries may be
+/// stale, e.g. after entities were evicted, so lookups must verify the name of the entity they
+/// resolve to.
+///
+/// [`Settings::index_names`]: super::Settings::index_names
+#[derive(Clone, Debug, Default)]
+pub(crate) struct NameIndex {
+    guilds: Arc<DashMap<String, Vec<GuildId>, BuildHasher>>,
+    guild_contents: Arc<DashMap<GuildId, GuildNameIndex, BuildHasher>>,
+}
+
+#[derive(Debug, Default)]
+struct GuildNameIndex {
+    channels: HashMap<String, Vec<ChannelId>>,
+    members: HashMap<String, UserId>,
+}
+
+/// Normalizes a name for case-insensitive lookups.
+pub(crate) fn index_key(name: &str) -> String {
+    name.to_lowercase()
+}
+
+fn insert_id<T: PartialEq>(ids: &mut Vec<T>, id: T) {
+    if !ids.contains(&id) {
+        ids.push(id);
+    }
+}
+
+fn remove_id<K: Eq + std::hash::Hash, T: PartialEq>(map: &mut HashMap<K, Vec<T>>, key: &K, id: &T) {
+    if let Some(ids) = map.get_mut(key) {
+        ids.retain(|other| other != id);
+        if ids.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+impl NameIndex {
+    pub fn guilds(&self, name: &str) -> Vec<GuildId> {
+        self.guilds.get(&index_key(name)).map(|ids| ids.clone()).unwrap_or_default()
+    }
+
+    pub fn channels(&self, guild_id: GuildId, name: &str) -> Vec<ChannelId> {
+        self.guild_contents
+            .get(&guild_id)
+            .and_then(|index| index.channels.get(&index_key(name)).cloned())
+            .unwrap_or_default()
+    }
+
+    pub fn member(&self, guild_id: GuildId, username: &str) -> Option<UserId> {
+        self.guild_contents.get(&guild_id)?.members.get(&index_key(username)).copied()
+    }
+
+    /// Replaces the indexes of a guild and its channels and members.
+    pub fn index_guild(&self, guild: &Guild) {
+        self.insert_guild_name(guild);
+
+        let mut index = GuildNameIndex::default();
+        for channel in guild.channels.values().chain(&guild.threads) {
+            index.channels.entry(index_key(&channel.name)).or_default().push(channel.id);
+        }
+        for member in guild.members.values() {
+            index.members.insert(index_key(&member.user.name), member.user.id);
+        }
+        self.guild_contents.insert(guild.id, index);
+    }
+
+    fn insert_guild_name(&self, guild: &Guild) {
+        insert_id(&mut self.guilds.entry(index_key(&guild.name)).or_default(), guild.id);
+    }
+
+    fn remove_guild_name(&self, guild: &Guild) {
+        let key = index_key(&guild.name);
+        if let Some(mut ids) = self.guilds.get_mut(&key) {
+            ids.retain(|id| *id != guild.id);
+        }
+        self.guilds.remove_if(&key, |_, ids| ids.is_empty());
+    }
+
+    fn update_guild(&self, old: Option<&Guild>, new: Option<&Guild>) {
+        if let Some(old) = old {
+            self.remove_guild_name(old);
+        }
+
+        if let Some(new) = new {
+            self.index_guild(new);
+        } else if let Some(old) = old {
+            self.guild_contents.remove(&old.id);
+        }
+    }
+
+    fn update_channel(&self, old: Option<&GuildChannel>, new: Option<&GuildChannel>) {
+        let Some(guild_id) = old.or(new).map(|channel| channel.guild_id) else { return };
+        let mut index = self.guild_contents.entry(guild_id).or_default();
+
+        if let Some(old) = old {
+            remove_id(&mut index.channels, &index_key(&old.name), &old.id);
+        }
+        if let Some(new) = new {
+            insert_id(index.channels.entry(index_key(&new.name)).or_default(), new.id);
+        }
+    }
+
+    fn update_member(&self, old: Option<&Member>, new: Option<&Member>) {
+        let Some(guild_id) = old.or(new).map(|member| member.guild_id) else { return };
+        let mut index = self.guild_contents.entry(guild_id).or_default();
+
+        if let Some(old) = old {
+            let key = index_key(&old.user.name);
+            if index.members.get(&key) == Some(&old.user.id) {
+                index.members.remove(&key);
+            }
+        }
+        if let Some(new) = new {
+            index.members.insert(index_key(&new.user.name), new.user.id);
+        }
+    }
+}
+
+impl CacheUpdateHook for NameIndex {
+    fn on_update(
+        &self,
+        kind: CacheEntityKind,
+        old: Option<CacheEntity<'_>>,
+        new: Option<CacheEntity<'_>>,
+    ) {
+        match kind {
+            CacheEntityKind::Guild => {
+                let as_guild = |entity| match entity {
+                    CacheEntity::Guild(guild) => Some(guild),
+                    _ => None,
+                };
+                self.update_guild(old.and_then(as_guild), new.and_then(as_guild));
+            },
+            CacheEntityKind::Channel => {
+                let as_channel = |entity| match entity {
+                    CacheEntity::Channel(channel) => Some(channel),
+                    _ => None,
+                };
+                self.update_channel(old.and_then(as_channel), new.and_then(as_channel));
+            },
+            CacheEntityKind::Member => {
+                let as_member = |entity| match entity {
+                    CacheEntity::Member(member) => Some(member),
+                    _ => None,
+                };
+                self.update_member(old.and_then(as_member), new.and_then(as_member));
+            },
+            _ => {},
+        }
+    }
+}
+
+#[cfg(feature = "typesize")]
+impl typesize::TypeSize for NameIndex {}
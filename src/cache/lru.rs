@@ -0,0 +1,94 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use parking_lot::Mutex;
+
+/// Tracks the order in which the keys of a cached resource were last inserted or updated, to
+/// evict the least recently updated entries once a limit is exceeded.
+#[derive(Debug)]
+pub(crate) struct LruTracker<K> {
+    inner: Mutex<LruInner<K>>,
+}
+
+#[derive(Debug)]
+struct LruInner<K> {
+    /// The last stamp of each key.
+    stamps: HashMap<K, u64>,
+    /// The keys ordered by their last stamp, oldest first.
+    order: BTreeMap<u64, K>,
+    next_stamp: u64,
+}
+
+impl<K: Copy + Eq + Hash> LruTracker<K> {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(LruInner {
+                stamps: HashMap::new(),
+                order: BTreeMap::new(),
+                next_stamp: 0,
+            }),
+        }
+    }
+
+    /// Marks the given keys as most recently used, returning the least recently used keys which
+    /// exceed the limit and must be evicted.
+    pub fn touch(&self, keys: impl IntoIterator<Item = K>, max: usize) -> Vec<K> {
+        let mut inner = self.inner.lock();
+
+        for key in keys {
+            let stamp = inner.next_stamp;
+            inner.next_stamp += 1;
+            if let Some(old) = inner.stamps.insert(key, stamp) {
+                inner.order.remove(&old);
+            }
+            inner.order.insert(stamp, key);
+        }
+
+        let excess = inner.stamps.len().saturating_sub(max);
+        let mut evicted = Vec::with_capacity(excess);
+        for _ in 0..excess {
+            let Some((_, key)) = inner.order.pop_first() else { break };
+            inner.stamps.remove(&key);
+            evicted.push(key);
+        }
+        evicted
+    }
+
+    /// Stops tracking the given key, e.g. because it was removed from the cache.
+    pub fn remove(&self, key: &K) {
+        let mut inner = self.inner.lock();
+        if let Some(stamp) = inner.stamps.remove(key) {
+            inner.order.remove(&stamp);
+        }
+    }
+
+    /// Stops tracking all keys matching the predicate.
+    pub fn remove_where(&self, mut predicate: impl FnMut(&K) -> bool) {
+        let mut inner = self.inner.lock();
+        let LruInner {
+            stamps,
+            order,
+            ..
+        } = &mut *inner;
+        stamps.retain(|key, stamp| {
+            let remove = predicate(key);
+            if remove {
+                order.remove(stamp);
+            }
+            !remove
+        });
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.inner.lock().stamps.len()
+    }
+}
+
+#[cfg(feature = "typesize")]
+impl<K: typesize::TypeSize> typesize::TypeSize for LruTracker<K> {
+    fn extra_size(&self) -> usize {
+        let inner = self.inner.lock();
+        inner.stamps.extra_size() + inner.order.len() * std::mem::size_of::<(u64, K)>()
+    }
+}
@@ -0,0 +1,74 @@
+use super::CacheUpdateHook;
+use crate::model::prelude::*;
+
+/// A write-mirror of the in-memory [`Cache`], e.g. into Redis or an external state service.
+///
+/// A mirror is not a storage backend: the cache always stores its state in memory, and all
+/// accessors read from there. A mirror is a [`CacheUpdateHook`] which receives every write to the
+/// cache, and can additionally be asked for guilds, users and messages which are missing from
+/// memory, e.g. after a restart or after they were evicted due to the cache [`Settings`] limits.
+/// No other accessors consult it, and entities it returns are not inserted back into memory.
+///
+/// Lookups are synchronous, as they happen from within the synchronous cache accessors. Mirrors
+/// talking to a remote service should therefore keep a local buffer or use a blocking client with
+/// a short timeout.
+///
+/// Register a mirror with [`Cache::set_mirror`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use std::sync::RwLock;
+///
+/// use serenity::cache::{Cache, CacheEntity, CacheEntityKind, CacheMirror, CacheUpdateHook};
+/// use serenity::model::prelude::*;
+///
+/// #[derive(Default)]
+/// struct UserStore(RwLock<HashMap<UserId, User>>);
+///
+/// impl CacheUpdateHook for UserStore {
+///     fn on_update(
+///         &self,
+///         _: CacheEntityKind,
+///         _: Option<CacheEntity<'_>>,
+///         new: Option<CacheEntity<'_>>,
+///     ) {
+///         if let Some(CacheEntity::User(user)) = new {
+///             self.0.write().unwrap().insert(user.id, user.clone());
+///         }
+///     }
+/// }
+///
+/// impl CacheMirror for UserStore {
+///     fn user(&self, user_id: UserId) -> Option<User> {
+///         self.0.read().unwrap().get(&user_id).cloned()
+///     }
+/// }
+///
+/// let cache = Cache::new();
+/// cache.set_mirror(UserStore::default());
+/// ```
+///
+/// [`Cache`]: super::Cache
+/// [`Cache::set_mirror`]: super::Cache::set_mirror
+/// [`Settings`]: super::Settings
+pub trait CacheMirror: CacheUpdateHook {
+    /// Loads a guild which is not held in memory.
+    fn guild(&self, guild_id: GuildId) -> Option<Guild> {
+        let _ = guild_id;
+        None
+    }
+
+    /// Loads a user which is not held in memory.
+    fn user(&self, user_id: UserId) -> Option<User> {
+        let _ = user_id;
+        None
+    }
+
+    /// Loads a message which is not held in memory.
+    fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
+        let _ = (channel_id, message_id);
+        None
+    }
+}
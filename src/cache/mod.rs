@@ -13,6 +13,16 @@
 //! the permissions of the current user. This is an inexpensive method of being able to access data
 //! required by these sugary methods.
 //!
+//! # Concurrency
+//!
+//! Guilds, channels, users and messages are stored in sharded concurrent maps, so events for
+//! different guilds update the cache in parallel instead of contending on a single lock. Only
+//! small, rarely written data, such as the current user and the settings, is kept behind a lock.
+//!
+//! Lookups return a [`CacheRef`], which holds a read lock on a single shard of a map for as long
+//! as it is alive. Clone the data you need and drop the reference quickly; in particular, never
+//! hold one across an `.await`.
+//!
 //! # Do I need the Cache?
 //!
 //! If you're asking this, the answer is likely "definitely yes" or "definitely no"; any in-between
@@ -26,7 +36,6 @@
 
 use std::collections::{HashSet, VecDeque};
 use std::hash::Hash;
-#[cfg(feature = "temp_cache")]
 use std::sync::Arc;
 #[cfg(feature = "temp_cache")]
 use std::time::Duration;
@@ -40,14 +49,28 @@ use parking_lot::RwLock;
 use tracing::instrument;
 
 pub use self::cache_update::CacheUpdate;
+pub use self::hook::{CacheEntity, CacheEntityKind, CacheUpdateHook};
+pub use self::mirror::CacheMirror;
 pub use self::settings::Settings;
+pub use self::stats::{CacheStats, ResourceStats};
+pub use self::unread::{ChannelUnread, UnreadTracker};
 use crate::model::prelude::*;
 
 mod cache_update;
 mod event;
+mod hook;
+mod index;
+mod lru;
+mod mirror;
 mod settings;
+mod snapshot;
+mod stats;
+mod unread;
 mod wrappers;
 
+use hook::UpdateHooks;
+use index::{index_key, NameIndex};
+use lru::LruTracker;
 #[cfg(feature = "temp_cache")]
 pub(crate) use wrappers::MaybeOwnedArc;
 use wrappers::{BuildHasher, MaybeMap, ReadOnlyMapRef};
@@ -59,11 +82,40 @@ struct NotSend;
 enum CacheRefInner<'a, K, V, T> {
     #[cfg(feature = "temp_cache")]
     Arc(Arc<V>),
+    // Entities loaded from a `CacheMirror`, which are not held in memory.
+    Owned(Box<V>),
     DashRef(Ref<'a, K, V, BuildHasher>),
     DashMappedRef(MappedRef<'a, K, T, V, BuildHasher>),
     ReadGuard(parking_lot::RwLockReadGuard<'a, V>),
 }
 
+/// A short-lived reference to data stored in the [`Cache`].
+///
+/// This holds a read lock on the part of the cache the data is stored in, which blocks cache
+/// updates touching the same part until the reference is dropped. To discourage holding it for
+/// long, it does not implement [`Send`], so it can not be held across an `.await` in a spawned
+/// task:
+///
+/// ```rust,compile_fail
+/// # use serenity::cache::Cache;
+/// # use serenity::model::id::GuildId;
+/// fn assert_send<T: Send>(_: T) {}
+///
+/// # fn run(cache: &Cache) {
+/// let guild = cache.guild(GuildId::new(1)).unwrap();
+/// assert_send(guild);
+/// # }
+/// ```
+///
+/// Instead, clone the needed data out of it:
+///
+/// ```rust,no_run
+/// # use serenity::cache::Cache;
+/// # use serenity::model::id::GuildId;
+/// # fn run(cache: &Cache) {
+/// let name = cache.guild(GuildId::new(1)).map(|guild| guild.name.clone());
+/// # }
+/// ```
 pub struct CacheRef<'a, K, V, T = ()> {
     inner: CacheRefInner<'a, K, V, T>,
     phantom: std::marker::PhantomData<*const NotSend>,
@@ -82,6 +134,10 @@ impl<'a, K, V, T> CacheRef<'a, K, V, T> {
         Self::new(CacheRefInner::Arc(inner.get_inner()))
     }
 
+    fn from_owned(inner: V) -> Self {
+        Self::new(CacheRefInner::Owned(Box::new(inner)))
+    }
+
     fn from_ref(inner: Ref<'a, K, V, BuildHasher>) -> Self {
         Self::new(CacheRefInner::DashRef(inner))
     }
@@ -102,6 +158,7 @@ impl<K: Eq + Hash, V, T> std::ops::Deref for CacheRef<'_, K, V, T> {
         match &self.inner {
             #[cfg(feature = "temp_cache")]
             CacheRefInner::Arc(inner) => inner,
+            CacheRefInner::Owned(inner) => inner,
             CacheRefInner::DashRef(inner) => inner.value(),
             CacheRefInner::DashMappedRef(inner) => inner.value(),
             CacheRefInner::ReadGuard(inner) => inner,
@@ -122,6 +179,8 @@ pub type GuildRolesRef<'a> = MappedGuildRef<'a, HashMap<RoleId, Role>>;
 pub type GuildChannelsRef<'a> = MappedGuildRef<'a, HashMap<ChannelId, GuildChannel>>;
 pub type ChannelMessagesRef<'a> = CacheRef<'a, ChannelId, HashMap<MessageId, Message>>;
 pub type MessageRef<'a> = CacheRef<'a, ChannelId, Message, HashMap<MessageId, Message>>;
+pub type GuildInvitesRef<'a> = CacheRef<'a, GuildId, HashMap<String, CachedInvite>>;
+pub type RelationshipRef<'a> = CacheRef<'a, UserId, Relationship>;
 
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Debug)]
@@ -131,6 +190,51 @@ pub(crate) struct CachedShardData {
     pub has_sent_shards_ready: bool,
 }
 
+/// An invite tracked by the cache, used to attribute member joins to the invite they most likely
+/// used.
+///
+/// Invites are added via [`InviteCreateEvent`]s and removed via [`InviteDeleteEvent`]s. Since the
+/// gateway does not send use counts, the counts are refreshed via [`Cache::attribute_join`].
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CachedInvite {
+    /// The unique code for the invite.
+    pub code: String,
+    /// The channel the invite points to.
+    pub channel_id: ChannelId,
+    /// The user that created the invite.
+    pub inviter: Option<User>,
+    /// The amount of times that the invite has been used.
+    pub uses: u64,
+    /// The maximum number of times that the invite may be used. `0` means unlimited.
+    pub max_uses: u8,
+}
+
+impl From<&InviteCreateEvent> for CachedInvite {
+    fn from(event: &InviteCreateEvent) -> Self {
+        Self {
+            code: event.code.clone(),
+            channel_id: event.channel_id,
+            inviter: event.inviter.clone(),
+            uses: event.uses,
+            max_uses: event.max_uses,
+        }
+    }
+}
+
+impl From<&RichInvite> for CachedInvite {
+    fn from(invite: &RichInvite) -> Self {
+        Self {
+            code: invite.code.clone(),
+            channel_id: invite.channel.id,
+            inviter: invite.inviter.clone(),
+            uses: invite.uses,
+            max_uses: invite.max_uses,
+        }
+    }
+}
+
 /// A cache containing data received from [`Shard`]s.
 ///
 /// Using the cache allows to avoid REST API requests via the [`http`] module where possible.
@@ -139,11 +243,20 @@ pub(crate) struct CachedShardData {
 /// This is the list of cached resources and the events that populate them:
 /// - channels: [`ChannelCreateEvent`], [`ChannelUpdateEvent`], [`GuildCreateEvent`]
 /// - guilds: [`GuildCreateEvent`]
+/// - threads: [`GuildCreateEvent`], [`ThreadCreateEvent`], [`ThreadUpdateEvent`],
+///   [`ThreadDeleteEvent`], [`ThreadListSyncEvent`], [`ThreadMemberUpdateEvent`],
+///   [`ThreadMembersUpdateEvent`]
 /// - unavailable_guilds: [`ReadyEvent`], [`GuildDeleteEvent`]
 /// - users: [`GuildMemberAddEvent`], [`GuildMemberRemoveEvent`], [`GuildMembersChunkEvent`],
 ///   [`PresenceUpdateEvent`], [`ReadyEvent`]
 /// - presences: [`PresenceUpdateEvent`], [`ReadyEvent`]
-/// - messages: [`MessageCreateEvent`]
+/// - messages: [`MessageCreateEvent`], [`MessageUpdateEvent`], [`MessageDeleteEvent`],
+///   [`MessageDeleteBulkEvent`]
+/// - invites: [`InviteCreateEvent`], [`InviteDeleteEvent`], [`GuildDeleteEvent`]
+/// - relationships: [`ReadyEvent`], [`RelationshipAddEvent`], [`RelationshipUpdateEvent`],
+///   [`RelationshipRemoveEvent`]
+/// - unreads: [`ReadyEvent`], [`MessageCreateEvent`], [`MessageAckEvent`]
+/// - sessions: [`ReadyEvent`]
 ///
 /// The documentation of each event contains the required gateway intents.
 ///
@@ -175,6 +288,11 @@ pub struct Cache {
     /// The TTL for each value is configured in CacheSettings.
     #[cfg(feature = "temp_cache")]
     pub(crate) temp_users: MokaCache<UserId, MaybeOwnedArc<User>, BuildHasher>,
+    /// Cache of members who have been fetched from `GuildId::member`.
+    ///
+    /// The TTL for each value is configured in CacheSettings.
+    #[cfg(feature = "temp_cache")]
+    pub(crate) temp_members: MokaCache<(GuildId, UserId), MaybeOwnedArc<Member>, BuildHasher>,
 
     // Channels cache:
     /// A map of channel ids to the guilds in which the channel data is stored.
@@ -190,6 +308,9 @@ pub struct Cache {
     /// Additionally, guilds are always unavailable for bot users when a Ready is received. Guilds
     /// are "sent in" over time through the receiving of [`Event::GuildCreate`]s.
     pub(crate) unavailable_guilds: MaybeMap<GuildId, ()>,
+    /// The amount of received member chunks of each pending chunk request, keyed by guild and
+    /// nonce.
+    pub(crate) member_chunks: DashMap<(GuildId, Option<String>), u32, BuildHasher>,
 
     // Users cache:
     // ---
@@ -207,6 +328,36 @@ pub struct Cache {
     /// [`GuildMemberRemove`][`GuildMemberRemoveEvent`], as other structs such as members or
     /// recipients may still exist.
     pub(crate) users: MaybeMap<UserId, User>,
+    /// The update order of [`Self::users`], if [`Settings::max_users`] is set.
+    pub(crate) users_lru: LruTracker<UserId>,
+    /// The update order of the members of all guilds, if [`Settings::max_members`] is set.
+    pub(crate) members_lru: LruTracker<(GuildId, UserId)>,
+    /// The update order of the presences of all guilds, if [`Settings::max_presences`] is set.
+    pub(crate) presences_lru: LruTracker<(GuildId, UserId)>,
+
+    // Invites cache:
+    // ---
+    /// A map of guilds to their known invites, keyed by invite code.
+    pub(crate) invites: MaybeMap<GuildId, HashMap<String, CachedInvite>>,
+
+    // Relationships cache:
+    // ---
+    /// A map of the current user's relationships, keyed by the Id of the other user.
+    ///
+    /// Only populated for user accounts.
+    pub(crate) relationships: MaybeMap<UserId, Relationship>,
+
+    // Unreads cache:
+    // ---
+    /// A map of channels to their unread state, see [`Cache::unreads`].
+    pub(crate) unreads: MaybeMap<ChannelId, ChannelUnread>,
+
+    // Sessions cache:
+    // ---
+    /// The gateway sessions of the current user, as received on connect.
+    ///
+    /// Only populated for user accounts.
+    pub(crate) sessions: RwLock<Vec<GatewaySession>>,
 
     // Messages cache:
     // ---
@@ -217,6 +368,9 @@ pub struct Cache {
     /// cache. When a maximum number of messages are in a channel's cache, we can pop the front and
     /// remove that ID from the cache.
     pub(crate) message_queue: DashMap<ChannelId, VecDeque<MessageId>, BuildHasher>,
+    /// The previous revisions of edited messages, oldest first, up to
+    /// [`Settings::max_message_revisions`] each.
+    pub(crate) message_revisions: DashMap<MessageId, VecDeque<Message>, BuildHasher>,
 
     // Miscellanous fixed-size data
     // ---
@@ -229,6 +383,11 @@ pub struct Cache {
     ///
     /// Refer to the documentation for [`CurrentUser`] for more information.
     pub(crate) user: RwLock<CurrentUser>,
+    /// The hooks notified of changes to the cache, see [`Cache::add_update_hook`].
+    pub(crate) update_hooks: UpdateHooks,
+    /// The name indexes, if [`Settings::index_names`] is set. Registered in
+    /// [`Self::update_hooks`].
+    pub(crate) name_index: Option<NameIndex>,
     /// The settings for the cache.
     settings: RwLock<Settings>,
 }
@@ -264,6 +423,12 @@ impl Cache {
             MokaCache::builder().time_to_live(ttl).build_with_hasher(BuildHasher::default())
         }
 
+        let update_hooks = UpdateHooks::default();
+        let name_index = settings.index_names.then(NameIndex::default);
+        if let Some(name_index) = &name_index {
+            update_hooks.add(Arc::new(name_index.clone()));
+        }
+
         Self {
             #[cfg(feature = "temp_cache")]
             temp_private_channels: temp_cache(settings.time_to_live),
@@ -273,16 +438,31 @@ impl Cache {
             temp_messages: temp_cache(settings.time_to_live),
             #[cfg(feature = "temp_cache")]
             temp_users: temp_cache(settings.time_to_live),
+            #[cfg(feature = "temp_cache")]
+            temp_members: temp_cache(settings.time_to_live),
 
             channels: MaybeMap(settings.cache_channels.then(DashMap::default)),
 
             guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
             unavailable_guilds: MaybeMap(settings.cache_guilds.then(DashMap::default)),
+            member_chunks: DashMap::default(),
 
             users: MaybeMap(settings.cache_users.then(DashMap::default)),
+            users_lru: LruTracker::new(),
+            members_lru: LruTracker::new(),
+            presences_lru: LruTracker::new(),
+
+            invites: MaybeMap(settings.cache_guilds.then(DashMap::default)),
+
+            relationships: MaybeMap(settings.cache_users.then(DashMap::default)),
+
+            unreads: MaybeMap(settings.cache_channels.then(DashMap::default)),
+
+            sessions: RwLock::new(Vec::new()),
 
             messages: DashMap::default(),
             message_queue: DashMap::default(),
+            message_revisions: DashMap::default(),
 
             shard_data: RwLock::new(CachedShardData {
                 total: 1,
@@ -290,6 +470,8 @@ impl Cache {
                 has_sent_shards_ready: false,
             }),
             user: RwLock::new(CurrentUser::default()),
+            update_hooks,
+            name_index,
             settings: RwLock::new(settings),
         }
     }
@@ -436,7 +618,11 @@ impl Cache {
     }
 
     fn _guild(&self, id: GuildId) -> Option<GuildRef<'_>> {
-        self.guilds.get(&id).map(CacheRef::from_ref)
+        if let Some(guild) = self.guilds.get(&id) {
+            return Some(CacheRef::from_ref(guild));
+        }
+
+        self.update_hooks.mirror()?.guild(id).map(CacheRef::from_owned)
     }
 
     /// Returns the number of cached guilds.
@@ -444,6 +630,97 @@ impl Cache {
         self.guilds.len()
     }
 
+    /// Retrieves a cached [`Guild`] by its name, ignoring case.
+    ///
+    /// If several guilds share the name, any of them is returned. This is a linear scan unless
+    /// [`Settings::index_names`] is enabled.
+    pub fn guild_by_name(&self, name: &str) -> Option<GuildRef<'_>> {
+        let key = index_key(name);
+        let guild_id = if let Some(index) = &self.name_index {
+            index.guilds(name).into_iter().find(|guild_id| {
+                self.guilds.get(guild_id).is_some_and(|guild| index_key(&guild.name) == key)
+            })
+        } else {
+            self.guilds.iter().find(|guild| index_key(&guild.name) == key).map(|guild| *guild.key())
+        }?;
+
+        self.guild(guild_id)
+    }
+
+    /// Retrieves a channel or thread of a cached guild by its name, ignoring case.
+    ///
+    /// If several channels share the name, any of them is returned. This is a linear scan unless
+    /// [`Settings::index_names`] is enabled.
+    pub fn channel_by_name(
+        &self,
+        guild_id: impl Into<GuildId>,
+        name: &str,
+    ) -> Option<GuildChannelRef<'_>> {
+        fn find(guild: &Guild, channel_id: ChannelId) -> Option<&GuildChannel> {
+            guild
+                .channels
+                .get(&channel_id)
+                .or_else(|| guild.threads.iter().find(|thread| thread.id == channel_id))
+        }
+
+        let guild_id = guild_id.into();
+        let key = index_key(name);
+        let guild = self.guilds.get(&guild_id)?;
+        let channel_id = if let Some(index) = &self.name_index {
+            index.channels(guild_id, name).into_iter().find(|channel_id| {
+                find(&guild, *channel_id).is_some_and(|channel| index_key(&channel.name) == key)
+            })
+        } else {
+            let mut channels = guild.channels.values().chain(&guild.threads);
+            channels.find(|channel| index_key(&channel.name) == key).map(|channel| channel.id)
+        }?;
+
+        let channel = guild.try_map(|guild| find(guild, channel_id)).ok()?;
+        Some(CacheRef::from_mapped_ref(channel))
+    }
+
+    /// Retrieves a member of a cached guild by their username, ignoring case.
+    ///
+    /// This is a linear scan unless [`Settings::index_names`] is enabled.
+    pub fn member_by_username(
+        &self,
+        guild_id: impl Into<GuildId>,
+        username: &str,
+    ) -> Option<MemberRef<'_>> {
+        let guild_id = guild_id.into();
+        let key = index_key(username);
+        let guild = self.guilds.get(&guild_id)?;
+        let user_id = if let Some(index) = &self.name_index {
+            index.member(guild_id, username).filter(|user_id| {
+                guild.members.get(user_id).is_some_and(|m| index_key(&m.user.name) == key)
+            })
+        } else {
+            guild.members.values().find(|m| index_key(&m.user.name) == key).map(|m| m.user.id)
+        }?;
+
+        let member = guild.try_map(|guild| guild.members.get(&user_id)).ok()?;
+        Some(CacheRef::from_mapped_ref(member))
+    }
+
+    /// Returns the amount of cached entities per resource and, with the `typesize` feature, their
+    /// approximate memory usage.
+    ///
+    /// This iterates the whole cache, so it should not be called in a hot path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # fn run(cache: &Cache) {
+    /// let stats = cache.stats();
+    /// println!("{} members and {} presences", stats.members.count, stats.presences.count);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats::collect(self)
+    }
+
     /// Retrieves a [`Guild`]'s member from the cache based on the guild's and user's given Ids.
     ///
     /// # Examples
@@ -497,6 +774,18 @@ impl Cache {
         self._member(guild_id.into(), user_id.into())
     }
 
+    #[cfg(feature = "temp_cache")]
+    fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<MemberRef<'_>> {
+        let member =
+            self.guilds.get(&guild_id).and_then(|g| g.try_map(|g| g.members.get(&user_id)).ok());
+        if let Some(member) = member {
+            Some(CacheRef::from_mapped_ref(member))
+        } else {
+            self.temp_members.get(&(guild_id, user_id)).map(CacheRef::from_arc)
+        }
+    }
+
+    #[cfg(not(feature = "temp_cache"))]
     fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<MemberRef<'_>> {
         let member = self.guilds.get(&guild_id)?.try_map(|g| g.members.get(&user_id)).ok()?;
         Some(CacheRef::from_mapped_ref(member))
@@ -519,6 +808,29 @@ impl Cache {
         self.unavailable_guilds.as_read_only()
     }
 
+    /// Whether a [`Ready`] was received and all guilds it listed have been received since, i.e.
+    /// whether [`EventHandler::cache_ready`] was dispatched.
+    ///
+    /// [`EventHandler::cache_ready`]: crate::client::EventHandler::cache_ready
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        !self.shard_data.read().connected.is_empty() && self.unavailable_guilds.len() == 0
+    }
+
+    /// Whether all members of the given guild are cached, either because the guild was small
+    /// enough for all of them to be sent on connect or because they were requested via
+    /// [`Shard::chunk_guild`].
+    ///
+    /// Returns `false` if the guild is not cached.
+    ///
+    /// [`Shard::chunk_guild`]: crate::gateway::Shard::chunk_guild
+    #[must_use]
+    pub fn is_chunked(&self, guild_id: impl Into<GuildId>) -> bool {
+        self.guilds
+            .get(&guild_id.into())
+            .is_some_and(|guild| guild.members.len() as u64 >= guild.member_count)
+    }
+
     /// This method returns all channels from a guild of with the given `guild_id`.
     #[inline]
     #[deprecated = "Use Cache::guild and Guild::channels instead"]
@@ -580,9 +892,43 @@ impl Cache {
             return Some(CacheRef::from_arc(message));
         }
 
-        let channel_messages = self.messages.get(&channel_id)?;
-        let message = channel_messages.try_map(|messages| messages.get(&message_id)).ok()?;
-        Some(CacheRef::from_mapped_ref(message))
+        if let Some(channel_messages) = self.messages.get(&channel_id) {
+            if let Ok(message) = channel_messages.try_map(|messages| messages.get(&message_id)) {
+                return Some(CacheRef::from_mapped_ref(message));
+            }
+        }
+
+        self.update_hooks.mirror()?.message(channel_id, message_id).map(CacheRef::from_owned)
+    }
+
+    /// Returns the previous revisions of a cached message, oldest first, not including its current
+    /// state returned by [`Self::message`].
+    ///
+    /// Revisions are only kept if [`Settings::max_message_revisions`] is set, and are dropped
+    /// together with the message.
+    pub fn message_history(
+        &self,
+        channel_id: impl Into<ChannelId>,
+        message_id: impl Into<MessageId>,
+    ) -> Vec<Message> {
+        let channel_id = channel_id.into();
+        let Some(revisions) = self.message_revisions.get(&message_id.into()) else {
+            return Vec::new();
+        };
+
+        revisions.iter().filter(|message| message.channel_id == channel_id).cloned().collect()
+    }
+
+    pub(crate) fn remove_message_revisions<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a MessageId>,
+    ) {
+        if self.message_revisions.is_empty() {
+            return;
+        }
+        for id in ids {
+            self.message_revisions.remove(id);
+        }
     }
 
     /// Retrieves a [`Guild`]'s role by their Ids.
@@ -674,14 +1020,20 @@ impl Cache {
     fn _user(&self, user_id: UserId) -> Option<UserRef<'_>> {
         if let Some(user) = self.users.get(&user_id) {
             Some(CacheRef::from_ref(user))
+        } else if let Some(user) = self.temp_users.get(&user_id) {
+            Some(CacheRef::from_arc(user))
         } else {
-            self.temp_users.get(&user_id).map(CacheRef::from_arc)
+            self.update_hooks.mirror()?.user(user_id).map(CacheRef::from_owned)
         }
     }
 
     #[cfg(not(feature = "temp_cache"))]
     fn _user(&self, user_id: UserId) -> Option<UserRef<'_>> {
-        self.users.get(&user_id).map(CacheRef::from_ref)
+        if let Some(user) = self.users.get(&user_id) {
+            return Some(CacheRef::from_ref(user));
+        }
+
+        self.update_hooks.mirror()?.user(user_id).map(CacheRef::from_owned)
     }
 
     /// Clones all users and returns them.
@@ -696,6 +1048,181 @@ impl Cache {
         self.users.len()
     }
 
+    /// Retrieves the current user's [`Relationship`] with the given user, if it exists.
+    pub fn relationship(&self, user_id: impl Into<UserId>) -> Option<RelationshipRef<'_>> {
+        self.relationships.get(&user_id.into()).map(CacheRef::from_ref)
+    }
+
+    /// Returns all relationships of the current user, keyed by the Id of the other user.
+    #[inline]
+    pub fn relationships(&self) -> ReadOnlyMapRef<'_, UserId, Relationship> {
+        self.relationships.as_read_only()
+    }
+
+    /// Returns the gateway sessions of the current user, i.e. its connected clients, as received in
+    /// the last [`Ready`].
+    #[must_use]
+    pub fn sessions(&self) -> Vec<GatewaySession> {
+        self.sessions.read().clone()
+    }
+
+    /// Returns a view of the unread state of channels, combining the read states received on
+    /// connect with the messages and acknowledgements received since.
+    ///
+    /// Only tracked for user accounts.
+    #[inline]
+    #[must_use]
+    pub fn unreads(&self) -> UnreadTracker<'_> {
+        UnreadTracker::new(self)
+    }
+
+    /// Registers a hook which is notified whenever the cache inserts, updates or removes an
+    /// entity. See [`CacheUpdateHook`] for more info.
+    pub fn add_update_hook(&self, hook: impl CacheUpdateHook + 'static) {
+        self.update_hooks.add(Arc::new(hook));
+    }
+
+    /// Sets the mirror receiving every write to the cache, replacing any previously set one. See
+    /// [`CacheMirror`] for what it can and can't do.
+    pub fn set_mirror(&self, mirror: impl CacheMirror + 'static) {
+        self.update_hooks.set_mirror(Arc::new(mirror));
+    }
+
+    /// Writes a snapshot of the cache as JSON into the given writer, to be loaded again with
+    /// [`Self::restore_from`].
+    ///
+    /// The snapshot contains the current user, guilds, users, relationships, unread states and
+    /// cached messages. Transient data such as invites, gateway sessions and shard info is left
+    /// out.
+    ///
+    /// # Examples
+    ///
+    /// Persisting the cache before shutting down:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # fn run(cache: &Cache) -> serenity::Result<()> {
+    /// let file = std::fs::File::create("cache.json")?;
+    /// cache.serialize_to(std::io::BufWriter::new(file))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] or [`Error::Json`] if writing the snapshot failed.
+    ///
+    /// [`Error::Io`]: crate::Error::Io
+    /// [`Error::Json`]: crate::Error::Json
+    pub fn serialize_to(&self, writer: impl std::io::Write) -> Result<()> {
+        snapshot::serialize_to(self, writer)
+    }
+
+    /// Loads a snapshot written by [`Self::serialize_to`] into the cache, overwriting entries
+    /// which are already cached.
+    ///
+    /// This is meant to be called before the client connects, so a process can resume its gateway
+    /// session without starting with a cold cache. The cache [`Settings`] still apply, so
+    /// resources which are disabled or over their limit are not restored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # fn run() -> serenity::Result<()> {
+    /// let cache = Cache::new();
+    /// let file = std::fs::File::open("cache.json")?;
+    /// cache.restore_from(std::io::BufReader::new(file))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] or [`Error::Json`] if the snapshot could not be read or is invalid.
+    ///
+    /// [`Error::Io`]: crate::Error::Io
+    /// [`Error::Json`]: crate::Error::Json
+    pub fn restore_from(&self, reader: impl std::io::Read) -> Result<()> {
+        snapshot::restore_from(self, reader)
+    }
+
+    /// Returns the invites of the given guild known to the cache, keyed by invite code.
+    pub fn guild_invites(&self, guild_id: impl Into<GuildId>) -> Option<GuildInvitesRef<'_>> {
+        self.invites.get(&guild_id.into()).map(CacheRef::from_ref)
+    }
+
+    /// Compares a fresh list of a guild's invites against the cached use counts and returns the
+    /// invite which was most likely used by a member that just joined. The cached invites of the
+    /// guild are replaced with the given list afterwards.
+    ///
+    /// The invite whose use count increased the most is picked. If no use count increased, a
+    /// cached invite that is missing from the given list and was one use away from its limit is
+    /// picked instead, as Discord deletes invites once they run out of uses.
+    ///
+    /// Returns [`None`] if the guild had no cached invites to compare against, in which case the
+    /// given list only seeds the cache for the next join.
+    ///
+    /// See [`Context::resolve_join_invite`] for a version of this which fetches the invites.
+    ///
+    /// [`Context::resolve_join_invite`]: crate::client::Context::resolve_join_invite
+    pub fn attribute_join(
+        &self,
+        guild_id: impl Into<GuildId>,
+        current: impl IntoIterator<Item = CachedInvite>,
+    ) -> Option<CachedInvite> {
+        let guild_id = guild_id.into();
+        let current: HashMap<String, CachedInvite> =
+            current.into_iter().map(|invite| (invite.code.clone(), invite)).collect();
+
+        let previous = self.invites.insert(guild_id, current.clone())?;
+
+        let increased = current
+            .values()
+            .filter_map(|invite| {
+                let old = previous.get(&invite.code).map_or(0, |old| old.uses);
+                invite.uses.checked_sub(old).filter(|delta| *delta > 0).map(|d| (d, invite))
+            })
+            .max_by_key(|(delta, _)| *delta)
+            .map(|(_, invite)| invite.clone());
+
+        increased.or_else(|| {
+            previous.into_values().find(|invite| {
+                !current.contains_key(&invite.code)
+                    && invite.max_uses != 0
+                    && invite.uses + 1 >= u64::from(invite.max_uses)
+            })
+        })
+    }
+
+    /// Removes a guild and everything cached about it - its channels and threads, their messages,
+    /// and the guild's invites - from the cache, returning the removed guild.
+    ///
+    /// This can be used to drop a guild whose cached state is suspected to be corrupt. Refer to
+    /// [`Context::rebuild_guild_cache`] to fetch it again afterwards.
+    ///
+    /// [`Context::rebuild_guild_cache`]: crate::client::Context::rebuild_guild_cache
+    pub fn clear_guild(&self, guild_id: impl Into<GuildId>) -> Option<Guild> {
+        let guild_id = guild_id.into();
+        self.invites.remove(&guild_id);
+
+        self.members_lru.remove_where(|(g, _)| *g == guild_id);
+        self.presences_lru.remove_where(|(g, _)| *g == guild_id);
+        self.member_chunks.retain(|(g, _), _| *g != guild_id);
+
+        let (_, guild) = self.guilds.remove(&guild_id)?;
+        let thread_ids = guild.threads.iter().map(|thread| &thread.id);
+        for channel_id in guild.channels.keys().chain(thread_ids) {
+            self.channels.remove(channel_id);
+            if let Some((_, messages)) = self.messages.remove(channel_id) {
+                self.remove_message_revisions(messages.keys());
+            }
+            self.message_queue.remove(channel_id);
+        }
+
+        Some(guild)
+    }
+
     /// This method provides a reference to the user used by the bot.
     #[inline]
     pub fn current_user(&self) -> CurrentUserRef<'_> {
@@ -734,6 +1261,38 @@ impl Cache {
         )
     }
 
+    /// Clones the active threads of the given channel.
+    ///
+    /// Threads are tracked from the thread events and [`ThreadListSyncEvent`]s, so threads
+    /// created before the current user gained access to the channel are only known after the
+    /// next sync.
+    pub fn channel_threads(&self, channel_id: impl Into<ChannelId>) -> Vec<GuildChannel> {
+        self.threads_of_channel(channel_id.into(), false)
+    }
+
+    /// Clones the archived threads of the given channel which are known to the cache, i.e. the
+    /// threads which were archived while cached.
+    pub fn archived_threads(&self, channel_id: impl Into<ChannelId>) -> Vec<GuildChannel> {
+        self.threads_of_channel(channel_id.into(), true)
+    }
+
+    fn threads_of_channel(&self, channel_id: ChannelId, archived: bool) -> Vec<GuildChannel> {
+        let guild_id = self.channels.get(&channel_id).map(|guild_id| *guild_id).or_else(|| {
+            let mut guilds = self.guilds.iter();
+            guilds.find(|guild| guild.channels.contains_key(&channel_id)).map(|guild| guild.id)
+        });
+        let Some(guild_id) = guild_id else { return Vec::new() };
+        let Some(guild) = self.guilds.get(&guild_id) else { return Vec::new() };
+
+        guild
+            .threads
+            .iter()
+            .filter(|thread| thread.parent_id == Some(channel_id))
+            .filter(|thread| thread.thread_metadata.is_some_and(|m| m.archived) == archived)
+            .cloned()
+            .collect()
+    }
+
     /// Updates the cache with the update implementation for an event or other custom update
     /// implementation.
     ///
@@ -751,13 +1310,92 @@ impl Cache {
 
     pub(crate) fn update_user_entry(&self, user: &User) {
         if let Some(users) = &self.users.0 {
-            match users.entry(user.id) {
+            let hooks_active = self.update_hooks.is_active();
+            let old_user = match users.entry(user.id) {
                 Entry::Vacant(e) => {
                     e.insert(user.clone());
+                    None
                 },
                 Entry::Occupied(mut e) => {
+                    let old_user = hooks_active.then(|| e.get().clone());
                     e.get_mut().clone_from(user);
+                    old_user
                 },
+            };
+            if hooks_active {
+                self.update_hooks.notify(CacheEntity::User, old_user.as_ref(), Some(user));
+            }
+
+            let max = self.settings().max_users;
+            if let Some(max) = max {
+                for user_id in self.users_lru.touch([user.id], max) {
+                    users.remove(&user_id);
+                }
+            }
+        }
+    }
+
+    /// Removes the data of a guild which is disabled in the [`Settings`] before it is cached.
+    pub(crate) fn strip_guild(&self, guild: &mut Guild) {
+        let settings = self.settings();
+        if !settings.cache_presences {
+            guild.presences.clear();
+        }
+        if !settings.cache_voice_states {
+            guild.voice_states.clear();
+        }
+        if !settings.cache_emojis {
+            guild.emojis.clear();
+        }
+        if !settings.cache_stickers {
+            guild.stickers.clear();
+        }
+    }
+
+    /// Removes the content of a message before it is cached, if disabled in the [`Settings`].
+    pub(crate) fn strip_message(&self, message: &mut Message) {
+        if !self.settings().cache_message_content {
+            message.content.clear();
+            for snapshot in &mut message.message_snapshots {
+                snapshot.message.content.clear();
+            }
+        }
+    }
+
+    /// Marks the given members of a guild as recently updated, evicting the least recently updated
+    /// members if [`Settings::max_members`] is exceeded.
+    ///
+    /// Must not be called while holding a reference into [`Self::guilds`].
+    pub(crate) fn track_members(
+        &self,
+        guild_id: GuildId,
+        user_ids: impl IntoIterator<Item = UserId>,
+    ) {
+        let Some(max) = self.settings().max_members else { return };
+
+        let evicted = self.members_lru.touch(user_ids.into_iter().map(|id| (guild_id, id)), max);
+        for (guild_id, user_id) in evicted {
+            if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+                guild.members.remove(&user_id);
+            }
+        }
+    }
+
+    /// Marks the given presences of a guild as recently updated, evicting the least recently
+    /// updated presences if [`Settings::max_presences`] is exceeded.
+    ///
+    /// Must not be called while holding a reference into [`Self::guilds`].
+    pub(crate) fn track_presences(
+        &self,
+        guild_id: GuildId,
+        user_ids: impl IntoIterator<Item = UserId>,
+    ) {
+        let Some(max) = self.settings().max_presences else { return };
+
+        let evicted = self.presences_lru.touch(user_ids.into_iter().map(|id| (guild_id, id)), max);
+        for (guild_id, user_id) in evicted {
+            if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+                guild.presences.remove(&user_id);
             }
         }
     }
@@ -772,7 +1410,8 @@ impl Default for Cache {
 #[cfg(test)]
 mod test {
 
-    use crate::cache::{Cache, CacheUpdate, Settings};
+    use crate::cache::{Cache, CacheUpdate, CachedInvite, Settings};
+    use crate::json::{from_value, json};
     use crate::model::prelude::*;
 
     #[test]
@@ -855,5 +1494,861 @@ mod test {
 
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId::new(2)));
+        assert!(!cache.channels.contains(&ChannelId::new(2)));
+    }
+
+    #[test]
+    fn test_attribute_join() {
+        fn invite(code: &str, uses: u64, max_uses: u8) -> CachedInvite {
+            CachedInvite {
+                code: code.to_string(),
+                channel_id: ChannelId::new(2),
+                inviter: None,
+                uses,
+                max_uses,
+            }
+        }
+
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+
+        // Nothing to compare against yet, so this only seeds the cache.
+        let seed = [invite("a", 3, 0), invite("b", 5, 0), invite("c", 0, 1)];
+        assert!(cache.attribute_join(guild_id, seed).is_none());
+        assert_eq!(cache.guild_invites(guild_id).unwrap().len(), 3);
+
+        // The invite whose use count went up is picked.
+        let used = cache.attribute_join(guild_id, [
+            invite("a", 3, 0),
+            invite("b", 6, 0),
+            invite("c", 0, 1),
+        ]);
+        assert_eq!(used.unwrap().code, "b");
+
+        // A single-use invite that disappeared is picked if no use count went up.
+        let used = cache.attribute_join(guild_id, [invite("a", 3, 0), invite("b", 6, 0)]);
+        assert_eq!(used.unwrap().code, "c");
+
+        // Nothing changed, nothing to attribute.
+        assert!(cache.attribute_join(guild_id, [invite("a", 3, 0), invite("b", 6, 0)]).is_none());
+    }
+
+    #[test]
+    fn test_relationships() {
+        let cache = Cache::default();
+        let user_id = UserId::new(2);
+
+        let mut add: RelationshipAddEvent = from_value(json!({
+            "id": "2",
+            "type": 3,
+            "nickname": null,
+            "user": {"id": "2", "username": "friend", "discriminator": "0", "avatar": null},
+        }))
+        .unwrap();
+        assert!(cache.update(&mut add).is_none());
+        assert_eq!(cache.relationship(user_id).unwrap().kind, RelationshipType::IncomingRequest);
+
+        // The update lacks the user, which is kept from the cached relationship.
+        let mut update: RelationshipUpdateEvent = from_value(json!({
+            "id": "2",
+            "type": 1,
+            "nickname": "bestie",
+        }))
+        .unwrap();
+        let old = cache.update(&mut update).unwrap();
+        assert_eq!(old.kind, RelationshipType::IncomingRequest);
+        {
+            let relationship = cache.relationship(user_id).unwrap();
+            assert_eq!(relationship.kind, RelationshipType::Friend);
+            assert_eq!(relationship.nickname.as_deref(), Some("bestie"));
+            assert_eq!(relationship.user.as_ref().unwrap().name, "friend");
+        }
+
+        let mut remove: RelationshipRemoveEvent = from_value(json!({
+            "id": "2",
+            "type": 1,
+        }))
+        .unwrap();
+        assert!(cache.update(&mut remove).is_some());
+        assert!(cache.relationship(user_id).is_none());
+    }
+
+    #[test]
+    fn test_unreads() {
+        let cache = Cache::default();
+        cache.user.write().id = UserId::new(1);
+        let channel_id = ChannelId::new(10);
+
+        let message = |id: u64, author: u64, mentions: Vec<User>| MessageCreateEvent {
+            message: Message {
+                id: MessageId::new(id),
+                channel_id,
+                guild_id: Some(GuildId::new(100)),
+                author: User {
+                    id: UserId::new(author),
+                    ..Default::default()
+                },
+                mentions,
+                ..Default::default()
+            },
+        };
+
+        assert!(cache.unreads().get(channel_id).is_none());
+
+        cache.update(&mut message(20, 2, vec![]));
+        let mentioned = User {
+            id: UserId::new(1),
+            ..Default::default()
+        };
+        cache.update(&mut message(21, 2, vec![mentioned]));
+        let unreads = cache.unreads();
+        assert_eq!(unreads.unread_count(channel_id), 2);
+        assert_eq!(unreads.mention_count(channel_id), 1);
+        assert_eq!(unreads.unread_channels(), vec![channel_id]);
+
+        let mut ack = MessageAckEvent {
+            channel_id,
+            message_id: MessageId::new(21),
+            version: 1,
+            manual: false,
+            mention_count: None,
+        };
+        let old = cache.update(&mut ack).unwrap();
+        assert_eq!(old.unread_count, 2);
+        assert!(!unreads.is_unread(channel_id));
+        assert_eq!(unreads.total_mention_count(), 0);
+
+        // Messages sent by the current user don't make the channel unread.
+        cache.update(&mut message(22, 1, vec![]));
+        assert!(!unreads.is_unread(channel_id));
+        assert_eq!(
+            unreads.get(channel_id).unwrap().last_acked_message_id,
+            Some(MessageId::new(22))
+        );
+
+        // Bots have no read states, so their unreads aren't tracked.
+        let cache = Cache::default();
+        cache.user.write().bot = true;
+        cache.update(&mut message(20, 2, vec![]));
+        assert!(cache.unreads().get(channel_id).is_none());
+    }
+
+    #[test]
+    fn test_lru_limits() {
+        let settings = Settings {
+            max_users: Some(2),
+            max_members: Some(2),
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings);
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let member_add = |user_id: u64| {
+            let mut event = GuildMemberAddEvent {
+                member: Member {
+                    guild_id,
+                    user: User {
+                        id: UserId::new(user_id),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            };
+            cache.update(&mut event);
+        };
+        member_add(10);
+        member_add(11);
+        member_add(12);
+
+        // The least recently updated member and user were evicted.
+        let guild = cache.guild(guild_id).unwrap();
+        assert_eq!(guild.members.len(), 2);
+        assert!(!guild.members.contains_key(&UserId::new(10)));
+        drop(guild);
+        assert!(cache.user(UserId::new(10)).is_none());
+        assert!(cache.user(UserId::new(12)).is_some());
+        assert_eq!(cache.members_lru.len(), 2);
+
+        // Removed members are no longer tracked.
+        let mut member_remove = GuildMemberRemoveEvent {
+            guild_id,
+            user: User {
+                id: UserId::new(11),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut member_remove);
+        assert_eq!(cache.members_lru.len(), 1);
+    }
+
+    #[test]
+    fn test_presence_members_lru() {
+        let settings = Settings {
+            max_members: Some(2),
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings);
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        for user_id in ["10", "11", "12"] {
+            let mut event: PresenceUpdateEvent = from_value(json!({
+                "user": {"id": user_id, "username": "user", "bot": false},
+                "guild_id": "1",
+                "status": "online",
+            }))
+            .unwrap();
+            cache.update(&mut event);
+        }
+
+        // Members created from presences are evicted like any other member.
+        let guild = cache.guild(guild_id).unwrap();
+        assert_eq!(guild.members.len(), 2);
+        assert!(!guild.members.contains_key(&UserId::new(10)));
+        drop(guild);
+        assert_eq!(cache.members_lru.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "temp_cache")]
+    #[allow(deprecated)]
+    fn test_temp_members() {
+        use crate::cache::MaybeOwnedArc;
+
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+        let user = User {
+            id: UserId::new(2),
+            ..Default::default()
+        };
+        let member = Member {
+            guild_id,
+            user: user.clone(),
+            ..Default::default()
+        };
+        cache.temp_members.insert((guild_id, user.id), MaybeOwnedArc::new(member));
+        assert!(cache.member(guild_id, user.id).is_some());
+
+        // Gateway updates for the member invalidate the fetched copy.
+        let mut member_remove = GuildMemberRemoveEvent {
+            guild_id,
+            user,
+        };
+        cache.update(&mut member_remove);
+        assert!(cache.member(guild_id, UserId::new(2)).is_none());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let settings = Settings {
+            max_messages: 2,
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings.clone());
+        let guild_id = GuildId::new(1);
+        let channel_id = ChannelId::new(2);
+        let user = User {
+            id: UserId::new(3),
+            name: "snapshot".into(),
+            ..Default::default()
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                channels: HashMap::from([(channel_id, GuildChannel {
+                    id: channel_id,
+                    guild_id,
+                    ..Default::default()
+                })]),
+                members: HashMap::from([(user.id, Member {
+                    guild_id,
+                    user: user.clone(),
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+        for id in 10..13 {
+            let mut message = MessageCreateEvent {
+                message: Message {
+                    id: MessageId::new(id),
+                    channel_id,
+                    guild_id: Some(guild_id),
+                    author: user.clone(),
+                    ..Default::default()
+                },
+            };
+            cache.update(&mut message);
+        }
+
+        let mut snapshot = Vec::new();
+        cache.serialize_to(&mut snapshot).unwrap();
+
+        let restored = Cache::new_with_settings(settings);
+        restored.restore_from(snapshot.as_slice()).unwrap();
+
+        let guild = restored.guild(guild_id).unwrap();
+        assert!(guild.members.contains_key(&user.id));
+        assert!(guild.channels.contains_key(&channel_id));
+        drop(guild);
+        assert_eq!(restored.user(user.id).unwrap().name, "snapshot");
+        let queue = restored.message_queue.get(&channel_id).unwrap();
+        assert_eq!(*queue, [MessageId::new(11), MessageId::new(12)]);
+        assert!(restored.message(channel_id, MessageId::new(12)).is_some());
+    }
+
+    #[test]
+    fn test_update_hooks() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::cache::{CacheEntity, CacheEntityKind, CacheUpdateHook};
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(CacheEntityKind, bool, bool)>>);
+
+        impl CacheUpdateHook for Arc<Recorder> {
+            fn on_update(
+                &self,
+                kind: CacheEntityKind,
+                old: Option<CacheEntity<'_>>,
+                new: Option<CacheEntity<'_>>,
+            ) {
+                self.0.lock().unwrap().push((kind, old.is_some(), new.is_some()));
+            }
+        }
+
+        let cache = Cache::default();
+        let recorder = Arc::new(Recorder::default());
+        cache.add_update_hook(Arc::clone(&recorder));
+
+        let guild_id = GuildId::new(1);
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let user = User {
+            id: UserId::new(2),
+            ..Default::default()
+        };
+        let mut member_add = GuildMemberAddEvent {
+            member: Member {
+                guild_id,
+                user: user.clone(),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut member_add);
+        let mut member_remove = GuildMemberRemoveEvent {
+            guild_id,
+            user,
+        };
+        cache.update(&mut member_remove);
+
+        assert_eq!(*recorder.0.lock().unwrap(), [
+            (CacheEntityKind::Guild, false, true),
+            (CacheEntityKind::User, false, true),
+            (CacheEntityKind::Member, false, true),
+            (CacheEntityKind::Member, true, false),
+        ]);
+    }
+
+    #[test]
+    fn test_mirror() {
+        use std::sync::Mutex;
+
+        use crate::cache::{CacheEntity, CacheEntityKind, CacheMirror, CacheUpdateHook};
+
+        #[derive(Default)]
+        struct GuildStore(Mutex<HashMap<GuildId, Guild>>);
+
+        impl CacheUpdateHook for GuildStore {
+            fn on_update(
+                &self,
+                _: CacheEntityKind,
+                old: Option<CacheEntity<'_>>,
+                new: Option<CacheEntity<'_>>,
+            ) {
+                let mut guilds = self.0.lock().unwrap();
+                match (old, new) {
+                    (_, Some(CacheEntity::Guild(guild))) => {
+                        guilds.insert(guild.id, guild.clone());
+                    },
+                    (Some(CacheEntity::Guild(guild)), None) => {
+                        guilds.remove(&guild.id);
+                    },
+                    _ => {},
+                }
+            }
+        }
+
+        impl CacheMirror for GuildStore {
+            fn guild(&self, guild_id: GuildId) -> Option<Guild> {
+                self.0.lock().unwrap().get(&guild_id).cloned()
+            }
+        }
+
+        let cache = Cache::default();
+        cache.set_mirror(GuildStore::default());
+
+        let guild_id = GuildId::new(1);
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                name: "stored".into(),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        // Evict the guild from memory only, the mirror still serves it.
+        cache.guilds.remove(&guild_id);
+        assert_eq!(cache.guild_count(), 0);
+        assert_eq!(cache.guild(guild_id).unwrap().name, "stored");
+        assert!(cache.guild(GuildId::new(2)).is_none());
+        assert!(cache.user(UserId::new(1)).is_none());
+    }
+
+    #[test]
+    fn test_threads() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+        let parent_id = ChannelId::new(2);
+        let thread = |id: u64, archived: bool| {
+            from_value::<GuildChannel>(json!({
+                "id": id.to_string(),
+                "guild_id": guild_id.to_string(),
+                "parent_id": parent_id.to_string(),
+                "name": "thread",
+                "type": 11,
+                "thread_metadata": {
+                    "archived": archived,
+                    "auto_archive_duration": 60,
+                    "archive_timestamp": null,
+                    "locked": false,
+                },
+            }))
+            .unwrap()
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                channels: [(parent_id, GuildChannel {
+                    id: parent_id,
+                    guild_id,
+                    ..Default::default()
+                })]
+                .into(),
+                threads: vec![thread(3, false), thread(4, false)],
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+        assert_eq!(cache.channel_threads(parent_id).len(), 2);
+
+        // Archiving a thread keeps it cached, but no longer active.
+        cache.update(&mut ThreadUpdateEvent {
+            thread: thread(3, true),
+        });
+        let active = cache.channel_threads(parent_id);
+        assert_eq!(active.iter().map(|t| t.id.get()).collect::<Vec<_>>(), [4]);
+        assert_eq!(cache.archived_threads(parent_id)[0].id, ChannelId::new(3));
+
+        // Syncing drops active threads missing from the sync, keeping archived ones.
+        let mut sync: ThreadListSyncEvent = from_value(json!({
+            "guild_id": guild_id.to_string(),
+            "channel_ids": [parent_id.to_string()],
+            "threads": [thread(5, false)],
+            "members": [{
+                "id": "5",
+                "user_id": "6",
+                "join_timestamp": "2024-01-01T00:00:00Z",
+                "flags": 0,
+            }],
+        }))
+        .unwrap();
+        cache.update(&mut sync);
+        let active = cache.channel_threads(parent_id);
+        assert_eq!(active.iter().map(|t| t.id.get()).collect::<Vec<_>>(), [5]);
+        assert!(active[0].member.is_some());
+        assert_eq!(cache.archived_threads(parent_id).len(), 1);
+
+        let mut members_update: ThreadMembersUpdateEvent = from_value(json!({
+            "id": "5",
+            "guild_id": guild_id.to_string(),
+            "member_count": 7,
+        }))
+        .unwrap();
+        cache.update(&mut members_update);
+        assert_eq!(cache.channel_threads(parent_id)[0].member_count, Some(7));
+
+        cache.update(&mut ThreadDeleteEvent {
+            thread: PartialGuildChannel {
+                id: ChannelId::new(5),
+                guild_id,
+                parent_id,
+                kind: ChannelType::PublicThread,
+            },
+        });
+        assert!(cache.channel_threads(parent_id).is_empty());
+    }
+
+    #[test]
+    fn test_old_values() {
+        let cache = Cache::new_with_settings(Settings {
+            max_messages: 10,
+            ..Default::default()
+        });
+        let guild_id = GuildId::new(1);
+        let channel_id = ChannelId::new(2);
+        cache.update(&mut GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        });
+
+        let stage_instance = |topic: &str| StageInstanceUpdateEvent {
+            stage_instance: from_value(json!({
+                "id": "3",
+                "guild_id": guild_id.to_string(),
+                "channel_id": channel_id.to_string(),
+                "topic": topic,
+                "privacy_level": 2,
+                "discoverable_disabled": false,
+                "guild_scheduled_event_id": null,
+            }))
+            .unwrap(),
+        };
+        assert!(cache.update(&mut stage_instance("old")).is_none());
+        let old = cache.update(&mut stage_instance("new")).unwrap();
+        assert_eq!(old.topic, "old");
+
+        let mut emojis_update = GuildEmojisUpdateEvent {
+            emojis: HashMap::new(),
+            guild_id,
+        };
+        assert!(cache.update(&mut emojis_update).is_some_and(|old| old.is_empty()));
+
+        for id in [4, 5, 6] {
+            cache.update(&mut MessageCreateEvent {
+                message: Message {
+                    id: MessageId::new(id),
+                    channel_id,
+                    guild_id: Some(guild_id),
+                    ..Default::default()
+                },
+            });
+        }
+        let mut delete: MessageDeleteEvent = from_value(json!({
+            "id": "4",
+            "channel_id": channel_id.to_string(),
+        }))
+        .unwrap();
+        assert_eq!(cache.update(&mut delete).unwrap().id, MessageId::new(4));
+        assert!(cache.message(channel_id, MessageId::new(4)).is_none());
+        assert!(cache.update(&mut delete).is_none());
+
+        let mut delete_bulk: MessageDeleteBulkEvent = from_value(json!({
+            "ids": ["4", "5", "6"],
+            "channel_id": channel_id.to_string(),
+        }))
+        .unwrap();
+        assert_eq!(cache.update(&mut delete_bulk).unwrap().len(), 2);
+        assert!(cache.message_queue.get(&channel_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_message_history() {
+        let cache = Cache::new_with_settings(Settings {
+            max_messages: 10,
+            max_message_revisions: 2,
+            ..Default::default()
+        });
+        let channel_id = ChannelId::new(1);
+        let message_id = MessageId::new(2);
+
+        cache.update(&mut MessageCreateEvent {
+            message: Message {
+                id: message_id,
+                channel_id,
+                content: "v0".into(),
+                ..Default::default()
+            },
+        });
+        for content in ["v1", "v2", "v3"] {
+            let mut update: MessageUpdateEvent = from_value(json!({
+                "id": message_id.to_string(),
+                "channel_id": channel_id.to_string(),
+                "content": content,
+            }))
+            .unwrap();
+            cache.update(&mut update);
+        }
+
+        let history = cache.message_history(channel_id, message_id);
+        let contents: Vec<_> = history.iter().map(|message| message.content.as_str()).collect();
+        assert_eq!(contents, ["v1", "v2"]);
+        assert_eq!(cache.message(channel_id, message_id).unwrap().content, "v3");
+        assert!(cache.message_history(ChannelId::new(3), message_id).is_empty());
+
+        let mut delete: MessageDeleteEvent = from_value(json!({
+            "id": message_id.to_string(),
+            "channel_id": channel_id.to_string(),
+        }))
+        .unwrap();
+        cache.update(&mut delete);
+        assert!(cache.message_history(channel_id, message_id).is_empty());
+    }
+
+    #[test]
+    fn test_poll_votes() {
+        let cache = Cache::new_with_settings(Settings {
+            max_messages: 10,
+            ..Default::default()
+        });
+        let channel_id = ChannelId::new(1);
+        let message_id = MessageId::new(2);
+
+        cache.update(&mut MessageCreateEvent {
+            message: Message {
+                id: message_id,
+                channel_id,
+                poll: Some(Box::new(
+                    from_value(json!({
+                        "question": {"text": "?"},
+                        "answers": [{"answer_id": 1, "poll_media": {"text": "a"}}],
+                        "expiry": null,
+                        "allow_multiselect": false,
+                        "layout_type": 1,
+                        "results": {
+                            "is_finalized": false,
+                            "answer_counts": [{"id": 1, "count": 1, "me_voted": false}],
+                        },
+                    }))
+                    .unwrap(),
+                )),
+                ..Default::default()
+            },
+        });
+
+        let vote = json!({
+            "user_id": "3",
+            "channel_id": channel_id.to_string(),
+            "message_id": message_id.to_string(),
+            "answer_id": 1,
+        });
+        let count = || {
+            let message = cache.message(channel_id, message_id).unwrap();
+            message.poll.as_ref().unwrap().results.as_ref().unwrap().answer_counts[0].count
+        };
+
+        let mut add: MessagePollVoteAddEvent = from_value(vote.clone()).unwrap();
+        cache.update(&mut add);
+        assert_eq!(count(), 2);
+
+        let mut remove: MessagePollVoteRemoveEvent = from_value(vote).unwrap();
+        cache.update(&mut remove);
+        cache.update(&mut remove);
+        cache.update(&mut remove);
+        assert_eq!(count(), 0);
+    }
+
+    #[test]
+    fn test_stats() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+        let user = User {
+            id: UserId::new(2),
+            ..Default::default()
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                members: HashMap::from([(user.id, Member {
+                    user,
+                    guild_id,
+                    ..Default::default()
+                })]),
+                channels: HashMap::from([(ChannelId::new(3), GuildChannel::default())]),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let stats = cache.stats();
+        assert_eq!(stats.guilds.count, 1);
+        assert_eq!(stats.channels.count, 1);
+        assert_eq!(stats.members.count, 1);
+        assert_eq!(stats.presences.count, 0);
+        assert_eq!(stats.users.count, 1);
+        assert_eq!(stats.messages.count, 0);
+        #[cfg(feature = "typesize")]
+        assert!(stats.members.bytes > 0);
+    }
+
+    #[test]
+    fn test_selective_caching() {
+        let settings = Settings {
+            max_messages: 1,
+            cache_presences: false,
+            cache_message_content: false,
+            ..Default::default()
+        };
+        let cache = Cache::new_with_settings(settings);
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let mut presence_update: PresenceUpdateEvent = from_value(json!({
+            "user": {"id": "2"},
+            "guild_id": "1",
+            "status": "online",
+            "activities": [],
+            "client_status": null,
+        }))
+        .unwrap();
+        cache.update(&mut presence_update);
+        assert!(cache.guild(guild_id).unwrap().presences.is_empty());
+
+        let mut message = MessageCreateEvent {
+            message: Message {
+                id: MessageId::new(3),
+                channel_id: ChannelId::new(4),
+                content: "secret".into(),
+                ..Default::default()
+            },
+        };
+        cache.update(&mut message);
+        assert_eq!(cache.message(ChannelId::new(4), MessageId::new(3)).unwrap().content, "");
+    }
+
+    #[test]
+    fn test_member_chunks() {
+        let cache = Cache::default();
+        let guild_id = GuildId::new(1);
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                member_count: 2,
+                ..Default::default()
+            },
+        };
+        cache.update(&mut guild_create);
+        assert!(!cache.is_chunked(guild_id));
+
+        let chunk = |user_id: u64, chunk_index: u32| -> GuildMembersChunkEvent {
+            from_value(json!({
+                "guild_id": "1",
+                "members": [{
+                    "user": {"id": user_id.to_string(), "username": "member", "discriminator": "0"},
+                    "roles": [],
+                    "deaf": false,
+                    "mute": false,
+                    "flags": 0,
+                }],
+                "chunk_index": chunk_index,
+                "chunk_count": 2,
+                "nonce": "nonce",
+            }))
+            .unwrap()
+        };
+        assert!(cache.update(&mut chunk(2, 0)).is_none());
+        assert!(cache.update(&mut chunk(3, 1)).is_some());
+        assert!(cache.is_chunked(guild_id));
+        assert!(cache.member_chunks.is_empty());
+    }
+
+    #[test]
+    fn test_name_lookups() {
+        for index_names in [false, true] {
+            let settings = Settings {
+                index_names,
+                ..Default::default()
+            };
+            let cache = Cache::new_with_settings(settings);
+            let guild_id = GuildId::new(1);
+            let channel_id = ChannelId::new(2);
+            let user = User {
+                id: UserId::new(3),
+                name: "Someone".into(),
+                ..Default::default()
+            };
+
+            let mut guild_create = GuildCreateEvent {
+                guild: Guild {
+                    id: guild_id,
+                    name: "Guild".into(),
+                    member_count: 1,
+                    members: HashMap::from([(user.id, Member {
+                        user: user.clone(),
+                        guild_id,
+                        ..Default::default()
+                    })]),
+                    channels: HashMap::from([(channel_id, GuildChannel {
+                        id: channel_id,
+                        guild_id,
+                        name: "general".into(),
+                        ..Default::default()
+                    })]),
+                    ..Default::default()
+                },
+            };
+            cache.update(&mut guild_create);
+
+            assert_eq!(cache.guild_by_name("guild").unwrap().id, guild_id);
+            assert_eq!(cache.channel_by_name(guild_id, "General").unwrap().id, channel_id);
+            assert_eq!(cache.member_by_username(guild_id, "someone").unwrap().user.id, user.id);
+            assert!(cache.channel_by_name(guild_id, "random").is_none());
+
+            // Renamed channels are found by their new name only.
+            let mut channel_update = ChannelUpdateEvent {
+                channel: GuildChannel {
+                    id: channel_id,
+                    guild_id,
+                    name: "random".into(),
+                    ..Default::default()
+                },
+            };
+            cache.update(&mut channel_update);
+            assert!(cache.channel_by_name(guild_id, "general").is_none());
+            assert_eq!(cache.channel_by_name(guild_id, "random").unwrap().id, channel_id);
+
+            let mut member_remove = GuildMemberRemoveEvent {
+                guild_id,
+                user,
+            };
+            cache.update(&mut member_remove);
+            assert!(cache.member_by_username(guild_id, "someone").is_none());
+        }
     }
 }
@@ -20,6 +20,13 @@ pub struct Settings {
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// The maximum number of previous revisions to keep for each cached message, returned by
+    /// [`Cache::message_history`]. Revisions are recorded when a cached message is edited.
+    ///
+    /// Defaults to 0.
+    ///
+    /// [`Cache::message_history`]: super::Cache::message_history
+    pub max_message_revisions: usize,
     /// How long temporarily-cached data should be stored before being thrown out.
     ///
     /// Defaults to one hour.
@@ -36,16 +43,74 @@ pub struct Settings {
     ///
     /// Defaults to true.
     pub cache_users: bool,
+    /// Whether to cache the presences of guild members.
+    ///
+    /// Defaults to true.
+    pub cache_presences: bool,
+    /// Whether to cache the voice states of guild members.
+    ///
+    /// Defaults to true.
+    pub cache_voice_states: bool,
+    /// Whether to cache the emojis of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_emojis: bool,
+    /// Whether to cache the stickers of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_stickers: bool,
+    /// Whether to keep the content of cached messages. If disabled, messages are still cached,
+    /// but with an empty [`Message::content`].
+    ///
+    /// Defaults to true.
+    ///
+    /// [`Message::content`]: crate::model::channel::Message::content
+    pub cache_message_content: bool,
+    /// Whether to index the names of cached guilds, channels and members, speeding up
+    /// [`Cache::guild_by_name`], [`Cache::channel_by_name`] and [`Cache::member_by_username`] at
+    /// the cost of some memory and slower cache updates.
+    ///
+    /// Defaults to false.
+    ///
+    /// [`Cache::guild_by_name`]: super::Cache::guild_by_name
+    /// [`Cache::channel_by_name`]: super::Cache::channel_by_name
+    /// [`Cache::member_by_username`]: super::Cache::member_by_username
+    pub index_names: bool,
+    /// The maximum number of users to cache. Once exceeded, the least recently updated users are
+    /// evicted.
+    ///
+    /// Defaults to [`None`], i.e. unlimited.
+    pub max_users: Option<usize>,
+    /// The maximum number of guild members to cache, across all guilds. Once exceeded, the least
+    /// recently updated members are evicted.
+    ///
+    /// Defaults to [`None`], i.e. unlimited.
+    pub max_members: Option<usize>,
+    /// The maximum number of presences to cache, across all guilds. Once exceeded, the least
+    /// recently updated presences are evicted.
+    ///
+    /// Defaults to [`None`], i.e. unlimited.
+    pub max_presences: Option<usize>,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             max_messages: 0,
+            max_message_revisions: 0,
             time_to_live: Duration::from_secs(60 * 60),
             cache_guilds: true,
             cache_channels: true,
             cache_users: true,
+            cache_presences: true,
+            cache_voice_states: true,
+            cache_emojis: true,
+            cache_stickers: true,
+            cache_message_content: true,
+            index_names: false,
+            max_users: None,
+            max_members: None,
+            max_presences: None,
         }
     }
 }
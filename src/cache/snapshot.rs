@@ -0,0 +1,149 @@
+use std::io::{Read, Write};
+use std::ops::Deref;
+
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use super::{Cache, ChannelUnread};
+use crate::internal::prelude::*;
+use crate::json;
+use crate::model::prelude::*;
+
+/// The persisted state of a [`Cache`], as written by [`Cache::serialize_to`].
+///
+/// Transient data, such as invites, sessions and shard info, is not persisted.
+#[derive(Deserialize)]
+struct CacheSnapshot {
+    user: CurrentUser,
+    guilds: Vec<Guild>,
+    unavailable_guilds: Vec<GuildId>,
+    users: Vec<User>,
+    relationships: Vec<Relationship>,
+    unreads: Vec<(ChannelId, ChannelUnread)>,
+    /// The cached messages of all channels, oldest first.
+    messages: Vec<Message>,
+}
+
+/// Serializes a [`Cache`] in the format of [`CacheSnapshot`], without cloning the guilds and
+/// users first.
+struct SnapshotRef<'a>(&'a Cache);
+
+impl Serialize for SnapshotRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        let cache = self.0;
+
+        let mut messages = Vec::new();
+        for queue in &cache.message_queue {
+            if let Some(channel_messages) = cache.messages.get(queue.key()) {
+                messages.extend(queue.iter().filter_map(|id| channel_messages.get(id).cloned()));
+            }
+        }
+
+        let mut s = serializer.serialize_struct("CacheSnapshot", 7)?;
+        s.serialize_field("user", &*cache.user.read())?;
+        s.serialize_field("guilds", &Seq(|| cache.guilds.iter().map(ValueRef)))?;
+        s.serialize_field(
+            "unavailable_guilds",
+            &Seq(|| cache.unavailable_guilds.iter().map(|entry| *entry.key())),
+        )?;
+        s.serialize_field("users", &Seq(|| cache.users.iter().map(ValueRef)))?;
+        s.serialize_field("relationships", &Seq(|| cache.relationships.iter().map(ValueRef)))?;
+        s.serialize_field(
+            "unreads",
+            &Seq(|| cache.unreads.iter().map(|entry| (*entry.key(), *entry.value()))),
+        )?;
+        s.serialize_field("messages", &messages)?;
+        s.end()
+    }
+}
+
+/// Serializes the items returned by the iterator as a sequence.
+struct Seq<F>(F);
+
+impl<F, I> Serialize for Seq<F>
+where
+    F: Fn() -> I,
+    I: Iterator,
+    I::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.collect_seq((self.0)())
+    }
+}
+
+/// Serializes the value behind a map reference.
+struct ValueRef<R>(R);
+
+impl<R> Serialize for ValueRef<R>
+where
+    R: Deref,
+    R::Target: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+pub(super) fn serialize_to(cache: &Cache, writer: impl Write) -> Result<()> {
+    json::to_writer(writer, &SnapshotRef(cache))
+}
+
+pub(super) fn restore_from(cache: &Cache, reader: impl Read) -> Result<()> {
+    let snapshot: CacheSnapshot = json::from_reader(reader)?;
+
+    *cache.user.write() = snapshot.user;
+
+    for user in &snapshot.users {
+        cache.update_user_entry(user);
+    }
+
+    for guild_id in snapshot.unavailable_guilds {
+        cache.unavailable_guilds.insert(guild_id, ());
+    }
+
+    for mut guild in snapshot.guilds {
+        cache.strip_guild(&mut guild);
+        let guild_id = guild.id;
+        let member_ids: Vec<_> = guild.members.keys().copied().collect();
+        let presence_ids: Vec<_> = guild.presences.keys().copied().collect();
+
+        for channel_id in guild.channels.keys() {
+            cache.channels.insert(*channel_id, guild_id);
+        }
+        if let Some(name_index) = &cache.name_index {
+            name_index.index_guild(&guild);
+        }
+        cache.unavailable_guilds.remove(&guild_id);
+        cache.guilds.insert(guild_id, guild);
+
+        cache.track_members(guild_id, member_ids);
+        cache.track_presences(guild_id, presence_ids);
+    }
+
+    for relationship in snapshot.relationships {
+        cache.relationships.insert(relationship.id, relationship);
+    }
+
+    for (channel_id, unread) in snapshot.unreads {
+        cache.unreads.insert(channel_id, unread);
+    }
+
+    let max_messages = cache.settings().max_messages;
+    if max_messages > 0 {
+        for mut message in snapshot.messages {
+            cache.strip_message(&mut message);
+            let mut messages = cache.messages.entry(message.channel_id).or_default();
+            let mut queue = cache.message_queue.entry(message.channel_id).or_default();
+
+            if messages.len() == max_messages {
+                if let Some(id) = queue.pop_front() {
+                    messages.remove(&id);
+                }
+            }
+
+            queue.push_back(message.id);
+            messages.insert(message.id, message);
+        }
+    }
+
+    Ok(())
+}
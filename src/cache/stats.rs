@@ -0,0 +1,93 @@
+#[cfg(feature = "typesize")]
+use typesize::TypeSize;
+
+use super::Cache;
+
+/// The amount of cached entities per resource, returned by [`Cache::stats`].
+///
+/// With the `typesize` feature enabled, the approximate memory usage of each resource is included
+/// as well. Guild sizes exclude their channels, members and presences, which are counted
+/// separately.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// The cached guilds.
+    pub guilds: ResourceStats,
+    /// The channels and threads of all cached guilds.
+    pub channels: ResourceStats,
+    /// The members of all cached guilds.
+    pub members: ResourceStats,
+    /// The presences of all cached guilds.
+    pub presences: ResourceStats,
+    /// The cached users.
+    pub users: ResourceStats,
+    /// The cached messages of all channels.
+    pub messages: ResourceStats,
+    /// The relationships of the current user.
+    pub relationships: ResourceStats,
+}
+
+/// Statistics about a single resource of the cache, see [`CacheStats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResourceStats {
+    /// The amount of cached entities.
+    pub count: usize,
+    /// The approximate amount of memory used by the entities, in bytes.
+    #[cfg(feature = "typesize")]
+    pub bytes: usize,
+}
+
+impl ResourceStats {
+    #[cfg_attr(not(feature = "typesize"), allow(unused_variables))]
+    fn add(&mut self, count: usize, bytes: usize) {
+        self.count += count;
+        #[cfg(feature = "typesize")]
+        {
+            self.bytes += bytes;
+        }
+    }
+}
+
+#[cfg(feature = "typesize")]
+fn size<T: TypeSize>(value: &T) -> usize {
+    value.get_size()
+}
+
+#[cfg(not(feature = "typesize"))]
+fn size<T>(_: &T) -> usize {
+    0
+}
+
+impl CacheStats {
+    pub(super) fn collect(cache: &Cache) -> Self {
+        let mut stats = Self::default();
+
+        for guild in cache.guilds.iter() {
+            let channels_size = size(&guild.channels) + size(&guild.threads);
+            let members_size = size(&guild.members);
+            let presences_size = size(&guild.presences);
+            let guild_size =
+                size(guild.value()).saturating_sub(channels_size + members_size + presences_size);
+
+            stats.guilds.add(1, guild_size);
+            stats.channels.add(guild.channels.len() + guild.threads.len(), channels_size);
+            stats.members.add(guild.members.len(), members_size);
+            stats.presences.add(guild.presences.len(), presences_size);
+        }
+
+        for user in cache.users.iter() {
+            stats.users.add(1, size(user.value()));
+        }
+
+        for messages in &cache.messages {
+            stats.messages.add(messages.len(), size(messages.value()));
+        }
+
+        for relationship in cache.relationships.iter() {
+            stats.relationships.add(1, size(relationship.value()));
+        }
+
+        stats
+    }
+}
@@ -0,0 +1,213 @@
+use super::Cache;
+use crate::model::channel::{Message, ReadStateType, ReadStates};
+use crate::model::id::{ChannelId, MessageId};
+
+/// The unread state of a channel, as tracked by the cache.
+///
+/// Read states are loaded from [`Ready::read_state`], advanced by every received message and
+/// reset by [`MessageAckEvent`]s. They are only tracked for user accounts.
+///
+/// [`Ready::read_state`]: crate::model::gateway::Ready::read_state
+/// [`MessageAckEvent`]: crate::model::event::MessageAckEvent
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ChannelUnread {
+    /// The Id of the last message acknowledged by the current user.
+    pub last_acked_message_id: Option<MessageId>,
+    /// The Id of the last message received in the channel.
+    pub last_message_id: Option<MessageId>,
+    /// The amount of messages received since the last acknowledgement.
+    ///
+    /// Only messages received while connected are counted, so this is a lower bound.
+    pub unread_count: u64,
+    /// The amount of mentions of the current user since the last acknowledgement.
+    pub mention_count: u64,
+}
+
+impl ChannelUnread {
+    /// Whether the channel has messages which were not acknowledged yet.
+    #[must_use]
+    pub fn is_unread(&self) -> bool {
+        self.unread_count > 0
+            || self.mention_count > 0
+            || match (self.last_message_id, self.last_acked_message_id) {
+                (Some(last), Some(acked)) => last > acked,
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+    }
+
+    fn ack(&mut self, message_id: MessageId, mention_count: u64) {
+        self.last_acked_message_id = Some(message_id);
+        self.unread_count = 0;
+        self.mention_count = mention_count;
+    }
+}
+
+/// A view of the unread state of channels, returned by [`Cache::unreads`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::cache::Cache;
+/// # use serenity::model::id::ChannelId;
+/// # fn run(cache: &Cache, channel_id: ChannelId) {
+/// let unreads = cache.unreads();
+/// println!(
+///     "{} unread messages and {} mentions",
+///     unreads.unread_count(channel_id),
+///     unreads.mention_count(channel_id),
+/// );
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnreadTracker<'a> {
+    cache: &'a Cache,
+}
+
+impl<'a> UnreadTracker<'a> {
+    pub(super) fn new(cache: &'a Cache) -> Self {
+        Self {
+            cache,
+        }
+    }
+
+    /// Returns the unread state of the given channel, if it is tracked.
+    #[must_use]
+    pub fn get(&self, channel_id: impl Into<ChannelId>) -> Option<ChannelUnread> {
+        self.cache.unreads.get(&channel_id.into()).map(|unread| *unread)
+    }
+
+    /// Returns the amount of messages received in the given channel since it was last
+    /// acknowledged.
+    #[must_use]
+    pub fn unread_count(&self, channel_id: impl Into<ChannelId>) -> u64 {
+        self.get(channel_id).map_or(0, |unread| unread.unread_count)
+    }
+
+    /// Returns the amount of mentions of the current user in the given channel since it was last
+    /// acknowledged.
+    #[must_use]
+    pub fn mention_count(&self, channel_id: impl Into<ChannelId>) -> u64 {
+        self.get(channel_id).map_or(0, |unread| unread.mention_count)
+    }
+
+    /// Whether the given channel has messages which were not acknowledged yet.
+    #[must_use]
+    pub fn is_unread(&self, channel_id: impl Into<ChannelId>) -> bool {
+        self.get(channel_id).is_some_and(|unread| unread.is_unread())
+    }
+
+    /// Returns the total amount of unacknowledged mentions across all channels.
+    #[must_use]
+    pub fn total_mention_count(&self) -> u64 {
+        self.cache.unreads.iter().map(|unread| unread.mention_count).sum()
+    }
+
+    /// Returns the Ids of all channels with messages which were not acknowledged yet.
+    #[must_use]
+    pub fn unread_channels(&self) -> Vec<ChannelId> {
+        self.cache
+            .unreads
+            .iter()
+            .filter(|unread| unread.is_unread())
+            .map(|unread| *unread.key())
+            .collect()
+    }
+}
+
+/// Read states only exist for user accounts, so unreads aren't tracked for bots.
+fn is_tracked(cache: &Cache) -> bool {
+    !cache.user.read().bot
+}
+
+pub(super) fn load_read_states(cache: &Cache, read_states: &ReadStates) {
+    if !is_tracked(cache) {
+        cache.unreads.clear();
+        return;
+    }
+
+    if !read_states.partial {
+        cache.unreads.clear();
+    }
+
+    for entry in &read_states.entries {
+        if entry.kind != ReadStateType::Channel {
+            continue;
+        }
+
+        let mut unread = cache.unreads.get(&entry.id).map(|unread| *unread).unwrap_or_default();
+        unread.last_acked_message_id = entry.last_message_id;
+        unread.unread_count = 0;
+        unread.mention_count = entry.mention_count;
+        cache.unreads.insert(entry.id, unread);
+    }
+}
+
+pub(super) fn record_message(cache: &Cache, message: &Message) {
+    if !is_tracked(cache) {
+        return;
+    }
+
+    let current_user_id = cache.user.read().id;
+
+    let mut unread = cache.unreads.get(&message.channel_id).map(|u| *u).unwrap_or_default();
+    if unread.last_message_id.is_some_and(|last| last >= message.id) {
+        return;
+    }
+    unread.last_message_id = Some(message.id);
+
+    // Messages sent by the current user acknowledge the channel.
+    if message.author.id == current_user_id {
+        unread.ack(message.id, 0);
+    } else {
+        unread.unread_count += 1;
+        if mentions_current_user(cache, message) {
+            unread.mention_count += 1;
+        }
+    }
+
+    cache.unreads.insert(message.channel_id, unread);
+}
+
+pub(super) fn ack(
+    cache: &Cache,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    mention_count: u64,
+) -> Option<ChannelUnread> {
+    if !is_tracked(cache) {
+        return None;
+    }
+
+    let old = cache.unreads.get(&channel_id).map(|unread| *unread);
+
+    let mut unread = old.unwrap_or_default();
+    unread.ack(message_id, mention_count);
+    if !unread.last_message_id.is_some_and(|last| last >= message_id) {
+        unread.last_message_id = Some(message_id);
+    }
+    cache.unreads.insert(channel_id, unread);
+
+    old
+}
+
+fn mentions_current_user(cache: &Cache, message: &Message) -> bool {
+    let current_user_id = cache.user.read().id;
+
+    let Some(guild_id) = message.guild_id else {
+        // Every message in a private channel counts as a mention.
+        return true;
+    };
+
+    if message.mention_everyone || message.mentions.iter().any(|u| u.id == current_user_id) {
+        return true;
+    }
+
+    cache.guilds.get(&guild_id).is_some_and(|guild| {
+        guild.members.get(&current_user_id).is_some_and(|member| {
+            message.mention_roles.iter().any(|role_id| member.roles.contains(role_id))
+        })
+    })
+}
@@ -26,6 +26,10 @@ impl<K: Eq + Hash, V> MaybeMap<K, V> {
         self.0.as_ref()?.get_mut(k)
     }
 
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
     pub fn contains(&self, k: &K) -> bool {
         self.0.as_ref().is_some_and(|m| m.contains_key(k))
     }
@@ -38,6 +42,12 @@ impl<K: Eq + Hash, V> MaybeMap<K, V> {
         self.0.as_ref()?.remove(k)
     }
 
+    pub fn clear(&self) {
+        if let Some(map) = self.0.as_ref() {
+            map.clear();
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.0.as_ref().map_or(0, |map| map.len())
     }
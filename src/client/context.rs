@@ -6,10 +6,15 @@ use typemap_rev::TypeMap;
 
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
+#[cfg(feature = "cache")]
+use crate::cache::CachedInvite;
+#[cfg(feature = "collector")]
+use crate::collector::MessageCollector;
 use crate::gateway::ActivityData;
 #[cfg(feature = "gateway")]
-use crate::gateway::{ShardMessenger, ShardRunner};
+use crate::gateway::{ChunkGuildFilter, ShardMessenger, ShardRunner};
 use crate::http::Http;
+use crate::json::json;
 use crate::model::prelude::*;
 
 /// The context is a general utility struct provided on event dispatches, which helps with dealing
@@ -308,6 +313,231 @@ impl Context {
     pub fn set_presence(&self, activity: Option<ActivityData>, status: OnlineStatus) {
         self.shard.set_presence(activity, status);
     }
+
+    /// Returns a builder which can be awaited to obtain the next message sent by the author of the
+    /// given message, in the same channel. Refer to [`Message::await_reply`].
+    ///
+    /// # Examples
+    ///
+    /// Asking a question and waiting up to 30 seconds for a numeric answer:
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// # async fn run(ctx: Context, msg: Message) -> Result<(), serenity::Error> {
+    /// msg.reply(&ctx, "How old are you?").await?;
+    ///
+    /// let answer = ctx
+    ///     .await_reply(&msg)
+    ///     .filter(|m| m.content.parse::<u8>().is_ok())
+    ///     .timeout(Duration::from_secs(30))
+    ///     .await;
+    ///
+    /// match answer {
+    ///     Some(answer) => answer.reply(&ctx, "Thanks!").await?,
+    ///     None => msg.reply(&ctx, "No answer within 30 seconds.").await?,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "collector")]
+    pub fn await_reply(&self, message: &Message) -> MessageCollector {
+        message.await_reply(&self.shard)
+    }
+
+    /// Fetches the current status of Discord from the [Discord status page], including all
+    /// unresolved incidents. This is a shortcut for [`Http::get_status_summary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the status page could not be reached, or an [`Error::Json`]
+    /// if its response could not be parsed.
+    ///
+    /// [Discord status page]: https://discordstatus.com
+    #[cfg(feature = "status_monitor")]
+    pub async fn discord_status(&self) -> Result<StatusSummary> {
+        self.http.get_status_summary().await
+    }
+
+    /// Gets all emojis owned by the current application.
+    ///
+    /// Application emojis can be used by the application in any guild or DM, without taking up a
+    /// guild's emoji slots. Reference them in message or embed text via their [`Display`] impl,
+    /// or react with them by converting them into a [`ReactionType`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::builder::{CreateEmbed, CreateMessage};
+    ///
+    /// # async fn run(ctx: Context, channel_id: ChannelId) -> Result<(), serenity::Error> {
+    /// let emojis = ctx.get_application_emojis().await?;
+    /// if let Some(emoji) = emojis.into_iter().find(|e| e.name == "ferris") {
+    ///     let embed = CreateEmbed::new().description(format!("{emoji} says hi"));
+    ///     let builder = CreateMessage::new().embed(embed).reactions([emoji]);
+    ///     channel_id.send_message(&ctx, builder).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the application Id of the [`Http`] client is not set.
+    ///
+    /// [`Display`]: fmt::Display
+    pub async fn get_application_emojis(&self) -> Result<Vec<Emoji>> {
+        self.http.get_application_emojis().await
+    }
+
+    /// Gets an emoji owned by the current application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the application has no emoji with the given Id.
+    pub async fn get_application_emoji(&self, emoji_id: impl Into<EmojiId>) -> Result<Emoji> {
+        self.http.get_application_emoji(emoji_id.into()).await
+    }
+
+    /// Creates an emoji owned by the current application.
+    ///
+    /// Refer to the documentation for [`GuildId::create_emoji`] for how the image is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the name is already taken or too long, or if the image is too
+    /// big.
+    pub async fn create_application_emoji(&self, name: &str, image: &str) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+            "image": image,
+        });
+
+        self.http.create_application_emoji(&map).await
+    }
+
+    /// Changes the name of an emoji owned by the current application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the application has no emoji with the given Id, or if the
+    /// name is invalid.
+    pub async fn edit_application_emoji(
+        &self,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+        });
+
+        self.http.edit_application_emoji(emoji_id.into(), &map).await
+    }
+
+    /// Deletes an emoji owned by the current application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the application has no emoji with the given Id.
+    pub async fn delete_application_emoji(&self, emoji_id: impl Into<EmojiId>) -> Result<()> {
+        self.http.delete_application_emoji(emoji_id.into()).await
+    }
+
+    /// Fetches the current invites of a guild and compares their use counts against the ones known
+    /// to the cache, returning the invite (and through it, the inviter) most likely used by a
+    /// member who just joined.
+    ///
+    /// This is intended to be called from [`EventHandler::guild_member_addition`]. The cache only
+    /// knows the use counts from the previous call, so the first call for a guild only seeds the
+    /// cache and returns [`None`].
+    ///
+    /// Refer to [`Cache::attribute_join`] for how the invite is picked.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// # struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn guild_member_addition(&self, ctx: Context, member: Member) {
+    ///         if let Ok(Some(invite)) = ctx.resolve_join_invite(member.guild_id).await {
+    ///             let inviter = invite.inviter.map(|u| u.name);
+    ///             println!("{} joined via {} ({:?})", member.user.name, invite.code, inviter);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission to view the guild's
+    /// invites.
+    ///
+    /// [`EventHandler::guild_member_addition`]: super::EventHandler::guild_member_addition
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[cfg(feature = "cache")]
+    pub async fn resolve_join_invite(
+        &self,
+        guild_id: impl Into<GuildId>,
+    ) -> Result<Option<CachedInvite>> {
+        let guild_id = guild_id.into();
+        let invites = self.http.get_guild_invites(guild_id).await?;
+
+        Ok(self.cache.attribute_join(guild_id, invites.iter().map(CachedInvite::from)))
+    }
+
+    /// Drops everything the cache knows about a guild and fetches it again, for recovering from a
+    /// suspected cache corruption without restarting the process.
+    ///
+    /// The guild's settings, roles, emojis, stickers, channels and active threads are fetched via
+    /// the REST API, while its members are requested from the gateway and arrive as
+    /// [`GuildMembersChunkEvent`]s afterwards. Presences and voice states are kept, as they can
+    /// only be received from the gateway.
+    ///
+    /// Refer to [`Cache::clear_guild`] to only drop the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if fetching the guild fails, in which case the cache is left
+    /// untouched. Returns an [`Error::Model`] with [`ModelError::GuildNotFound`] if the guild was
+    /// not in the cache, as the REST API does not provide all data of a cached guild.
+    #[cfg(all(feature = "cache", feature = "gateway"))]
+    pub async fn rebuild_guild_cache(&self, guild_id: impl Into<GuildId>) -> Result<()> {
+        let guild_id = guild_id.into();
+
+        let partial_guild = self.http.get_guild(guild_id).await?;
+        let channels = self.http.get_channels(guild_id).await?;
+        let threads = self.http.get_guild_active_threads(guild_id).await?.threads;
+
+        let Some(mut guild) = self.cache.clear_guild(guild_id) else {
+            return Err(Error::Model(ModelError::GuildNotFound));
+        };
+
+        guild.channels = channels.into_iter().map(|channel| (channel.id, channel)).collect();
+        guild.threads = threads;
+        guild.emojis.clone_from(&partial_guild.emojis);
+        guild.stickers.clone_from(&partial_guild.stickers);
+        guild.members.clear();
+
+        self.cache.update(&mut GuildCreateEvent {
+            guild,
+        });
+        self.cache.update(&mut GuildUpdateEvent {
+            guild: partial_guild,
+        });
+
+        self.shard.chunk_guild(guild_id, None, false, ChunkGuildFilter::None, None);
+
+        Ok(())
+    }
 }
 
 impl AsRef<Http> for Context {
@@ -1,9 +1,14 @@
+use std::any::Any;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 
-use tracing::debug;
+use futures::FutureExt as _;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info_span, Instrument as _, Span};
 
 #[cfg(feature = "gateway")]
-use super::event_handler::{EventHandler, RawEventHandler};
+use super::event_handler::{EventHandler, HandlerErrorHook, RawEventHandler};
 use super::{Context, FullEvent};
 #[cfg(feature = "cache")]
 use crate::cache::{Cache, CacheUpdate};
@@ -13,8 +18,7 @@ use crate::internal::tokio::spawn_named;
 use crate::model::channel::ChannelType;
 use crate::model::event::Event;
 use crate::model::guild::Member;
-#[cfg(feature = "cache")]
-use crate::model::id::GuildId;
+use crate::model::id::{ChannelId, GuildId};
 
 #[cfg(feature = "cache")]
 macro_rules! if_cache {
@@ -48,46 +52,115 @@ pub(crate) fn dispatch_model(
     #[cfg(feature = "framework")] framework: Option<Arc<dyn Framework>>,
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    handler_error_hook: Option<&HandlerErrorHook>,
 ) {
+    let guild_id = event.guild_id();
+    let channel_id = event.channel_id();
+    let span = |name: &'static str| {
+        info_span!(
+            "dispatch",
+            event = name,
+            shard_id = context.shard_id.0,
+            guild_id = guild_id.map(GuildId::get),
+            channel_id = channel_id.map(ChannelId::get),
+        )
+    };
+
+    let raw_span = span("raw_event");
     for raw_handler in raw_event_handlers {
         let (context, event) = (context.clone(), event.clone());
-        tokio::spawn(async move { raw_handler.raw_event(context, event).await });
+        let future = async move {
+            raw_handler.raw_event(context, event).await;
+        };
+        spawn_handler(
+            "raw_event",
+            handler_error_hook.cloned(),
+            future.instrument(raw_span.clone()),
+        );
     }
 
-    let full_events = update_cache_with_event(
-        #[cfg(feature = "cache")]
-        &context.cache,
-        event,
-    );
+    let full_events = raw_span.in_scope(|| {
+        update_cache_with_event(
+            #[cfg(feature = "cache")]
+            &context.cache,
+            event,
+        )
+    });
 
     if let Some(events) = full_events {
-        let iter = std::iter::once(events.0).chain(events.1);
+        let events: Vec<(FullEvent, Span)> = std::iter::once(events.0)
+            .chain(events.1)
+            .map(|event| {
+                let span = span(event.snake_case_name());
+                (event, span)
+            })
+            .collect();
+
+        #[cfg(feature = "collector")]
+        context.shard.full_event_collectors.lock().expect("poison").retain_mut(|callback| {
+            events.iter().fold(true, |keep, (event, _)| (callback.0)(event) && keep)
+        });
+
         for handler in event_handlers {
-            for event in iter.clone() {
+            for (event, span) in events.clone() {
                 let context = context.clone();
                 let handler = Arc::clone(&handler);
-                spawn_named(event.snake_case_name(), async move {
+                let name = event.snake_case_name();
+                let future = async move {
                     event.dispatch(context, &*handler).await;
-                });
+                };
+                spawn_handler(name, handler_error_hook.cloned(), future.instrument(span));
             }
         }
 
         #[cfg(feature = "framework")]
         if let Some(framework) = framework {
-            for event in iter {
+            for (event, span) in events {
                 let context = context.clone();
                 let framework = Arc::clone(&framework);
-                spawn_named("dispatch::framework::dispatch", async move {
+                let future = async move {
                     framework.dispatch(context, event).await;
-                });
+                };
+                spawn_handler("framework", handler_error_hook.cloned(), future.instrument(span));
             }
         }
     }
 }
 
+/// Spawns an event handler future, catching any panic so that it is reported to the
+/// [`HandlerErrorHook`] (or logged, if there is none) instead of silently killing the task.
+pub(crate) fn spawn_handler<F>(
+    name: &'static str,
+    hook: Option<HandlerErrorHook>,
+    future: F,
+) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    spawn_named(name, async move {
+        if let Err(payload) = AssertUnwindSafe(future).catch_unwind().await {
+            if let Some(hook) = hook {
+                hook(name, payload);
+            } else {
+                error!("Event handler for {name} panicked: {}", panic_message(&*payload));
+            }
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "<non-string payload>"
+    }
+}
+
 /// Updates the cache with the incoming event data and builds the full event data out of it.
 ///
-/// Can return a secondary [`FullEvent`] for "virtual" events like [`FullEvent::CacheReady`] or
+/// Can return secondary [`FullEvent`]s for "virtual" events like [`FullEvent::CacheReady`] or
 /// [`FullEvent::ShardsReady`]. Secondary events are traditionally dispatched first.
 ///
 /// Can return `None` if an event is unknown.
@@ -95,8 +168,8 @@ pub(crate) fn dispatch_model(
 fn update_cache_with_event(
     #[cfg(feature = "cache")] cache: &Cache,
     event: Event,
-) -> Option<(FullEvent, Option<FullEvent>)> {
-    let mut extra_event = None;
+) -> Option<(FullEvent, Vec<FullEvent>)> {
+    let mut extra_events = Vec::new();
     let event = match event {
         Event::CommandPermissionsUpdate(event) => FullEvent::CommandPermissionsUpdate {
             permission: event.permission,
@@ -113,6 +186,16 @@ fn update_cache_with_event(
         Event::AutoModActionExecution(event) => FullEvent::AutoModActionExecution {
             execution: event.execution,
         },
+        Event::CallCreate(event) => FullEvent::CallCreate {
+            call: event.call,
+        },
+        Event::CallUpdate(event) => FullEvent::CallUpdate {
+            call: event.call,
+        },
+        Event::CallDelete(event) => FullEvent::CallDelete {
+            channel_id: event.channel_id,
+            unavailable: event.unavailable,
+        },
         Event::ChannelCreate(mut event) => {
             update_cache!(cache, event);
 
@@ -170,15 +253,16 @@ fn update_cache_with_event(
 
             update_cache!(cache, event);
 
+            // Only the last of the guilds received on Ready completes the cache.
             #[cfg(feature = "cache")]
             {
-                if cache.unavailable_guilds.len() == 0 {
+                if is_new == Some(false) && cache.unavailable_guilds.len() == 0 {
                     cache.unavailable_guilds.shrink_to_fit();
 
                     let guild_amount =
                         cache.guilds.iter().map(|i| *i.key()).collect::<Vec<GuildId>>();
 
-                    extra_event = Some(FullEvent::CacheReady {
+                    extra_events.push(FullEvent::CacheReady {
                         guilds: guild_amount,
                     });
                 }
@@ -198,10 +282,11 @@ fn update_cache_with_event(
             }
         },
         Event::GuildEmojisUpdate(mut event) => {
-            update_cache!(cache, event);
+            let old_if_available = if_cache!(event.update(cache));
 
             FullEvent::GuildEmojisUpdate {
                 guild_id: event.guild_id,
+                old_if_available,
                 current_state: event.emojis,
             }
         },
@@ -238,7 +323,13 @@ fn update_cache_with_event(
             }
         },
         Event::GuildMembersChunk(mut event) => {
-            update_cache!(cache, event);
+            #[cfg(feature = "cache")]
+            if event.update(cache).is_some() {
+                extra_events.push(FullEvent::GuildMembersChunked {
+                    guild_id: event.guild_id,
+                    nonce: event.nonce.clone(),
+                });
+            }
 
             FullEvent::GuildMembersChunk {
                 chunk: event,
@@ -269,43 +360,94 @@ fn update_cache_with_event(
             }
         },
         Event::GuildStickersUpdate(mut event) => {
-            update_cache!(cache, event);
+            let old_if_available = if_cache!(event.update(cache));
 
             FullEvent::GuildStickersUpdate {
                 guild_id: event.guild_id,
+                old_if_available,
                 current_state: event.stickers,
             }
         },
-        Event::GuildUpdate(event) => {
+        Event::GuildUpdate(mut event) => {
             let before = if_cache!(cache.guild(event.guild.id).map(|g| g.clone()));
+            update_cache!(cache, event);
 
             FullEvent::GuildUpdate {
                 old_data_if_available: before,
                 new_data: event.guild,
             }
         },
-        Event::InviteCreate(event) => FullEvent::InviteCreate {
-            data: event,
+        Event::InviteCreate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::InviteCreate {
+                data: event,
+            }
+        },
+        Event::InviteDelete(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::InviteDelete {
+                data: event,
+            }
         },
-        Event::InviteDelete(event) => FullEvent::InviteDelete {
-            data: event,
+        Event::MessageAck(mut event) => {
+            #[cfg(feature = "cache")]
+            {
+                let old = event.update(cache);
+                if let Some(new) = cache.unreads().get(event.channel_id) {
+                    extra_events.push(FullEvent::UnreadUpdate {
+                        channel_id: event.channel_id,
+                        old,
+                        new,
+                    });
+                }
+            }
+
+            FullEvent::MessageAck {
+                event,
+            }
         },
         Event::MessageCreate(mut event) => {
+            #[cfg(feature = "cache")]
+            let old_unread = cache.unreads().get(event.message.channel_id);
+
             update_cache!(cache, event);
 
+            #[cfg(feature = "cache")]
+            if let Some(new) = cache.unreads().get(event.message.channel_id) {
+                if old_unread != Some(new) {
+                    extra_events.push(FullEvent::UnreadUpdate {
+                        channel_id: event.message.channel_id,
+                        old: old_unread,
+                        new,
+                    });
+                }
+            }
+
             FullEvent::Message {
                 new_message: event.message,
             }
         },
-        Event::MessageDeleteBulk(event) => FullEvent::MessageDeleteBulk {
-            channel_id: event.channel_id,
-            multiple_deleted_messages_ids: event.ids,
-            guild_id: event.guild_id,
+        Event::MessageDeleteBulk(mut event) => {
+            let messages = if_cache!(event.update(cache));
+
+            FullEvent::MessageDeleteBulk {
+                channel_id: event.channel_id,
+                multiple_deleted_messages_ids: event.ids,
+                guild_id: event.guild_id,
+                deleted_messages_if_available: messages,
+            }
         },
-        Event::MessageDelete(event) => FullEvent::MessageDelete {
-            channel_id: event.channel_id,
-            deleted_message_id: event.message_id,
-            guild_id: event.guild_id,
+        Event::MessageDelete(mut event) => {
+            let message = if_cache!(event.update(cache));
+
+            FullEvent::MessageDelete {
+                channel_id: event.channel_id,
+                deleted_message_id: event.message_id,
+                guild_id: event.guild_id,
+                deleted_message_if_available: message,
+            }
         },
         Event::MessageUpdate(mut event) => {
             let before = if_cache!(event.update(cache));
@@ -322,9 +464,10 @@ fn update_cache_with_event(
             presences: event.presences,
         },
         Event::PresenceUpdate(mut event) => {
-            update_cache!(cache, event);
+            let old_data = if_cache!(event.update(cache));
 
             FullEvent::PresenceUpdate {
+                old_data,
                 new_data: event.presence,
             }
         },
@@ -352,16 +495,47 @@ fn update_cache_with_event(
                     let total = shards.total;
                     drop(shards);
 
-                    extra_event = Some(FullEvent::ShardsReady {
+                    extra_events.push(FullEvent::ShardsReady {
                         total_shards: total,
                     });
                 }
+
+                // Without guilds to wait for, e.g. if Ready listed none or guilds aren't cached,
+                // no GuildCreate would complete the cache, so it is ready right away.
+                if cache.unavailable_guilds.len() == 0 {
+                    extra_events.push(FullEvent::CacheReady {
+                        guilds: cache.guilds.iter().map(|i| *i.key()).collect(),
+                    });
+                }
             }
 
             FullEvent::Ready {
                 data_about_bot: event.ready,
             }
         },
+        Event::RelationshipAdd(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::RelationshipAdd {
+                relationship: event.relationship,
+            }
+        },
+        Event::RelationshipRemove(mut event) => {
+            let removed = if_cache!(event.update(cache));
+
+            FullEvent::RelationshipRemove {
+                relationship: event.relationship,
+                removed_relationship_if_available: removed,
+            }
+        },
+        Event::RelationshipUpdate(mut event) => {
+            let before = if_cache!(event.update(cache));
+
+            FullEvent::RelationshipUpdate {
+                old_data_if_available: before,
+                new: event.relationship,
+            }
+        },
         Event::Resumed(event) => FullEvent::Resume {
             event,
         },
@@ -380,6 +554,9 @@ fn update_cache_with_event(
                 new: event.current_user,
             }
         },
+        Event::UserSettingsUpdate(event) => FullEvent::UserSettingsUpdate {
+            event,
+        },
         Event::VoiceServerUpdate(event) => FullEvent::VoiceServerUpdate {
             event,
         },
@@ -420,14 +597,27 @@ fn update_cache_with_event(
             guild_id: event.guild_id,
             application_id: event.application_id,
         },
-        Event::StageInstanceCreate(event) => FullEvent::StageInstanceCreate {
-            stage_instance: event.stage_instance,
+        Event::StageInstanceCreate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::StageInstanceCreate {
+                stage_instance: event.stage_instance,
+            }
         },
-        Event::StageInstanceUpdate(event) => FullEvent::StageInstanceUpdate {
-            stage_instance: event.stage_instance,
+        Event::StageInstanceUpdate(mut event) => {
+            let old = if_cache!(event.update(cache));
+
+            FullEvent::StageInstanceUpdate {
+                old,
+                stage_instance: event.stage_instance,
+            }
         },
-        Event::StageInstanceDelete(event) => FullEvent::StageInstanceDelete {
-            stage_instance: event.stage_instance,
+        Event::StageInstanceDelete(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::StageInstanceDelete {
+                stage_instance: event.stage_instance,
+            }
         },
         Event::ThreadCreate(mut event) => {
             update_cache!(cache, event);
@@ -452,23 +642,48 @@ fn update_cache_with_event(
                 full_thread_data,
             }
         },
-        Event::ThreadListSync(event) => FullEvent::ThreadListSync {
-            thread_list_sync: event,
+        Event::ThreadListSync(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::ThreadListSync {
+                thread_list_sync: event,
+            }
         },
-        Event::ThreadMemberUpdate(event) => FullEvent::ThreadMemberUpdate {
-            thread_member: event.member,
+        Event::ThreadMemberUpdate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::ThreadMemberUpdate {
+                thread_member: event.member,
+            }
         },
-        Event::ThreadMembersUpdate(event) => FullEvent::ThreadMembersUpdate {
-            thread_members_update: event,
+        Event::ThreadMembersUpdate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::ThreadMembersUpdate {
+                thread_members_update: event,
+            }
         },
-        Event::GuildScheduledEventCreate(event) => FullEvent::GuildScheduledEventCreate {
-            event: event.event,
+        Event::GuildScheduledEventCreate(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventCreate {
+                event: event.event,
+            }
         },
-        Event::GuildScheduledEventUpdate(event) => FullEvent::GuildScheduledEventUpdate {
-            event: event.event,
+        Event::GuildScheduledEventUpdate(mut event) => {
+            let old = if_cache!(event.update(cache));
+
+            FullEvent::GuildScheduledEventUpdate {
+                old,
+                event: event.event,
+            }
         },
-        Event::GuildScheduledEventDelete(event) => FullEvent::GuildScheduledEventDelete {
-            event: event.event,
+        Event::GuildScheduledEventDelete(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::GuildScheduledEventDelete {
+                event: event.event,
+            }
         },
         Event::GuildScheduledEventUserAdd(event) => FullEvent::GuildScheduledEventUserAdd {
             subscribed: event,
@@ -476,6 +691,20 @@ fn update_cache_with_event(
         Event::GuildScheduledEventUserRemove(event) => FullEvent::GuildScheduledEventUserRemove {
             unsubscribed: event,
         },
+        Event::GuildSoundboardSoundCreate(event) => FullEvent::GuildSoundboardSoundCreate {
+            sound: event.sound,
+        },
+        Event::GuildSoundboardSoundUpdate(event) => FullEvent::GuildSoundboardSoundUpdate {
+            sound: event.sound,
+        },
+        Event::GuildSoundboardSoundDelete(event) => FullEvent::GuildSoundboardSoundDelete {
+            guild_id: event.guild_id,
+            sound_id: event.sound_id,
+        },
+        Event::GuildSoundboardSoundsUpdate(event) => FullEvent::GuildSoundboardSoundsUpdate {
+            guild_id: event.guild_id,
+            sounds: event.soundboard_sounds,
+        },
         Event::EntitlementCreate(event) => FullEvent::EntitlementCreate {
             entitlement: event.entitlement,
         },
@@ -485,13 +714,44 @@ fn update_cache_with_event(
         Event::EntitlementDelete(event) => FullEvent::EntitlementDelete {
             entitlement: event.entitlement,
         },
-        Event::MessagePollVoteAdd(event) => FullEvent::MessagePollVoteAdd {
-            event,
+        Event::MessagePollVoteAdd(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::MessagePollVoteAdd {
+                event,
+            }
         },
-        Event::MessagePollVoteRemove(event) => FullEvent::MessagePollVoteRemove {
-            event,
+        Event::MessagePollVoteRemove(mut event) => {
+            update_cache!(cache, event);
+
+            FullEvent::MessagePollVoteRemove {
+                event,
+            }
         },
     };
 
-    Some((event, extra_event))
+    Some((event, extra_events))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::spawn_handler;
+    use crate::client::HandlerErrorHook;
+
+    #[tokio::test]
+    async fn test_handler_panic_reaches_hook() {
+        let caught = Arc::new(Mutex::new(None));
+        let caught_clone = Arc::clone(&caught);
+        let hook: HandlerErrorHook = Arc::new(move |name, payload| {
+            let message = payload.downcast_ref::<&str>().copied();
+            *caught_clone.lock().unwrap() = Some((name, message));
+        });
+
+        let handle = spawn_handler("message", Some(hook), async { panic!("boom") });
+        assert!(handle.await.is_ok());
+
+        assert_eq!(*caught.lock().unwrap(), Some(("message", Some("boom"))));
+    }
 }
@@ -14,6 +14,11 @@ pub enum Error {
     ShardBootFailure,
     /// When all shards that the client is responsible for have shutdown with an error.
     Shutdown,
+    /// When the environment variable which should contain the token, named by
+    /// [`ClientSettings::token_env`], is not set.
+    ///
+    /// [`ClientSettings::token_env`]: super::ClientSettings::token_env
+    MissingToken(String),
 }
 
 impl fmt::Display for Error {
@@ -21,6 +26,7 @@ impl fmt::Display for Error {
         match self {
             Self::ShardBootFailure => f.write_str("Failed to (re-)boot a shard"),
             Self::Shutdown => f.write_str("The clients shards shutdown"),
+            Self::MissingToken(var) => write!(f, "The environment variable {var} is not set"),
         }
     }
 }
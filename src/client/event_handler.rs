@@ -1,6 +1,11 @@
+use std::any::Any;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use super::context::Context;
+#[cfg(feature = "cache")]
+use crate::cache::ChannelUnread;
 use crate::gateway::ShardStageUpdateEvent;
 use crate::http::RatelimitInfo;
 use crate::model::prelude::*;
@@ -106,10 +111,15 @@ event_handler! {
     /// Provides said action execution's data.
     AutoModActionExecution { execution: ActionExecution } => async fn auto_moderation_action_execution(&self, ctx: Context);
 
-    /// Dispatched when the cache has received and inserted all data from guilds.
+    /// Dispatched when the cache has received and inserted all data from guilds, i.e. once the
+    /// last of the guilds listed in [`Ready`] was received, or directly after [`Ready`] if there
+    /// are no guilds to wait for.
     ///
     /// This process happens upon starting your bot and should be fairly quick. However, cache
-    /// actions performed prior this event may fail as the data could be not inserted yet.
+    /// actions performed prior this event may fail as the data could be not inserted yet. See
+    /// also [`Cache::is_ready`].
+    ///
+    /// [`Cache::is_ready`]: crate::cache::Cache::is_ready
     ///
     /// Provides the cached guilds' ids.
     #[cfg(feature = "cache")]
@@ -119,6 +129,23 @@ event_handler! {
     #[cfg(feature = "cache")]
     ShardsReady { total_shards: u32 } => async fn shards_ready(&self, ctx: Context);
 
+    /// Dispatched when a call is started in a private channel.
+    ///
+    /// Provides the call's data. Only dispatched for user accounts.
+    CallCreate { call: Call } => async fn call_create(&self, ctx: Context);
+
+    /// Dispatched when a call in a private channel is updated, e.g. users start or stop being
+    /// rung.
+    ///
+    /// Provides the call's data. Only dispatched for user accounts.
+    CallUpdate { call: Call } => async fn call_update(&self, ctx: Context);
+
+    /// Dispatched when a call in a private channel ends.
+    ///
+    /// Provides the channel's Id and whether the call only became unavailable due to an outage.
+    /// Only dispatched for user accounts.
+    CallDelete { channel_id: ChannelId, unavailable: bool } => async fn call_delete(&self, ctx: Context);
+
     /// Dispatched when a channel is created.
     ///
     /// Provides said channel's data.
@@ -185,8 +212,9 @@ event_handler! {
 
     /// Dispatched when the emojis are updated.
     ///
-    /// Provides the guild's id and the new state of the emojis in the guild.
-    GuildEmojisUpdate { guild_id: GuildId, current_state: HashMap<EmojiId, Emoji> } => async fn guild_emojis_update(&self, ctx: Context);
+    /// Provides the guild's id, the old state of the emojis (if cache feature is enabled and the
+    /// data is available) and the new state of the emojis in the guild.
+    GuildEmojisUpdate { guild_id: GuildId, old_if_available: Option<HashMap<EmojiId, Emoji>>, current_state: HashMap<EmojiId, Emoji> } => async fn guild_emojis_update(&self, ctx: Context);
 
     /// Dispatched when a guild's integration is added, updated or removed.
     ///
@@ -224,6 +252,16 @@ event_handler! {
     /// Provides the guild's id and the data.
     GuildMembersChunk { chunk: GuildMembersChunkEvent } => async fn guild_members_chunk(&self, ctx: Context);
 
+    /// Dispatched when the last chunk of a member request was received, i.e. all requested
+    /// members are cached.
+    ///
+    /// Provides the guild's id and the nonce of the request. See [`Cache::is_chunked`] to check
+    /// whether all members of the guild are cached.
+    ///
+    /// [`Cache::is_chunked`]: crate::cache::Cache::is_chunked
+    #[cfg(feature = "cache")]
+    GuildMembersChunked { guild_id: GuildId, nonce: Option<String> } => async fn guild_members_chunked(&self, ctx: Context);
+
     /// Dispatched when a role is created.
     ///
     /// Provides the guild's id and the new role's data.
@@ -243,8 +281,9 @@ event_handler! {
 
     /// Dispatched when the stickers are updated.
     ///
-    /// Provides the guild's id and the new state of the stickers in the guild.
-    GuildStickersUpdate { guild_id: GuildId, current_state: HashMap<StickerId, Sticker> } => async fn guild_stickers_update(&self, ctx: Context);
+    /// Provides the guild's id, the old state of the stickers (if cache feature is enabled and the
+    /// data is available) and the new state of the stickers in the guild.
+    GuildStickersUpdate { guild_id: GuildId, old_if_available: Option<HashMap<StickerId, Sticker>>, current_state: HashMap<StickerId, Sticker> } => async fn guild_stickers_update(&self, ctx: Context);
 
     /// Dispatched when the guild is updated.
     ///
@@ -267,15 +306,23 @@ event_handler! {
     /// Provides the message's data.
     Message { new_message: Message } => async fn message(&self, ctx: Context);
 
+    /// Dispatched when a channel is acknowledged, i.e. marked as read, by any of the current
+    /// user's sessions.
+    ///
+    /// Only dispatched for user accounts.
+    MessageAck { event: MessageAckEvent } => async fn message_ack(&self, ctx: Context);
+
     /// Dispatched when a message is deleted.
     ///
-    /// Provides the guild's id, the channel's id and the message's id.
-    MessageDelete { channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId> } => async fn message_delete(&self, ctx: Context);
+    /// Provides the guild's id, the channel's id, the message's id and the message's data (if cache
+    /// feature is enabled and the message was cached).
+    MessageDelete { channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId>, deleted_message_if_available: Option<Message> } => async fn message_delete(&self, ctx: Context);
 
     /// Dispatched when multiple messages were deleted at once.
     ///
-    /// Provides the guild's id, channel's id and the deleted messages' ids.
-    MessageDeleteBulk { channel_id: ChannelId, multiple_deleted_messages_ids: Vec<MessageId>, guild_id: Option<GuildId> } => async fn message_delete_bulk(&self, ctx: Context);
+    /// Provides the guild's id, channel's id, the deleted messages' ids and the data of those which
+    /// were cached (if cache feature is enabled).
+    MessageDeleteBulk { channel_id: ChannelId, multiple_deleted_messages_ids: Vec<MessageId>, guild_id: Option<GuildId>, deleted_messages_if_available: Option<Vec<Message>> } => async fn message_delete_bulk(&self, ctx: Context);
 
     /// Dispatched when a message is updated.
     ///
@@ -308,11 +355,12 @@ event_handler! {
 
     /// Dispatched when a user's presence is updated (e.g off -> on).
     ///
-    /// Provides the presence's new data.
+    /// Provides the presence's old (if cache feature is enabled and the data is available) and new
+    /// data.
     ///
     /// Note: This event will not trigger unless the "guild presences" privileged intent is enabled
     /// on the bot application page.
-    PresenceUpdate { new_data: Presence } => async fn presence_update(&self, ctx: Context);
+    PresenceUpdate { old_data: Option<Presence>, new_data: Presence } => async fn presence_update(&self, ctx: Context);
 
     /// Dispatched upon startup.
     ///
@@ -322,6 +370,25 @@ event_handler! {
     /// Dispatched upon reconnection.
     Resume { event: ResumedEvent } => async fn resume(&self, ctx: Context);
 
+    /// Dispatched when the current user gains a relationship, e.g. a friend is added, a user is
+    /// blocked or a friend request is sent or received.
+    ///
+    /// Only dispatched for user accounts.
+    RelationshipAdd { relationship: Relationship } => async fn relationship_add(&self, ctx: Context);
+
+    /// Dispatched when a relationship of the current user is removed, e.g. a friend is removed or
+    /// a friend request is declined.
+    ///
+    /// Provides the removed relationship from the cache, if available, and the event data, which
+    /// does not contain the user.
+    RelationshipRemove { relationship: Relationship, removed_relationship_if_available: Option<Relationship> } => async fn relationship_remove(&self, ctx: Context);
+
+    /// Dispatched when a relationship of the current user is updated, e.g. the nickname of a
+    /// friend is changed.
+    ///
+    /// Provides the old (if cache feature is enabled and the data is available) and new data.
+    RelationshipUpdate { old_data_if_available: Option<Relationship>, new: Relationship } => async fn relationship_update(&self, ctx: Context);
+
     /// Dispatched when a shard's connection stage is updated
     ///
     /// Provides the context of the shard and the event information about the update.
@@ -330,11 +397,26 @@ event_handler! {
     /// Dispatched when a user starts typing.
     TypingStart { event: TypingStartEvent } => async fn typing_start(&self, ctx: Context);
 
+    /// Dispatched when the unread state of a channel changes, after a message is received or the
+    /// channel is acknowledged.
+    ///
+    /// Provides the previous state, if the channel was tracked, and the new state. See
+    /// [`Cache::unreads`].
+    ///
+    /// [`Cache::unreads`]: crate::cache::Cache::unreads
+    #[cfg(feature = "cache")]
+    UnreadUpdate { channel_id: ChannelId, old: Option<ChannelUnread>, new: ChannelUnread } => async fn unread_update(&self, ctx: Context);
+
     /// Dispatched when the bot's data is updated.
     ///
     /// Provides the old (if cache feature is enabled and the data is available) and new data.
     UserUpdate { old_data: Option<CurrentUser>, new: CurrentUser } => async fn user_update(&self, ctx: Context);
 
+    /// Dispatched when the settings of the current user are updated.
+    ///
+    /// Provides the changed settings. Only dispatched for user accounts.
+    UserSettingsUpdate { event: UserSettingsUpdateEvent } => async fn user_settings_update(&self, ctx: Context);
+
     /// Dispatched when a guild's voice server was updated (or changed to another one).
     ///
     /// Provides the voice server's data.
@@ -383,8 +465,9 @@ event_handler! {
 
     /// Dispatched when a stage instance is updated.
     ///
-    /// Provides the updated stage instance.
-    StageInstanceUpdate { stage_instance: StageInstance } => async fn stage_instance_update(&self, ctx: Context);
+    /// Provides the old stage instance (if cache feature is enabled and the data is available) and
+    /// the updated one.
+    StageInstanceUpdate { old: Option<StageInstance>, stage_instance: StageInstance } => async fn stage_instance_update(&self, ctx: Context);
 
     /// Dispatched when a stage instance is deleted.
     ///
@@ -435,8 +518,9 @@ event_handler! {
 
     /// Dispatched when a scheduled event is updated.
     ///
-    /// Provides data about the scheduled event.
-    GuildScheduledEventUpdate { event: ScheduledEvent } => async fn guild_scheduled_event_update(&self, ctx: Context);
+    /// Provides the old data about the scheduled event (if cache feature is enabled and the data
+    /// is available) and the new data.
+    GuildScheduledEventUpdate { old: Option<ScheduledEvent>, event: ScheduledEvent } => async fn guild_scheduled_event_update(&self, ctx: Context);
 
     /// Dispatched when a scheduled event is deleted.
     ///
@@ -453,6 +537,26 @@ event_handler! {
     /// Provides data about the cancelled subscription.
     GuildScheduledEventUserRemove { unsubscribed: GuildScheduledEventUserRemoveEvent } => async fn guild_scheduled_event_user_remove(&self, ctx: Context);
 
+    /// Dispatched when a soundboard sound is added to a guild.
+    ///
+    /// Provides data about the sound.
+    GuildSoundboardSoundCreate { sound: SoundboardSound } => async fn guild_soundboard_sound_create(&self, ctx: Context);
+
+    /// Dispatched when a soundboard sound of a guild is updated.
+    ///
+    /// Provides the new data of the sound.
+    GuildSoundboardSoundUpdate { sound: SoundboardSound } => async fn guild_soundboard_sound_update(&self, ctx: Context);
+
+    /// Dispatched when a soundboard sound is deleted from a guild.
+    ///
+    /// Provides the guild's id and the deleted sound's id.
+    GuildSoundboardSoundDelete { guild_id: GuildId, sound_id: SoundId } => async fn guild_soundboard_sound_delete(&self, ctx: Context);
+
+    /// Dispatched when multiple soundboard sounds of a guild are updated at once.
+    ///
+    /// Provides the guild's id and the new data of the sounds.
+    GuildSoundboardSoundsUpdate { guild_id: GuildId, sounds: Vec<SoundboardSound> } => async fn guild_soundboard_sounds_update(&self, ctx: Context);
+
     /// Dispatched when a user subscribes to a SKU.
     ///
     /// Provides data about the subscription.
@@ -483,6 +587,14 @@ event_handler! {
 
     /// Dispatched when an HTTP rate limit is hit
     Ratelimit { data: RatelimitInfo } => async fn ratelimit(&self);
+
+    /// Dispatched when an unresolved incident is posted or updated on the Discord status page.
+    ///
+    /// Requires enabling [`ClientBuilder::status_monitor`].
+    ///
+    /// [`ClientBuilder::status_monitor`]: super::ClientBuilder::status_monitor
+    #[cfg(feature = "status_monitor")]
+    DiscordIncident { incident: Incident } => async fn discord_incident(&self);
 }
 
 /// This core trait for handling raw events
@@ -491,3 +603,19 @@ pub trait RawEventHandler: Send + Sync {
     /// Dispatched when any event occurs
     async fn raw_event(&self, _ctx: Context, _ev: Event) {}
 }
+
+/// A hook called when an event handler panics, with the [name] of the event being handled and the
+/// panic payload.
+///
+/// Set one via [`ClientBuilder::on_handler_error`].
+///
+/// [name]: FullEvent::snake_case_name
+/// [`ClientBuilder::on_handler_error`]: super::ClientBuilder::on_handler_error
+pub type HandlerErrorHook = Arc<dyn Fn(&'static str, Box<dyn Any + Send>) + Send + Sync>;
+
+/// A hook called with every [`RatelimitInfo`] as soon as a request is ratelimited.
+///
+/// Set one via [`ClientBuilder::on_ratelimit`].
+///
+/// [`ClientBuilder::on_ratelimit`]: super::ClientBuilder::on_ratelimit
+pub type RatelimitHook = Arc<dyn Fn(&RatelimitInfo) + Send + Sync>;
@@ -20,24 +20,55 @@ pub(crate) mod dispatch;
 mod error;
 #[cfg(feature = "gateway")]
 mod event_handler;
+#[cfg(feature = "gateway")]
+mod pool;
+#[cfg(feature = "gateway")]
+mod settings;
+#[cfg(feature = "status_monitor")]
+mod status;
 
+use std::any::Any;
 use std::future::IntoFuture;
 use std::ops::Range;
-use std::sync::Arc;
 #[cfg(feature = "framework")]
 use std::sync::OnceLock;
+use std::sync::{Arc, Weak};
+#[cfg(feature = "status_monitor")]
+use std::time::Duration;
 
 use futures::channel::mpsc::UnboundedReceiver as Receiver;
 use futures::future::BoxFuture;
 use futures::StreamExt as _;
 use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+#[cfg(feature = "gateway")]
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument};
 use typemap_rev::{TypeMap, TypeMapKey};
 
 pub use self::context::Context;
 pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
-pub use self::event_handler::{EventHandler, FullEvent, RawEventHandler};
+pub use self::event_handler::{
+    EventHandler,
+    FullEvent,
+    HandlerErrorHook,
+    RatelimitHook,
+    RawEventHandler,
+};
+#[cfg(feature = "gateway")]
+pub use self::pool::{AccountEvent, ClientPool};
+#[cfg(all(feature = "gateway", feature = "cache"))]
+pub use self::settings::CacheLimits;
+#[cfg(feature = "gateway")]
+pub use self::settings::{
+    ActivitySettings,
+    ClientSettings,
+    PresenceSettings,
+    RatelimitSettings,
+    RetrySettings,
+    ShardSettings,
+};
 #[cfg(feature = "gateway")]
 use super::gateway::GatewayError;
 #[cfg(feature = "cache")]
@@ -51,9 +82,11 @@ use crate::gateway::VoiceGatewayManager;
 use crate::gateway::{ActivityData, PresenceData};
 #[cfg(feature = "gateway")]
 use crate::gateway::{ShardManager, ShardManagerOptions};
-use crate::http::Http;
+use crate::http::{Http, RatelimitInfo};
 use crate::internal::prelude::*;
 #[cfg(feature = "gateway")]
+use crate::internal::tokio::spawn_named;
+#[cfg(feature = "gateway")]
 use crate::model::gateway::GatewayIntents;
 use crate::model::id::ApplicationId;
 use crate::model::user::OnlineStatus;
@@ -63,6 +96,7 @@ use crate::model::user::OnlineStatus;
 #[must_use = "Builders do nothing unless they are awaited"]
 pub struct ClientBuilder {
     data: TypeMap,
+    data_arc: Option<Arc<RwLock<TypeMap>>>,
     http: Http,
     intents: GatewayIntents,
     #[cfg(feature = "cache")]
@@ -73,7 +107,13 @@ pub struct ClientBuilder {
     voice_manager: Option<Arc<dyn VoiceGatewayManager>>,
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    handler_error_hook: Option<HandlerErrorHook>,
+    ratelimit_hook: Option<RatelimitHook>,
+    cancellation_token: Option<CancellationToken>,
+    #[cfg(feature = "status_monitor")]
+    status_monitor: Option<Duration>,
     presence: PresenceData,
+    shards: Option<(Range<u32>, u32)>,
 }
 
 #[cfg(feature = "gateway")]
@@ -81,6 +121,7 @@ impl ClientBuilder {
     fn _new(http: Http, intents: GatewayIntents) -> Self {
         Self {
             data: TypeMap::new(),
+            data_arc: None,
             http,
             intents,
             #[cfg(feature = "cache")]
@@ -91,7 +132,13 @@ impl ClientBuilder {
             voice_manager: None,
             event_handlers: vec![],
             raw_event_handlers: vec![],
+            handler_error_hook: None,
+            ratelimit_hook: None,
+            cancellation_token: None,
+            #[cfg(feature = "status_monitor")]
+            status_monitor: None,
             presence: PresenceData::default(),
+            shards: None,
         }
     }
 
@@ -150,6 +197,17 @@ impl ClientBuilder {
         self
     }
 
+    /// This method allows to pass an already shared [`TypeMap`], to be used as [`Client::data`]
+    /// as-is. This is useful for sharing data between multiple clients, see [`ClientPool`].
+    ///
+    /// If set, the [`TypeMap`] configured via [`Self::type_map`] and [`Self::type_map_insert`] is
+    /// ignored.
+    pub fn type_map_arc(mut self, type_map: Arc<RwLock<TypeMap>>) -> Self {
+        self.data_arc = Some(type_map);
+
+        self
+    }
+
     /// Gets the type map. See [`Self::type_map`] for more info.
     pub fn get_type_map(&self) -> &TypeMap {
         &self.data
@@ -302,6 +360,117 @@ impl ClientBuilder {
         &self.raw_event_handlers
     }
 
+    /// Sets a hook which is called whenever an event handler panics, with the name of the event
+    /// being handled and the panic payload.
+    ///
+    /// Handlers are run in their own tasks, and panics are always caught so that they don't
+    /// silently kill the task. Without a hook, panics are only logged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder("token", GatewayIntents::default())
+    ///     .on_handler_error(|event, payload| {
+    ///         let message = payload.downcast_ref::<&str>().copied().unwrap_or("unknown panic");
+    ///         eprintln!("handler for {event} panicked: {message}");
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_handler_error<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&'static str, Box<dyn Any + Send>) + Send + Sync + 'static,
+    {
+        self.handler_error_hook = Some(Arc::new(hook));
+
+        self
+    }
+
+    /// Gets the handler error hook, if set. See [`Self::on_handler_error`] for more info.
+    pub fn get_handler_error_hook(&self) -> Option<&HandlerErrorHook> {
+        self.handler_error_hook.as_ref()
+    }
+
+    /// Sets a hook which is called synchronously, from within the HTTP client, whenever a request
+    /// is delayed because of a ratelimit.
+    ///
+    /// Unlike [`EventHandler::ratelimit`], which is dispatched to a new task for every event
+    /// handler, the hook is called before the request waits, making it suited for metrics.
+    pub fn on_ratelimit<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RatelimitInfo) + Send + Sync + 'static,
+    {
+        self.ratelimit_hook = Some(Arc::new(hook));
+
+        self
+    }
+
+    /// Gets the ratelimit hook, if set. See [`Self::on_ratelimit`] for more info.
+    pub fn get_ratelimit_hook(&self) -> Option<&RatelimitHook> {
+        self.ratelimit_hook.as_ref()
+    }
+
+    /// Sets the token which, once cancelled, shuts the client down.
+    ///
+    /// Cancelling the token shuts down all shards - which also ends their collectors - and
+    /// deregisters them from the voice manager, if any. Use [`Client::wait_for_shutdown`] to wait
+    /// until this teardown has finished. If no token is given, the client creates its own, which
+    /// can be retrieved via [`Client::cancellation_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = CancellationToken::new();
+    /// let mut client = Client::builder("token", GatewayIntents::default())
+    ///     .cancellation_token(token.clone())
+    ///     .await?;
+    ///
+    /// client.start().await?;
+    ///
+    /// // Later, possibly from another task:
+    /// token.cancel();
+    /// client.wait_for_shutdown().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+
+        self
+    }
+
+    /// Gets the cancellation token, if set. See [`Self::cancellation_token`] for more info.
+    pub fn get_cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    /// Polls the [Discord status page] at the given interval, dispatching
+    /// [`EventHandler::discord_incident`] whenever an incident is posted or updated.
+    ///
+    /// Polling stops once the client's [cancellation token] is cancelled.
+    ///
+    /// [Discord status page]: https://discordstatus.com
+    /// [cancellation token]: Self::cancellation_token
+    #[cfg(feature = "status_monitor")]
+    pub fn status_monitor(mut self, interval: Duration) -> Self {
+        self.status_monitor = Some(interval);
+
+        self
+    }
+
+    /// Gets the status page polling interval, if set. See [`Self::status_monitor`] for more info.
+    #[cfg(feature = "status_monitor")]
+    pub fn get_status_monitor(&self) -> Option<Duration> {
+        self.status_monitor
+    }
+
     /// Sets the initial activity.
     pub fn activity(mut self, activity: ActivityData) -> Self {
         self.presence.activity = Some(activity);
@@ -320,6 +489,21 @@ impl ClientBuilder {
     pub fn get_presence(&self) -> &PresenceData {
         &self.presence
     }
+
+    /// Sets the range of shards this process is responsible for, out of `total_shards`.
+    ///
+    /// If set, [`Client::start`] starts these shards, as if [`Client::start_shard_range`] was
+    /// called with the same arguments.
+    pub fn shards(mut self, range: Range<u32>, total_shards: u32) -> Self {
+        self.shards = Some((range, total_shards));
+
+        self
+    }
+
+    /// Gets the shard range and total shard count, if set. See [`Self::shards`] for more info.
+    pub fn get_shards(&self) -> Option<&(Range<u32>, u32)> {
+        self.shards.as_ref()
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -330,22 +514,34 @@ impl IntoFuture for ClientBuilder {
 
     #[instrument(skip(self))]
     fn into_future(self) -> Self::IntoFuture {
-        let data = Arc::new(RwLock::new(self.data));
+        let data = self.data_arc.unwrap_or_else(|| Arc::new(RwLock::new(self.data)));
         #[cfg(feature = "framework")]
         let framework = self.framework;
         let event_handlers = self.event_handlers;
         let raw_event_handlers = self.raw_event_handlers;
+        let handler_error_hook = self.handler_error_hook;
+        let cancellation_token = self.cancellation_token.unwrap_or_default();
+        #[cfg(feature = "status_monitor")]
+        let status_monitor = self.status_monitor;
         let intents = self.intents;
         let presence = self.presence;
+        let shards = self.shards;
 
         let mut http = self.http;
 
         if let Some(ratelimiter) = &mut http.ratelimiter {
             let event_handlers_clone = event_handlers.clone();
+            let hook = handler_error_hook.clone();
+            let ratelimit_hook = self.ratelimit_hook;
             ratelimiter.set_ratelimit_callback(Box::new(move |info| {
+                if let Some(ratelimit_hook) = &ratelimit_hook {
+                    ratelimit_hook(&info);
+                }
                 for event_handler in event_handlers_clone.iter().map(Arc::clone) {
                     let info = info.clone();
-                    tokio::spawn(async move { event_handler.ratelimit(info).await });
+                    dispatch::spawn_handler("ratelimit", hook.clone(), async move {
+                        event_handler.ratelimit(info).await;
+                    });
                 }
             }));
         }
@@ -367,12 +563,27 @@ impl IntoFuture for ClientBuilder {
                 },
             }));
 
+            #[cfg(feature = "status_monitor")]
+            if let Some(interval) = status_monitor {
+                spawn_named(
+                    "client::status_monitor",
+                    status::monitor(
+                        interval,
+                        Arc::clone(&http),
+                        event_handlers.clone(),
+                        handler_error_hook.clone(),
+                        cancellation_token.clone(),
+                    ),
+                );
+            }
+
             #[cfg(feature = "framework")]
             let framework_cell = Arc::new(OnceLock::new());
             let (shard_manager, shard_manager_ret_value) = ShardManager::new(ShardManagerOptions {
                 data: Arc::clone(&data),
                 event_handlers,
                 raw_event_handlers,
+                handler_error_hook,
                 #[cfg(feature = "framework")]
                 framework: Arc::clone(&framework_cell),
                 shard_index: 0,
@@ -388,10 +599,26 @@ impl IntoFuture for ClientBuilder {
                 presence: Some(presence),
             });
 
+            let shutdown_complete = CancellationToken::new();
+            let cancellation_watcher = spawn_named(
+                "client::cancellation",
+                watch_cancellation(
+                    cancellation_token.clone(),
+                    shutdown_complete.clone(),
+                    Arc::downgrade(&shard_manager),
+                    #[cfg(feature = "voice")]
+                    voice_manager.clone(),
+                ),
+            );
+
             let client = Client {
                 data,
                 shard_manager,
                 shard_manager_return_value: shard_manager_ret_value,
+                cancellation_token,
+                cancellation_watcher,
+                shutdown_complete,
+                shards,
                 #[cfg(feature = "voice")]
                 voice_manager,
                 ws_url,
@@ -411,6 +638,40 @@ impl IntoFuture for ClientBuilder {
     }
 }
 
+/// Shuts down the shards of a client once its cancellation token is cancelled, then marks the
+/// shutdown as complete.
+///
+/// Only a weak reference to the [`ShardManager`] is held, so that the manager can still be dropped
+/// together with the [`Client`] if the token is never cancelled. In that case, the task is aborted
+/// when the [`Client`] is dropped.
+#[cfg(feature = "gateway")]
+async fn watch_cancellation(
+    cancellation_token: CancellationToken,
+    shutdown_complete: CancellationToken,
+    shard_manager: Weak<ShardManager>,
+    #[cfg(feature = "voice")] voice_manager: Option<Arc<dyn VoiceGatewayManager>>,
+) {
+    cancellation_token.cancelled().await;
+
+    if let Some(shard_manager) = shard_manager.upgrade() {
+        info!("Cancellation requested, shutting down client");
+
+        #[cfg(feature = "voice")]
+        let shard_ids = shard_manager.shards_instantiated().await;
+
+        shard_manager.shutdown_all().await;
+
+        #[cfg(feature = "voice")]
+        if let Some(voice_manager) = voice_manager {
+            for shard_id in shard_ids {
+                voice_manager.deregister_shard(shard_id.0).await;
+            }
+        }
+    }
+
+    shutdown_complete.cancel();
+}
+
 /// The Client is the way to be able to start sending authenticated requests over the REST API, as
 /// well as initializing a WebSocket connection through [`Shard`]s. Refer to the [documentation on
 /// using sharding][sharding docs] for more information.
@@ -605,6 +866,10 @@ pub struct Client {
     /// ```
     pub shard_manager: Arc<ShardManager>,
     shard_manager_return_value: Receiver<Result<(), GatewayError>>,
+    cancellation_token: CancellationToken,
+    cancellation_watcher: JoinHandle<()>,
+    shutdown_complete: CancellationToken,
+    shards: Option<(Range<u32>, u32)>,
     /// The voice manager for the client.
     ///
     /// This is an ergonomic structure for interfacing over shards' voice
@@ -624,11 +889,35 @@ pub struct Client {
     pub http: Arc<Http>,
 }
 
+impl Drop for Client {
+    fn drop(&mut self) {
+        // Once cancelled, let the watcher finish shutting down the shards.
+        if !self.cancellation_token.is_cancelled() {
+            self.cancellation_watcher.abort();
+        }
+    }
+}
+
 impl Client {
     pub fn builder(token: impl AsRef<str>, intents: GatewayIntents) -> ClientBuilder {
         ClientBuilder::new(token, intents)
     }
 
+    /// Returns the token which shuts the client down once cancelled.
+    ///
+    /// This is either the token given to [`ClientBuilder::cancellation_token`], or one created by
+    /// the client. Clones of it can be handed to other tasks.
+    #[must_use]
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
+    /// Waits until the client has been cancelled via its [`Self::cancellation_token`] and all of
+    /// its shards have been shut down and deregistered from the voice manager.
+    pub async fn wait_for_shutdown(&self) {
+        self.shutdown_complete.cancelled().await;
+    }
+
     /// Establish the connection and start listening for events.
     ///
     /// This will start receiving events in a loop and start dispatching the events to your
@@ -641,6 +930,9 @@ impl Client {
     /// Refer to the [Gateway documentation][gateway docs] for more information on effectively
     /// using sharding.
     ///
+    /// If a shard range was configured via [`ClientBuilder::shards`], that range is started
+    /// instead.
+    ///
     /// # Examples
     ///
     /// Starting a Client with only 1 shard, out of 1 total:
@@ -664,7 +956,10 @@ impl Client {
     /// [gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<()> {
-        self.start_connection(0, 0, 1).await
+        match self.shards.clone() {
+            Some((range, total_shards)) => self.start_shard_range(range, total_shards).await,
+            None => self.start_connection(0, 0, 1).await,
+        }
     }
 
     /// Establish the connection(s) and start listening for events.
@@ -0,0 +1,192 @@
+use std::sync::{Arc, Mutex as StdMutex};
+
+use async_trait::async_trait;
+use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
+use futures::future::join_all;
+use tokio::sync::RwLock;
+use typemap_rev::TypeMap;
+
+use super::{Client, ClientBuilder, Context, RawEventHandler};
+use crate::internal::prelude::*;
+use crate::model::event::Event;
+
+/// An event received by one of the clients of a [`ClientPool`], tagged with the label of the
+/// account which received it.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct AccountEvent {
+    /// The label the client was added to the pool with.
+    pub account: Arc<str>,
+    /// The context of the client and shard that received the event.
+    pub context: Context,
+    /// The raw event, as also received by [`RawEventHandler`]s.
+    pub event: Event,
+}
+
+type Subscribers = Arc<StdMutex<Vec<Sender<AccountEvent>>>>;
+
+struct PoolForwarder {
+    account: Arc<str>,
+    subscribers: Subscribers,
+}
+
+#[async_trait]
+impl RawEventHandler for PoolForwarder {
+    async fn raw_event(&self, context: Context, event: Event) {
+        let mut subscribers = self.subscribers.lock().expect("poison");
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let event = AccountEvent {
+            account: Arc::clone(&self.account),
+            context,
+            event,
+        };
+        subscribers.retain(|tx| tx.unbounded_send(event.clone()).is_ok());
+    }
+}
+
+/// Manages several [`Client`]s - usually logged into different accounts - under one runtime.
+///
+/// All clients share the same [`Self::data`], the events they receive can be consumed as a single
+/// stream via [`Self::subscribe`], and they can be started and shut down together.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serenity::client::ClientPool;
+/// use serenity::prelude::*;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut pool = ClientPool::new();
+/// pool.add("main", Client::builder("first token", GatewayIntents::default())).await?;
+/// pool.add("alt", Client::builder("second token", GatewayIntents::default())).await?;
+///
+/// let mut events = pool.subscribe();
+/// pool.start_all().await?;
+///
+/// while let Some(event) = events.next().await {
+///     println!("{} received {:?}", event.account, event.event);
+/// }
+///
+/// pool.shutdown_all().await;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientPool {
+    /// The [`TypeMap`] shared by all clients of the pool.
+    ///
+    /// This is the same map as each client's [`Client::data`] and [`Context::data`].
+    pub data: Arc<RwLock<TypeMap>>,
+    clients: Vec<(Arc<str>, Client)>,
+    subscribers: Subscribers,
+}
+
+impl ClientPool {
+    /// Creates an empty pool with an empty shared [`TypeMap`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_type_map(TypeMap::new())
+    }
+
+    /// Creates an empty pool, sharing the given [`TypeMap`] between all clients.
+    #[must_use]
+    pub fn with_type_map(type_map: TypeMap) -> Self {
+        Self {
+            data: Arc::new(RwLock::new(type_map)),
+            clients: Vec::new(),
+            subscribers: Arc::default(),
+        }
+    }
+
+    /// Builds a client from the given builder and adds it to the pool under the given label.
+    ///
+    /// The builder's own [`TypeMap`] is replaced by the pool's shared [`Self::data`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if building the client fails.
+    pub async fn add(
+        &mut self,
+        account: impl Into<Arc<str>>,
+        builder: ClientBuilder,
+    ) -> Result<()> {
+        let account = account.into();
+        let forwarder = PoolForwarder {
+            account: Arc::clone(&account),
+            subscribers: Arc::clone(&self.subscribers),
+        };
+
+        let client =
+            builder.type_map_arc(Arc::clone(&self.data)).raw_event_handler(forwarder).await?;
+        self.clients.push((account, client));
+
+        Ok(())
+    }
+
+    /// Returns a stream of the events received by all clients of the pool, tagged by account.
+    ///
+    /// Every call returns a new stream, which receives all events from the point it was created.
+    #[must_use]
+    pub fn subscribe(&self) -> Receiver<AccountEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.subscribers.lock().expect("poison").push(tx);
+        rx
+    }
+
+    /// Returns the client added under the given label.
+    #[must_use]
+    pub fn get(&self, account: &str) -> Option<&Client> {
+        self.clients.iter().find(|(label, _)| &**label == account).map(|(_, client)| client)
+    }
+
+    /// Returns a mutable reference to the client added under the given label.
+    pub fn get_mut(&mut self, account: &str) -> Option<&mut Client> {
+        self.clients.iter_mut().find(|(label, _)| &**label == account).map(|(_, client)| client)
+    }
+
+    /// Returns an iterator over the labels and clients of the pool, in insertion order.
+    pub fn clients(&self) -> impl Iterator<Item = (&str, &Client)> {
+        self.clients.iter().map(|(label, client)| (&**label, client))
+    }
+
+    /// Returns the number of clients in the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns whether the pool contains no clients.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Starts all clients of the pool, each with a single shard. Refer to [`Client::start`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by a client. Clients which already started keep running;
+    /// use [`Self::shutdown_all`] to stop them.
+    pub async fn start_all(&mut self) -> Result<()> {
+        for (_, client) in &mut self.clients {
+            client.start().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Shuts down the shards of all clients of the pool concurrently, waiting until all of them
+    /// have finished.
+    pub async fn shutdown_all(&self) {
+        join_all(self.clients.iter().map(|(_, client)| client.shard_manager.shutdown_all())).await;
+    }
+}
+
+impl Default for ClientPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,408 @@
+//! Deserializable [`Client`] configuration.
+//!
+//! [`Client`]: super::Client
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::de::{Deserializer, Error as DeError, SeqAccess, Visitor};
+
+use super::{ClientBuilder, ClientError};
+#[cfg(feature = "cache")]
+use crate::cache::Settings as CacheSettings;
+use crate::gateway::ActivityData;
+use crate::http::{HttpBuilder, RetryPolicy};
+use crate::internal::prelude::*;
+use crate::model::gateway::GatewayIntents;
+use crate::model::user::OnlineStatus;
+
+/// Settings for a [`Client`], which can be loaded from a configuration file.
+///
+/// Every field is optional; missing fields fall back to the same defaults as [`ClientBuilder`].
+/// Pass the settings to [`ClientBuilder::from_settings`] to construct a builder.
+///
+/// # Examples
+///
+/// Loading the settings from JSON:
+///
+/// ```rust,no_run
+/// use serenity::client::{ClientBuilder, ClientSettings};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let settings: ClientSettings = serde_json::from_str(
+///     r#"{
+///         "token_env": "MY_BOT_TOKEN",
+///         "intents": ["GUILDS", "GUILD_MESSAGES", "MESSAGE_CONTENT"],
+///         "cache": {"max_messages": 100},
+///         "presence": {"status": "idle", "activity": {"type": "watching", "name": "the logs"}},
+///         "shards": {"start": 0, "end": 3, "total": 8}
+///     }"#,
+/// )?;
+///
+/// let mut client = ClientBuilder::from_settings(settings)?.await?;
+/// client.start().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: super::Client
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ClientSettings {
+    /// The name of the environment variable to read the bot token from.
+    ///
+    /// Defaults to `DISCORD_TOKEN`.
+    pub token_env: String,
+    /// The gateway intents, either as their raw bits or as a list of flag names such as
+    /// `"GUILD_MESSAGES"`.
+    ///
+    /// Defaults to [`GatewayIntents::default`].
+    #[serde(deserialize_with = "deserialize_intents")]
+    pub intents: GatewayIntents,
+    /// The cache settings.
+    #[cfg(feature = "cache")]
+    pub cache: CacheLimits,
+    /// The initial presence.
+    pub presence: PresenceSettings,
+    /// The range of shards this process runs. See [`ClientBuilder::shards`] for more info.
+    pub shards: Option<ShardSettings>,
+    /// The proxy to send HTTP API requests to. See [`HttpBuilder::proxy`] for more info.
+    pub proxy: Option<String>,
+    /// How requests that hit a ratelimit are handled.
+    pub ratelimits: RatelimitSettings,
+    /// How requests that failed in a transient way are retried.
+    pub retry: RetrySettings,
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        Self {
+            token_env: "DISCORD_TOKEN".into(),
+            intents: GatewayIntents::default(),
+            #[cfg(feature = "cache")]
+            cache: CacheLimits::default(),
+            presence: PresenceSettings::default(),
+            shards: None,
+            proxy: None,
+            ratelimits: RatelimitSettings::default(),
+            retry: RetrySettings::default(),
+        }
+    }
+}
+
+/// The deserializable counterpart of the cache [`Settings`].
+///
+/// [`Settings`]: CacheSettings
+#[cfg(feature = "cache")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct CacheLimits {
+    /// The maximum number of messages to store in a channel's message cache.
+    pub max_messages: usize,
+    /// The maximum number of previous revisions to keep for each cached message.
+    pub max_message_revisions: usize,
+    /// How many seconds temporarily-cached data should be stored before being thrown out.
+    pub time_to_live: u64,
+    /// Whether to cache guild data received from gateway.
+    pub cache_guilds: bool,
+    /// Whether to cache channel data received from gateway.
+    pub cache_channels: bool,
+    /// Whether to cache user data received from gateway.
+    pub cache_users: bool,
+    /// Whether to cache the presences of guild members.
+    pub cache_presences: bool,
+    /// Whether to cache the voice states of guild members.
+    pub cache_voice_states: bool,
+    /// Whether to cache the emojis of guilds.
+    pub cache_emojis: bool,
+    /// Whether to cache the stickers of guilds.
+    pub cache_stickers: bool,
+    /// Whether to keep the content of cached messages.
+    pub cache_message_content: bool,
+    /// Whether to index the names of cached guilds, channels and members.
+    pub index_names: bool,
+    /// The maximum number of users to store in the cache.
+    pub max_users: Option<usize>,
+    /// The maximum number of members to store in the cache, across all guilds.
+    pub max_members: Option<usize>,
+    /// The maximum number of presences to store in the cache, across all guilds.
+    pub max_presences: Option<usize>,
+}
+
+#[cfg(feature = "cache")]
+impl Default for CacheLimits {
+    fn default() -> Self {
+        CacheSettings::default().into()
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<CacheSettings> for CacheLimits {
+    fn from(settings: CacheSettings) -> Self {
+        Self {
+            max_messages: settings.max_messages,
+            max_message_revisions: settings.max_message_revisions,
+            time_to_live: settings.time_to_live.as_secs(),
+            cache_guilds: settings.cache_guilds,
+            cache_channels: settings.cache_channels,
+            cache_users: settings.cache_users,
+            cache_presences: settings.cache_presences,
+            cache_voice_states: settings.cache_voice_states,
+            cache_emojis: settings.cache_emojis,
+            cache_stickers: settings.cache_stickers,
+            cache_message_content: settings.cache_message_content,
+            index_names: settings.index_names,
+            max_users: settings.max_users,
+            max_members: settings.max_members,
+            max_presences: settings.max_presences,
+        }
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<CacheLimits> for CacheSettings {
+    fn from(limits: CacheLimits) -> Self {
+        Self {
+            max_messages: limits.max_messages,
+            max_message_revisions: limits.max_message_revisions,
+            time_to_live: Duration::from_secs(limits.time_to_live),
+            cache_guilds: limits.cache_guilds,
+            cache_channels: limits.cache_channels,
+            cache_users: limits.cache_users,
+            cache_presences: limits.cache_presences,
+            cache_voice_states: limits.cache_voice_states,
+            cache_emojis: limits.cache_emojis,
+            cache_stickers: limits.cache_stickers,
+            cache_message_content: limits.cache_message_content,
+            index_names: limits.index_names,
+            max_users: limits.max_users,
+            max_members: limits.max_members,
+            max_presences: limits.max_presences,
+        }
+    }
+}
+
+/// The initial presence of the client.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct PresenceSettings {
+    /// The initial online status.
+    pub status: OnlineStatus,
+    /// The initial activity.
+    pub activity: Option<ActivitySettings>,
+}
+
+/// The initial activity of the client, tagged by its `type`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ActivitySettings {
+    Playing { name: String },
+    Streaming { name: String, url: String },
+    Listening { name: String },
+    Watching { name: String },
+    Competing { name: String },
+    Custom { state: String },
+}
+
+impl ActivitySettings {
+    /// Converts the settings into [`ActivityData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the url of a streaming activity is invalid.
+    pub fn into_activity(self) -> Result<ActivityData> {
+        Ok(match self {
+            Self::Playing {
+                name,
+            } => ActivityData::playing(name),
+            Self::Streaming {
+                name,
+                url,
+            } => ActivityData::streaming(name, url.as_str())?,
+            Self::Listening {
+                name,
+            } => ActivityData::listening(name),
+            Self::Watching {
+                name,
+            } => ActivityData::watching(name),
+            Self::Competing {
+                name,
+            } => ActivityData::competing(name),
+            Self::Custom {
+                state,
+            } => ActivityData::custom(state),
+        })
+    }
+}
+
+/// The range of shards a process is responsible for.
+///
+/// Like [`Client::start_shard_range`], `start` and `end` are both inclusive.
+///
+/// [`Client::start_shard_range`]: super::Client::start_shard_range
+#[derive(Clone, Debug, Deserialize)]
+#[non_exhaustive]
+pub struct ShardSettings {
+    /// The first shard to start.
+    pub start: u32,
+    /// The last shard to start.
+    pub end: u32,
+    /// The total number of shards the bot is sharding for.
+    pub total: u32,
+}
+
+/// How the HTTP client handles ratelimits.
+///
+/// Requests that are ratelimited are always retried once the ratelimit has passed, unless the
+/// ratelimiter is disabled.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RatelimitSettings {
+    /// Disables the ratelimiter. See [`HttpBuilder::ratelimiter_disabled`] for more info.
+    pub disabled: bool,
+    /// Whether to use the absolute `x-ratelimit-reset` header instead of the relative
+    /// `x-ratelimit-reset-after` one to determine when a ratelimit resets.
+    pub absolute: bool,
+}
+
+/// The deserializable counterpart of [`RetryPolicy`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RetrySettings {
+    /// The maximum number of attempts per request. Defaults to 1, meaning no retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, in milliseconds.
+    pub base_delay_ms: u64,
+    /// The maximum delay between two attempts, in milliseconds.
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetrySettings {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay_ms: 500,
+            max_delay_ms: 30_000,
+        }
+    }
+}
+
+impl From<RetrySettings> for RetryPolicy {
+    fn from(settings: RetrySettings) -> Self {
+        RetryPolicy::new(settings.max_attempts)
+            .base_delay(Duration::from_millis(settings.base_delay_ms))
+            .max_delay(Duration::from_millis(settings.max_delay_ms))
+    }
+}
+
+impl ClientBuilder {
+    /// Constructs a builder from [`ClientSettings`], reading the token from the environment
+    /// variable named by [`ClientSettings::token_env`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::MissingToken`] if the environment variable is not set, or
+    /// [`Error::Url`] if the url of a streaming activity is invalid.
+    pub fn from_settings(settings: ClientSettings) -> Result<Self> {
+        let token = std::env::var(&settings.token_env)
+            .map_err(|_| ClientError::MissingToken(settings.token_env.clone()))?;
+
+        let mut http = HttpBuilder::new(token)
+            .ratelimiter_disabled(settings.ratelimits.disabled)
+            .retry_policy(settings.retry.into());
+        if let Some(proxy) = settings.proxy {
+            http = http.proxy(proxy);
+        }
+        let mut http = http.build();
+        if let Some(ratelimiter) = &mut http.ratelimiter {
+            ratelimiter.set_absolute_ratelimits(settings.ratelimits.absolute);
+        }
+
+        let mut builder =
+            Self::new_with_http(http, settings.intents).status(settings.presence.status);
+        if let Some(activity) = settings.presence.activity {
+            builder = builder.activity(activity.into_activity()?);
+        }
+        if let Some(shards) = settings.shards {
+            builder = builder.shards(shards.start..shards.end, shards.total);
+        }
+        #[cfg(feature = "cache")]
+        {
+            builder = builder.cache_settings(settings.cache.into());
+        }
+
+        Ok(builder)
+    }
+}
+
+fn deserialize_intents<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<GatewayIntents, D::Error> {
+    struct IntentsVisitor;
+
+    impl<'de> Visitor<'de> for IntentsVisitor {
+        type Value = GatewayIntents;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("intent bits or a list of intent names")
+        }
+
+        fn visit_u64<E: DeError>(self, bits: u64) -> StdResult<Self::Value, E> {
+            Ok(GatewayIntents::from_bits_truncate(bits))
+        }
+
+        fn visit_i64<E: DeError>(self, bits: i64) -> StdResult<Self::Value, E> {
+            u64::try_from(bits)
+                .map(GatewayIntents::from_bits_truncate)
+                .map_err(|_| E::custom("intent bits must not be negative"))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> StdResult<Self::Value, A::Error> {
+            let mut intents = GatewayIntents::empty();
+            while let Some(name) = seq.next_element::<String>()? {
+                intents |= GatewayIntents::from_name(&name.to_uppercase())
+                    .ok_or_else(|| DeError::custom(format_args!("unknown intent `{name}`")))?;
+            }
+            Ok(intents)
+        }
+    }
+
+    deserializer.deserialize_any(IntentsVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn deserialize_settings() {
+        let settings = json!({
+            "token_env": "BOT_TOKEN",
+            "intents": ["guilds", "GUILD_MESSAGES"],
+            "presence": {"status": "dnd", "activity": {"type": "playing", "name": "a game"}},
+            "shards": {"start": 2, "end": 3, "total": 4},
+            "ratelimits": {"absolute": true},
+        });
+
+        let settings: ClientSettings = from_value(settings).unwrap();
+        assert_eq!(settings.token_env, "BOT_TOKEN");
+        assert_eq!(settings.intents, GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES);
+        assert_eq!(settings.presence.status, OnlineStatus::DoNotDisturb);
+        assert!(settings.ratelimits.absolute && !settings.ratelimits.disabled);
+        assert!(settings.proxy.is_none());
+        assert_eq!(RetryPolicy::from(settings.retry), RetryPolicy::none());
+
+        let settings: ClientSettings = from_value(json!({"intents": 513})).unwrap();
+        assert_eq!(settings.token_env, "DISCORD_TOKEN");
+        assert_eq!(settings.intents, GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES);
+
+        assert!(from_value::<ClientSettings>(json!({"intents": ["NOPE"]})).is_err());
+    }
+}
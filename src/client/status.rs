@@ -0,0 +1,57 @@
+//! Monitoring of the [Discord status page](https://discordstatus.com).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use super::dispatch::spawn_handler;
+use super::{EventHandler, HandlerErrorHook};
+use crate::http::Http;
+
+/// Polls the unresolved incidents of the status page every `interval` until cancelled,
+/// dispatching [`EventHandler::discord_incident`] for every new or updated incident.
+pub(crate) async fn monitor(
+    interval: Duration,
+    http: Arc<Http>,
+    event_handlers: Vec<Arc<dyn EventHandler>>,
+    handler_error_hook: Option<HandlerErrorHook>,
+    cancellation_token: CancellationToken,
+) {
+    let mut seen: HashMap<String, String> = HashMap::new();
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            () = cancellation_token.cancelled() => return,
+            _ = interval.tick() => {},
+        }
+
+        let incidents = match http.get_unresolved_incidents().await {
+            Ok(incidents) => incidents,
+            Err(why) => {
+                warn!("Failed to poll the Discord status page: {why:?}");
+                continue;
+            },
+        };
+
+        // Forget incidents once they are resolved, so that the map doesn't grow forever.
+        seen.retain(|id, _| incidents.iter().any(|incident| incident.id == *id));
+
+        for incident in incidents {
+            let last_update = seen.insert(incident.id.clone(), incident.updated_at.clone());
+            if last_update.as_ref() == Some(&incident.updated_at) {
+                continue;
+            }
+
+            for handler in &event_handlers {
+                let (handler, incident) = (Arc::clone(handler), incident.clone());
+                spawn_handler("discord_incident", handler_error_hook.clone(), async move {
+                    handler.discord_incident(incident).await;
+                });
+            }
+        }
+    }
+}
@@ -4,7 +4,8 @@
 use futures::future::pending;
 use futures::{Stream, StreamExt as _};
 
-use crate::gateway::{CollectorCallback, ShardMessenger};
+use crate::client::FullEvent;
+use crate::gateway::{CollectorCallback, FullEventCollectorCallback, ShardMessenger};
 use crate::model::prelude::*;
 
 /// Fundamental collector function. All collector types in this module are just wrappers around
@@ -47,6 +48,42 @@ pub fn collect<T: Send + 'static>(
     futures::stream::poll_fn(move |cx| receiver.poll_recv(cx))
 }
 
+/// Like [`collect`], but the extractor is called with the [`FullEvent`]s passed to event handlers,
+/// after the cache has been updated.
+///
+/// Example: creating a collector stream over members joining a guild
+/// ```rust
+/// # use futures::StreamExt as _;
+/// # use serenity::client::FullEvent;
+/// # use serenity::gateway::ShardMessenger;
+/// # use serenity::collector::collect_full_events;
+/// # async fn _example(shard: &ShardMessenger) {
+/// let stream = collect_full_events(shard, |event| match event {
+///     FullEvent::GuildMemberAddition {
+///         new_member,
+///     } => Some(new_member.user.id),
+///     _ => None,
+/// });
+///
+/// stream.for_each(|user_id| async move { println!("{user_id} joined") }).await;
+/// # }
+/// ```
+pub fn collect_full_events<T: Send + 'static>(
+    shard: &ShardMessenger,
+    extractor: impl Fn(&FullEvent) -> Option<T> + Send + Sync + 'static,
+) -> impl Stream<Item = T> {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    shard.add_full_event_collector(FullEventCollectorCallback(Box::new(
+        move |event| match extractor(event) {
+            Some(item) => sender.send(item).is_ok(),
+            None => !sender.is_closed(),
+        },
+    )));
+
+    futures::stream::poll_fn(move |cx| receiver.poll_recv(cx))
+}
+
 macro_rules! make_specific_collector {
     (
         $( #[ $($meta:tt)* ] )*
@@ -167,6 +204,7 @@ make_specific_collector!(
     channel_id: ChannelId => interaction.channel_id == *channel_id,
     guild_id: GuildId => interaction.guild_id.map_or(true, |x| x == *guild_id),
     message_id: MessageId => interaction.message.id == *message_id,
+    custom_id: String => interaction.data.custom_id == *custom_id,
     custom_ids: Vec<String> => custom_ids.contains(&interaction.data.custom_id),
 );
 make_specific_collector!(
@@ -178,16 +216,9 @@ make_specific_collector!(
     channel_id: ChannelId => interaction.channel_id == *channel_id,
     guild_id: GuildId => interaction.guild_id.map_or(true, |g| g == *guild_id),
     message_id: MessageId => interaction.message.as_ref().map_or(true, |m| m.id == *message_id),
+    custom_id: String => interaction.data.custom_id == *custom_id,
     custom_ids: Vec<String> => custom_ids.contains(&interaction.data.custom_id),
 );
-make_specific_collector!(
-    ReactionCollector, Reaction,
-    Event::ReactionAdd(ReactionAddEvent { reaction }) => reaction,
-    author_id: UserId => reaction.user_id.map_or(true, |a| a == *author_id),
-    channel_id: ChannelId => reaction.channel_id == *channel_id,
-    guild_id: GuildId => reaction.guild_id.map_or(true, |g| g == *guild_id),
-    message_id: MessageId => reaction.message_id == *message_id,
-);
 make_specific_collector!(
     MessageCollector, Message,
     Event::MessageCreate(MessageCreateEvent { message }) => message,
@@ -200,3 +231,287 @@ make_specific_collector!(
     EventCollector, Event,
     event => event,
 );
+
+/// A reaction which was either added to or removed from a message.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ReactionAction {
+    Added(Reaction),
+    Removed(Reaction),
+}
+
+impl ReactionAction {
+    /// Returns the reaction which was added or removed.
+    #[must_use]
+    pub fn reaction(&self) -> &Reaction {
+        match self {
+            Self::Added(reaction) | Self::Removed(reaction) => reaction,
+        }
+    }
+
+    /// Returns whether the reaction was added.
+    #[must_use]
+    pub fn is_added(&self) -> bool {
+        matches!(self, Self::Added(_))
+    }
+
+    /// Returns whether the reaction was removed.
+    #[must_use]
+    pub fn is_removed(&self) -> bool {
+        matches!(self, Self::Removed(_))
+    }
+}
+
+type ReactionFilter = Box<dyn Fn(&ReactionAction) -> bool + Send + Sync>;
+
+/// A [`ReactionCollector`] receives [`ReactionAction`]s - added and removed reactions - which match
+/// the given filters for a set duration.
+#[must_use]
+pub struct ReactionCollector {
+    shard: ShardMessenger,
+    duration: Option<std::time::Duration>,
+    max: Option<usize>,
+    filter: Option<ReactionFilter>,
+    author_id: Option<UserId>,
+    channel_id: Option<ChannelId>,
+    guild_id: Option<GuildId>,
+    message_id: Option<MessageId>,
+    emoji: Option<ReactionType>,
+    removed: bool,
+}
+
+impl ReactionCollector {
+    /// Creates a new collector without any filters configured, which only receives added
+    /// reactions.
+    pub fn new(shard: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            shard: shard.as_ref().clone(),
+            duration: None,
+            max: None,
+            filter: None,
+            author_id: None,
+            channel_id: None,
+            guild_id: None,
+            message_id: None,
+            emoji: None,
+            removed: false,
+        }
+    }
+
+    /// Sets a duration for how long the collector shall receive reactions.
+    pub fn timeout(mut self, duration: std::time::Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the maximum number of reactions to collect, after which the stream ends.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets a generic filter function.
+    pub fn filter(
+        mut self,
+        filter: impl Fn(&ReactionAction) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Filters reactions by the user who added or removed them.
+    pub fn author_id(mut self, author_id: UserId) -> Self {
+        self.author_id = Some(author_id);
+        self
+    }
+
+    /// Filters reactions by the channel they were added or removed in.
+    pub fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Filters reactions by the guild they were added or removed in.
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id = Some(guild_id);
+        self
+    }
+
+    /// Filters reactions by the message they were added to or removed from.
+    pub fn message_id(mut self, message_id: MessageId) -> Self {
+        self.message_id = Some(message_id);
+        self
+    }
+
+    /// Filters reactions by their emoji.
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+
+    /// Sets whether removed reactions are collected as well. Defaults to `false`.
+    pub fn removed(mut self, removed: bool) -> Self {
+        self.removed = removed;
+        self
+    }
+
+    fn filters_pass(&self, action: &ReactionAction) -> bool {
+        let reaction = action.reaction();
+
+        (self.removed || action.is_added())
+            && self.author_id.map_or(true, |id| reaction.user_id.map_or(true, |a| a == id))
+            && self.channel_id.map_or(true, |id| reaction.channel_id == id)
+            && self.guild_id.map_or(true, |id| reaction.guild_id.map_or(true, |g| g == id))
+            && self.message_id.map_or(true, |id| reaction.message_id == id)
+            && self.emoji.as_ref().map_or(true, |emoji| reaction.emoji == *emoji)
+            && self.filter.as_ref().map_or(true, |filter| filter(action))
+    }
+
+    /// Returns a [`Stream`] over all collected [`ReactionAction`]s.
+    pub fn stream(self) -> impl Stream<Item = ReactionAction> {
+        let shard = self.shard.clone();
+        let (duration, max) = (self.duration, self.max.unwrap_or(usize::MAX));
+
+        // A future that completes once the timeout is triggered
+        let timeout = async move {
+            match duration {
+                Some(d) => tokio::time::sleep(d).await,
+                None => pending::<()>().await,
+            }
+        };
+
+        let stream = collect(&shard, move |event| {
+            let action = match event {
+                Event::ReactionAdd(ReactionAddEvent {
+                    reaction,
+                }) => ReactionAction::Added(reaction.clone()),
+                Event::ReactionRemove(ReactionRemoveEvent {
+                    reaction,
+                }) => ReactionAction::Removed(reaction.clone()),
+                _ => return None,
+            };
+            self.filters_pass(&action).then_some(action)
+        });
+        // Need to Box::pin this, or else users have to `pin_mut!()` the stream to the stack
+        stream.take_until(Box::pin(timeout)).take(max)
+    }
+
+    /// Deprecated, use [`Self::stream()`] instead.
+    #[deprecated = "use `.stream()` instead"]
+    pub fn build(self) -> impl Stream<Item = ReactionAction> {
+        self.stream()
+    }
+
+    /// Returns the next [`ReactionAction`] which passes the filters.
+    /// You can also call `.await` on the [`ReactionCollector`] directly.
+    pub async fn next(self) -> Option<ReactionAction> {
+        self.stream().next().await
+    }
+}
+
+impl std::future::IntoFuture for ReactionCollector {
+    type Output = Option<ReactionAction>;
+    type IntoFuture = futures::future::BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.next())
+    }
+}
+
+type FullEventFilter = Box<dyn Fn(&FullEvent) -> bool + Send + Sync>;
+
+/// A [`FullEventCollector`] receives any [`FullEvent`] which matches the given filter for a set
+/// duration.
+///
+/// # Examples
+///
+/// Wait up to a minute for a user to join a voice channel:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use serenity::client::FullEvent;
+/// # use serenity::collector::FullEventCollector;
+/// # use serenity::gateway::ShardMessenger;
+/// # use serenity::model::id::UserId;
+/// # async fn _example(shard: &ShardMessenger, user_id: UserId) {
+/// let joined = FullEventCollector::new(shard)
+///     .timeout(Duration::from_secs(60))
+///     .filter(move |event| {
+///         matches!(event, FullEvent::VoiceStateUpdate { new, .. }
+///             if new.user_id == user_id && new.channel_id.is_some())
+///     })
+///     .await;
+/// # }
+/// ```
+#[must_use]
+pub struct FullEventCollector {
+    shard: ShardMessenger,
+    duration: Option<std::time::Duration>,
+    max: Option<usize>,
+    filter: Option<FullEventFilter>,
+}
+
+impl FullEventCollector {
+    /// Creates a new collector without any filters configured, which receives every event.
+    pub fn new(shard: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            shard: shard.as_ref().clone(),
+            duration: None,
+            max: None,
+            filter: None,
+        }
+    }
+
+    /// Sets a duration for how long the collector shall receive events.
+    pub fn timeout(mut self, duration: std::time::Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the maximum number of events to collect, after which the stream ends.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the predicate events must match to be collected.
+    pub fn filter(mut self, filter: impl Fn(&FullEvent) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Returns a [`Stream`] over all collected [`FullEvent`]s.
+    pub fn stream(self) -> impl Stream<Item = FullEvent> {
+        let (duration, max) = (self.duration, self.max.unwrap_or(usize::MAX));
+
+        // A future that completes once the timeout is triggered
+        let timeout = async move {
+            match duration {
+                Some(d) => tokio::time::sleep(d).await,
+                None => pending::<()>().await,
+            }
+        };
+
+        let filter = self.filter;
+        let stream = collect_full_events(&self.shard, move |event| {
+            filter.as_ref().map_or(true, |filter| filter(event)).then(|| event.clone())
+        });
+        // Need to Box::pin this, or else users have to `pin_mut!()` the stream to the stack
+        stream.take_until(Box::pin(timeout)).take(max)
+    }
+
+    /// Returns the next [`FullEvent`] which passes the filter.
+    /// You can also call `.await` on the [`FullEventCollector`] directly.
+    pub async fn next(self) -> Option<FullEvent> {
+        self.stream().next().await
+    }
+}
+
+impl std::future::IntoFuture for FullEventCollector {
+    type Output = Option<FullEvent>;
+    type IntoFuture = futures::future::BoxFuture<'static, Self::Output>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.next())
+    }
+}
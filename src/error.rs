@@ -1,9 +1,9 @@
 use std::error::Error as StdError;
 use std::fmt::{self, Error as FormatError};
-use std::io::Error as IoError;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 
 #[cfg(feature = "http")]
-use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
+use reqwest::{header::InvalidHeaderValue, Error as ReqwestError, StatusCode};
 #[cfg(feature = "gateway")]
 use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 use tracing::instrument;
@@ -13,7 +13,7 @@ use crate::client::ClientError;
 #[cfg(feature = "gateway")]
 use crate::gateway::GatewayError;
 #[cfg(feature = "http")]
-use crate::http::HttpError;
+use crate::http::{CaptchaSolution, HttpError, JsonErrorCode};
 use crate::internal::prelude::*;
 use crate::json::JsonError;
 use crate::model::ModelError;
@@ -83,17 +83,205 @@ pub enum Error {
     /// [`http`]: crate::http
     #[cfg(feature = "http")]
     Http(HttpError),
+    /// Discord requires a captcha to be solved before performing the request.
+    ///
+    /// This is only sent to user accounts, e.g. when joining a guild or sending a friend request.
+    /// Solve the captcha for the `sitekey` (passing the `rqdata`, if any) and retry the request
+    /// with an [`Http`] returned by [`Http::clone_with_captcha`].
+    ///
+    /// [`Http`]: crate::http::Http
+    /// [`Http::clone_with_captcha`]: crate::http::Http::clone_with_captcha
+    #[cfg(feature = "http")]
+    Captcha {
+        /// Why the captcha is required, e.g. `captcha-required`, or `invalid-response` if a
+        /// submitted solution was rejected.
+        key: Vec<String>,
+        /// The sitekey of the hCaptcha to solve.
+        sitekey: String,
+        /// Additional data to pass to hCaptcha, for enterprise captchas.
+        rqdata: Option<String>,
+        /// The token to send back along with the solution.
+        rqtoken: Option<String>,
+        /// The Id of the captcha session, to send back along with the solution.
+        session_id: Option<String>,
+    },
     /// An error from the `tungstenite` crate.
     #[cfg(feature = "gateway")]
     Tungstenite(TungsteniteError),
 }
 
+impl Error {
+    /// Returns whether the operation that caused this error may succeed if attempted again.
+    ///
+    /// This is the case for transient failures: ratelimits and server errors returned by Discord,
+    /// timed out or dropped connections, requests which failed to be sent or whose body failed to
+    /// be transferred, and gateway connections which closed or failed to heartbeat. Errors caused
+    /// by the input or by missing permissions are never retryable.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Io(inner) => is_transient_io(inner.kind()),
+            #[cfg(feature = "http")]
+            Self::Http(HttpError::Request(inner)) => {
+                inner.is_timeout() || inner.is_connect() || inner.is_request() || inner.is_body()
+            },
+            #[cfg(feature = "http")]
+            Self::Http(inner) => inner.status_code().is_some_and(|status| {
+                status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }),
+            #[cfg(feature = "gateway")]
+            Self::Gateway(inner) => matches!(
+                inner,
+                GatewayError::Closed(_)
+                    | GatewayError::ExpectedHello
+                    | GatewayError::HeartbeatFailed
+                    | GatewayError::InvalidHandshake
+            ),
+            #[cfg(feature = "gateway")]
+            Self::Tungstenite(inner) => match inner {
+                TungsteniteError::ConnectionClosed | TungsteniteError::AlreadyClosed => true,
+                TungsteniteError::Io(inner) => is_transient_io(inner.kind()),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns whether this error was caused by the current user lacking permissions.
+    ///
+    /// This covers `403 Forbidden` responses from Discord, permission and role hierarchy checks
+    /// done by the library before making a request, and privileged gateway intents which were not
+    /// enabled for the application.
+    #[must_use]
+    pub fn is_permission_error(&self) -> bool {
+        match self {
+            Self::Model(inner) => {
+                matches!(inner, ModelError::InvalidPermissions { .. } | ModelError::Hierarchy)
+            },
+            #[cfg(feature = "http")]
+            Self::Http(inner) => inner.status_code() == Some(StatusCode::FORBIDDEN),
+            #[cfg(feature = "gateway")]
+            Self::Gateway(inner) => matches!(inner, GatewayError::DisallowedGatewayIntents),
+            _ => false,
+        }
+    }
+
+    /// Returns the HTTP status code of the response, if this error was caused by an unsuccessful
+    /// request to Discord.
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn http_status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Http(inner) => inner.status_code(),
+            _ => None,
+        }
+    }
+
+    /// Returns Discord's error code, if this error was caused by an unsuccessful request to
+    /// Discord.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// use serenity::http::JsonErrorCode;
+    ///
+    /// let channel_id = ChannelId::new(1);
+    /// match channel_id.delete_message(http, MessageId::new(2)).await {
+    ///     Err(why) if why.json_error_code() == Some(JsonErrorCode::UnknownMessage) => {},
+    ///     result => result?,
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        match self {
+            Self::Http(inner) => inner.json_error_code(),
+            _ => None,
+        }
+    }
+
+    /// Creates a [`CaptchaSolution`] for the given response token, if this error is an
+    /// [`Error::Captcha`], copying the `rqtoken` and `session_id` of the challenge.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::UserId;
+    /// # fn solve(sitekey: &str, rqdata: Option<&str>) -> String { unimplemented!() }
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// use serenity::Error;
+    ///
+    /// let user_id = UserId::new(1);
+    /// if let Err(why) = user_id.send_friend_request(http).await {
+    ///     let Error::Captcha {
+    ///         sitekey,
+    ///         rqdata,
+    ///         ..
+    ///     } = &why
+    ///     else {
+    ///         return Err(why);
+    ///     };
+    ///
+    ///     let solution = why.captcha_solution(solve(sitekey, rqdata.as_deref())).unwrap();
+    ///     user_id.send_friend_request(http.clone_with_captcha(&solution)?).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "http")]
+    #[must_use]
+    pub fn captcha_solution(&self, key: impl Into<String>) -> Option<CaptchaSolution> {
+        let Self::Captcha {
+            rqtoken,
+            session_id,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let mut solution = CaptchaSolution::new(key);
+        if let Some(rqtoken) = rqtoken {
+            solution = solution.rqtoken(rqtoken);
+        }
+        if let Some(session_id) = session_id {
+            solution = solution.session_id(session_id);
+        }
+        Some(solution)
+    }
+}
+
+fn is_transient_io(kind: IoErrorKind) -> bool {
+    matches!(
+        kind,
+        IoErrorKind::TimedOut
+            | IoErrorKind::Interrupted
+            | IoErrorKind::ConnectionReset
+            | IoErrorKind::ConnectionAborted
+            | IoErrorKind::BrokenPipe
+            | IoErrorKind::UnexpectedEof
+    )
+}
+
 impl From<FormatError> for Error {
     fn from(e: FormatError) -> Error {
         Error::Format(e)
     }
 }
 
+#[cfg(feature = "client")]
+impl From<ClientError> for Error {
+    fn from(e: ClientError) -> Error {
+        Error::Client(e)
+    }
+}
+
 #[cfg(feature = "gateway")]
 impl From<GatewayError> for Error {
     fn from(e: GatewayError) -> Error {
@@ -152,6 +340,10 @@ impl fmt::Display for Error {
         match self {
             Self::Decode(msg, _) | Self::Other(msg) => f.write_str(msg),
             Self::ExceededLimit(..) => f.write_str("Input exceeded a limit"),
+            #[cfg(feature = "http")]
+            Self::Captcha {
+                ..
+            } => f.write_str("A captcha must be solved to perform this request"),
             Self::NotInRange(..) => f.write_str("Input is not in the specified range"),
             Self::Format(inner) => fmt::Display::fmt(&inner, f),
             Self::Io(inner) => fmt::Display::fmt(&inner, f),
@@ -180,6 +372,8 @@ impl StdError for Error {
             Self::Model(inner) => Some(inner),
             #[cfg(feature = "client")]
             Self::Client(inner) => Some(inner),
+            #[cfg(feature = "gateway")]
+            Self::Gateway(inner) => Some(inner),
             #[cfg(feature = "http")]
             Self::Http(inner) => Some(inner),
             #[cfg(feature = "gateway")]
@@ -188,3 +382,29 @@ impl StdError for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "gateway")]
+    fn gateway_error_is_source() {
+        let error = Error::from(GatewayError::HeartbeatFailed);
+
+        let source = error.source().and_then(|e| e.downcast_ref::<GatewayError>());
+        assert!(matches!(source, Some(GatewayError::HeartbeatFailed)));
+        assert!(error.is_retryable());
+        assert!(!error.is_permission_error());
+    }
+
+    #[test]
+    fn categorization() {
+        let error = Error::from(IoError::from(IoErrorKind::ConnectionReset));
+        assert!(error.is_retryable());
+
+        let error = Error::from(ModelError::Hierarchy);
+        assert!(error.is_permission_error());
+        assert!(!error.is_retryable());
+    }
+}
@@ -218,64 +218,80 @@ fn starts_with_whole_word(search_on: &str, word: &str) -> bool {
         && search_on.get(word.len()..=word.len()).is_some_and(|slice| slice == " ")
 }
 
-// Decides how a listed help entry shall be displayed.
+/// The reason a group or command is not available to the author of a message.
 #[cfg(all(feature = "cache", feature = "http"))]
-fn check_common_behaviour(
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unavailable {
+    WrongChannel,
+    LackingOwnership,
+    LackingPermissions,
+    LackingRole,
+    LackingConditions,
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+impl Unavailable {
+    fn behaviour(self, help_options: &HelpOptions) -> HelpBehaviour {
+        match self {
+            Self::WrongChannel => help_options.wrong_channel,
+            Self::LackingOwnership => help_options.lacking_ownership,
+            Self::LackingPermissions => help_options.lacking_permissions,
+            Self::LackingRole => help_options.lacking_role,
+            Self::LackingConditions => help_options.lacking_conditions,
+        }
+    }
+}
+
+// Decides whether a group or command is available to the author of a message, ignoring checks.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn check_common_availability(
     cache: impl AsRef<Cache>,
     msg: &Message,
     options: &impl CommonOptions,
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
-    help_options: &HelpOptions,
-) -> HelpBehaviour {
-    if !options.help_available() {
-        return HelpBehaviour::Hide;
-    }
-
+) -> Result<(), Unavailable> {
     if options.only_in() == OnlyIn::Dm && !msg.is_private()
         || options.only_in() == OnlyIn::Guild && msg.is_private()
     {
-        return help_options.wrong_channel;
+        return Err(Unavailable::WrongChannel);
     }
 
     if options.owners_only() && !owners.contains(&msg.author.id) {
-        return help_options.lacking_ownership;
+        return Err(Unavailable::LackingOwnership);
     }
 
     if options.owner_privilege() && owners.contains(&msg.author.id) {
-        return HelpBehaviour::Nothing;
+        return Ok(());
     }
 
     if !has_correct_permissions(&cache, options, msg) {
-        return help_options.lacking_permissions;
+        return Err(Unavailable::LackingPermissions);
     }
 
-    msg.guild(cache.as_ref())
-        .and_then(|guild| {
-            if let Some(member) = guild.members.get(&msg.author.id) {
-                if !has_correct_roles(options, &guild.roles, member) {
-                    return Some(help_options.lacking_role);
-                }
+    if let Some(guild) = msg.guild(cache.as_ref()) {
+        if let Some(member) = guild.members.get(&msg.author.id) {
+            if !has_correct_roles(options, &guild.roles, member) {
+                return Err(Unavailable::LackingRole);
             }
+        }
+    }
 
-            None
-        })
-        .unwrap_or(HelpBehaviour::Nothing)
+    Ok(())
 }
 
+// Decides whether a command is available to the author of a message, including the checks that
+// are enabled in help.
 #[cfg(all(feature = "cache", feature = "http"))]
-async fn check_command_behaviour(
+async fn check_command_availability(
     ctx: &Context,
     msg: &Message,
     options: &CommandOptions,
     group_checks: &[&Check],
     owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
-    help_options: &HelpOptions,
-) -> HelpBehaviour {
-    let behaviour = check_common_behaviour(ctx, msg, &options, owners, help_options);
+) -> Result<(), Unavailable> {
+    check_common_availability(ctx, msg, &options, owners)?;
 
-    if behaviour == HelpBehaviour::Nothing
-        && (!options.owner_privilege || !owners.contains(&msg.author.id))
-    {
+    if !options.owner_privilege || !owners.contains(&msg.author.id) {
         for check in group_checks.iter().chain(options.checks) {
             if !check.check_in_help {
                 continue;
@@ -284,12 +300,87 @@ async fn check_command_behaviour(
             let mut args = Args::new("", &[]);
 
             if (check.function)(ctx, msg, &mut args, options).await.is_err() {
-                return help_options.lacking_conditions;
+                return Err(Unavailable::LackingConditions);
             }
         }
     }
 
-    behaviour
+    Ok(())
+}
+
+// Decides how a listed help entry shall be displayed.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn check_common_behaviour(
+    cache: impl AsRef<Cache>,
+    msg: &Message,
+    options: &impl CommonOptions,
+    owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
+    help_options: &HelpOptions,
+) -> HelpBehaviour {
+    if !options.help_available() {
+        return HelpBehaviour::Hide;
+    }
+
+    check_common_availability(cache, msg, options, owners)
+        .map_or_else(|reason| reason.behaviour(help_options), |()| HelpBehaviour::Nothing)
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn check_command_behaviour(
+    ctx: &Context,
+    msg: &Message,
+    options: &CommandOptions,
+    group_checks: &[&Check],
+    owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
+    help_options: &HelpOptions,
+) -> HelpBehaviour {
+    if !options.help_available {
+        return HelpBehaviour::Hide;
+    }
+
+    check_command_availability(ctx, msg, options, group_checks, owners)
+        .await
+        .map_or_else(|reason| reason.behaviour(help_options), |()| HelpBehaviour::Nothing)
+}
+
+/// Returns all commands of the given groups and their sub-groups which the author of the message
+/// can run in the message's channel.
+///
+/// This applies the same filtering as the help commands: the channel type, ownership, required
+/// permissions and roles of both the groups and the commands, as well as all checks that are
+/// enabled in help. Commands that are only hidden from help are still included.
+///
+/// For application commands, use [`Command::usable_by`] instead.
+///
+/// [`Command::usable_by`]: crate::model::application::Command::usable_by
+#[cfg(all(feature = "cache", feature = "http"))]
+pub async fn runnable_commands(
+    ctx: &Context,
+    msg: &Message,
+    groups: &[&'static CommandGroup],
+    owners: &HashSet<UserId, impl std::hash::BuildHasher + Send + Sync>,
+) -> Vec<&'static InternalCommand> {
+    let mut runnable = Vec::new();
+    let mut queue = groups.to_vec();
+    while let Some(group) = queue.pop() {
+        if check_common_availability(ctx, msg, &group.options, owners).is_err() {
+            continue;
+        }
+
+        for command in group.options.commands {
+            let result =
+                check_command_availability(ctx, msg, command.options, group.options.checks, owners)
+                    .await;
+
+            if result.is_ok() {
+                runnable.push(*command);
+            }
+        }
+
+        queue.extend(group.options.sub_groups);
+    }
+
+    runnable
 }
 
 // This function will recursively go through all commands and their sub-commands, trying to find
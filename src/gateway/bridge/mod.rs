@@ -61,6 +61,8 @@ pub use self::shard_runner_message::ShardRunnerMessage;
 #[cfg(feature = "voice")]
 pub use self::voice::VoiceGatewayManager;
 use super::ChunkGuildFilter;
+#[cfg(feature = "collector")]
+use crate::client::FullEvent;
 use crate::gateway::ConnectionStage;
 use crate::model::event::Event;
 use crate::model::id::ShardId;
@@ -107,3 +109,14 @@ impl std::fmt::Debug for CollectorCallback {
         f.debug_tuple("CollectorCallback").finish()
     }
 }
+
+/// Like [`CollectorCallback`], but called with the [`FullEvent`]s an incoming event is turned
+/// into, after the cache has been updated.
+#[cfg(feature = "collector")]
+pub struct FullEventCollectorCallback(pub Box<dyn Fn(&FullEvent) -> bool + Send + Sync>);
+#[cfg(feature = "collector")]
+impl std::fmt::Debug for FullEventCollectorCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FullEventCollectorCallback").finish()
+    }
+}
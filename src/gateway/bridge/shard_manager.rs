@@ -17,13 +17,14 @@ use super::VoiceGatewayManager;
 use super::{ShardId, ShardQueuer, ShardQueuerMessage, ShardRunnerInfo};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
-use crate::client::{EventHandler, RawEventHandler};
+use crate::client::{EventHandler, HandlerErrorHook, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::gateway::{ConnectionStage, GatewayError, PresenceData};
 use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
+use crate::model::event::Event;
 use crate::model::gateway::GatewayIntents;
 
 /// A manager for handling the status of shards by starting them, restarting them, and stopping
@@ -74,6 +75,7 @@ use crate::model::gateway::GatewayIntents;
 ///     data,
 ///     event_handlers: vec![event_handler],
 ///     raw_event_handlers: vec![],
+///     handler_error_hook: None,
 ///     framework: Arc::new(OnceLock::from(framework)),
 ///     // the shard index to start initiating from
 ///     shard_index: 0,
@@ -145,6 +147,7 @@ impl ShardManager {
             data: opt.data,
             event_handlers: opt.event_handlers,
             raw_event_handlers: opt.raw_event_handlers,
+            handler_error_hook: opt.handler_error_hook,
             #[cfg(feature = "framework")]
             framework: opt.framework,
             last_start: None,
@@ -249,6 +252,24 @@ impl ShardManager {
         self.runners.lock().await.keys().copied().collect()
     }
 
+    /// Dispatches a synthetic event on the given shard, as if it had been received from the
+    /// gateway.
+    ///
+    /// The event goes through the normal dispatch path: it updates the cache, and is passed on to
+    /// the voice manager, the collectors and all event handlers. This can be used to, for example,
+    /// re-dispatch a stored [`Event::GuildCreate`] after the cache has been flushed.
+    ///
+    /// Returns whether the shard is running. If it is not, the event is dropped.
+    #[instrument(skip(self, event))]
+    pub async fn inject_event(&self, shard_id: ShardId, event: Event) -> bool {
+        if let Some(runner) = self.runners.lock().await.get(&shard_id) {
+            runner.runner_tx.inject_event(event);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Attempts to shut down the shard runner by Id.
     ///
     /// Returns a boolean indicating whether a shard runner was present. This is _not_ necessary an
@@ -387,6 +408,7 @@ pub struct ShardManagerOptions {
     pub data: Arc<RwLock<TypeMap>>,
     pub event_handlers: Vec<Arc<dyn EventHandler>>,
     pub raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    pub handler_error_hook: Option<HandlerErrorHook>,
     #[cfg(feature = "framework")]
     pub framework: Arc<OnceLock<Arc<dyn Framework>>>,
     pub shard_index: u32,
@@ -4,9 +4,9 @@ use std::sync::Arc;
 use futures::channel::mpsc::UnboundedSender as Sender;
 use tokio_tungstenite::tungstenite::Message;
 
-#[cfg(feature = "collector")]
-use super::CollectorCallback;
 use super::{ChunkGuildFilter, ShardRunner, ShardRunnerMessage};
+#[cfg(feature = "collector")]
+use super::{CollectorCallback, FullEventCollectorCallback};
 use crate::gateway::ActivityData;
 use crate::model::prelude::*;
 
@@ -22,6 +22,8 @@ pub struct ShardMessenger {
     pub(crate) tx: Sender<ShardRunnerMessage>,
     #[cfg(feature = "collector")]
     pub(crate) collectors: Arc<std::sync::Mutex<Vec<CollectorCallback>>>,
+    #[cfg(feature = "collector")]
+    pub(crate) full_event_collectors: Arc<std::sync::Mutex<Vec<FullEventCollectorCallback>>>,
 }
 
 impl ShardMessenger {
@@ -37,6 +39,8 @@ impl ShardMessenger {
             tx: shard.runner_tx(),
             #[cfg(feature = "collector")]
             collectors: Arc::clone(&shard.collectors),
+            #[cfg(feature = "collector")]
+            full_event_collectors: Arc::clone(&shard.full_event_collectors),
         }
     }
 
@@ -262,6 +266,14 @@ impl ShardMessenger {
         self.send_to_shard(ShardRunnerMessage::Message(message));
     }
 
+    /// Dispatches the given event as if it had been received by the shard from the gateway.
+    ///
+    /// The event goes through the same path as any other: it updates the cache, and is passed on
+    /// to the voice manager, the collectors and all event handlers.
+    pub fn inject_event(&self, event: Event) {
+        self.send_to_shard(ShardRunnerMessage::Event(Box::new(event)));
+    }
+
     /// Sends a message to the shard.
     #[inline]
     pub fn send_to_shard(&self, msg: ShardRunnerMessage) {
@@ -274,6 +286,18 @@ impl ShardMessenger {
     pub fn add_collector(&self, collector: CollectorCallback) {
         self.collectors.lock().expect("poison").push(collector);
     }
+
+    /// Registers a callback which is called with each [`FullEvent`] dispatched on this shard,
+    /// after the cache has been updated. The callback is removed once it returns `false`.
+    ///
+    /// See [`collect_full_events`] for a stream over these events.
+    ///
+    /// [`FullEvent`]: crate::client::FullEvent
+    /// [`collect_full_events`]: crate::collector::collect_full_events
+    #[cfg(feature = "collector")]
+    pub fn add_full_event_collector(&self, collector: FullEventCollectorCallback) {
+        self.full_event_collectors.lock().expect("poison").push(collector);
+    }
 }
 
 impl AsRef<ShardMessenger> for ShardMessenger {
@@ -23,7 +23,7 @@ use super::{
 };
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
-use crate::client::{EventHandler, RawEventHandler};
+use crate::client::{EventHandler, HandlerErrorHook, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::gateway::{ConnectionStage, PresenceData, Shard, ShardRunnerMessage};
@@ -51,6 +51,10 @@ pub struct ShardQueuer {
     ///
     /// [`Client`]: crate::Client
     pub raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    /// A hook called when an event handler panics, such as the one given to the [`Client`].
+    ///
+    /// [`Client`]: crate::Client
+    pub handler_error_hook: Option<HandlerErrorHook>,
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<OnceLock<Arc<dyn Framework>>>,
@@ -182,6 +186,7 @@ impl ShardQueuer {
             data: Arc::clone(&self.data),
             event_handlers: self.event_handlers.clone(),
             raw_event_handlers: self.raw_event_handlers.clone(),
+            handler_error_hook: self.handler_error_hook.clone(),
             #[cfg(feature = "framework")]
             framework: self.framework.get().cloned(),
             manager: Arc::clone(&self.manager),
@@ -10,21 +10,20 @@ use tracing::{debug, error, info, instrument, trace, warn};
 use typemap_rev::TypeMap;
 
 use super::event::ShardStageUpdateEvent;
-#[cfg(feature = "collector")]
-use super::CollectorCallback;
 #[cfg(feature = "voice")]
 use super::VoiceGatewayManager;
+#[cfg(feature = "collector")]
+use super::{CollectorCallback, FullEventCollectorCallback};
 use super::{ShardId, ShardManager, ShardRunnerMessage};
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
-use crate::client::dispatch::dispatch_model;
-use crate::client::{Context, EventHandler, RawEventHandler};
+use crate::client::dispatch::{dispatch_model, spawn_handler};
+use crate::client::{Context, EventHandler, HandlerErrorHook, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::gateway::{GatewayError, ReconnectType, Shard, ShardAction};
 use crate::http::Http;
 use crate::internal::prelude::*;
-use crate::internal::tokio::spawn_named;
 use crate::model::event::{Event, GatewayEvent};
 
 /// A runner for managing a [`Shard`] and its respective WebSocket client.
@@ -32,6 +31,7 @@ pub struct ShardRunner {
     data: Arc<RwLock<TypeMap>>,
     event_handlers: Vec<Arc<dyn EventHandler>>,
     raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    handler_error_hook: Option<HandlerErrorHook>,
     #[cfg(feature = "framework")]
     framework: Option<Arc<dyn Framework>>,
     manager: Arc<ShardManager>,
@@ -47,6 +47,8 @@ pub struct ShardRunner {
     pub http: Arc<Http>,
     #[cfg(feature = "collector")]
     pub(crate) collectors: Arc<std::sync::Mutex<Vec<CollectorCallback>>>,
+    #[cfg(feature = "collector")]
+    pub(crate) full_event_collectors: Arc<std::sync::Mutex<Vec<FullEventCollectorCallback>>>,
 }
 
 impl ShardRunner {
@@ -60,6 +62,7 @@ impl ShardRunner {
             data: opt.data,
             event_handlers: opt.event_handlers,
             raw_event_handlers: opt.raw_event_handlers,
+            handler_error_hook: opt.handler_error_hook,
             #[cfg(feature = "framework")]
             framework: opt.framework,
             manager: opt.manager,
@@ -71,6 +74,8 @@ impl ShardRunner {
             http: opt.http,
             #[cfg(feature = "collector")]
             collectors: Arc::new(std::sync::Mutex::new(vec![])),
+            #[cfg(feature = "collector")]
+            full_event_collectors: Arc::new(std::sync::Mutex::new(vec![])),
         }
     }
 
@@ -125,7 +130,8 @@ impl ShardRunner {
                         old: pre,
                         shard_id: self.shard.shard_info().id,
                     };
-                    spawn_named("dispatch::event_handler::shard_stage_update", async move {
+                    let hook = self.handler_error_hook.clone();
+                    spawn_handler("shard_stage_update", hook, async move {
                         event_handler.shard_stage_update(context, event).await;
                     });
                 }
@@ -163,17 +169,7 @@ impl ShardRunner {
             }
 
             if let Some(event) = event {
-                #[cfg(feature = "collector")]
-                self.collectors.lock().expect("poison").retain_mut(|callback| (callback.0)(&event));
-
-                dispatch_model(
-                    event,
-                    &self.make_context(),
-                    #[cfg(feature = "framework")]
-                    self.framework.clone(),
-                    self.event_handlers.clone(),
-                    self.raw_event_handlers.clone(),
-                );
+                self.dispatch(event);
             }
 
             if !successful && !self.shard.stage().is_connecting() {
@@ -183,6 +179,23 @@ impl ShardRunner {
         }
     }
 
+    /// Passes an event on to the collectors and the event handlers, updating the cache along the
+    /// way.
+    fn dispatch(&self, event: Event) {
+        #[cfg(feature = "collector")]
+        self.collectors.lock().expect("poison").retain_mut(|callback| (callback.0)(&event));
+
+        dispatch_model(
+            event,
+            &self.make_context(),
+            #[cfg(feature = "framework")]
+            self.framework.clone(),
+            self.event_handlers.clone(),
+            self.raw_event_handlers.clone(),
+            self.handler_error_hook.as_ref(),
+        );
+    }
+
     /// Clones the internal copy of the Sender to the shard runner.
     pub(super) fn runner_tx(&self) -> Sender<ShardRunnerMessage> {
         self.runner_tx.clone()
@@ -306,6 +319,13 @@ impl ShardRunner {
                 self.shard.set_status(status);
                 self.shard.update_presence().await.is_ok()
             },
+            ShardRunnerMessage::Event(event) => {
+                #[cfg(feature = "voice")]
+                self.handle_voice_event(&event).await;
+
+                self.dispatch(*event);
+                true
+            },
         }
     }
 
@@ -476,6 +496,7 @@ pub struct ShardRunnerOptions {
     pub data: Arc<RwLock<TypeMap>>,
     pub event_handlers: Vec<Arc<dyn EventHandler>>,
     pub raw_event_handlers: Vec<Arc<dyn RawEventHandler>>,
+    pub handler_error_hook: Option<HandlerErrorHook>,
     #[cfg(feature = "framework")]
     pub framework: Option<Arc<dyn Framework>>,
     pub manager: Arc<ShardManager>,
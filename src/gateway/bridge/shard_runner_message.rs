@@ -2,6 +2,7 @@ use tokio_tungstenite::tungstenite::Message;
 
 use super::ShardId;
 use crate::gateway::{ActivityData, ChunkGuildFilter};
+use crate::model::event::Event;
 use crate::model::id::GuildId;
 use crate::model::user::OnlineStatus;
 
@@ -50,4 +51,7 @@ pub enum ShardRunnerMessage {
     SetPresence(Option<ActivityData>, OnlineStatus),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
+    /// Indicates that the shard runner is to dispatch the given event as if it had been received
+    /// from the gateway.
+    Event(Box<Event>),
 }
@@ -42,6 +42,8 @@
 mod bridge;
 mod error;
 mod shard;
+#[cfg(feature = "unstable_discord_api")]
+mod spotify;
 mod ws;
 
 use std::fmt;
@@ -53,10 +55,19 @@ use reqwest::Url;
 pub use self::bridge::*;
 pub use self::error::Error as GatewayError;
 pub use self::shard::Shard;
+#[cfg(feature = "unstable_discord_api")]
+pub use self::spotify::*;
 pub use self::ws::WsClient;
 #[cfg(feature = "http")]
 use crate::internal::prelude::*;
-use crate::model::gateway::{Activity, ActivityType};
+use crate::model::gateway::{
+    Activity,
+    ActivityAssets,
+    ActivityFlags,
+    ActivityParty,
+    ActivityTimestamps,
+    ActivityType,
+};
 use crate::model::id::UserId;
 use crate::model::user::OnlineStatus;
 
@@ -70,7 +81,11 @@ pub struct PresenceData {
 }
 
 /// Activity data of the current user.
+///
+/// Create it with one of the constructors, such as [`Self::playing`], and set the other fields
+/// with the setters.
 #[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
 pub struct ActivityData {
     /// The name of the activity
     pub name: String,
@@ -81,20 +96,56 @@ pub struct ActivityData {
     pub state: Option<String>,
     /// The url of the activity, if the type is [`ActivityType::Streaming`]
     pub url: Option<Url>,
+    /// What the user is doing, shown below the name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// Images for the activity and their texts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assets: Option<ActivityAssets>,
+    /// Unix timestamps for the start and/or end times of the activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<ActivityTimestamps>,
+    /// Information about the user's current party.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub party: Option<ActivityParty>,
+    /// Flags describing what the activity supports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<ActivityFlags>,
+    /// The sync ID of the activity, e.g. the track ID of a Spotify activity.
+    #[cfg(feature = "unstable_discord_api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_id: Option<String>,
+    /// The session ID of the activity, e.g. the gateway session of a Spotify activity.
+    #[cfg(feature = "unstable_discord_api")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 impl ActivityData {
-    /// Creates an activity that appears as `Playing <name>`.
-    #[must_use]
-    pub fn playing(name: impl Into<String>) -> Self {
+    fn new(name: String, kind: ActivityType) -> Self {
         Self {
-            name: name.into(),
-            kind: ActivityType::Playing,
+            name,
+            kind,
             state: None,
             url: None,
+            details: None,
+            assets: None,
+            timestamps: None,
+            party: None,
+            flags: None,
+            #[cfg(feature = "unstable_discord_api")]
+            sync_id: None,
+            #[cfg(feature = "unstable_discord_api")]
+            session_id: None,
         }
     }
 
+    /// Creates an activity that appears as `Playing <name>`.
+    #[must_use]
+    pub fn playing(name: impl Into<String>) -> Self {
+        Self::new(name.into(), ActivityType::Playing)
+    }
+
     /// Creates an activity that appears as `Streaming <name>`.
     ///
     /// # Errors
@@ -103,58 +154,90 @@ impl ActivityData {
     #[cfg(feature = "http")]
     pub fn streaming(name: impl Into<String>, url: impl IntoUrl) -> Result<Self> {
         Ok(Self {
-            name: name.into(),
-            kind: ActivityType::Streaming,
-            state: None,
             url: Some(url.into_url()?),
+            ..Self::new(name.into(), ActivityType::Streaming)
         })
     }
 
     /// Creates an activity that appears as `Listening to <name>`.
     #[must_use]
     pub fn listening(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            kind: ActivityType::Listening,
-            state: None,
-            url: None,
-        }
+        Self::new(name.into(), ActivityType::Listening)
     }
 
     /// Creates an activity that appears as `Watching <name>`.
     #[must_use]
     pub fn watching(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            kind: ActivityType::Watching,
-            state: None,
-            url: None,
-        }
+        Self::new(name.into(), ActivityType::Watching)
     }
 
     /// Creates an activity that appears as `Competing in <name>`.
     #[must_use]
     pub fn competing(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            kind: ActivityType::Competing,
-            state: None,
-            url: None,
-        }
+        Self::new(name.into(), ActivityType::Competing)
     }
 
     /// Creates an activity that appears as `<state>`.
     #[must_use]
     pub fn custom(state: impl Into<String>) -> Self {
         Self {
+            state: Some(state.into()),
             // discord seems to require a name for custom activities
             // even though it's not displayed
-            name: "~".to_string(),
-            kind: ActivityType::Custom,
-            state: Some(state.into()),
-            url: None,
+            ..Self::new("~".to_string(), ActivityType::Custom)
         }
     }
+
+    /// Sets what the user is doing, shown below the name.
+    #[must_use]
+    pub fn details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Sets the images of the activity and their texts.
+    #[must_use]
+    pub fn assets(mut self, assets: ActivityAssets) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Sets the start and/or end times of the activity.
+    #[must_use]
+    pub fn timestamps(mut self, timestamps: ActivityTimestamps) -> Self {
+        self.timestamps = Some(timestamps);
+        self
+    }
+
+    /// Sets the party of the user.
+    #[must_use]
+    pub fn party(mut self, party: ActivityParty) -> Self {
+        self.party = Some(party);
+        self
+    }
+
+    /// Sets the flags describing what the activity supports.
+    #[must_use]
+    pub fn flags(mut self, flags: ActivityFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Sets the sync ID of the activity, e.g. the track ID of a Spotify activity.
+    #[cfg(feature = "unstable_discord_api")]
+    #[must_use]
+    pub fn sync_id(mut self, sync_id: impl Into<String>) -> Self {
+        self.sync_id = Some(sync_id.into());
+        self
+    }
+
+    /// Sets the session ID of the activity, e.g. the gateway session of a Spotify activity.
+    #[cfg(feature = "unstable_discord_api")]
+    #[must_use]
+    pub fn session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
 }
 
 impl From<Activity> for ActivityData {
@@ -164,6 +247,15 @@ impl From<Activity> for ActivityData {
             kind: activity.kind,
             state: activity.state,
             url: activity.url,
+            details: activity.details,
+            assets: activity.assets,
+            timestamps: activity.timestamps,
+            party: activity.party,
+            flags: activity.flags,
+            #[cfg(feature = "unstable_discord_api")]
+            sync_id: activity.sync_id,
+            #[cfg(feature = "unstable_discord_api")]
+            session_id: activity.session_id,
         }
     }
 }
@@ -0,0 +1,234 @@
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::task::JoinHandle;
+use tokio::time::{interval, MissedTickBehavior};
+
+use super::{ActivityData, ShardMessenger};
+use crate::internal::tokio::spawn_named;
+use crate::model::gateway::{
+    ActivityAssets,
+    ActivityFlags,
+    ActivityParty,
+    ActivityTimestamps,
+    ActivityType,
+};
+use crate::model::id::UserId;
+
+/// How far the start of a track may drift before the activity is updated, e.g. after seeking.
+const MAX_DRIFT_MS: u64 = 2000;
+
+/// A track playing on Spotify, used to show a `Listening to Spotify` activity with
+/// [`ActivityData::spotify`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct SpotifyTrack {
+    /// The Spotify Id of the track.
+    pub id: String,
+    /// The title of the track.
+    pub title: String,
+    /// The names of the artists of the track.
+    pub artists: Vec<String>,
+    /// The name of the album of the track.
+    pub album: Option<String>,
+    /// The Id of the album cover, the last path segment of its `i.scdn.co/image` URL.
+    pub album_cover: Option<String>,
+    /// The length of the track.
+    pub duration: Duration,
+    /// How far the track has been played.
+    pub progress: Duration,
+}
+
+impl SpotifyTrack {
+    /// Creates a track with the given Spotify Id, title and length.
+    #[must_use]
+    pub fn new(id: impl Into<String>, title: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            artists: Vec::new(),
+            album: None,
+            album_cover: None,
+            duration,
+            progress: Duration::ZERO,
+        }
+    }
+
+    /// Sets the names of the artists of the track.
+    #[must_use]
+    pub fn artists<I: Into<String>>(mut self, artists: impl IntoIterator<Item = I>) -> Self {
+        self.artists = artists.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the name and the cover Id of the album of the track.
+    #[must_use]
+    pub fn album(mut self, name: impl Into<String>, cover: Option<String>) -> Self {
+        self.album = Some(name.into());
+        self.album_cover = cover;
+        self
+    }
+
+    /// Sets how far the track has been played.
+    #[must_use]
+    pub fn progress(mut self, progress: Duration) -> Self {
+        self.progress = progress;
+        self
+    }
+}
+
+impl ActivityData {
+    /// Creates an activity that appears as `Listening to Spotify`, showing the given track and
+    /// allowing friends to listen along.
+    ///
+    /// The `session_id` is the Id of the gateway session of the current user, received in
+    /// [`Ready::session_id`]. The timestamps are calculated from the track's progress, so the
+    /// activity must be recreated when playback is paused or seeked; [`SpotifyPresence`] does this
+    /// automatically.
+    ///
+    /// [`Ready::session_id`]: crate::model::gateway::Ready::session_id
+    #[must_use]
+    pub fn spotify(track: &SpotifyTrack, user_id: UserId, session_id: impl Into<String>) -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let start = now.saturating_sub(track.progress);
+
+        Self {
+            details: Some(track.title.clone()),
+            state: Some(track.artists.join("; ")),
+            assets: Some(ActivityAssets {
+                large_image: track.album_cover.as_ref().map(|cover| format!("spotify:{cover}")),
+                large_text: track.album.clone(),
+                small_image: None,
+                small_text: None,
+            }),
+            timestamps: Some(ActivityTimestamps {
+                start: Some(start.as_millis() as u64),
+                end: Some((start + track.duration).as_millis() as u64),
+            }),
+            party: Some(ActivityParty {
+                id: Some(format!("spotify:{user_id}")),
+                size: None,
+            }),
+            flags: Some(ActivityFlags::SYNC | ActivityFlags::PLAY),
+            sync_id: Some(track.id.clone()),
+            session_id: Some(session_id.into()),
+            ..Self::new("Spotify".into(), ActivityType::Listening)
+        }
+    }
+}
+
+/// A background task keeping the Spotify activity of a shard up to date.
+///
+/// The task is stopped when this is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::gateway::{ShardMessenger, SpotifyPresence, SpotifyTrack};
+/// # use serenity::model::id::UserId;
+/// # async fn current_track() -> Option<SpotifyTrack> { None }
+/// # fn run(shard: ShardMessenger, user_id: UserId, session_id: String) {
+/// use std::time::Duration;
+///
+/// let presence =
+///     SpotifyPresence::spawn(shard, user_id, session_id, Duration::from_secs(5), current_track);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SpotifyPresence {
+    task: JoinHandle<()>,
+}
+
+impl SpotifyPresence {
+    /// Spawns a task which calls `poll` every `period` to get the track that is currently playing,
+    /// and updates the activity of the shard whenever the track changes, playback is paused
+    /// (`poll` returning [`None`]) or seeked.
+    ///
+    /// See [`ActivityData::spotify`] for the `session_id`.
+    pub fn spawn<F, Fut>(
+        shard: ShardMessenger,
+        user_id: UserId,
+        session_id: impl Into<String>,
+        period: Duration,
+        mut poll: F,
+    ) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Option<SpotifyTrack>> + Send,
+    {
+        let session_id = session_id.into();
+        let task = spawn_named("spotify_presence", async move {
+            let mut interval = interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            let mut current: Option<ActivityData> = None;
+            loop {
+                interval.tick().await;
+
+                let activity = poll()
+                    .await
+                    .map(|track| ActivityData::spotify(&track, user_id, session_id.as_str()));
+                if needs_update(current.as_ref(), activity.as_ref()) {
+                    shard.set_activity(activity.clone());
+                    current = activity;
+                }
+            }
+        });
+
+        Self {
+            task,
+        }
+    }
+}
+
+impl Drop for SpotifyPresence {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn needs_update(current: Option<&ActivityData>, new: Option<&ActivityData>) -> bool {
+    let start = |a: &ActivityData| a.timestamps.as_ref().and_then(|t| t.start).unwrap_or(0);
+
+    match (current, new) {
+        (None, None) => false,
+        (Some(current), Some(new)) => {
+            current.sync_id != new.sync_id || start(current).abs_diff(start(new)) > MAX_DRIFT_MS
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn spotify_activity() {
+        let track = SpotifyTrack::new("4uLU6hMCjMI75M1A2tKUQC", "Song", Duration::from_secs(213))
+            .artists(["Artist", "Other Artist"])
+            .album("Album", Some("ab67616d0000b273".into()))
+            .progress(Duration::from_secs(13));
+        let activity = ActivityData::spotify(&track, UserId::new(1), "session");
+
+        let value = to_value(&activity).unwrap();
+        assert_eq!(value["type"], 2);
+        assert_eq!(value["details"], "Song");
+        assert_eq!(value["state"], "Artist; Other Artist");
+        assert_eq!(value["assets"]["large_image"], "spotify:ab67616d0000b273");
+        assert_eq!(value["party"], json!({"id": "spotify:1", "size": null}));
+        assert_eq!(value["flags"], 48);
+        assert_eq!(value["sync_id"], "4uLU6hMCjMI75M1A2tKUQC");
+        assert_eq!(value["session_id"], "session");
+
+        let timestamps = activity.timestamps.as_ref().unwrap();
+        assert_eq!(timestamps.end.unwrap() - timestamps.start.unwrap(), 213_000);
+
+        assert!(!needs_update(Some(&activity), Some(&activity.clone())));
+        assert!(needs_update(Some(&activity), None));
+
+        let seeked = ActivityData::spotify(&track.progress(Duration::ZERO), UserId::new(1), "s");
+        assert!(needs_update(Some(&activity), Some(&seeked)));
+    }
+}
@@ -0,0 +1,112 @@
+use reqwest::header::{HeaderMap as Headers, HeaderName, HeaderValue, InvalidHeaderValue};
+
+use crate::internal::prelude::*;
+
+/// The body of a response requiring a captcha to be solved, see [`Error::Captcha`].
+#[derive(Deserialize)]
+pub(super) struct CaptchaResponse {
+    #[serde(rename = "captcha_key", default)]
+    key: Vec<String>,
+    #[serde(rename = "captcha_sitekey")]
+    sitekey: String,
+    #[serde(rename = "captcha_rqdata")]
+    rqdata: Option<String>,
+    #[serde(rename = "captcha_rqtoken")]
+    rqtoken: Option<String>,
+    #[serde(rename = "captcha_session_id")]
+    session_id: Option<String>,
+}
+
+impl From<CaptchaResponse> for Error {
+    fn from(response: CaptchaResponse) -> Self {
+        Error::Captcha {
+            key: response.key,
+            sitekey: response.sitekey,
+            rqdata: response.rqdata,
+            rqtoken: response.rqtoken,
+            session_id: response.session_id,
+        }
+    }
+}
+
+/// A solved captcha, used to retry a request which failed with [`Error::Captcha`] via
+/// [`Http::clone_with_captcha`].
+///
+/// [`Http::clone_with_captcha`]: super::Http::clone_with_captcha
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CaptchaSolution {
+    key: String,
+    rqtoken: Option<String>,
+    session_id: Option<String>,
+}
+
+impl CaptchaSolution {
+    /// Creates a solution from the response token of the solved captcha.
+    ///
+    /// If the challenge contained an `rqtoken` or a `session_id`, these must be set as well. Use
+    /// [`Error::captcha_solution`] to copy them from the error.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            rqtoken: None,
+            session_id: None,
+        }
+    }
+
+    /// Sets the `rqtoken` of the challenge.
+    pub fn rqtoken(mut self, rqtoken: impl Into<String>) -> Self {
+        self.rqtoken = Some(rqtoken.into());
+        self
+    }
+
+    /// Sets the `session_id` of the challenge.
+    pub fn session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+
+    pub(super) fn headers(&self) -> StdResult<Headers, InvalidHeaderValue> {
+        let mut headers = Headers::new();
+        headers.insert(HeaderName::from_static("x-captcha-key"), HeaderValue::from_str(&self.key)?);
+        if let Some(rqtoken) = &self.rqtoken {
+            headers.insert(
+                HeaderName::from_static("x-captcha-rqtoken"),
+                HeaderValue::from_str(rqtoken)?,
+            );
+        }
+        if let Some(session_id) = &self.session_id {
+            headers.insert(
+                HeaderName::from_static("x-captcha-session-id"),
+                HeaderValue::from_str(session_id)?,
+            );
+        }
+        Ok(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn challenge_and_solution() {
+        let response: CaptchaResponse = from_value(json!({
+            "captcha_key": ["captcha-required"],
+            "captcha_sitekey": "a9b5fb07-92ff-493f-86fe-352a2803b3df",
+            "captcha_service": "hcaptcha",
+            "captcha_rqdata": "data",
+            "captcha_rqtoken": "token",
+        }))
+        .unwrap();
+
+        let error = Error::from(response);
+        assert!(matches!(&error, Error::Captcha { rqdata: Some(rqdata), .. } if rqdata == "data"));
+
+        let headers = error.captcha_solution("solved").unwrap().headers().unwrap();
+        assert_eq!(headers["x-captcha-key"], "solved");
+        assert_eq!(headers["x-captcha-rqtoken"], "token");
+        assert!(!headers.contains_key("x-captcha-session-id"));
+    }
+}
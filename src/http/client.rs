@@ -4,23 +4,28 @@ use std::borrow::Cow;
 use std::num::NonZeroU64;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap as Headers, HeaderValue};
 #[cfg(feature = "utils")]
 use reqwest::Url;
-use reqwest::{Client, ClientBuilder, Response as ReqwestResponse, StatusCode};
+use reqwest::{Client, ClientBuilder, Proxy, Response as ReqwestResponse, StatusCode};
 use secrecy::{ExposeSecret, SecretString};
 use serde::de::DeserializeOwned;
 use tracing::{debug, instrument, trace};
 
+use super::error::error_from_response;
 use super::multipart::{Multipart, MultipartUpload};
-use super::ratelimiting::Ratelimiter;
+use super::ratelimiting::{RatelimitSnapshot, Ratelimiter};
 use super::request::Request;
+use super::retry::RetryPolicy;
 use super::routing::Route;
 use super::typing::Typing;
 use super::{
-    ErrorResponse,
+    ApiVersion,
+    CaptchaSolution,
+    ClientProperties,
     GuildPagination,
     HttpError,
     LightMethod,
@@ -55,6 +60,14 @@ pub struct HttpBuilder {
     ratelimiter_disabled: bool,
     token: SecretString,
     proxy: Option<String>,
+    egress_proxy: Option<Proxy>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    upload_timeout: Option<Duration>,
+    api_version: ApiVersion,
+    client_properties: Option<ClientProperties>,
+    send_authorization: bool,
+    retry_policy: RetryPolicy,
     application_id: Option<ApplicationId>,
     default_allowed_mentions: Option<CreateAllowedMentions>,
 }
@@ -69,6 +82,14 @@ impl HttpBuilder {
             ratelimiter_disabled: false,
             token: SecretString::new(parse_token(token)),
             proxy: None,
+            egress_proxy: None,
+            timeout: None,
+            connect_timeout: None,
+            upload_timeout: None,
+            api_version: ApiVersion::V10,
+            client_properties: None,
+            send_authorization: true,
+            retry_policy: RetryPolicy::none(),
             application_id: None,
             default_allowed_mentions: None,
         }
@@ -88,6 +109,23 @@ impl HttpBuilder {
     }
 
     /// Sets the [`reqwest::Client`]. If one isn't provided, a default one will be used.
+    ///
+    /// This allows sharing a connection pool with the rest of an application, or configuring the
+    /// client beyond what this builder offers, such as timeouts or custom root certificates. The
+    /// client is used as-is: the TLS backend selected via this crate's features and
+    /// [`Self::egress_proxy`] are not applied to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use serenity::http::HttpBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?;
+    /// let http = HttpBuilder::new("token").client(client).build();
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
         self
@@ -129,6 +167,120 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets whether the token is sent in the `Authorization` header. By default it is.
+    ///
+    /// Disable this when the API proxy set via [`Self::proxy`] injects the token itself, so that
+    /// it never has to leave the proxy.
+    pub fn send_authorization(mut self, send_authorization: bool) -> Self {
+        self.send_authorization = send_authorization;
+        self
+    }
+
+    /// Sets a native proxy which tunnels all HTTP API requests, as often required for egress in
+    /// hosting environments.
+    ///
+    /// Unlike [`Self::proxy`], requests are still addressed to the Discord API. If no proxy is set,
+    /// the `HTTP_PROXY`, `HTTPS_PROXY` and `ALL_PROXY` environment variables are respected. Has no
+    /// effect if a custom [`Client`] is passed via [`Self::client`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::http::HttpBuilder;
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let proxy = reqwest::Proxy::https("http://egress.internal:3128")?;
+    /// let http = HttpBuilder::new("token").egress_proxy(proxy).build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn egress_proxy(mut self, proxy: Proxy) -> Self {
+        self.egress_proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the total timeout of each HTTP API request, from connecting until the response body
+    /// has been read. By default, requests never time out.
+    ///
+    /// Individual requests can override this via [`Request::timeout`]. Has no effect if a custom
+    /// [`Client`] is passed via [`Self::client`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// let http = HttpBuilder::new("token")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .build();
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the version of the Discord API to send requests to. Defaults to [`ApiVersion::V10`].
+    ///
+    /// Individual requests can override this via [`Request::api_version`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::http::{ApiVersion, HttpBuilder};
+    ///
+    /// let http = HttpBuilder::new("token").api_version(ApiVersion::V9).build();
+    /// ```
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Sets the properties of the client, to send the same headers as official clients with every
+    /// request. By default, they are not sent. See [`ClientProperties`] for more info.
+    pub fn client_properties(mut self, client_properties: ClientProperties) -> Self {
+        self.client_properties = Some(client_properties);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection to the Discord API. By default, connecting
+    /// never times out.
+    ///
+    /// Has no effect if a custom [`Client`] is passed via [`Self::client`].
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the timeout of requests which upload files, such as attachments, stickers, emojis and
+    /// soundboard sounds, overriding the one set with [`Self::timeout`] for them. By default, the
+    /// general timeout applies.
+    ///
+    /// Individual requests can still override this via [`Request::timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// let http = HttpBuilder::new("token")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .upload_timeout(Duration::from_secs(300))
+    ///     .build();
+    /// ```
+    pub fn upload_timeout(mut self, upload_timeout: Duration) -> Self {
+        self.upload_timeout = Some(upload_timeout);
+        self
+    }
+
+    /// Sets the policy for retrying requests which failed in a transient way, such as a `502 Bad
+    /// Gateway` from Discord. By default, requests are not retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Sets the [`CreateAllowedMentions`] used by default for each request that would use it.
     ///
     /// This only takes effect if you are calling through the model or builder methods, not directly
@@ -144,19 +296,32 @@ impl HttpBuilder {
         let application_id = AtomicU64::new(self.application_id.map_or(0, ApplicationId::get));
 
         let client = self.client.unwrap_or_else(|| {
-            let builder = configure_client_backend(Client::builder());
+            let mut builder = configure_client_backend(Client::builder());
+            if let Some(proxy) = self.egress_proxy {
+                builder = builder.proxy(proxy);
+            }
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(connect_timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
             builder.build().expect("Cannot build reqwest::Client")
         });
 
-        let ratelimiter = (!self.ratelimiter_disabled).then(|| {
-            self.ratelimiter
-                .unwrap_or_else(|| Ratelimiter::new(client.clone(), self.token.expose_secret()))
-        });
+        let token = if self.send_authorization { self.token.expose_secret().as_str() } else { "" };
+        let ratelimiter = (!self.ratelimiter_disabled)
+            .then(|| self.ratelimiter.unwrap_or_else(|| Ratelimiter::new(client.clone(), token)));
 
         Http {
             client,
             ratelimiter,
             proxy: self.proxy,
+            send_authorization: self.send_authorization,
+            retry_policy: self.retry_policy,
+            upload_timeout: self.upload_timeout,
+            api_version: self.api_version,
+            client_headers: self.client_properties.as_ref().map(ClientProperties::headers),
             token: self.token,
             application_id,
             default_allowed_mentions: self.default_allowed_mentions,
@@ -189,6 +354,18 @@ fn reason_into_header(reason: &str) -> Headers {
     headers
 }
 
+/// The response to a request made with [`Http::request_raw`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RawResponse {
+    /// The status code of the response, which may indicate failure.
+    pub status: StatusCode,
+    /// The headers of the response.
+    pub headers: Headers,
+    /// The unparsed body of the response.
+    pub body: Vec<u8>,
+}
+
 /// **Note**: For all member functions that return a [`Result`], the Error kind will be either
 /// [`Error::Http`] or [`Error::Json`].
 #[derive(Debug)]
@@ -196,6 +373,11 @@ pub struct Http {
     pub(crate) client: Client,
     pub ratelimiter: Option<Ratelimiter>,
     pub proxy: Option<String>,
+    send_authorization: bool,
+    retry_policy: RetryPolicy,
+    upload_timeout: Option<Duration>,
+    api_version: ApiVersion,
+    client_headers: Option<Headers>,
     token: SecretString,
     application_id: AtomicU64,
     pub default_allowed_mentions: Option<CreateAllowedMentions>,
@@ -224,6 +406,134 @@ impl Http {
         self.token.expose_secret()
     }
 
+    /// Creates an [`Http`] which authorizes its requests with another token, for example that of a
+    /// second account.
+    ///
+    /// Unlike building a new instance, this is cheap: the connection pool is shared with this
+    /// instance, as are all other settings. As Discord tracks ratelimits per token, the clone
+    /// starts out with its own ratelimit buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// let alt = http.clone_with_token("other token");
+    /// let user = alt.get_current_user().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn clone_with_token(&self, token: &str) -> Self {
+        let token = parse_token(token);
+        let ratelimiter_token = if self.send_authorization { token.as_str() } else { "" };
+        let ratelimiter = self.ratelimiter.as_ref().map(|r| r.clone_with_token(ratelimiter_token));
+
+        self.clone_with(SecretString::new(token), ratelimiter)
+    }
+
+    fn clone_with(&self, token: SecretString, ratelimiter: Option<Ratelimiter>) -> Self {
+        Self {
+            client: self.client.clone(),
+            ratelimiter,
+            proxy: self.proxy.clone(),
+            send_authorization: self.send_authorization,
+            retry_policy: self.retry_policy,
+            upload_timeout: self.upload_timeout,
+            api_version: self.api_version,
+            client_headers: self.client_headers.clone(),
+            token,
+            application_id: AtomicU64::new(self.application_id.load(Ordering::Relaxed)),
+            default_allowed_mentions: self.default_allowed_mentions.clone(),
+        }
+    }
+
+    /// Creates an [`Http`] which attaches the given solved captcha to its requests, to retry a
+    /// request which failed with [`Error::Captcha`].
+    ///
+    /// This is cheap and shares everything else, including the ratelimit buckets, with this
+    /// instance. A solution is only valid once, so the returned instance should only be used for
+    /// the retried request.
+    ///
+    /// See [`Error::captcha_solution`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::InvalidHeader`] if the solution contains characters which are not
+    /// allowed in a header.
+    pub fn clone_with_captcha(&self, solution: &CaptchaSolution) -> Result<Self> {
+        let ratelimiter = self.ratelimiter.as_ref().map(Ratelimiter::share);
+        let mut http = self.clone_with(self.token.clone(), ratelimiter);
+        http.client_headers.get_or_insert_with(Headers::new).extend(solution.headers()?);
+        Ok(http)
+    }
+
+    /// Returns the policy for retrying failed requests. See [`HttpBuilder::retry_policy`].
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Returns the version of the API requests are sent to. See [`HttpBuilder::api_version`].
+    #[must_use]
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Returns the [`reqwest::Client`] used to perform requests, e.g. to reuse its connection
+    /// pool for requests outside of the Discord API.
+    #[must_use]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns the current state of every ratelimit bucket known to the [`Ratelimiter`], or an
+    /// empty list if the ratelimiter is disabled.
+    pub async fn ratelimit_snapshot(&self) -> Vec<RatelimitSnapshot> {
+        match &self.ratelimiter {
+            Some(ratelimiter) => ratelimiter.snapshot().await,
+            None => Vec::new(),
+        }
+    }
+
+    /// Marks a channel as read up to and including the given message.
+    ///
+    /// The map should contain a `token` of `null`.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn ack_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        map: &Value,
+    ) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(
+            200,
+            Request::new(
+                Route::ChannelMessageAck {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body)),
+        )
+        .await
+    }
+
+    /// Marks multiple channels as read at once.
+    ///
+    /// The map must contain a `read_states` list of objects with a `channel_id` and `message_id`.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn ack_messages(&self, map: &Value) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(204, Request::new(Route::ReadStatesAckBulk, LightMethod::Post).body(Some(body)))
+            .await
+    }
+
     /// Adds a [`User`] to a [`Guild`] with a valid OAuth2 access token.
     ///
     /// Returns the created [`Member`] object, or nothing if the user is already a guild member.
@@ -236,17 +546,16 @@ impl Http {
         let body = to_vec(map)?;
 
         let response = self
-            .request(Request {
-                body: Some(body),
-                multipart: None,
-                headers: None,
-                method: LightMethod::Put,
-                route: Route::GuildMember {
-                    guild_id,
-                    user_id,
-                },
-                params: None,
-            })
+            .request(
+                Request::new(
+                    Route::GuildMember {
+                        guild_id,
+                        user_id,
+                    },
+                    LightMethod::Put,
+                )
+                .body(Some(body)),
+            )
             .await?;
 
         if response.status() == 204 {
@@ -268,18 +577,40 @@ impl Http {
         role_id: RoleId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Put,
-            route: Route::GuildMemberRole {
-                guild_id,
-                role_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildMemberRole {
+                    guild_id,
+                    role_id,
+                    user_id,
+                },
+                LightMethod::Put,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Creates or changes the current user's [`Relationship`] with a user.
+    ///
+    /// An empty map sends a friend request to the user, or accepts their incoming one. A map with a
+    /// `type` of [`RelationshipType::Blocked`] blocks the user.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn add_relationship(&self, user_id: UserId, map: &Value) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(
+            204,
+            Request::new(
+                Route::UserMeRelationship {
+                    user_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(body)),
+        )
         .await
     }
 
@@ -301,17 +632,18 @@ impl Http {
     ) -> Result<()> {
         let delete_message_seconds = u32::from(delete_message_days) * 86400;
 
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: reason.map(reason_into_header),
-            method: LightMethod::Put,
-            route: Route::GuildBan {
-                guild_id,
-                user_id,
-            },
-            params: Some(vec![("delete_message_seconds", delete_message_seconds.to_string())]),
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildBan {
+                    guild_id,
+                    user_id,
+                },
+                LightMethod::Put,
+            )
+            .headers(reason.map(reason_into_header))
+            .params(Some(vec![("delete_message_seconds", delete_message_seconds.to_string())])),
+        )
         .await
     }
 
@@ -324,16 +656,16 @@ impl Http {
         map: &impl serde::Serialize,
         reason: Option<&str>,
     ) -> Result<BulkBanResponse> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildBulkBan {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildBulkBan {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -345,16 +677,46 @@ impl Http {
     /// This should rarely be used for bots, although it is a good indicator that a long-running
     /// command is still being processed.
     pub async fn broadcast_typing(&self, channel_id: ChannelId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::ChannelTyping {
-                channel_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelTyping {
+                    channel_id,
+                },
+                LightMethod::Post,
+            ),
+        )
+        .await
+    }
+
+    /// Joins the given HypeSquad house, leaving the current one.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn change_hypesquad_house(&self, house: HypeSquadHouse) -> Result<()> {
+        let body = to_vec(&json!({
+            "house_id": house,
+        }))?;
+
+        self.wind(204, Request::new(Route::HypesquadOnline, LightMethod::Post).body(Some(body)))
+            .await
+    }
+
+    /// Marks a one-time purchase entitlement of the current application as consumed.
+    ///
+    /// Only entitlements for [consumable] SKUs can be consumed.
+    ///
+    /// [consumable]: SkuKind::Consumable
+    pub async fn consume_entitlement(&self, entitlement_id: EntitlementId) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::EntitlementConsume {
+                    application_id: self.try_application_id()?,
+                    entitlement_id,
+                },
+                LightMethod::Post,
+            ),
+        )
         .await
     }
 
@@ -374,16 +736,16 @@ impl Http {
     ) -> Result<GuildChannel> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildChannels {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildChannels {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -393,14 +755,11 @@ impl Http {
         map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
     ) -> Result<StageInstance> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::StageInstances,
-            params: None,
-        })
+        self.fire(
+            Request::new(Route::StageInstances, LightMethod::Post)
+                .body(Some(to_vec(map)?))
+                .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -414,17 +773,17 @@ impl Http {
     ) -> Result<GuildChannel> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelMessageThreads {
-                channel_id,
-                message_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelMessageThreads {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -437,16 +796,16 @@ impl Http {
     ) -> Result<GuildChannel> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelThreads {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelThreads {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -468,23 +827,43 @@ impl Http {
         files: Vec<CreateAttachment>,
         audit_log_reason: Option<&str>,
     ) -> Result<GuildChannel> {
-        self.fire(Request {
-            body: None,
-            multipart: Some(Multipart {
+        self.fire(
+            Request::new(
+                Route::ChannelForumPosts {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .multipart(Some(Multipart {
                 upload: MultipartUpload::Attachments(files.into_iter().collect()),
                 payload_json: Some(to_string(map)?),
                 fields: vec![],
-            }),
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelForumPosts {
-                channel_id,
-            },
-            params: None,
-        })
+            }))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
+    /// Creates an emoji owned by the current application.
+    ///
+    /// View the source code for [`Context::create_application_emoji`] method to see what fields
+    /// this requires.
+    ///
+    /// [`Context::create_application_emoji`]: crate::client::Context::create_application_emoji
+    pub async fn create_application_emoji(&self, map: &Value) -> Result<Emoji> {
+        let mut request = Request::new(
+            Route::ApplicationEmojis {
+                application_id: self.try_application_id()?,
+            },
+            LightMethod::Post,
+        )
+        .body(Some(to_vec(map)?));
+        // The image is uploaded as base64 in the body, so `send` does not see it as an upload.
+        request.timeout = self.upload_timeout;
+
+        self.fire(request).await
+    }
+
     /// Creates an emoji in the given [`Guild`] with the given data.
     ///
     /// View the source code for [`Guild::create_emoji`] method to see what fields this requires.
@@ -498,17 +877,17 @@ impl Http {
         map: &Value,
         audit_log_reason: Option<&str>,
     ) -> Result<Emoji> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildEmojis {
+        let mut request = Request::new(
+            Route::GuildEmojis {
                 guild_id,
             },
-            params: None,
-        })
-        .await
+            LightMethod::Post,
+        )
+        .body(Some(to_vec(map)?))
+        .headers(audit_log_reason.map(reason_into_header));
+        request.timeout = self.upload_timeout;
+
+        self.fire(request).await
     }
 
     /// Create a follow-up message for an Interaction.
@@ -520,17 +899,13 @@ impl Http {
         map: &impl serde::Serialize,
         files: Vec<CreateAttachment>,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::WebhookFollowupMessages {
+        let mut request = Request::new(
+            Route::WebhookFollowupMessages {
                 application_id: self.try_application_id()?,
                 token: interaction_token,
             },
-            params: None,
-        };
+            LightMethod::Post,
+        );
 
         if files.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -556,16 +931,15 @@ impl Http {
     ///
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-global-application-command
     pub async fn create_global_command(&self, map: &impl serde::Serialize) -> Result<Command> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::Commands {
-                application_id: self.try_application_id()?,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Commands {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -574,16 +948,15 @@ impl Http {
         &self,
         map: &impl serde::Serialize,
     ) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::Commands {
-                application_id: self.try_application_id()?,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Commands {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -593,17 +966,16 @@ impl Http {
         guild_id: GuildId,
         map: &impl serde::Serialize,
     ) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::GuildCommands {
-                application_id: self.try_application_id()?,
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildCommands {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -641,14 +1013,64 @@ impl Http {
     /// https://discord.com/developers/docs/resources/guild#create-guild
     /// [whitelist]: https://discord.com/developers/docs/resources/guild#create-guild
     pub async fn create_guild(&self, map: &Value) -> Result<PartialGuild> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::Guilds,
-            params: None,
-        })
+        self.fire(Request::new(Route::Guilds, LightMethod::Post).body(Some(to_vec(map)?))).await
+    }
+
+    /// Creates a guild from a guild template.
+    ///
+    /// **Note**: This endpoint is usually only available for user accounts.
+    pub async fn create_guild_from_template(
+        &self,
+        code: &str,
+        map: &impl serde::Serialize,
+    ) -> Result<PartialGuild> {
+        self.fire(
+            Request::new(
+                Route::GuildsTemplate {
+                    code,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?)),
+        )
+        .await
+    }
+
+    /// Creates a soundboard sound in a guild.
+    pub async fn create_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        let mut request = Request::new(
+            Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            LightMethod::Post,
+        )
+        .body(Some(to_vec(map)?))
+        .headers(audit_log_reason.map(reason_into_header));
+        request.timeout = self.upload_timeout;
+
+        self.fire(request).await
+    }
+
+    /// Creates a template from the current state of a guild.
+    pub async fn create_guild_template(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+    ) -> Result<GuildTemplate> {
+        self.fire(
+            Request::new(
+                Route::GuildTemplates {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -664,17 +1086,16 @@ impl Http {
         guild_id: GuildId,
         map: &impl serde::Serialize,
     ) -> Result<Command> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::GuildCommands {
-                application_id: self.try_application_id()?,
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildCommands {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -693,18 +1114,41 @@ impl Http {
         map: &Value,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildIntegration {
-                guild_id,
-                integration_id,
-            },
-            params: None,
-        })
-        .await
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildIntegration {
+                    guild_id,
+                    integration_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Boosts a guild with the given boost slots of the current user.
+    ///
+    /// Refer to [`Self::get_guild_subscription_slots`] for the available slots.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn create_guild_subscriptions(
+        &self,
+        guild_id: GuildId,
+        map: &Value,
+    ) -> Result<Vec<GuildSubscription>> {
+        self.fire(
+            Request::new(
+                Route::GuildPremiumSubscriptions {
+                    guild_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
+        .await
     }
 
     /// Creates a response to an [`Interaction`] from the gateway.
@@ -720,17 +1164,13 @@ impl Http {
         map: &impl serde::Serialize,
         files: Vec<CreateAttachment>,
     ) -> Result<()> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::InteractionResponse {
+        let mut request = Request::new(
+            Route::InteractionResponse {
                 interaction_id,
                 token: interaction_token,
             },
-            params: None,
-        };
+            LightMethod::Post,
+        );
 
         if files.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -745,6 +1185,19 @@ impl Http {
         self.wind(204, request).await
     }
 
+    /// Invokes an application command of another application, as if the current user used it.
+    ///
+    /// Refer to [`CreateInteraction`] for the fields of the map.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// [`CreateInteraction`]: crate::builder::CreateInteraction
+    pub async fn create_interaction(&self, map: &impl serde::Serialize) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(204, Request::new(Route::Interactions, LightMethod::Post).body(Some(body))).await
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -763,16 +1216,16 @@ impl Http {
     ) -> Result<RichInvite> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelInvites {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelInvites {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -786,17 +1239,18 @@ impl Http {
     ) -> Result<()> {
         let body = to_vec(map)?;
 
-        self.wind(204, Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Put,
-            route: Route::ChannelPermission {
-                channel_id,
-                target_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelPermission {
+                    channel_id,
+                    target_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -804,15 +1258,7 @@ impl Http {
     pub async fn create_private_channel(&self, map: &Value) -> Result<PrivateChannel> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::UserMeDmChannels,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::UserMeDmChannels, LightMethod::Post).body(Some(body))).await
     }
 
     async fn _create_reaction(
@@ -822,18 +1268,25 @@ impl Http {
         reaction_type: &ReactionType,
         burst: bool,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::ChannelMessageReactionMe {
-                channel_id,
-                message_id,
-                reaction: &reaction_type.as_data(),
-            },
-            params: Some(vec![("burst", burst.to_string())]),
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessageReactionMe {
+                    channel_id,
+                    message_id,
+                    reaction: &reaction_type.as_data(),
+                },
+                LightMethod::Put,
+            )
+            .params(Some(vec![
+                ("burst", burst.to_string()),
+                (
+                    "type",
+                    u8::from(if burst { ReactionTypes::Burst } else { ReactionTypes::Normal })
+                        .to_string(),
+                ),
+            ])),
+        )
         .await
     }
 
@@ -865,16 +1318,16 @@ impl Http {
         audit_log_reason: Option<&str>,
     ) -> Result<Role> {
         let mut value: Value = self
-            .fire(Request {
-                body: Some(to_vec(body)?),
-                multipart: None,
-                headers: audit_log_reason.map(reason_into_header),
-                method: LightMethod::Post,
-                route: Route::GuildRoles {
-                    guild_id,
-                },
-                params: None,
-            })
+            .fire(
+                Request::new(
+                    Route::GuildRoles {
+                        guild_id,
+                    },
+                    LightMethod::Post,
+                )
+                .body(Some(to_vec(body)?))
+                .headers(audit_log_reason.map(reason_into_header)),
+            )
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -898,16 +1351,16 @@ impl Http {
         audit_log_reason: Option<&str>,
     ) -> Result<ScheduledEvent> {
         let body = to_vec(map)?;
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildScheduledEvents {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildScheduledEvents {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -923,20 +1376,20 @@ impl Http {
         file: CreateAttachment,
         audit_log_reason: Option<&str>,
     ) -> Result<Sticker> {
-        self.fire(Request {
-            body: None,
-            multipart: Some(Multipart {
+        self.fire(
+            Request::new(
+                Route::GuildStickers {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .multipart(Some(Multipart {
                 upload: MultipartUpload::File(file),
                 fields: map.into_iter().map(|(k, v)| (k.into(), v.into())).collect(),
                 payload_json: None,
-            }),
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildStickers {
-                guild_id,
-            },
-            params: None,
-        })
+            }))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -957,16 +1410,15 @@ impl Http {
             "owner_id": owner_id,
             "owner_type": owner_type
         });
-        self.fire(Request {
-            body: Some(to_vec(&map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::Entitlements {
-                application_id: self.try_application_id()?,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Entitlements {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(&map)?)),
+        )
         .await
     }
 
@@ -1004,16 +1456,16 @@ impl Http {
     ) -> Result<Webhook> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelWebhooks {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelWebhooks {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1023,16 +1475,15 @@ impl Http {
         channel_id: ChannelId,
         audit_log_reason: Option<&str>,
     ) -> Result<Channel> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::Channel {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Channel {
+                    channel_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1042,16 +1493,31 @@ impl Http {
         channel_id: ChannelId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::StageInstance {
-                channel_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::StageInstance {
+                    channel_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Deletes an emoji owned by the current application.
+    pub async fn delete_application_emoji(&self, emoji_id: EmojiId) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::ApplicationEmoji {
+                    application_id: self.try_application_id()?,
+                    emoji_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1064,17 +1530,17 @@ impl Http {
         emoji_id: EmojiId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildEmoji {
-                guild_id,
-                emoji_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildEmoji {
+                    guild_id,
+                    emoji_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1084,49 +1550,46 @@ impl Http {
         interaction_token: &str,
         message_id: MessageId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::WebhookFollowupMessage {
-                application_id: self.try_application_id()?,
-                token: interaction_token,
-                message_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::WebhookFollowupMessage {
+                    application_id: self.try_application_id()?,
+                    token: interaction_token,
+                    message_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
     /// Deletes a global command.
     pub async fn delete_global_command(&self, command_id: CommandId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::Command {
-                application_id: self.try_application_id()?,
-                command_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::Command {
+                    application_id: self.try_application_id()?,
+                    command_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
     /// Deletes a guild, only if connected account owns it.
     pub async fn delete_guild(&self, guild_id: GuildId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::Guild {
-                guild_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::Guild {
+                    guild_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1136,18 +1599,17 @@ impl Http {
         guild_id: GuildId,
         command_id: CommandId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::GuildCommand {
-                application_id: self.try_application_id()?,
-                guild_id,
-                command_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildCommand {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                    command_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1158,17 +1620,76 @@ impl Http {
         integration_id: IntegrationId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildIntegration {
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildIntegration {
+                    guild_id,
+                    integration_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Removes a boost of the current user from a guild. The boost slot is then on cooldown
+    /// before it can be used again.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn delete_guild_subscription(
+        &self,
+        guild_id: GuildId,
+        subscription_id: GuildSubscriptionId,
+    ) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildPremiumSubscription {
+                    guild_id,
+                    subscription_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
+        .await
+    }
+
+    /// Deletes a soundboard sound from a guild.
+    pub async fn delete_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildSoundboardSound {
+                    guild_id,
+                    sound_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Deletes a template of a guild.
+    pub async fn delete_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request::new(
+            Route::GuildTemplate {
                 guild_id,
-                integration_id,
+                code,
             },
-            params: None,
-        })
+            LightMethod::Delete,
+        ))
         .await
     }
 
@@ -1178,16 +1699,15 @@ impl Http {
         code: &str,
         audit_log_reason: Option<&str>,
     ) -> Result<Invite> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::Invite {
-                code,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Invite {
+                    code,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1198,17 +1718,17 @@ impl Http {
         message_id: MessageId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::ChannelMessage {
-                channel_id,
-                message_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessage {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1219,16 +1739,17 @@ impl Http {
         map: &Value,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::ChannelMessagesBulkDelete {
-                channel_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessagesBulkDelete {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1255,17 +1776,16 @@ impl Http {
         channel_id: ChannelId,
         message_id: MessageId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelMessageReactions {
-                channel_id,
-                message_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessageReactions {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1276,18 +1796,17 @@ impl Http {
         message_id: MessageId,
         reaction_type: &ReactionType,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelMessageReactionEmoji {
-                channel_id,
-                message_id,
-                reaction: &reaction_type.as_data(),
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessageReactionEmoji {
+                    channel_id,
+                    message_id,
+                    reaction: &reaction_type.as_data(),
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1296,17 +1815,16 @@ impl Http {
         &self,
         interaction_token: &str,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::WebhookOriginalInteractionResponse {
-                application_id: self.try_application_id()?,
-                token: interaction_token,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::WebhookOriginalInteractionResponse {
+                    application_id: self.try_application_id()?,
+                    token: interaction_token,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1317,17 +1835,17 @@ impl Http {
         target_id: TargetId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::ChannelPermission {
-                channel_id,
-                target_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelPermission {
+                    channel_id,
+                    target_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1339,19 +1857,18 @@ impl Http {
         user_id: UserId,
         reaction_type: &ReactionType,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelMessageReaction {
-                channel_id,
-                message_id,
-                user_id,
-                reaction: &reaction_type.as_data(),
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessageReaction {
+                    channel_id,
+                    message_id,
+                    user_id,
+                    reaction: &reaction_type.as_data(),
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1362,18 +1879,17 @@ impl Http {
         message_id: MessageId,
         reaction_type: &ReactionType,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelMessageReactionMe {
-                channel_id,
-                message_id,
-                reaction: &reaction_type.as_data(),
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelMessageReactionMe {
+                    channel_id,
+                    message_id,
+                    reaction: &reaction_type.as_data(),
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1384,17 +1900,17 @@ impl Http {
         role_id: RoleId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildRole {
-                guild_id,
-                role_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildRole {
+                    guild_id,
+                    role_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1409,17 +1925,16 @@ impl Http {
         guild_id: GuildId,
         event_id: ScheduledEventId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::GuildScheduledEvent {
-                guild_id,
-                event_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildScheduledEvent {
+                    guild_id,
+                    event_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1432,34 +1947,33 @@ impl Http {
         sticker_id: StickerId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildSticker {
-                guild_id,
-                sticker_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildSticker {
+                    guild_id,
+                    sticker_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
     /// Deletes a currently active test entitlement. Discord will act as though the corresponding
     /// user/guild *no longer has* an entitlement to the corresponding SKU.
     pub async fn delete_test_entitlement(&self, entitlement_id: EntitlementId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::Entitlement {
-                application_id: self.try_application_id()?,
-                entitlement_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::Entitlement {
+                    application_id: self.try_application_id()?,
+                    entitlement_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -1487,16 +2001,16 @@ impl Http {
         webhook_id: WebhookId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::Webhook {
-                webhook_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::Webhook {
+                    webhook_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1527,17 +2041,17 @@ impl Http {
         token: &str,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::WebhookWithToken {
-                webhook_id,
-                token,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::WebhookWithToken {
+                    webhook_id,
+                    token,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1550,16 +2064,16 @@ impl Http {
     ) -> Result<GuildChannel> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::Channel {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Channel {
+                    channel_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1570,16 +2084,31 @@ impl Http {
         map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
     ) -> Result<StageInstance> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::StageInstance {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::StageInstance {
+                    channel_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Changes the name of an emoji owned by the current application.
+    pub async fn edit_application_emoji(&self, emoji_id: EmojiId, map: &Value) -> Result<Emoji> {
+        self.fire(
+            Request::new(
+                Route::ApplicationEmoji {
+                    application_id: self.try_application_id()?,
+                    emoji_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -1595,17 +2124,17 @@ impl Http {
     ) -> Result<Emoji> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildEmoji {
-                guild_id,
-                emoji_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildEmoji {
+                    guild_id,
+                    emoji_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1621,18 +2150,14 @@ impl Http {
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment>,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::WebhookFollowupMessage {
+        let mut request = Request::new(
+            Route::WebhookFollowupMessage {
                 application_id: self.try_application_id()?,
                 token: interaction_token,
                 message_id,
             },
-            params: None,
-        };
+            LightMethod::Patch,
+        );
 
         if new_attachments.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -1657,18 +2182,14 @@ impl Http {
         interaction_token: &str,
         message_id: MessageId,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::WebhookFollowupMessage {
+        self.fire(Request::new(
+            Route::WebhookFollowupMessage {
                 application_id: self.try_application_id()?,
                 token: interaction_token,
                 message_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -1684,17 +2205,16 @@ impl Http {
         command_id: CommandId,
         map: &impl serde::Serialize,
     ) -> Result<Command> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::Command {
-                application_id: self.try_application_id()?,
-                command_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Command {
+                    application_id: self.try_application_id()?,
+                    command_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -1707,16 +2227,16 @@ impl Http {
     ) -> Result<PartialGuild> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::Guild {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Guild {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1733,18 +2253,17 @@ impl Http {
         command_id: CommandId,
         map: &impl serde::Serialize,
     ) -> Result<Command> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::GuildCommand {
-                application_id: self.try_application_id()?,
-                guild_id,
-                command_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildCommand {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                    command_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -1761,18 +2280,17 @@ impl Http {
         command_id: CommandId,
         map: &impl serde::Serialize,
     ) -> Result<CommandPermissions> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::GuildCommandPermissions {
-                application_id: self.try_application_id()?,
-                guild_id,
-                command_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildCommandPermissions {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                    command_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -1784,16 +2302,16 @@ impl Http {
     ) -> Result<()> {
         let body = to_vec(value)?;
 
-        self.wind(204, Request {
-            body: Some(body),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::GuildChannels {
-                guild_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildChannels {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body)),
+        )
         .await
     }
 
@@ -1811,16 +2329,16 @@ impl Http {
 
         let body = to_vec(value)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildMfa {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildMfa {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
         .map(|mfa: GuildMfaLevel| mfa.level)
     }
@@ -1834,16 +2352,80 @@ impl Http {
     ) -> Result<GuildWidget> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildWidget {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildWidget {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Edits the onboarding of a guild.
+    pub async fn edit_guild_onboarding(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<Onboarding> {
+        let body = to_vec(map)?;
+
+        self.fire(
+            Request::new(
+                Route::GuildOnboarding {
+                    guild_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Edits a soundboard sound of a guild.
+    pub async fn edit_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        self.fire(
+            Request::new(
+                Route::GuildSoundboardSound {
+                    guild_id,
+                    sound_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Edits the name or description of a guild template.
+    pub async fn edit_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+        map: &impl serde::Serialize,
+    ) -> Result<GuildTemplate> {
+        self.fire(
+            Request::new(
+                Route::GuildTemplate {
+                    guild_id,
+                    code,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -1856,16 +2438,16 @@ impl Http {
     ) -> Result<GuildWelcomeScreen> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildWelcomeScreen {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildWelcomeScreen {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1880,17 +2462,17 @@ impl Http {
         let body = to_vec(map)?;
 
         let mut value: Value = self
-            .fire(Request {
-                body: Some(body),
-                multipart: None,
-                headers: audit_log_reason.map(reason_into_header),
-                method: LightMethod::Patch,
-                route: Route::GuildMember {
-                    guild_id,
-                    user_id,
-                },
-                params: None,
-            })
+            .fire(
+                Request::new(
+                    Route::GuildMember {
+                        guild_id,
+                        user_id,
+                    },
+                    LightMethod::Patch,
+                )
+                .body(Some(body))
+                .headers(audit_log_reason.map(reason_into_header)),
+            )
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -1910,17 +2492,13 @@ impl Http {
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment>,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::ChannelMessage {
+        let mut request = Request::new(
+            Route::ChannelMessage {
                 channel_id,
                 message_id,
             },
-            params: None,
-        };
+            LightMethod::Patch,
+        );
 
         if new_attachments.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -1943,17 +2521,13 @@ impl Http {
         channel_id: ChannelId,
         message_id: MessageId,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::ChannelMessageCrosspost {
+        self.fire(Request::new(
+            Route::ChannelMessageCrosspost {
                 channel_id,
                 message_id,
             },
-            params: None,
-        })
+            LightMethod::Post,
+        ))
         .await
     }
 
@@ -1966,16 +2540,16 @@ impl Http {
     ) -> Result<Member> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildMemberMe {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildMemberMe {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -1991,16 +2565,17 @@ impl Http {
         let map = json!({ "nick": new_nickname });
         let body = to_vec(&map)?;
 
-        self.wind(200, Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildMemberMe {
-                guild_id,
-            },
-            params: None,
-        })
+        self.wind(
+            200,
+            Request::new(
+                Route::GuildMemberMe {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2013,16 +2588,15 @@ impl Http {
         let map = json!({ "webhook_channel_id": target_channel_id });
         let body = to_vec(&map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::ChannelFollowNews {
-                channel_id: news_channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelFollowNews {
+                    channel_id: news_channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body)),
+        )
         .await
     }
 
@@ -2031,17 +2605,13 @@ impl Http {
         &self,
         interaction_token: &str,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::WebhookOriginalInteractionResponse {
+        self.fire(Request::new(
+            Route::WebhookOriginalInteractionResponse {
                 application_id: self.try_application_id()?,
                 token: interaction_token,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -2056,17 +2626,13 @@ impl Http {
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment>,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::WebhookOriginalInteractionResponse {
+        let mut request = Request::new(
+            Route::WebhookOriginalInteractionResponse {
                 application_id: self.try_application_id()?,
                 token: interaction_token,
             },
-            params: None,
-        };
+            LightMethod::Patch,
+        );
 
         if new_attachments.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -2085,15 +2651,30 @@ impl Http {
     pub async fn edit_profile(&self, map: &impl serde::Serialize) -> Result<CurrentUser> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::UserMe,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::UserMe, LightMethod::Patch).body(Some(body))).await
+    }
+
+    /// Edits the profile of the current user, such as their pronouns.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn edit_user_profile(&self, map: &impl serde::Serialize) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(200, Request::new(Route::UserMeProfile, LightMethod::Patch).body(Some(body)))
+            .await
+    }
+
+    /// Edits the current user's settings.
+    ///
+    /// Refer to [`EditUserSettings`] for the fields of the map.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// [`EditUserSettings`]: crate::builder::EditUserSettings
+    pub async fn edit_user_settings(&self, map: &impl serde::Serialize) -> Result<UserSettings> {
+        let body = to_vec(map)?;
+
+        self.fire(Request::new(Route::UserMeSettings, LightMethod::Patch).body(Some(body))).await
     }
 
     /// Changes a role in a guild.
@@ -2105,17 +2686,17 @@ impl Http {
         audit_log_reason: Option<&str>,
     ) -> Result<Role> {
         let mut value: Value = self
-            .fire(Request {
-                body: Some(to_vec(map)?),
-                multipart: None,
-                headers: audit_log_reason.map(reason_into_header),
-                method: LightMethod::Patch,
-                route: Route::GuildRole {
-                    guild_id,
-                    role_id,
-                },
-                params: None,
-            })
+            .fire(
+                Request::new(
+                    Route::GuildRole {
+                        guild_id,
+                        role_id,
+                    },
+                    LightMethod::Patch,
+                )
+                .body(Some(to_vec(map)?))
+                .headers(audit_log_reason.map(reason_into_header)),
+            )
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -2140,16 +2721,16 @@ impl Http {
         let body = to_vec(&map)?;
 
         let mut value: Value = self
-            .fire(Request {
-                body: Some(body),
-                multipart: None,
-                headers: audit_log_reason.map(reason_into_header),
-                method: LightMethod::Patch,
-                route: Route::GuildRoles {
-                    guild_id,
-                },
-                params: None,
-            })
+            .fire(
+                Request::new(
+                    Route::GuildRoles {
+                        guild_id,
+                    },
+                    LightMethod::Patch,
+                )
+                .body(Some(body))
+                .headers(audit_log_reason.map(reason_into_header)),
+            )
             .await?;
 
         if let Some(array) = value.as_array_mut() {
@@ -2176,17 +2757,17 @@ impl Http {
         audit_log_reason: Option<&str>,
     ) -> Result<ScheduledEvent> {
         let body = to_vec(map)?;
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildScheduledEvent {
-                guild_id,
-                event_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildScheduledEvent {
+                    guild_id,
+                    event_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2203,17 +2784,17 @@ impl Http {
         let body = to_vec(&map)?;
 
         let mut value: Value = self
-            .fire(Request {
-                body: Some(body),
-                multipart: None,
-                headers: audit_log_reason.map(reason_into_header),
-                method: LightMethod::Patch,
-                route: Route::GuildSticker {
-                    guild_id,
-                    sticker_id,
-                },
-                params: None,
-            })
+            .fire(
+                Request::new(
+                    Route::GuildSticker {
+                        guild_id,
+                        sticker_id,
+                    },
+                    LightMethod::Patch,
+                )
+                .body(Some(body))
+                .headers(audit_log_reason.map(reason_into_header)),
+            )
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -2230,16 +2811,16 @@ impl Http {
         map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
     ) -> Result<GuildChannel> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::Channel {
-                channel_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Channel {
+                    channel_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2279,17 +2860,17 @@ impl Http {
         user_id: UserId,
         map: &impl serde::Serialize,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::GuildVoiceStates {
-                guild_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildVoiceStates {
+                    guild_id,
+                    user_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -2331,16 +2912,16 @@ impl Http {
         guild_id: GuildId,
         map: &impl serde::Serialize,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::GuildVoiceStateMe {
-                guild_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildVoiceStateMe {
+                    guild_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -2382,16 +2963,16 @@ impl Http {
         map: &impl serde::Serialize,
         audit_log_reason: Option<&str>,
     ) -> Result<Webhook> {
-        self.fire(Request {
-            body: Some(to_vec(map)?),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::Webhook {
-                webhook_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::Webhook {
+                    webhook_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(to_vec(map)?))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2429,17 +3010,17 @@ impl Http {
     ) -> Result<Webhook> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::WebhookWithToken {
-                webhook_id,
-                token,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::WebhookWithToken {
+                    webhook_id,
+                    token,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2507,17 +3088,14 @@ impl Http {
             params.push(("thread_id", thread_id.to_string()));
         }
 
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::WebhookWithToken {
+        let mut request = Request::new(
+            Route::WebhookWithToken {
                 webhook_id,
                 token,
             },
-            params: Some(params),
-        };
+            LightMethod::Post,
+        )
+        .params(Some(params));
 
         if files.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -2546,18 +3124,17 @@ impl Http {
         token: &str,
         message_id: MessageId,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::WebhookMessage {
-                webhook_id,
-                token,
-                message_id,
-            },
-            params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::WebhookMessage {
+                    webhook_id,
+                    token,
+                    message_id,
+                },
+                LightMethod::Get,
+            )
+            .params(thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())])),
+        )
         .await
     }
 
@@ -2571,18 +3148,15 @@ impl Http {
         map: &impl serde::Serialize,
         new_attachments: Vec<CreateAttachment>,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Patch,
-            route: Route::WebhookMessage {
+        let mut request = Request::new(
+            Route::WebhookMessage {
                 webhook_id,
                 token,
                 message_id,
             },
-            params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
-        };
+            LightMethod::Patch,
+        )
+        .params(thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]));
 
         if new_attachments.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -2605,18 +3179,18 @@ impl Http {
         token: &str,
         message_id: MessageId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::WebhookMessage {
-                webhook_id,
-                token,
-                message_id,
-            },
-            params: thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())]),
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::WebhookMessage {
+                    webhook_id,
+                    token,
+                    message_id,
+                },
+                LightMethod::Delete,
+            )
+            .params(thread_id.map(|thread_id| vec![("thread_id", thread_id.to_string())])),
+        )
         .await
     }
 
@@ -2630,16 +3204,8 @@ impl Http {
             scheduled_maintenances: Vec<Maintenance>,
         }
 
-        let status: StatusResponse = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::StatusMaintenancesActive,
-                params: None,
-            })
-            .await?;
+        let status: StatusResponse =
+            self.fire(Request::new(Route::StatusMaintenancesActive, LightMethod::Get)).await?;
 
         Ok(status.scheduled_maintenances)
     }
@@ -2673,16 +3239,15 @@ impl Http {
             }
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildBans {
-                guild_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildBans {
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
@@ -2693,6 +3258,7 @@ impl Http {
         action_type: Option<audit_log::Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
+        after: Option<AuditLogEntryId>,
         limit: Option<u8>,
     ) -> Result<AuditLogs> {
         let mut params = vec![];
@@ -2702,6 +3268,9 @@ impl Http {
         if let Some(before) = before {
             params.push(("before", before.to_string()));
         }
+        if let Some(after) = after {
+            params.push(("after", after.to_string()));
+        }
         if let Some(limit) = limit {
             params.push(("limit", limit.to_string()));
         }
@@ -2709,33 +3278,42 @@ impl Http {
             params.push(("user_id", user_id.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildAuditLogs {
-                guild_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildAuditLogs {
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
+    /// Gets the devices logged in to the current user's account.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_auth_sessions(&self) -> Result<Vec<AuthSession>> {
+        #[derive(Deserialize)]
+        struct AuthSessions {
+            user_sessions: Vec<AuthSession>,
+        }
+
+        self.fire::<AuthSessions>(Request::new(Route::AuthSessions, LightMethod::Get))
+            .await
+            .map(|x| x.user_sessions)
+    }
+
     /// Retrieves all auto moderation rules in a guild.
     ///
     /// This method requires `MANAGE_GUILD` permissions.
     pub async fn get_automod_rules(&self, guild_id: GuildId) -> Result<Vec<Rule>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildAutomodRules {
+        self.fire(Request::new(
+            Route::GuildAutomodRules {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -2743,17 +3321,13 @@ impl Http {
     ///
     /// This method requires `MANAGE_GUILD` permissions.
     pub async fn get_automod_rule(&self, guild_id: GuildId, rule_id: RuleId) -> Result<Rule> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildAutomodRule {
+        self.fire(Request::new(
+            Route::GuildAutomodRule {
                 guild_id,
                 rule_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -2768,16 +3342,16 @@ impl Http {
     ) -> Result<Rule> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildAutomodRules {
-                guild_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildAutomodRules {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2793,17 +3367,17 @@ impl Http {
     ) -> Result<Rule> {
         let body = to_vec(map)?;
 
-        self.fire(Request {
-            body: Some(body),
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Patch,
-            route: Route::GuildAutomodRule {
-                guild_id,
-                rule_id,
-            },
-            params: None,
-        })
+        self.fire(
+            Request::new(
+                Route::GuildAutomodRule {
+                    guild_id,
+                    rule_id,
+                },
+                LightMethod::Patch,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -2816,45 +3390,52 @@ impl Http {
         rule_id: RuleId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildAutomodRule {
-                guild_id,
-                rule_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildAutomodRule {
+                    guild_id,
+                    rule_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
     /// Gets current bot gateway.
     pub async fn get_bot_gateway(&self) -> Result<BotGateway> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GatewayBot,
-            params: None,
-        })
+        self.fire(Request::new(Route::GatewayBot, LightMethod::Get)).await
+    }
+
+    /// Gets the application commands which the current user can use in a private channel, along
+    /// with the applications they belong to.
+    ///
+    /// See [`Self::get_guild_application_command_index`] for guild channels.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_channel_application_command_index(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<CommandIndex> {
+        self.fire(Request::new(
+            Route::ChannelApplicationCommandIndex {
+                channel_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets all invites for a channel.
     pub async fn get_channel_invites(&self, channel_id: ChannelId) -> Result<Vec<RichInvite>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelInvites {
+        self.fire(Request::new(
+            Route::ChannelInvites {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -2863,31 +3444,43 @@ impl Http {
         &self,
         channel_id: ChannelId,
     ) -> Result<Vec<ThreadMember>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelThreadMembers {
+        self.fire(Request::new(
+            Route::ChannelThreadMembers {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the application commands which are available in a guild, along with the applications
+    /// they belong to.
+    ///
+    /// Unlike [`Self::search_application_commands`], this returns every command of every
+    /// application in the guild at once, regardless of the channel.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_guild_application_command_index(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<CommandIndex> {
+        self.fire(Request::new(
+            Route::GuildApplicationCommandIndex {
+                guild_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets all active threads from a guild.
     pub async fn get_guild_active_threads(&self, guild_id: GuildId) -> Result<ThreadsData> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildThreadsActive {
+        self.fire(Request::new(
+            Route::GuildThreadsActive {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -2906,16 +3499,15 @@ impl Http {
             params.push(("limit", limit.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            method: LightMethod::Get,
-            headers: None,
-            route: Route::ChannelArchivedPublicThreads {
-                channel_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelArchivedPublicThreads {
+                    channel_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
@@ -2934,16 +3526,15 @@ impl Http {
             params.push(("limit", limit.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelArchivedPrivateThreads {
-                channel_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelArchivedPrivateThreads {
+                    channel_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
@@ -2962,46 +3553,43 @@ impl Http {
             params.push(("limit", limit.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelJoinedPrivateThreads {
-                channel_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelJoinedPrivateThreads {
+                    channel_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
     /// Joins a thread channel.
     pub async fn join_thread_channel(&self, channel_id: ChannelId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::ChannelThreadMemberMe {
-                channel_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelThreadMemberMe {
+                    channel_id,
+                },
+                LightMethod::Put,
+            ),
+        )
         .await
     }
 
     /// Leaves a thread channel.
     pub async fn leave_thread_channel(&self, channel_id: ChannelId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelThreadMemberMe {
-                channel_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelThreadMemberMe {
+                    channel_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -3011,17 +3599,16 @@ impl Http {
         channel_id: ChannelId,
         user_id: UserId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Put,
-            route: Route::ChannelThreadMember {
-                channel_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelThreadMember {
+                    channel_id,
+                    user_id,
+                },
+                LightMethod::Put,
+            ),
+        )
         .await
     }
 
@@ -3031,17 +3618,16 @@ impl Http {
         channel_id: ChannelId,
         user_id: UserId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::ChannelThreadMember {
-                channel_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelThreadMember {
+                    channel_id,
+                    user_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
         .await
     }
 
@@ -3051,17 +3637,16 @@ impl Http {
         user_id: UserId,
         with_member: bool,
     ) -> Result<ThreadMember> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelThreadMember {
-                channel_id,
-                user_id,
-            },
-            params: Some(vec![("with_member", with_member.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelThreadMember {
+                    channel_id,
+                    user_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_member", with_member.to_string())])),
+        )
         .await
     }
 
@@ -3086,61 +3671,45 @@ impl Http {
     /// # }
     /// ```
     pub async fn get_channel_webhooks(&self, channel_id: ChannelId) -> Result<Vec<Webhook>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelWebhooks {
+        self.fire(Request::new(
+            Route::ChannelWebhooks {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets channel information.
     pub async fn get_channel(&self, channel_id: ChannelId) -> Result<Channel> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Channel {
+        self.fire(Request::new(
+            Route::Channel {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets all channels in a guild.
     pub async fn get_channels(&self, guild_id: GuildId) -> Result<Vec<GuildChannel>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildChannels {
+        self.fire(Request::new(
+            Route::GuildChannels {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets a stage instance.
     pub async fn get_stage_instance(&self, channel_id: ChannelId) -> Result<StageInstance> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::StageInstance {
+        self.fire(Request::new(
+            Route::StageInstance {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3168,18 +3737,17 @@ impl Http {
         }
 
         let resp: VotersResponse = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::ChannelPollGetAnswerVoters {
-                    channel_id,
-                    message_id,
-                    answer_id,
-                },
-                params: Some(params),
-            })
+            .fire(
+                Request::new(
+                    Route::ChannelPollGetAnswerVoters {
+                        channel_id,
+                        message_id,
+                        answer_id,
+                    },
+                    LightMethod::Get,
+                )
+                .params(Some(params)),
+            )
             .await?;
 
         Ok(resp.users)
@@ -3190,17 +3758,13 @@ impl Http {
         channel_id: ChannelId,
         message_id: MessageId,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::ChannelPollExpire {
+        self.fire(Request::new(
+            Route::ChannelPollExpire {
                 channel_id,
                 message_id,
             },
-            params: None,
-        })
+            LightMethod::Post,
+        ))
         .await
     }
 
@@ -3208,58 +3772,84 @@ impl Http {
     ///
     /// **Note**: Only applications may use this endpoint.
     pub async fn get_current_application_info(&self) -> Result<CurrentApplicationInfo> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Oauth2ApplicationCurrent,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::Oauth2ApplicationCurrent, LightMethod::Get)).await
+    }
+
+    /// Edits the current application.
+    ///
+    /// Refer to [`EditCurrentApplication`] for the fields which can be edited.
+    ///
+    /// **Note**: Only applications may use this endpoint.
+    ///
+    /// [`EditCurrentApplication`]: crate::builder::EditCurrentApplication
+    pub async fn edit_current_application(
+        &self,
+        map: &impl serde::Serialize,
+    ) -> Result<CurrentApplicationInfo> {
+        let body = to_vec(map)?;
+
+        self.fire(Request::new(Route::ApplicationMe, LightMethod::Patch).body(Some(body))).await
     }
 
     /// Gets information about the user we're connected with.
     pub async fn get_current_user(&self) -> Result<CurrentUser> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::UserMe,
-            params: None,
-        })
+        self.fire(Request::new(Route::UserMe, LightMethod::Get)).await
+    }
+
+    /// Gets all emojis owned by the current application.
+    pub async fn get_application_emojis(&self) -> Result<Vec<Emoji>> {
+        #[derive(Deserialize)]
+        struct ApplicationEmojis {
+            items: Vec<Emoji>,
+        }
+
+        self.fire(Request::new(
+            Route::ApplicationEmojis {
+                application_id: self.try_application_id()?,
+            },
+            LightMethod::Get,
+        ))
+        .await
+        .map(|emojis: ApplicationEmojis| emojis.items)
+    }
+
+    /// Gets an emoji owned by the current application.
+    pub async fn get_application_emoji(&self, emoji_id: EmojiId) -> Result<Emoji> {
+        self.fire(Request::new(
+            Route::ApplicationEmoji {
+                application_id: self.try_application_id()?,
+                emoji_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
+    /// Gets the default soundboard sounds, which can be played in every guild.
+    pub async fn get_default_soundboard_sounds(&self) -> Result<Vec<SoundboardSound>> {
+        self.fire(Request::new(Route::SoundboardDefaultSounds, LightMethod::Get)).await
+    }
+
     /// Gets all emojis of a guild.
     pub async fn get_emojis(&self, guild_id: GuildId) -> Result<Vec<Emoji>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildEmojis {
+        self.fire(Request::new(
+            Route::GuildEmojis {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets information about an emoji in a guild.
     pub async fn get_emoji(&self, guild_id: GuildId, emoji_id: EmojiId) -> Result<Emoji> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildEmoji {
+        self.fire(Request::new(
+            Route::GuildEmoji {
                 guild_id,
                 emoji_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3301,121 +3891,94 @@ impl Http {
             params.push(("exclude_ended", exclude_ended.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Entitlements {
-                application_id: self.try_application_id()?,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::Entitlements {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
     /// Gets current gateway.
     pub async fn get_gateway(&self) -> Result<Gateway> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Gateway,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::Gateway, LightMethod::Get)).await
     }
 
     /// Fetches all of the global commands for your application.
     pub async fn get_global_commands(&self) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Commands {
+        self.fire(Request::new(
+            Route::Commands {
                 application_id: self.try_application_id()?,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Fetches all of the global commands for your application with localizations.
     pub async fn get_global_commands_with_localizations(&self) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Commands {
-                application_id: self.try_application_id()?,
-            },
-            params: Some(vec![("with_localizations", true.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::Commands {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_localizations", true.to_string())])),
+        )
         .await
     }
 
     /// Fetches a global commands for your application by its Id.
     pub async fn get_global_command(&self, command_id: CommandId) -> Result<Command> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Command {
+        self.fire(Request::new(
+            Route::Command {
                 application_id: self.try_application_id()?,
                 command_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets guild information.
     pub async fn get_guild(&self, guild_id: GuildId) -> Result<PartialGuild> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Guild {
+        self.fire(Request::new(
+            Route::Guild {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets guild information with counts.
     pub async fn get_guild_with_counts(&self, guild_id: GuildId) -> Result<PartialGuild> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Guild {
-                guild_id,
-            },
-            params: Some(vec![("with_counts", true.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::Guild {
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_counts", true.to_string())])),
+        )
         .await
     }
 
     /// Fetches all of the guild commands for your application for a specific guild.
     pub async fn get_guild_commands(&self, guild_id: GuildId) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildCommands {
+        self.fire(Request::new(
+            Route::GuildCommands {
                 application_id: self.try_application_id()?,
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3425,17 +3988,16 @@ impl Http {
         &self,
         guild_id: GuildId,
     ) -> Result<Vec<Command>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildCommands {
-                application_id: self.try_application_id()?,
-                guild_id,
-            },
-            params: Some(vec![("with_localizations", true.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildCommands {
+                    application_id: self.try_application_id()?,
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_localizations", true.to_string())])),
+        )
         .await
     }
 
@@ -3445,18 +4007,14 @@ impl Http {
         guild_id: GuildId,
         command_id: CommandId,
     ) -> Result<Command> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildCommand {
+        self.fire(Request::new(
+            Route::GuildCommand {
                 application_id: self.try_application_id()?,
                 guild_id,
                 command_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3465,17 +4023,13 @@ impl Http {
         &self,
         guild_id: GuildId,
     ) -> Result<Vec<CommandPermissions>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildCommandsPermissions {
+        self.fire(Request::new(
+            Route::GuildCommandsPermissions {
                 application_id: self.try_application_id()?,
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3485,117 +4039,163 @@ impl Http {
         guild_id: GuildId,
         command_id: CommandId,
     ) -> Result<CommandPermissions> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildCommandPermissions {
+        self.fire(Request::new(
+            Route::GuildCommandPermissions {
                 application_id: self.try_application_id()?,
                 guild_id,
                 command_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
-    /// Gets a guild widget information.
-    // TODO: according to Discord, this returns different data; namely https://discord.com/developers/docs/resources/guild#guild-widget-object-guild-widget-structure.
-    // Should investigate if this endpoint actually works
+    /// Gets the widget settings of a guild.
     pub async fn get_guild_widget(&self, guild_id: GuildId) -> Result<GuildWidget> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildWidget {
+        self.fire(Request::new(
+            Route::GuildWidget {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the public data shown by a guild's widget.
+    ///
+    /// Does not require authentication, but the widget must be enabled.
+    pub async fn get_guild_widget_info(&self, guild_id: GuildId) -> Result<GuildWidgetInfo> {
+        self.fire(Request::new(
+            Route::GuildWidgetJson {
+                guild_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets a guild preview.
     pub async fn get_guild_preview(&self, guild_id: GuildId) -> Result<GuildPreview> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildPreview {
+        self.fire(Request::new(
+            Route::GuildPreview {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the onboarding of a guild.
+    pub async fn get_guild_onboarding(&self, guild_id: GuildId) -> Result<Onboarding> {
+        self.fire(Request::new(
+            Route::GuildOnboarding {
+                guild_id,
+            },
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the soundboard sounds of a guild.
+    pub async fn get_guild_soundboard_sounds(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<SoundboardSound>> {
+        #[derive(Deserialize)]
+        struct GuildSoundboardSounds {
+            items: Vec<SoundboardSound>,
+        }
+
+        self.fire(Request::new(
+            Route::GuildSoundboardSounds {
+                guild_id,
+            },
+            LightMethod::Get,
+        ))
+        .await
+        .map(|sounds: GuildSoundboardSounds| sounds.items)
+    }
+
+    /// Gets a soundboard sound of a guild.
+    pub async fn get_guild_soundboard_sound(
+        &self,
+        guild_id: GuildId,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request::new(
+            Route::GuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets a guild template by its code.
+    pub async fn get_guild_template(&self, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request::new(
+            Route::GuildsTemplate {
+                code,
+            },
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the templates of a guild.
+    pub async fn get_guild_templates(&self, guild_id: GuildId) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request::new(
+            Route::GuildTemplates {
+                guild_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets a guild welcome screen information.
     pub async fn get_guild_welcome_screen(&self, guild_id: GuildId) -> Result<GuildWelcomeScreen> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildWelcomeScreen {
+        self.fire(Request::new(
+            Route::GuildWelcomeScreen {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets integrations that a guild has.
     pub async fn get_guild_integrations(&self, guild_id: GuildId) -> Result<Vec<Integration>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildIntegrations {
+        self.fire(Request::new(
+            Route::GuildIntegrations {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets all invites to a guild.
     pub async fn get_guild_invites(&self, guild_id: GuildId) -> Result<Vec<RichInvite>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildInvites {
+        self.fire(Request::new(
+            Route::GuildInvites {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: GuildId) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
-
-        self.fire::<GuildVanityUrl>(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildVanityUrl {
+    pub async fn get_guild_vanity_url(&self, guild_id: GuildId) -> Result<GuildVanityUrl> {
+        self.fire(Request::new(
+            Route::GuildVanityUrl {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
-        .map(|x| x.code)
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the user to offset the
@@ -3619,16 +4219,15 @@ impl Http {
         }
 
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildMembers {
-                    guild_id,
-                },
-                params: Some(params),
-            })
+            .fire(
+                Request::new(
+                    Route::GuildMembers {
+                        guild_id,
+                    },
+                    LightMethod::Get,
+                )
+                .params(Some(params)),
+            )
             .await?;
 
         if let Some(values) = value.as_array_mut() {
@@ -3642,50 +4241,53 @@ impl Http {
         from_value(value).map_err(From::from)
     }
 
-    /// Gets the amount of users that can be pruned.
-    pub async fn get_guild_prune_count(&self, guild_id: GuildId, days: u8) -> Result<GuildPrune> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildPrune {
-                guild_id,
-            },
-            params: Some(vec![("days", days.to_string())]),
-        })
+    /// Gets the amount of users that can be pruned. Only users without roles are counted, unless
+    /// `include_roles` is non-empty.
+    pub async fn get_guild_prune_count(
+        &self,
+        guild_id: GuildId,
+        days: u8,
+        include_roles: &[RoleId],
+    ) -> Result<GuildPrune> {
+        let mut params = vec![("days", days.to_string())];
+        if !include_roles.is_empty() {
+            let roles = include_roles.iter().map(ToString::to_string).collect::<Vec<_>>();
+            params.push(("include_roles", roles.join(",")));
+        }
+
+        self.fire(
+            Request::new(
+                Route::GuildPrune {
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
     /// Gets regions that a guild can use. If a guild has the `VIP_REGIONS` feature enabled, then
     /// additional VIP-only regions are returned.
     pub async fn get_guild_regions(&self, guild_id: GuildId) -> Result<Vec<VoiceRegion>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildRegions {
+        self.fire(Request::new(
+            Route::GuildRegions {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Retrieves a list of roles in a [`Guild`].
     pub async fn get_guild_roles(&self, guild_id: GuildId) -> Result<Vec<Role>> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildRoles {
+            .fire(Request::new(
+                Route::GuildRoles {
                     guild_id,
                 },
-                params: None,
-            })
+                LightMethod::Get,
+            ))
             .await?;
 
         if let Some(array) = value.as_array_mut() {
@@ -3710,17 +4312,16 @@ impl Http {
         event_id: ScheduledEventId,
         with_user_count: bool,
     ) -> Result<ScheduledEvent> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildScheduledEvent {
-                guild_id,
-                event_id,
-            },
-            params: Some(vec![("with_user_count", with_user_count.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildScheduledEvent {
+                    guild_id,
+                    event_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_user_count", with_user_count.to_string())])),
+        )
         .await
     }
 
@@ -3734,16 +4335,15 @@ impl Http {
         guild_id: GuildId,
         with_user_count: bool,
     ) -> Result<Vec<ScheduledEvent>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildScheduledEvents {
-                guild_id,
-            },
-            params: Some(vec![("with_user_count", with_user_count.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildScheduledEvents {
+                    guild_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(vec![("with_user_count", with_user_count.to_string())])),
+        )
         .await
     }
 
@@ -3785,33 +4385,51 @@ impl Http {
             }
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildScheduledEventUsers {
+        self.fire(
+            Request::new(
+                Route::GuildScheduledEventUsers {
+                    guild_id,
+                    event_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
+        .await
+    }
+
+    /// Gets the boosts applied to a guild.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_guild_subscriptions(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Vec<GuildSubscription>> {
+        self.fire(Request::new(
+            Route::GuildPremiumSubscriptions {
                 guild_id,
-                event_id,
             },
-            params: Some(params),
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
+    /// Gets the boost slots of the current user, granted by their Nitro subscription.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_guild_subscription_slots(&self) -> Result<Vec<GuildSubscriptionSlot>> {
+        self.fire(Request::new(Route::UserMeGuildSubscriptionSlots, LightMethod::Get)).await
+    }
+
     /// Retrieves a list of stickers in a [`Guild`].
     pub async fn get_guild_stickers(&self, guild_id: GuildId) -> Result<Vec<Sticker>> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildStickers {
+            .fire(Request::new(
+                Route::GuildStickers {
                     guild_id,
                 },
-                params: None,
-            })
+                LightMethod::Get,
+            ))
             .await?;
 
         if let Some(array) = value.as_array_mut() {
@@ -3832,17 +4450,13 @@ impl Http {
         sticker_id: StickerId,
     ) -> Result<Sticker> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildSticker {
+            .fire(Request::new(
+                Route::GuildSticker {
                     guild_id,
                     sticker_id,
                 },
-                params: None,
-            })
+                LightMethod::Get,
+            ))
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -3873,16 +4487,12 @@ impl Http {
     /// # }
     /// ```
     pub async fn get_guild_webhooks(&self, guild_id: GuildId) -> Result<Vec<Webhook>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::GuildWebhooks {
+        self.fire(Request::new(
+            Route::GuildWebhooks {
                 guild_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -3928,15 +4538,7 @@ impl Http {
             }
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::UserMeGuilds,
-            params: Some(params),
-        })
-        .await
+        self.fire(Request::new(Route::UserMeGuilds, LightMethod::Get).params(Some(params))).await
     }
 
     /// Returns a guild [`Member`] object for the current user.
@@ -3970,16 +4572,12 @@ impl Http {
     /// [docs]: https://discord.com/developers/docs/resources/user#get-current-user-guild-member
     pub async fn get_current_user_guild_member(&self, guild_id: GuildId) -> Result<Member> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::UserMeGuildMember {
+            .fire(Request::new(
+                Route::UserMeGuildMember {
                     guild_id,
                 },
-                params: None,
-            })
+                LightMethod::Get,
+            ))
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -4018,33 +4616,28 @@ impl Http {
             params.push(("event_id", event_id.to_string()));
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Invite {
-                code,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::Invite {
+                    code,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
     /// Gets member of a guild.
     pub async fn get_member(&self, guild_id: GuildId, user_id: UserId) -> Result<Member> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildMember {
+            .fire(Request::new(
+                Route::GuildMember {
                     guild_id,
                     user_id,
                 },
-                params: None,
-            })
+                LightMethod::Get,
+            ))
             .await?;
 
         if let Some(map) = value.as_object_mut() {
@@ -4060,17 +4653,13 @@ impl Http {
         channel_id: ChannelId,
         message_id: MessageId,
     ) -> Result<Message> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelMessage {
+        self.fire(Request::new(
+            Route::ChannelMessage {
                 channel_id,
                 message_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -4093,16 +4682,15 @@ impl Http {
             }
         }
 
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelMessages {
-                channel_id,
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelMessages {
+                    channel_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
@@ -4113,30 +4701,30 @@ impl Http {
             sticker_packs: Vec<StickerPack>,
         }
 
-        self.fire::<StickerPacks>(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::StickerPacks,
-            params: None,
-        })
+        self.fire::<StickerPacks>(Request::new(Route::StickerPacks, LightMethod::Get))
+            .await
+            .map(|s| s.sticker_packs)
+    }
+
+    /// Gets a sticker pack available to nitro subscribers.
+    pub async fn get_sticker_pack(&self, sticker_pack_id: StickerPackId) -> Result<StickerPack> {
+        self.fire(Request::new(
+            Route::StickerPack {
+                sticker_pack_id,
+            },
+            LightMethod::Get,
+        ))
         .await
-        .map(|s| s.sticker_packs)
     }
 
     /// Gets all pins of a channel.
     pub async fn get_pins(&self, channel_id: ChannelId) -> Result<Vec<Message>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelPins {
+        self.fire(Request::new(
+            Route::ChannelPins {
                 channel_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -4149,55 +4737,202 @@ impl Http {
         limit: u8,
         after: Option<u64>,
     ) -> Result<Vec<User>> {
-        let mut params = vec![("limit", limit.to_string())];
+        self.get_reaction_users_by_type(
+            channel_id,
+            message_id,
+            reaction_type,
+            ReactionTypes::Normal,
+            limit,
+            after,
+        )
+        .await
+    }
+
+    /// Gets the users that have reacted to a message with the given emoji, only counting either
+    /// normal or super reactions.
+    pub async fn get_reaction_users_by_type(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        reaction_type: &ReactionType,
+        kind: ReactionTypes,
+        limit: u8,
+        after: Option<u64>,
+    ) -> Result<Vec<User>> {
+        let mut params = vec![("limit", limit.to_string()), ("type", u8::from(kind).to_string())];
         if let Some(after) = after {
             params.push(("after", after.to_string()));
         }
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::ChannelMessageReactionEmoji {
-                channel_id,
-                message_id,
-                reaction: &reaction_type.as_data(),
-            },
-            params: Some(params),
-        })
+        self.fire(
+            Request::new(
+                Route::ChannelMessageReactionEmoji {
+                    channel_id,
+                    message_id,
+                    reaction: &reaction_type.as_data(),
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
+    /// Gets the current user's relationships: friends, blocked users and pending friend requests.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn get_relationships(&self) -> Result<Vec<Relationship>> {
+        self.fire(Request::new(Route::UserMeRelationships, LightMethod::Get)).await
+    }
+
     /// Gets all SKUs for the current application.
     pub async fn get_skus(&self) -> Result<Vec<Sku>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Skus {
+        self.fire(Request::new(
+            Route::Skus {
                 application_id: self.try_application_id()?,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Gets the role connection metadata records of the current application.
+    pub async fn get_role_connection_metadata_records(
+        &self,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request::new(
+            Route::RoleConnectionMetadata {
+                application_id: self.try_application_id()?,
+            },
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Overrides all role connection metadata records of the current application.
+    pub async fn edit_role_connection_metadata_records(
+        &self,
+        map: &impl serde::Serialize,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(
+            Request::new(
+                Route::RoleConnectionMetadata {
+                    application_id: self.try_application_id()?,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
+        .await
+    }
+
+    /// Gets the role connection of the current user for an application.
+    ///
+    /// **Note**: Requires an OAuth2 access token with the [`Scope::RoleConnectionsWrite`] scope.
+    pub async fn get_user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+    ) -> Result<ApplicationRoleConnection> {
+        self.fire(Request::new(
+            Route::UserMeApplicationRoleConnection {
+                application_id,
+            },
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Updates the role connection of the current user for an application.
+    ///
+    /// **Note**: Requires an OAuth2 access token with the [`Scope::RoleConnectionsWrite`] scope.
+    pub async fn edit_user_application_role_connection(
+        &self,
+        application_id: ApplicationId,
+        map: &impl serde::Serialize,
+    ) -> Result<ApplicationRoleConnection> {
+        self.fire(
+            Request::new(
+                Route::UserMeApplicationRoleConnection {
+                    application_id,
+                },
+                LightMethod::Put,
+            )
+            .body(Some(to_vec(map)?)),
+        )
+        .await
+    }
+
+    /// Gets the subscriptions to a SKU of the current application.
+    ///
+    /// `user_id` is required unless the request is made with an OAuth2 token.
+    pub async fn get_sku_subscriptions(
+        &self,
+        sku_id: SkuId,
+        user_id: Option<UserId>,
+        before: Option<SubscriptionId>,
+        after: Option<SubscriptionId>,
+        limit: Option<u8>,
+    ) -> Result<Vec<Subscription>> {
+        let mut params = vec![];
+        if let Some(user_id) = user_id {
+            params.push(("user_id", user_id.to_string()));
+        }
+        if let Some(before) = before {
+            params.push(("before", before.to_string()));
+        }
+        if let Some(after) = after {
+            params.push(("after", after.to_string()));
+        }
+        if let Some(limit) = limit {
+            params.push(("limit", limit.to_string()));
+        }
+
+        self.fire(
+            Request::new(
+                Route::SkuSubscriptions {
+                    sku_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
+        .await
+    }
+
+    /// Gets a subscription to a SKU of the current application by its Id.
+    pub async fn get_sku_subscription(
+        &self,
+        sku_id: SkuId,
+        subscription_id: SubscriptionId,
+    ) -> Result<Subscription> {
+        self.fire(Request::new(
+            Route::SkuSubscription {
+                sku_id,
+                subscription_id,
+            },
+            LightMethod::Get,
+        ))
         .await
     }
 
     /// Gets a sticker.
     pub async fn get_sticker(&self, sticker_id: StickerId) -> Result<Sticker> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Sticker {
+        self.fire(Request::new(
+            Route::Sticker {
                 sticker_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
+    /// Gets the overall status of Discord, along with the unresolved incidents and the active and
+    /// upcoming maintenances, from Discord's Status API.
+    ///
+    /// Does not require authentication.
+    pub async fn get_status_summary(&self) -> Result<StatusSummary> {
+        self.fire(Request::new(Route::StatusSummary, LightMethod::Get)).await
+    }
+
     /// Gets the current unresolved incidents from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -4208,16 +4943,8 @@ impl Http {
             incidents: Vec<Incident>,
         }
 
-        let status: StatusResponse = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::StatusIncidentsUnresolved,
-                params: None,
-            })
-            .await?;
+        let status: StatusResponse =
+            self.fire(Request::new(Route::StatusIncidentsUnresolved, LightMethod::Get)).await?;
 
         Ok(status.incidents)
     }
@@ -4232,76 +4959,49 @@ impl Http {
             scheduled_maintenances: Vec<Maintenance>,
         }
 
-        let status: StatusResponse = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::StatusMaintenancesUpcoming,
-                params: None,
-            })
-            .await?;
+        let status: StatusResponse =
+            self.fire(Request::new(Route::StatusMaintenancesUpcoming, LightMethod::Get)).await?;
 
         Ok(status.scheduled_maintenances)
     }
 
     /// Gets a user by Id.
     pub async fn get_user(&self, user_id: UserId) -> Result<User> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::User {
+        self.fire(Request::new(
+            Route::User {
                 user_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
-    /// Gets the current user's third party connections.
+    /// Gets the current user's settings.
     ///
-    /// This method only works for user tokens with the [`Connections`] OAuth2 scope.
+    /// **Note**: Only works for user accounts.
+    pub async fn get_user_settings(&self) -> Result<UserSettings> {
+        self.fire(Request::new(Route::UserMeSettings, LightMethod::Get)).await
+    }
+
+    /// Gets the current user's third party connections, such as linked Steam, Spotify or Twitch
+    /// accounts.
+    ///
+    /// This method only works for user accounts, or for OAuth2 tokens with the [`Connections`]
+    /// scope.
     ///
     /// [`Connections`]: crate::model::application::Scope::Connections
     pub async fn get_user_connections(&self) -> Result<Vec<Connection>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::UserMeConnections,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::UserMeConnections, LightMethod::Get)).await
     }
 
     /// Gets our DM channels.
     pub async fn get_user_dm_channels(&self) -> Result<Vec<PrivateChannel>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::UserMeDmChannels,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::UserMeDmChannels, LightMethod::Get)).await
     }
 
     /// Gets all voice regions.
     pub async fn get_voice_regions(&self) -> Result<Vec<VoiceRegion>> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::VoiceRegions,
-            params: None,
-        })
-        .await
+        self.fire(Request::new(Route::VoiceRegions, LightMethod::Get)).await
     }
 
     /// Retrieves a webhook given its Id.
@@ -4325,16 +5025,12 @@ impl Http {
     /// # }
     /// ```
     pub async fn get_webhook(&self, webhook_id: WebhookId) -> Result<Webhook> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::Webhook {
+        self.fire(Request::new(
+            Route::Webhook {
                 webhook_id,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -4364,17 +5060,13 @@ impl Http {
         webhook_id: WebhookId,
         token: &str,
     ) -> Result<Webhook> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::WebhookWithToken {
+        self.fire(Request::new(
+            Route::WebhookWithToken {
                 webhook_id,
                 token,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
         .await
     }
 
@@ -4401,17 +5093,31 @@ impl Http {
         let url = Url::parse(url).map_err(HttpError::Url)?;
         let (webhook_id, token) =
             crate::utils::parse_webhook(&url).ok_or(HttpError::InvalidWebhook)?;
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Get,
-            route: Route::WebhookWithToken {
+        self.fire(Request::new(
+            Route::WebhookWithToken {
                 webhook_id,
                 token,
             },
-            params: None,
-        })
+            LightMethod::Get,
+        ))
+        .await
+    }
+
+    /// Joins the guild of an invite, returning the invite.
+    ///
+    /// **Note**: Only user accounts can join guilds via invites. Discord frequently requires a
+    /// captcha to be solved first, in which case this fails with an [`Error::Captcha`]. See
+    /// [`Invite::accept_with_captcha`] for retrying the request with a solution.
+    pub async fn join_guild_by_invite(&self, code: &str) -> Result<Invite> {
+        self.fire(
+            Request::new(
+                Route::Invite {
+                    code,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(&json!({}))?)),
+        )
         .await
     }
 
@@ -4422,32 +5128,95 @@ impl Http {
         user_id: UserId,
         reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildMember {
-                guild_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildMember {
+                    guild_id,
+                    user_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(reason.map(reason_into_header)),
+        )
         .await
     }
 
     /// Leaves a guild.
     pub async fn leave_guild(&self, guild_id: GuildId) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Delete,
-            route: Route::UserMeGuild {
-                guild_id,
-            },
-            params: None,
-        })
+        self.leave_guild_lurking(guild_id, false).await
+    }
+
+    /// Leaves a guild, setting whether the current user was only lurking in it, i.e. previewing
+    /// it without having joined.
+    ///
+    /// **Note**: The `lurking` flag is only meaningful for user accounts.
+    pub async fn leave_guild_lurking(&self, guild_id: GuildId, lurking: bool) -> Result<()> {
+        let body = to_vec(&json!({
+            "lurking": lurking,
+        }))?;
+
+        self.wind(
+            204,
+            Request::new(
+                Route::UserMeGuild {
+                    guild_id,
+                },
+                LightMethod::Delete,
+            )
+            .body(Some(body)),
+        )
+        .await
+    }
+
+    /// Leaves the current HypeSquad house.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn leave_hypesquad(&self) -> Result<()> {
+        self.wind(204, Request::new(Route::HypesquadOnline, LightMethod::Delete)).await
+    }
+
+    /// Logs out the given devices of the current user, identified by [`AuthSession::id_hash`].
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn logout_sessions(&self, id_hashes: &[&str]) -> Result<()> {
+        let body = to_vec(&json!({
+            "session_id_hashes": id_hashes,
+        }))?;
+
+        self.wind(204, Request::new(Route::AuthSessionsLogout, LightMethod::Post).body(Some(body)))
+            .await
+    }
+
+    /// Sends a friend request to a user by their username.
+    ///
+    /// The map must contain the `username` of the user, and a `discriminator` of `null` for users
+    /// that migrated to unique usernames.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn send_friend_request(&self, map: &Value) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(204, Request::new(Route::UserMeRelationships, LightMethod::Post).body(Some(body)))
+            .await
+    }
+
+    /// Plays a soundboard sound in a voice channel the current user is connected to.
+    pub async fn send_soundboard_sound(
+        &self,
+        channel_id: ChannelId,
+        map: &impl serde::Serialize,
+    ) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelSendSoundboardSound {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(to_vec(map)?)),
+        )
         .await
     }
 
@@ -4462,16 +5231,12 @@ impl Http {
         files: Vec<CreateAttachment>,
         map: &impl serde::Serialize,
     ) -> Result<Message> {
-        let mut request = Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::ChannelMessages {
+        let mut request = Request::new(
+            Route::ChannelMessages {
                 channel_id,
             },
-            params: None,
-        };
+            LightMethod::Post,
+        );
 
         if files.is_empty() {
             request.body = Some(to_vec(map)?);
@@ -4493,17 +5258,17 @@ impl Http {
         message_id: MessageId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Put,
-            route: Route::ChannelPin {
-                channel_id,
-                message_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelPin {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Put,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -4514,17 +5279,17 @@ impl Http {
         user_id: UserId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildBan {
-                guild_id,
-                user_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildBan {
+                    guild_id,
+                    user_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -4540,18 +5305,91 @@ impl Http {
         role_id: RoleId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::GuildMemberRole {
-                guild_id,
-                user_id,
-                role_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildMemberRole {
+                    guild_id,
+                    user_id,
+                    role_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+    }
+
+    /// Removes the current user's [`Relationship`] with a user.
+    ///
+    /// Depending on the relationship, this removes a friend, ignores or cancels a friend request,
+    /// or unblocks the user.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn remove_relationship(&self, user_id: UserId) -> Result<()> {
+        self.wind(
+            204,
+            Request::new(
+                Route::UserMeRelationship {
+                    user_id,
+                },
+                LightMethod::Delete,
+            ),
+        )
+        .await
+    }
+
+    /// Rings recipients of a private channel to start or join a call.
+    ///
+    /// The map may contain a list of `recipients` to ring, or `null` to ring all recipients.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn ring_call(&self, channel_id: ChannelId, map: &Value) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelCallRing {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body)),
+        )
+        .await
+    }
+
+    /// Searches the chat input commands which the current user can use in a channel, optionally
+    /// filtered by a query matching the start of the command names.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn search_application_commands(
+        &self,
+        channel_id: ChannelId,
+        query: Option<&str>,
+        limit: Option<u8>,
+    ) -> Result<CommandSearch> {
+        let mut params = vec![
+            ("type", u8::from(CommandType::ChatInput).to_string()),
+            ("include_applications", true.to_string()),
+        ];
+        if let Some(query) = query {
+            params.push(("query", query.to_string()));
+        }
+        if let Some(limit) = limit {
+            params.push(("limit", limit.to_string()));
+        }
+
+        self.fire(
+            Request::new(
+                Route::ChannelApplicationCommandsSearch {
+                    channel_id,
+                },
+                LightMethod::Get,
+            )
+            .params(Some(params)),
+        )
         .await
     }
 
@@ -4564,19 +5402,18 @@ impl Http {
         limit: Option<u64>,
     ) -> Result<Vec<Member>> {
         let mut value: Value = self
-            .fire(Request {
-                body: None,
-                multipart: None,
-                headers: None,
-                method: LightMethod::Get,
-                route: Route::GuildMembersSearch {
-                    guild_id,
-                },
-                params: Some(vec![
+            .fire(
+                Request::new(
+                    Route::GuildMembersSearch {
+                        guild_id,
+                    },
+                    LightMethod::Get,
+                )
+                .params(Some(vec![
                     ("query", query.to_string()),
                     ("limit", limit.unwrap_or(constants::MEMBER_FETCH_LIMIT).to_string()),
-                ]),
-            })
+                ])),
+            )
             .await?;
 
         if let Some(members) = value.as_array_mut() {
@@ -4590,6 +5427,56 @@ impl Http {
         from_value(value).map_err(From::from)
     }
 
+    /// Syncs a guild template to the current state of its guild.
+    pub async fn sync_guild_template(
+        &self,
+        guild_id: GuildId,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.fire(Request::new(
+            Route::GuildTemplate {
+                guild_id,
+                code,
+            },
+            LightMethod::Put,
+        ))
+        .await
+    }
+
+    /// Begins removing members from a guild based on the last time they've been online.
+    ///
+    /// Returns [`None`] if the body disabled `compute_prune_count`.
+    pub async fn begin_guild_prune(
+        &self,
+        guild_id: GuildId,
+        map: &impl serde::Serialize,
+        audit_log_reason: Option<&str>,
+    ) -> Result<Option<GuildPrune>> {
+        #[derive(Deserialize)]
+        struct BeginGuildPrune {
+            pruned: Option<u64>,
+        }
+
+        let body = to_vec(map)?;
+
+        self.fire::<BeginGuildPrune>(
+            Request::new(
+                Route::GuildPrune {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body))
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
+        .await
+        .map(|x| {
+            x.pruned.map(|pruned| GuildPrune {
+                pruned,
+            })
+        })
+    }
+
     /// Starts removing some members from a guild based on the last time they've been online.
     pub async fn start_guild_prune(
         &self,
@@ -4597,16 +5484,16 @@ impl Http {
         days: u8,
         audit_log_reason: Option<&str>,
     ) -> Result<GuildPrune> {
-        self.fire(Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Post,
-            route: Route::GuildPrune {
-                guild_id,
-            },
-            params: Some(vec![("days", days.to_string())]),
-        })
+        self.fire(
+            Request::new(
+                Route::GuildPrune {
+                    guild_id,
+                },
+                LightMethod::Post,
+            )
+            .headers(audit_log_reason.map(reason_into_header))
+            .params(Some(vec![("days", days.to_string())])),
+        )
         .await
     }
 
@@ -4616,17 +5503,37 @@ impl Http {
         guild_id: GuildId,
         integration_id: IntegrationId,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: None,
-            method: LightMethod::Post,
-            route: Route::GuildIntegrationSync {
-                guild_id,
-                integration_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::GuildIntegrationSync {
+                    guild_id,
+                    integration_id,
+                },
+                LightMethod::Post,
+            ),
+        )
+        .await
+    }
+
+    /// Stops ringing recipients of a call in a private channel, e.g. to decline an incoming call.
+    ///
+    /// The map may contain a list of `recipients` to stop ringing, or `null` for all recipients.
+    ///
+    /// **Note**: Only works for user accounts.
+    pub async fn stop_ringing_call(&self, channel_id: ChannelId, map: &Value) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelCallStopRinging {
+                    channel_id,
+                },
+                LightMethod::Post,
+            )
+            .body(Some(body)),
+        )
         .await
     }
 
@@ -4677,17 +5584,17 @@ impl Http {
         message_id: MessageId,
         audit_log_reason: Option<&str>,
     ) -> Result<()> {
-        self.wind(204, Request {
-            body: None,
-            multipart: None,
-            headers: audit_log_reason.map(reason_into_header),
-            method: LightMethod::Delete,
-            route: Route::ChannelPin {
-                channel_id,
-                message_id,
-            },
-            params: None,
-        })
+        self.wind(
+            204,
+            Request::new(
+                Route::ChannelPin {
+                    channel_id,
+                    message_id,
+                },
+                LightMethod::Delete,
+            )
+            .headers(audit_log_reason.map(reason_into_header)),
+        )
         .await
     }
 
@@ -4766,23 +5673,177 @@ impl Http {
     /// ```
     #[instrument]
     pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        if self.retry_policy.get_max_attempts() <= 1 {
+            return self.request_once(req).await;
+        }
+
+        let mut retry = 0;
+        loop {
+            let result = self.request_once(req.clone()).await;
+            retry += 1;
+
+            match result {
+                Err(why) if why.is_retryable() => match self.retry_policy.delay(retry) {
+                    Some(delay) => {
+                        debug!("Retrying request in {delay:?} after transient error: {why}");
+                        tokio::time::sleep(delay).await;
+                    },
+                    None => return Err(why),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    async fn request_once(&self, req: Request<'_>) -> Result<ReqwestResponse> {
         let method = req.method.reqwest_method();
-        let response = if let Some(ratelimiter) = &self.ratelimiter {
-            ratelimiter.perform(req).await?
-        } else {
-            let request = req.build(&self.client, self.token(), self.proxy.as_deref())?.build()?;
-            self.client.execute(request).await?
-        };
+        let response = self.send(req).await?;
 
         if response.status().is_success() {
             Ok(response)
         } else {
-            Err(Error::Http(HttpError::UnsuccessfulRequest(
-                ErrorResponse::from_response(response, method).await,
-            )))
+            Err(error_from_response(response, method).await)
+        }
+    }
+
+    async fn send(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+        req.api_version.get_or_insert(self.api_version);
+        if req.multipart.is_some() && req.timeout.is_none() {
+            req.timeout = self.upload_timeout;
+        }
+        if let Some(client_headers) = &self.client_headers {
+            let headers = req.headers.get_or_insert_with(Headers::new);
+            for (name, value) in client_headers {
+                headers.entry(name).or_insert_with(|| value.clone());
+            }
+        }
+
+        if let Some(ratelimiter) = &self.ratelimiter {
+            ratelimiter.perform(req).await
+        } else {
+            let token = if self.send_authorization { self.token() } else { "" };
+            let request = req.build(&self.client, token, self.proxy.as_deref())?.build()?;
+            Ok(self.client.execute(request).await?)
         }
     }
 
+    /// Performs a request to any endpoint of the Discord API and returns the status, headers and
+    /// body of the response without interpreting them.
+    ///
+    /// This is an escape hatch for endpoints the library does not model yet. Unlike
+    /// [`Self::request`], an unsuccessful status code is not turned into an error, and the
+    /// [`RetryPolicy`] is not applied. The request is still ratelimited per method and path: use
+    /// [`Route::CustomWithMajorId`] to also ratelimit it per a major parameter which is not a
+    /// channel, guild or webhook Id in the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// use std::num::NonZeroU64;
+    ///
+    /// use serenity::http::{LightMethod, Request, Route};
+    ///
+    /// let channel_id = NonZeroU64::new(381880193700069377).unwrap();
+    /// let path = format!("/channels/{channel_id}/some-new-endpoint");
+    /// let route = Route::CustomWithMajorId {
+    ///     path: &path,
+    ///     major_id: channel_id,
+    /// };
+    ///
+    /// let response = http.request_raw(Request::new(route, LightMethod::Get)).await?;
+    /// println!("{}: {}", response.status, String::from_utf8_lossy(&response.body));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the request could not be sent or its response could not be
+    /// read.
+    pub async fn request_raw(&self, req: Request<'_>) -> Result<RawResponse> {
+        let response = self.send(req).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Performs a request to any endpoint of the Discord API and deserializes the response into
+    /// the given type.
+    ///
+    /// This is an escape hatch for endpoints the library does not wrap yet. The request is
+    /// ratelimited and its errors are mapped like those of any other method. Endpoints without a
+    /// dedicated [`Route`] can be reached via [`Route::Custom`].
+    ///
+    /// The body is sent as JSON, or as the `payload_json` of a multipart form if any files are
+    /// given. Responses without content deserialize from `null`, so `()` can be used for them.
+    ///
+    /// # Examples
+    ///
+    /// Fetching the role connection metadata records of an application:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run(http: &Http) -> Result<(), serenity::Error> {
+    /// use serenity::http::{LightMethod, Route};
+    /// use serenity::json::Value;
+    ///
+    /// let path = "/applications/381880193700069377/role-connections/metadata";
+    /// let records: Vec<Value> = http
+    ///     .request_json(
+    ///         LightMethod::Get,
+    ///         Route::Custom {
+    ///             path,
+    ///         },
+    ///         None::<&()>,
+    ///         vec![],
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the request fails or Discord returns an error, or an
+    /// [`Error::Json`] if the body cannot be serialized or the response cannot be deserialized.
+    pub async fn request_json<T: DeserializeOwned>(
+        &self,
+        method: LightMethod,
+        route: Route<'_>,
+        body: Option<&impl serde::Serialize>,
+        files: Vec<CreateAttachment>,
+        audit_log_reason: Option<&str>,
+    ) -> Result<T> {
+        let mut request =
+            Request::new(route, method).headers(audit_log_reason.map(reason_into_header));
+
+        if !files.is_empty() {
+            request.multipart = Some(Multipart {
+                upload: MultipartUpload::Attachments(files),
+                payload_json: body.map(to_string).transpose()?,
+                fields: vec![],
+            });
+        } else if let Some(body) = body {
+            request.body = Some(to_vec(body)?);
+        }
+
+        let response = self.request(request).await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return from_value(NULL);
+        }
+
+        decode_resp(response).await
+    }
+
     /// Performs a request and then verifies that the response status code is equal to the expected
     /// value.
     ///
@@ -4799,9 +5860,7 @@ impl Http {
         debug!("Expected {}, got {}", expected, response.status());
         trace!("Unsuccessful response: {:?}", response);
 
-        Err(Error::Http(HttpError::UnsuccessfulRequest(
-            ErrorResponse::from_response(response, method).await,
-        )))
+        Err(error_from_response(response, method).await)
     }
 }
 
@@ -6,6 +6,7 @@ use reqwest::{Error as ReqwestError, Method, Response, StatusCode};
 use serde::de::{Deserialize, Deserializer, Error as _};
 use url::ParseError as UrlError;
 
+use super::captcha::CaptchaResponse;
 use crate::internal::prelude::*;
 use crate::json::*;
 
@@ -21,6 +22,136 @@ pub struct DiscordJsonError {
     pub errors: Vec<DiscordJsonSingleError>,
 }
 
+impl DiscordJsonError {
+    /// The typed error code, or `None` if the error response could not be decoded.
+    #[must_use]
+    pub fn error_code(&self) -> Option<JsonErrorCode> {
+        u32::try_from(self.code).ok().map(JsonErrorCode::from)
+    }
+}
+
+enum_number! {
+    /// An error code returned by Discord in the body of an unsuccessful response.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes).
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[non_exhaustive]
+    pub enum JsonErrorCode {
+        /// General error, such as a malformed request body.
+        General = 0,
+        UnknownAccount = 10001,
+        UnknownApplication = 10002,
+        UnknownChannel = 10003,
+        UnknownGuild = 10004,
+        UnknownIntegration = 10005,
+        UnknownInvite = 10006,
+        UnknownMember = 10007,
+        UnknownMessage = 10008,
+        UnknownPermissionOverwrite = 10009,
+        UnknownProvider = 10010,
+        UnknownRole = 10011,
+        UnknownToken = 10012,
+        UnknownUser = 10013,
+        UnknownEmoji = 10014,
+        UnknownWebhook = 10015,
+        UnknownWebhookService = 10016,
+        UnknownSession = 10020,
+        UnknownBan = 10026,
+        UnknownSku = 10027,
+        UnknownStoreListing = 10028,
+        UnknownEntitlement = 10029,
+        UnknownGuildTemplate = 10057,
+        UnknownDiscoveryCategory = 10059,
+        UnknownSticker = 10060,
+        UnknownInteraction = 10062,
+        UnknownApplicationCommand = 10063,
+        UnknownVoiceState = 10065,
+        UnknownApplicationCommandPermissions = 10066,
+        UnknownStageInstance = 10067,
+        UnknownScheduledEvent = 10070,
+        UnknownScheduledEventUser = 10071,
+        BotsCannotUseEndpoint = 20001,
+        OnlyBotsCanUseEndpoint = 20002,
+        ExplicitContentCannotBeSent = 20009,
+        NotAuthorizedForApplication = 20012,
+        SlowmodeRateLimited = 20016,
+        OnlyOwnerCanPerformAction = 20018,
+        AnnouncementRateLimited = 20022,
+        UnderMinimumAge = 20024,
+        ChannelWriteRateLimited = 20028,
+        GuildWriteRateLimited = 20029,
+        DisallowedWords = 20031,
+        GuildPremiumTooLow = 20035,
+        MaximumGuilds = 30001,
+        MaximumFriends = 30002,
+        MaximumPins = 30003,
+        MaximumRecipients = 30004,
+        MaximumRoles = 30005,
+        MaximumWebhooks = 30007,
+        MaximumEmojis = 30008,
+        MaximumReactions = 30010,
+        MaximumChannels = 30013,
+        MaximumAttachments = 30015,
+        MaximumInvites = 30016,
+        Unauthorized = 40001,
+        AccountVerificationRequired = 40002,
+        OpeningDirectMessagesTooFast = 40003,
+        SendMessagesTemporarilyDisabled = 40004,
+        RequestEntityTooLarge = 40005,
+        FeatureTemporarilyDisabled = 40006,
+        UserBannedFromGuild = 40007,
+        ConnectionRevoked = 40012,
+        TargetUserNotInVoice = 40032,
+        MessageAlreadyCrossposted = 40033,
+        ApplicationCommandNameExists = 40041,
+        InteractionAlreadyAcknowledged = 40060,
+        MissingAccess = 50001,
+        InvalidAccountType = 50002,
+        CannotExecuteOnDmChannel = 50003,
+        GuildWidgetDisabled = 50004,
+        CannotEditOtherUsersMessage = 50005,
+        CannotSendEmptyMessage = 50006,
+        CannotSendMessagesToUser = 50007,
+        CannotSendMessagesInNonTextChannel = 50008,
+        ChannelVerificationLevelTooHigh = 50009,
+        OAuth2ApplicationWithoutBot = 50010,
+        OAuth2ApplicationLimitReached = 50011,
+        InvalidOAuth2State = 50012,
+        MissingPermissions = 50013,
+        InvalidAuthenticationToken = 50014,
+        NoteTooLong = 50015,
+        InvalidBulkDeleteCount = 50016,
+        InvalidMfaLevel = 50017,
+        CannotPinInOtherChannel = 50019,
+        InvalidInviteCode = 50020,
+        CannotExecuteOnSystemMessage = 50021,
+        CannotExecuteOnChannelType = 50024,
+        InvalidOAuth2AccessToken = 50025,
+        MissingOAuth2Scope = 50026,
+        InvalidWebhookToken = 50027,
+        InvalidRole = 50028,
+        InvalidRecipients = 50033,
+        MessageTooOldToBulkDelete = 50034,
+        InvalidFormBody = 50035,
+        InviteAcceptedWithoutBot = 50036,
+        InvalidApiVersion = 50041,
+        FileTooLarge = 50045,
+        InvalidFileUploaded = 50046,
+        CannotSelfRedeemGift = 50054,
+        InvalidGuild = 50055,
+        TwoFactorRequired = 60003,
+        NoUsersWithDiscordTag = 80004,
+        ReactionBlocked = 90001,
+        ResourceOverloaded = 130000,
+        StageAlreadyOpen = 150006,
+        ThreadAlreadyCreated = 160004,
+        ThreadLocked = 160005,
+        MaximumActiveThreads = 160006,
+        MaximumActiveAnnouncementThreads = 160007,
+        _ => Unknown(u32),
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct DiscordJsonSingleError {
     /// The error code.
@@ -43,11 +174,27 @@ pub struct ErrorResponse {
 impl ErrorResponse {
     // We need a freestanding from-function since we cannot implement an async From-trait.
     pub async fn from_response(r: Response, method: Method) -> Self {
+        let status_code = r.status();
+        let url = r.url().to_string();
+        let body = r.bytes().await;
+        Self::from_body(status_code, url, method, body.as_deref())
+    }
+
+    fn from_body(
+        status_code: StatusCode,
+        url: String,
+        method: Method,
+        body: StdResult<&[u8], &ReqwestError>,
+    ) -> Self {
         ErrorResponse {
-            status_code: r.status(),
-            url: r.url().to_string(),
+            status_code,
+            url,
             method,
-            error: decode_resp(r).await.unwrap_or_else(|e| DiscordJsonError {
+            error: match body {
+                Ok(body) => from_slice(body).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            }
+            .unwrap_or_else(|e| DiscordJsonError {
                 code: -1,
                 message: format!("[Serenity] Could not decode json when receiving error response from discord:, {e}"),
                 errors: vec![],
@@ -56,6 +203,28 @@ impl ErrorResponse {
     }
 }
 
+/// Turns an unsuccessful response into an [`Error::Captcha`] if Discord requires a captcha to be
+/// solved, or into an [`HttpError::UnsuccessfulRequest`] otherwise.
+pub(super) async fn error_from_response(r: Response, method: Method) -> Error {
+    let status_code = r.status();
+    let url = r.url().to_string();
+    let body = r.bytes().await;
+
+    if status_code == StatusCode::BAD_REQUEST {
+        if let Ok(Ok(captcha)) = body.as_deref().map(from_slice::<CaptchaResponse>) {
+            return captcha.into();
+        }
+    }
+
+    HttpError::UnsuccessfulRequest(ErrorResponse::from_body(
+        status_code,
+        url,
+        method,
+        body.as_deref(),
+    ))
+    .into()
+}
+
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum HttpError {
@@ -109,6 +278,25 @@ impl HttpError {
             _ => None,
         }
     }
+
+    /// Returns Discord's error code if the error is an unsuccessful request.
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        match self {
+            Self::UnsuccessfulRequest(res) => res.error.error_code(),
+            _ => None,
+        }
+    }
+
+    /// Returns the validation errors of the individual fields of the request body if the error is
+    /// an unsuccessful request, such as one with [`JsonErrorCode::InvalidFormBody`].
+    #[must_use]
+    pub fn field_errors(&self) -> &[DiscordJsonSingleError] {
+        match self {
+            Self::UnsuccessfulRequest(res) => &res.error.errors,
+            _ => &[],
+        }
+    }
 }
 
 impl From<ErrorResponse> for HttpError {
@@ -176,6 +364,7 @@ impl StdError for HttpError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Self::Url(inner) => Some(inner),
+            Self::InvalidHeader(inner) => Some(inner),
             Self::Request(inner) => Some(inner),
             _ => None,
         }
@@ -278,4 +467,32 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn test_error_code_and_field_errors() {
+        let error: DiscordJsonError = from_value(json!({
+            "code": 50035,
+            "message": "Invalid Form Body",
+            "errors": {
+                "embeds": {"0": {"title": {"_errors": [
+                    {"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long"},
+                ]}}},
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(error.error_code(), Some(JsonErrorCode::InvalidFormBody));
+        assert_eq!(error.errors[0].path, "embeds.0.title");
+        assert_eq!(error.errors[0].code, "BASE_TYPE_MAX_LENGTH");
+
+        assert_eq!(JsonErrorCode::from(12345), JsonErrorCode::Unknown(12345));
+        assert_eq!(
+            DiscordJsonError {
+                code: -1,
+                ..error
+            }
+            .error_code(),
+            None
+        );
+    }
 }
@@ -19,11 +19,14 @@
 //! [`Client`]: crate::Client
 //! [model]: crate::model
 
+mod captcha;
 mod client;
 mod error;
 mod multipart;
+mod properties;
 mod ratelimiting;
 mod request;
+mod retry;
 mod routing;
 mod typing;
 
@@ -32,11 +35,14 @@ use std::sync::Arc;
 use reqwest::Method;
 pub use reqwest::StatusCode;
 
+pub use self::captcha::CaptchaSolution;
 pub use self::client::*;
 pub use self::error::*;
 pub use self::multipart::*;
+pub use self::properties::*;
 pub use self::ratelimiting::*;
 pub use self::request::*;
+pub use self::retry::*;
 pub use self::routing::*;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
@@ -165,6 +171,31 @@ impl LightMethod {
     }
 }
 
+/// The version of the Discord REST API to send requests to.
+///
+/// The library is tested against [`ApiVersion::V10`], which is the default. Some endpoints
+/// available to user accounts behave differently across versions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// Version 9 of the API.
+    V9,
+    /// Version 10 of the API.
+    #[default]
+    V10,
+}
+
+impl ApiVersion {
+    /// The number of the version, as used in the base path of the API.
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::V9 => 9,
+            Self::V10 => 10,
+        }
+    }
+}
+
 /// Representation of the method of a query to send for the [`Http::get_guilds`] function.
 #[non_exhaustive]
 pub enum GuildPagination {
@@ -0,0 +1,211 @@
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use reqwest::header::{HeaderMap as Headers, HeaderName, HeaderValue, USER_AGENT};
+
+use crate::internal::prelude::*;
+use crate::json::{to_string, Value};
+
+/// The properties of the client, sent to Discord along with every HTTP API request when set via
+/// [`HttpBuilder::client_properties`].
+///
+/// Official clients describe themselves in the `X-Super-Properties` header, their language in the
+/// `X-Discord-Locale` header and, for some actions, where in the interface the action was taken in
+/// the `X-Context-Properties` header. The `User-Agent` header is set to the
+/// [`Self::browser_user_agent`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::http::{ClientProperties, HttpBuilder};
+///
+/// let properties = ClientProperties::new(262355).system_locale("de").locale("de");
+/// let http = HttpBuilder::new("token").client_properties(properties).build();
+/// ```
+///
+/// [`HttpBuilder::client_properties`]: super::HttpBuilder::client_properties
+#[derive(Clone, Debug, Serialize)]
+#[must_use]
+pub struct ClientProperties {
+    os: String,
+    browser: String,
+    device: String,
+    system_locale: String,
+    browser_user_agent: String,
+    browser_version: String,
+    os_version: String,
+    referrer: String,
+    referring_domain: String,
+    release_channel: String,
+    client_build_number: u32,
+    client_event_source: Option<String>,
+    #[serde(skip)]
+    locale: Option<String>,
+    #[serde(skip)]
+    context_properties: Option<Value>,
+}
+
+impl ClientProperties {
+    /// Creates properties describing the stable desktop web client on Windows, with the given
+    /// build number of the client.
+    pub fn new(client_build_number: u32) -> Self {
+        Self {
+            os: "Windows".into(),
+            browser: "Chrome".into(),
+            device: String::new(),
+            system_locale: "en-US".into(),
+            browser_user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+                                 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"
+                .into(),
+            browser_version: "120.0.0.0".into(),
+            os_version: "10".into(),
+            referrer: String::new(),
+            referring_domain: String::new(),
+            release_channel: "stable".into(),
+            client_build_number,
+            client_event_source: None,
+            locale: None,
+            context_properties: None,
+        }
+    }
+
+    /// Sets the name of the operating system, e.g. `"Windows"` or `"Mac OS X"`.
+    pub fn os(mut self, os: impl Into<String>) -> Self {
+        self.os = os.into();
+        self
+    }
+
+    /// Sets the version of the operating system, e.g. `"10"`.
+    pub fn os_version(mut self, os_version: impl Into<String>) -> Self {
+        self.os_version = os_version.into();
+        self
+    }
+
+    /// Sets the name of the browser, e.g. `"Chrome"` or `"Discord Client"` for the desktop app.
+    pub fn browser(mut self, browser: impl Into<String>) -> Self {
+        self.browser = browser.into();
+        self
+    }
+
+    /// Sets the version of the browser.
+    pub fn browser_version(mut self, browser_version: impl Into<String>) -> Self {
+        self.browser_version = browser_version.into();
+        self
+    }
+
+    /// Sets the user agent of the browser, which is also sent as the `User-Agent` header.
+    pub fn browser_user_agent(mut self, browser_user_agent: impl Into<String>) -> Self {
+        self.browser_user_agent = browser_user_agent.into();
+        self
+    }
+
+    /// Sets the name of the device, which is empty for desktop clients.
+    pub fn device(mut self, device: impl Into<String>) -> Self {
+        self.device = device.into();
+        self
+    }
+
+    /// Sets the locale of the operating system, e.g. `"en-US"`.
+    pub fn system_locale(mut self, system_locale: impl Into<String>) -> Self {
+        self.system_locale = system_locale.into();
+        self
+    }
+
+    /// Sets the release channel of the client, e.g. `"stable"`, `"ptb"` or `"canary"`.
+    pub fn release_channel(mut self, release_channel: impl Into<String>) -> Self {
+        self.release_channel = release_channel.into();
+        self
+    }
+
+    /// Sets the page the client was referred from, and its domain.
+    pub fn referrer(mut self, referrer: impl Into<String>, domain: impl Into<String>) -> Self {
+        self.referrer = referrer.into();
+        self.referring_domain = domain.into();
+        self
+    }
+
+    /// Sets the language of the client, sent as the `X-Discord-Locale` header, e.g. `"en-US"`.
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Sets the context sent as the `X-Context-Properties` header, e.g.
+    /// `json!({"location": "Add Friend"})`.
+    ///
+    /// As the context depends on the action, prefer setting it on individual requests via
+    /// [`Request::headers`] with [`Self::context_header`].
+    ///
+    /// [`Request::headers`]: super::Request::headers
+    pub fn context_properties(mut self, context_properties: Value) -> Self {
+        self.context_properties = Some(context_properties);
+        self
+    }
+
+    /// Encodes a context as the `X-Context-Properties` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if the context can't be serialized.
+    pub fn context_header(context_properties: &Value) -> Result<(HeaderName, HeaderValue)> {
+        let value = encode(&to_string(context_properties)?);
+        Ok((HeaderName::from_static("x-context-properties"), value))
+    }
+
+    /// The headers to attach to every request.
+    pub(super) fn headers(&self) -> Headers {
+        let json = to_string(self).expect("client properties are always serializable");
+
+        let mut headers = Headers::new();
+        headers.insert(HeaderName::from_static("x-super-properties"), encode(&json));
+
+        if let Ok(user_agent) = HeaderValue::from_str(&self.browser_user_agent) {
+            headers.insert(USER_AGENT, user_agent);
+        }
+
+        if let Some(locale) = self.locale.as_deref().and_then(|l| HeaderValue::from_str(l).ok()) {
+            headers.insert(HeaderName::from_static("x-discord-locale"), locale);
+        }
+
+        if let Some((name, value)) =
+            self.context_properties.as_ref().and_then(|c| Self::context_header(c).ok())
+        {
+            headers.insert(name, value);
+        }
+
+        headers
+    }
+}
+
+fn encode(json: &str) -> HeaderValue {
+    HeaderValue::try_from(BASE64_STANDARD.encode(json))
+        .expect("base64 is always a valid header value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_slice, json};
+
+    #[test]
+    fn headers() {
+        #[derive(Deserialize)]
+        struct SuperProperties {
+            os: String,
+            client_build_number: u32,
+        }
+
+        let properties = ClientProperties::new(1).locale("fr").context_properties(json!({
+            "location": "Add Friend",
+        }));
+        let headers = properties.headers();
+
+        let super_properties = headers.get("x-super-properties").unwrap().as_bytes();
+        let super_properties: SuperProperties =
+            from_slice(&BASE64_STANDARD.decode(super_properties).unwrap()).unwrap();
+        assert_eq!(super_properties.client_build_number, 1);
+        assert_eq!(super_properties.os, "Windows");
+
+        assert_eq!(headers.get("x-discord-locale").unwrap(), "fr");
+        assert!(headers.get(USER_AGENT).unwrap().to_str().unwrap().starts_with("Mozilla"));
+        assert!(headers.contains_key("x-context-properties"));
+    }
+}
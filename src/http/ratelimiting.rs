@@ -41,6 +41,7 @@ use std::str::{self, FromStr};
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use async_trait::async_trait;
 use reqwest::header::HeaderMap;
 use reqwest::{Client, Response, StatusCode};
 use secrecy::{ExposeSecret, SecretString};
@@ -62,6 +63,219 @@ pub struct RatelimitInfo {
     pub method: LightMethod,
     pub path: String,
     pub global: bool,
+    /// The name of the [`Route`] of the request, e.g. `"ChannelMessages"`.
+    ///
+    /// [`Route`]: super::Route
+    pub route: &'static str,
+    /// The bucket the route was grouped into for ratelimiting.
+    pub bucket: RatelimitingBucket,
+    /// Discord's hash of the ratelimit bucket, if already known.
+    pub discord_bucket: Option<String>,
+    /// Which ratelimit was hit.
+    pub scope: RatelimitScope,
+    /// Whether the request was delayed pre-emptively, before being sent, instead of after having
+    /// received a 429 response.
+    pub preemptive: bool,
+}
+
+/// The scope of a ratelimit, as given by the `x-ratelimit-scope` header.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/rate-limits#header-format).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RatelimitScope {
+    /// The ratelimit applies to all requests of the bot.
+    Global,
+    /// The ratelimit applies to requests of the bot to a bucket. Pre-emptive delays always have
+    /// this scope.
+    User,
+    /// The ratelimit is shared between everyone accessing a resource, and does not count towards
+    /// the bot's invalid request limit.
+    Shared,
+}
+
+impl FromStr for RatelimitScope {
+    type Err = ();
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        match s {
+            "global" => Ok(Self::Global),
+            "user" => Ok(Self::User),
+            "shared" => Ok(Self::Shared),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The state of a single ratelimit bucket, as returned by [`Ratelimiter::snapshot`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RatelimitSnapshot {
+    /// The bucket the ratelimit applies to.
+    pub bucket: RatelimitingBucket,
+    /// The name of the last [`Route`] requested in this bucket.
+    ///
+    /// [`Route`]: super::Route
+    pub route: &'static str,
+    /// Discord's hash of the ratelimit bucket, if known.
+    pub discord_bucket: Option<String>,
+    /// The total number of requests that can be made in a period of time.
+    pub limit: i64,
+    /// The number of requests remaining in the period of time.
+    pub remaining: i64,
+    /// The absolute time when the interval resets.
+    pub reset: Option<SystemTime>,
+    /// The total time when the interval resets.
+    pub reset_after: Option<Duration>,
+}
+
+/// The ratelimit headers of a response, as passed to [`RatelimiterBackend::update`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/rate-limits#header-format).
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct RatelimitHeaders {
+    /// The total number of requests that can be made in a period of time.
+    pub limit: Option<i64>,
+    /// The number of requests remaining in the period of time.
+    pub remaining: Option<i64>,
+    /// Discord's hash of the ratelimit bucket.
+    pub bucket: Option<String>,
+    /// The absolute time when the interval resets, computed either from the `x-ratelimit-reset`
+    /// header or from the `x-ratelimit-reset-after` header, depending on
+    /// [`Ratelimiter::set_absolute_ratelimits`].
+    pub reset: Option<SystemTime>,
+    /// The total time when the interval resets.
+    pub reset_after: Option<Duration>,
+}
+
+impl RatelimitHeaders {
+    fn parse(headers: &HeaderMap, absolute_ratelimits: bool) -> Result<Self> {
+        let mut parsed = Self {
+            limit: parse_header(headers, "x-ratelimit-limit")?,
+            remaining: parse_header(headers, "x-ratelimit-remaining")?,
+            bucket: parse_header(headers, "x-ratelimit-bucket")?,
+            reset: None,
+            reset_after: None,
+        };
+
+        if absolute_ratelimits {
+            if let Some(reset) = parse_header::<f64>(headers, "x-ratelimit-reset")? {
+                parsed.reset = Some(std::time::UNIX_EPOCH + Duration::from_secs_f64(reset));
+            }
+        }
+
+        if let Some(reset_after) = parse_header::<f64>(headers, "x-ratelimit-reset-after")? {
+            if !absolute_ratelimits {
+                parsed.reset = Some(SystemTime::now() + Duration::from_secs_f64(reset_after));
+            }
+
+            parsed.reset_after = Some(Duration::from_secs_f64(reset_after));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Storage for the state of ratelimit buckets, used by the [`Ratelimiter`].
+///
+/// The default backend, [`InMemoryBackend`], keeps the state in the memory of the current process.
+/// Deployments running several processes with the same token can implement this trait to share
+/// buckets between them, for example by storing them in Redis or by delegating to a ratelimiting
+/// proxy. A bucket can be identified across processes by the name of its route and its
+/// [`RatelimitingBucket::major_id`].
+///
+/// The global ratelimit and `429` responses are still handled by the [`Ratelimiter`] itself.
+#[async_trait]
+pub trait RatelimiterBackend: Send + Sync {
+    /// Reserves a request in the bucket, before it is sent. `route` is the [`Route::name`] of the
+    /// request.
+    ///
+    /// If the bucket has no requests remaining, returns its state instead, and the request is
+    /// delayed until [`RatelimitSnapshot::reset`].
+    ///
+    /// [`Route::name`]: super::Route::name
+    async fn acquire(
+        &self,
+        route: &'static str,
+        bucket: RatelimitingBucket,
+    ) -> Option<RatelimitSnapshot>;
+
+    /// Updates the bucket with the ratelimit headers of the response to a request.
+    async fn update(
+        &self,
+        route: &'static str,
+        bucket: RatelimitingBucket,
+        headers: &RatelimitHeaders,
+    );
+
+    /// Returns the current state of every known bucket. Backends which can't list their buckets
+    /// may return an empty list, which is the default.
+    async fn snapshot(&self) -> Vec<RatelimitSnapshot> {
+        Vec::new()
+    }
+}
+
+/// The default [`RatelimiterBackend`], which keeps the state of each bucket in memory.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryBackend {
+    routes: Arc<RwLock<HashMap<RatelimitingBucket, Arc<Mutex<Ratelimit>>>>>,
+}
+
+impl InMemoryBackend {
+    /// Creates a new backend without any known buckets.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ratelimit information of each known bucket. See [`Ratelimiter::routes`] for more info.
+    #[must_use]
+    pub fn routes(&self) -> Arc<RwLock<HashMap<RatelimitingBucket, Arc<Mutex<Ratelimit>>>>> {
+        Arc::clone(&self.routes)
+    }
+
+    async fn bucket(&self, bucket: RatelimitingBucket) -> Arc<Mutex<Ratelimit>> {
+        Arc::clone(self.routes.write().await.entry(bucket).or_default())
+    }
+}
+
+#[async_trait]
+impl RatelimiterBackend for InMemoryBackend {
+    async fn acquire(
+        &self,
+        route: &'static str,
+        bucket: RatelimitingBucket,
+    ) -> Option<RatelimitSnapshot> {
+        let ratelimit = self.bucket(bucket).await;
+        let mut ratelimit = ratelimit.lock().await;
+        ratelimit.reserve(route).map(|_| ratelimit.snapshot(bucket))
+    }
+
+    async fn update(
+        &self,
+        _route: &'static str,
+        bucket: RatelimitingBucket,
+        headers: &RatelimitHeaders,
+    ) {
+        self.bucket(bucket).await.lock().await.update(headers);
+    }
+
+    async fn snapshot(&self) -> Vec<RatelimitSnapshot> {
+        let routes: Vec<_> = self
+            .routes
+            .read()
+            .await
+            .iter()
+            .map(|(bucket, ratelimit)| (*bucket, Arc::clone(ratelimit)))
+            .collect();
+
+        let mut snapshot = Vec::with_capacity(routes.len());
+        for (bucket, ratelimit) in routes {
+            snapshot.push(ratelimit.lock().await.snapshot(bucket));
+        }
+        snapshot
+    }
 }
 
 /// Ratelimiter for requests to the Discord API.
@@ -71,7 +285,8 @@ pub struct RatelimitInfo {
 /// [`reset`] time, and the [`limit`] of requests that can be made within that time.
 ///
 /// When no tickets are available for some time, then the thread sleeps until that time passes. The
-/// mechanism is known as "pre-emptive ratelimiting".
+/// mechanism is known as "pre-emptive ratelimiting". Where the data is stored can be changed by
+/// setting a [`RatelimiterBackend`].
 ///
 /// Occasionally for very high traffic bots, a global ratelimit may be reached which blocks all
 /// future requests until the global ratelimit is over, regardless of route. The value of this
@@ -87,9 +302,10 @@ pub struct Ratelimiter {
     // When futures is implemented, make tasks clear out their respective entry when the 'reset'
     // passes.
     routes: Arc<RwLock<HashMap<RatelimitingBucket, Arc<Mutex<Ratelimit>>>>>,
+    backend: Arc<dyn RatelimiterBackend>,
     token: SecretString,
     absolute_ratelimits: bool,
-    ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
+    ratelimit_callback: Arc<dyn Fn(RatelimitInfo) + Send + Sync>,
 }
 
 impl fmt::Debug for Ratelimiter {
@@ -98,6 +314,7 @@ impl fmt::Debug for Ratelimiter {
             .field("client", &self.client)
             .field("global", &self.global)
             .field("routes", &self.routes)
+            .field("backend", &"dyn RatelimiterBackend")
             .field("token", &self.token)
             .field("absolute_ratelimits", &self.absolute_ratelimits)
             .field("ratelimit_callback", &"Fn(RatelimitInfo)")
@@ -115,12 +332,15 @@ impl Ratelimiter {
     }
 
     fn _new(client: Client, token: String) -> Self {
+        let backend = InMemoryBackend::new();
+
         Self {
             client,
             global: Arc::default(),
-            routes: Arc::default(),
+            routes: backend.routes(),
+            backend: Arc::new(backend),
             token: SecretString::new(token),
-            ratelimit_callback: Box::new(|_| {}),
+            ratelimit_callback: Arc::new(|_| {}),
             absolute_ratelimits: false,
         }
     }
@@ -130,7 +350,32 @@ impl Ratelimiter {
         &mut self,
         ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
     ) {
-        self.ratelimit_callback = ratelimit_callback;
+        self.ratelimit_callback = Arc::from(ratelimit_callback);
+    }
+
+    /// Creates a ratelimiter which sends requests with another token. It shares the client and
+    /// the callback with this one, but has its own ratelimit buckets in an [`InMemoryBackend`],
+    /// as Discord tracks ratelimits per token.
+    #[must_use]
+    pub fn clone_with_token(&self, token: impl Into<String>) -> Self {
+        let mut ratelimiter = Self::_new(self.client.clone(), token.into());
+        ratelimiter.absolute_ratelimits = self.absolute_ratelimits;
+        ratelimiter.ratelimit_callback = Arc::clone(&self.ratelimit_callback);
+        ratelimiter
+    }
+
+    /// Creates a ratelimiter which shares everything, including the token and the ratelimit
+    /// buckets, with this one.
+    pub(super) fn share(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            global: Arc::clone(&self.global),
+            routes: Arc::clone(&self.routes),
+            backend: Arc::clone(&self.backend),
+            token: self.token.clone(),
+            absolute_ratelimits: self.absolute_ratelimits,
+            ratelimit_callback: Arc::clone(&self.ratelimit_callback),
+        }
     }
 
     // Sets whether absolute ratelimits should be used.
@@ -138,12 +383,32 @@ impl Ratelimiter {
         self.absolute_ratelimits = absolute_ratelimits;
     }
 
+    /// Sets the backend storing the state of ratelimit buckets. Defaults to an
+    /// [`InMemoryBackend`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::http::{HttpBuilder, InMemoryBackend, Ratelimiter};
+    ///
+    /// let mut ratelimiter = Ratelimiter::new(reqwest::Client::new(), "token");
+    /// ratelimiter.set_backend(InMemoryBackend::new());
+    ///
+    /// let http = HttpBuilder::new("token").ratelimiter(ratelimiter).build();
+    /// ```
+    pub fn set_backend(&mut self, backend: impl RatelimiterBackend + 'static) {
+        self.backend = Arc::new(backend);
+    }
+
     /// The routes mutex is a HashMap of each [`RatelimitingBucket`] and their respective ratelimit
     /// information.
     ///
     /// See the documentation for [`Ratelimit`] for more information on how the library handles
     /// ratelimiting.
     ///
+    /// **Note**: This is only populated by the default [`InMemoryBackend`]. Prefer
+    /// [`Self::snapshot`], which works with any [`RatelimiterBackend`].
+    ///
     /// # Examples
     ///
     /// View the `reset` time of the route for `ChannelsId(7)`:
@@ -175,6 +440,11 @@ impl Ratelimiter {
         Arc::clone(&self.routes)
     }
 
+    /// Returns the current state of every known ratelimit bucket.
+    pub async fn snapshot(&self) -> Vec<RatelimitSnapshot> {
+        self.backend.snapshot().await
+    }
+
     /// # Errors
     ///
     /// Only error kind that may be returned is [`Error::Http`].
@@ -190,11 +460,14 @@ impl Ratelimiter {
             // - get the global rate;
             // - sleep if there is 0 remaining
             // - then, perform the request
-            let ratelimiting_bucket = req.route.ratelimiting_bucket();
-            let bucket =
-                Arc::clone(self.routes.write().await.entry(ratelimiting_bucket).or_default());
+            let ratelimiting_bucket = req.ratelimiting_bucket();
+            let route = req.route.name();
 
-            bucket.lock().await.pre_hook(&req, &self.ratelimit_callback).await;
+            if !ratelimiting_bucket.is_none() {
+                if let Some(state) = self.backend.acquire(route, ratelimiting_bucket).await {
+                    preemptive_delay(&req, state, &*self.ratelimit_callback).await;
+                }
+            }
 
             let request = req.clone().build(&self.client, self.token.expose_secret(), None)?;
             let response = self.client.execute(request.build()?).await?;
@@ -230,8 +503,13 @@ impl Ratelimiter {
                             timeout: Duration::from_secs_f64(retry_after),
                             limit: 50,
                             method: req.method,
-                            path: req.route.path().to_string(),
+                            path: req.route.versioned_path(req.api_version.unwrap_or_default()),
                             global: true,
+                            route,
+                            bucket: ratelimiting_bucket,
+                            discord_bucket: None,
+                            scope: RatelimitScope::Global,
+                            preemptive: false,
                         });
                         sleep(Duration::from_secs_f64(retry_after)).await;
 
@@ -241,11 +519,7 @@ impl Ratelimiter {
                     },
                 )
             } else {
-                bucket
-                    .lock()
-                    .await
-                    .post_hook(&response, &req, &self.ratelimit_callback, self.absolute_ratelimits)
-                    .await
+                self.post_hook(&response, &req).await
             };
 
             if !redo.unwrap_or(true) {
@@ -253,6 +527,87 @@ impl Ratelimiter {
             }
         }
     }
+
+    async fn post_hook(&self, response: &Response, req: &Request<'_>) -> Result<bool> {
+        let headers = RatelimitHeaders::parse(response.headers(), self.absolute_ratelimits)?;
+        self.backend.update(req.route.name(), req.ratelimiting_bucket(), &headers).await;
+
+        let limit = headers.limit.unwrap_or(i64::MAX);
+        retry_ratelimited(response, req, limit, headers.bucket, &*self.ratelimit_callback).await
+    }
+}
+
+/// Calls the callback and sleeps until the reset of a bucket without remaining requests.
+async fn preemptive_delay(
+    req: &Request<'_>,
+    state: RatelimitSnapshot,
+    ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
+) {
+    let Some(delay) = state.reset.and_then(|reset| reset.duration_since(SystemTime::now()).ok())
+    else {
+        return;
+    };
+
+    debug!(
+        "Pre-emptive ratelimit on route {:?} for {}ms",
+        req.ratelimiting_bucket(),
+        delay.as_millis(),
+    );
+    ratelimit_callback(RatelimitInfo {
+        timeout: delay,
+        limit: state.limit,
+        method: req.method,
+        path: req.route.versioned_path(req.api_version.unwrap_or_default()),
+        global: false,
+        route: state.route,
+        bucket: state.bucket,
+        discord_bucket: state.discord_bucket,
+        scope: RatelimitScope::User,
+        preemptive: true,
+    });
+
+    sleep(delay).await;
+}
+
+/// If the response has status 429, calls the callback and sleeps for the `retry-after` time,
+/// returning whether the request should be retried.
+async fn retry_ratelimited(
+    response: &Response,
+    req: &Request<'_>,
+    limit: i64,
+    discord_bucket: Option<String>,
+    ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
+) -> Result<bool> {
+    if response.status() != StatusCode::TOO_MANY_REQUESTS {
+        return Ok(false);
+    }
+
+    let Some(retry_after) = parse_header::<f64>(response.headers(), "retry-after")? else {
+        return Ok(false);
+    };
+
+    debug!("Ratelimited on route {:?} for {:?}s", req.ratelimiting_bucket(), retry_after);
+    let scope = response
+        .headers()
+        .get("x-ratelimit-scope")
+        .and_then(|scope| scope.to_str().ok()?.parse().ok())
+        .unwrap_or(RatelimitScope::User);
+    ratelimit_callback(RatelimitInfo {
+        timeout: Duration::from_secs_f64(retry_after),
+        limit,
+        method: req.method,
+        path: req.route.versioned_path(req.api_version.unwrap_or_default()),
+        global: scope == RatelimitScope::Global,
+        route: req.route.name(),
+        bucket: req.ratelimiting_bucket(),
+        discord_bucket,
+        scope,
+        preemptive: false,
+    });
+
+    sleep(Duration::from_secs_f64(retry_after)).await;
+
+    Ok(true)
 }
 
 /// A set of data containing information about the ratelimits for a particular
@@ -266,6 +621,10 @@ impl Ratelimiter {
 /// [Discord docs]: https://discord.com/developers/docs/topics/rate-limits
 #[derive(Debug)]
 pub struct Ratelimit {
+    /// The name of the last route requested in this bucket.
+    route: &'static str,
+    /// Discord's hash of the bucket.
+    bucket: Option<String>,
     /// The total number of requests that can be made in a period of time.
     limit: i64,
     /// The number of requests remaining in the period of time.
@@ -283,14 +642,37 @@ impl Ratelimit {
         req: &Request<'_>,
         ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
     ) {
+        let bucket = req.ratelimiting_bucket();
+        if self.reserve(req.route.name()).is_some() {
+            preemptive_delay(req, self.snapshot(bucket), ratelimit_callback).await;
+        }
+    }
+
+    #[instrument(skip(ratelimit_callback))]
+    pub async fn post_hook(
+        &mut self,
+        response: &Response,
+        req: &Request<'_>,
+        ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
+        absolute_ratelimits: bool,
+    ) -> Result<bool> {
+        self.update(&RatelimitHeaders::parse(response.headers(), absolute_ratelimits)?);
+
+        retry_ratelimited(response, req, self.limit, self.bucket.clone(), ratelimit_callback).await
+    }
+
+    /// Reserves a request, returning the delay until the reset if none are remaining.
+    fn reserve(&mut self, route: &'static str) -> Option<Duration> {
+        self.route = route;
+
         if self.limit() == 0 {
-            return;
+            return None;
         }
 
         let Some(reset) = self.reset else {
             // We're probably in the past.
             self.remaining = self.limit;
-            return;
+            return None;
         };
 
         let Ok(delay) = reset.duration_since(SystemTime::now()) else {
@@ -298,85 +680,49 @@ impl Ratelimit {
             if self.remaining() != 0 {
                 self.remaining -= 1;
             }
-            return;
+            return None;
         };
 
         if self.remaining() == 0 {
-            debug!(
-                "Pre-emptive ratelimit on route {:?} for {}ms",
-                req.route.ratelimiting_bucket(),
-                delay.as_millis(),
-            );
-            ratelimit_callback(RatelimitInfo {
-                timeout: delay,
-                limit: self.limit,
-                method: req.method,
-                path: req.route.path().to_string(),
-                global: false,
-            });
-
-            sleep(delay).await;
-
-            return;
+            return Some(delay);
         }
 
         self.remaining -= 1;
+        None
     }
 
-    #[instrument(skip(ratelimit_callback))]
-    pub async fn post_hook(
-        &mut self,
-        response: &Response,
-        req: &Request<'_>,
-        ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
-        absolute_ratelimits: bool,
-    ) -> Result<bool> {
-        if let Some(limit) = parse_header(response.headers(), "x-ratelimit-limit")? {
+    fn update(&mut self, headers: &RatelimitHeaders) {
+        if let Some(limit) = headers.limit {
             self.limit = limit;
         }
 
-        if let Some(remaining) = parse_header(response.headers(), "x-ratelimit-remaining")? {
+        if let Some(remaining) = headers.remaining {
             self.remaining = remaining;
         }
 
-        if absolute_ratelimits {
-            if let Some(reset) = parse_header::<f64>(response.headers(), "x-ratelimit-reset")? {
-                self.reset = Some(std::time::UNIX_EPOCH + Duration::from_secs_f64(reset));
-            }
+        if let Some(bucket) = &headers.bucket {
+            self.bucket = Some(bucket.clone());
         }
 
-        if let Some(reset_after) =
-            parse_header::<f64>(response.headers(), "x-ratelimit-reset-after")?
-        {
-            if !absolute_ratelimits {
-                self.reset = Some(SystemTime::now() + Duration::from_secs_f64(reset_after));
-            }
+        if let Some(reset) = headers.reset {
+            self.reset = Some(reset);
+        }
 
-            self.reset_after = Some(Duration::from_secs_f64(reset_after));
+        if let Some(reset_after) = headers.reset_after {
+            self.reset_after = Some(reset_after);
         }
+    }
 
-        Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
-            false
-        } else if let Some(retry_after) = parse_header::<f64>(response.headers(), "retry-after")? {
-            debug!(
-                "Ratelimited on route {:?} for {:?}s",
-                req.route.ratelimiting_bucket(),
-                retry_after
-            );
-            ratelimit_callback(RatelimitInfo {
-                timeout: Duration::from_secs_f64(retry_after),
-                limit: self.limit,
-                method: req.method,
-                path: req.route.path().to_string(),
-                global: false,
-            });
-
-            sleep(Duration::from_secs_f64(retry_after)).await;
-
-            true
-        } else {
-            false
-        })
+    fn snapshot(&self, bucket: RatelimitingBucket) -> RatelimitSnapshot {
+        RatelimitSnapshot {
+            bucket,
+            route: self.route,
+            discord_bucket: self.bucket.clone(),
+            limit: self.limit,
+            remaining: self.remaining,
+            reset: self.reset,
+            reset_after: self.reset_after,
+        }
     }
 
     /// The total number of requests that can be made in a period of time.
@@ -406,11 +752,20 @@ impl Ratelimit {
     pub const fn reset_after(&self) -> Option<Duration> {
         self.reset_after
     }
+
+    /// Discord's hash of the bucket, if known.
+    #[inline]
+    #[must_use]
+    pub fn bucket(&self) -> Option<&str> {
+        self.bucket.as_deref()
+    }
 }
 
 impl Default for Ratelimit {
     fn default() -> Self {
         Self {
+            route: "",
+            bucket: None,
             limit: i64::MAX,
             remaining: i64::MAX,
             reset: None,
@@ -435,11 +790,10 @@ mod tests {
     use std::error::Error as StdError;
     use std::result::Result as StdResult;
 
-    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use reqwest::header::{HeaderName, HeaderValue};
 
-    use super::parse_header;
-    use crate::error::Error;
-    use crate::http::HttpError;
+    use super::*;
+    use crate::http::Route;
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
 
@@ -492,4 +846,41 @@ mod tests {
             Error::Http(HttpError::RateLimitUtf8)
         ));
     }
+
+    #[test]
+    fn test_parse_scope() {
+        assert_eq!("global".parse(), Ok(RatelimitScope::Global));
+        assert_eq!("user".parse(), Ok(RatelimitScope::User));
+        assert_eq!("shared".parse(), Ok(RatelimitScope::Shared));
+        assert!("bucket".parse::<RatelimitScope>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_backend() {
+        let backend = InMemoryBackend::new();
+        let bucket = Route::Gateway.ratelimiting_bucket();
+        assert!(backend.acquire("Gateway", bucket).await.is_none());
+
+        let headers = RatelimitHeaders {
+            limit: Some(1),
+            remaining: Some(0),
+            reset: Some(SystemTime::now() + Duration::from_secs(60)),
+            ..Default::default()
+        };
+        backend.update("Gateway", bucket, &headers).await;
+
+        let state = backend.acquire("Gateway", bucket).await.unwrap();
+        assert_eq!((state.limit, state.remaining, state.route), (1, 0, "Gateway"));
+        assert_eq!(backend.snapshot().await.len(), 1);
+    }
+
+    #[test]
+    fn test_clone_with_token() {
+        let ratelimiter = Ratelimiter::new(Client::new(), "token");
+        assert!(Arc::ptr_eq(&ratelimiter.routes(), &ratelimiter.share().routes()));
+        assert!(!Arc::ptr_eq(
+            &ratelimiter.routes(),
+            &ratelimiter.clone_with_token("other").routes()
+        ));
+    }
 }
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use reqwest::header::{
     HeaderMap as Headers,
@@ -12,8 +13,8 @@ use reqwest::{Client, RequestBuilder as ReqwestRequestBuilder, Url};
 use tracing::instrument;
 
 use super::multipart::Multipart;
-use super::routing::Route;
-use super::{HttpError, LightMethod};
+use super::routing::{RatelimitingBucket, Route};
+use super::{ApiVersion, HttpError, LightMethod};
 use crate::constants;
 use crate::internal::prelude::*;
 
@@ -29,6 +30,8 @@ pub struct Request<'a> {
     pub(super) method: LightMethod,
     pub(super) route: Route<'a>,
     pub(super) params: Option<Vec<(&'static str, String)>>,
+    pub(super) timeout: Option<Duration>,
+    pub(super) api_version: Option<ApiVersion>,
 }
 
 impl<'a> Request<'a> {
@@ -40,6 +43,8 @@ impl<'a> Request<'a> {
             method,
             route,
             params: None,
+            timeout: None,
+            api_version: None,
         }
     }
 
@@ -63,6 +68,28 @@ impl<'a> Request<'a> {
         self
     }
 
+    /// Sets the timeout of this request, overriding the ones set with [`HttpBuilder::timeout`] and
+    /// [`HttpBuilder::upload_timeout`].
+    ///
+    /// This is useful for requests which may take longer than usual. The timeout applies to each
+    /// attempt, and time spent waiting for a ratelimit does not count towards it.
+    ///
+    /// [`HttpBuilder::timeout`]: super::HttpBuilder::timeout
+    /// [`HttpBuilder::upload_timeout`]: super::HttpBuilder::upload_timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the version of the API this request is sent to, overriding the one set with
+    /// [`HttpBuilder::api_version`].
+    ///
+    /// [`HttpBuilder::api_version`]: super::HttpBuilder::api_version
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
     #[instrument(skip(token))]
     pub fn build(
         self,
@@ -70,7 +97,7 @@ impl<'a> Request<'a> {
         token: &str,
         proxy: Option<&str>,
     ) -> Result<ReqwestRequestBuilder> {
-        let mut path = self.route.path().to_string();
+        let mut path = self.route.versioned_path(self.api_version.unwrap_or_default());
 
         if let Some(proxy) = proxy {
             // trim_end_matches to prevent double slashes after the domain
@@ -88,9 +115,13 @@ impl<'a> Request<'a> {
             .request(self.method.reqwest_method(), Url::parse(&path).map_err(HttpError::Url)?);
 
         let mut headers = self.headers.unwrap_or_default();
-        headers.insert(USER_AGENT, HeaderValue::from_static(constants::USER_AGENT));
-        headers
-            .insert(AUTHORIZATION, HeaderValue::from_str(token).map_err(HttpError::InvalidHeader)?);
+        headers.entry(USER_AGENT).or_insert(HeaderValue::from_static(constants::USER_AGENT));
+        if !token.is_empty() {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(token).map_err(HttpError::InvalidHeader)?,
+            );
+        }
 
         if let Some(multipart) = self.multipart {
             // Setting multipart adds the content-length header.
@@ -103,6 +134,10 @@ impl<'a> Request<'a> {
             headers.insert(CONTENT_LENGTH, 0.into()); // Can we skip this?
         }
 
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
         Ok(builder.headers(headers))
     }
 
@@ -136,13 +171,90 @@ impl<'a> Request<'a> {
         &self.route
     }
 
+    /// The bucket this request is ratelimited in. This is the [`Route::ratelimiting_bucket`],
+    /// except for custom routes, whose requests are grouped by their method and path as well.
+    pub(super) fn ratelimiting_bucket(&self) -> RatelimitingBucket {
+        let bucket = self.route.ratelimiting_bucket();
+        match self.route {
+            Route::Custom {
+                path,
+            }
+            | Route::CustomWithMajorId {
+                path, ..
+            } => bucket.for_custom_route(self.method, path),
+            _ => bucket,
+        }
+    }
+
     #[must_use]
     pub fn params_ref(&self) -> Option<&[(&'static str, String)]> {
         self.params.as_deref()
     }
 
+    #[must_use]
+    pub fn timeout_ref(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    #[must_use]
+    pub fn api_version_ref(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+
     #[must_use]
     pub fn params_mut(&mut self) -> Option<&mut [(&'static str, String)]> {
         self.params.as_deref_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_version_path() {
+        let client = Client::new();
+        let route = Route::Gateway;
+
+        let request = Request::new(route, LightMethod::Get).build(&client, "", None).unwrap();
+        assert_eq!(request.build().unwrap().url().as_str(), "https://discord.com/api/v10/gateway");
+
+        let request = Request::new(route, LightMethod::Get)
+            .api_version(ApiVersion::V9)
+            .build(&client, "", None)
+            .unwrap();
+        assert_eq!(request.build().unwrap().url().as_str(), "https://discord.com/api/v9/gateway");
+    }
+
+    #[test]
+    fn status_route_path() {
+        let client = Client::new();
+        let request = Request::new(Route::StatusIncidentsUnresolved, LightMethod::Get)
+            .api_version(ApiVersion::V9)
+            .build(&client, "", None)
+            .unwrap();
+        assert_eq!(
+            request.build().unwrap().url().as_str(),
+            "https://status.discord.com/api/v2/incidents/unresolved.json"
+        );
+    }
+
+    #[test]
+    fn custom_route_bucket() {
+        let bucket = |method, path| {
+            Request::new(
+                Route::Custom {
+                    path,
+                },
+                method,
+            )
+            .ratelimiting_bucket()
+        };
+
+        let get = bucket(LightMethod::Get, "/channels/1/messages/2");
+        assert_eq!(get, bucket(LightMethod::Get, "/channels/1/messages/3"));
+        assert_ne!(get, bucket(LightMethod::Get, "/channels/4/messages/2"));
+        assert_ne!(get, bucket(LightMethod::Delete, "/channels/1/messages/2"));
+        assert_ne!(get, bucket(LightMethod::Get, "/channels/1/pins/2"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+/// Configures how often and when failed HTTP requests are retried.
+///
+/// A request is retried if it failed in a transient way, as determined by
+/// [`Error::is_retryable`]: a server error (`5xx`) or ratelimit returned by Discord, or a timed out
+/// or reset connection. Between attempts, the delay doubles, starting at [`Self::base_delay`] and
+/// capped at [`Self::max_delay`].
+///
+/// By default, requests are attempted only once. Note that retrying a request which creates a
+/// resource, such as sending a message, may create it twice if Discord processed the first attempt
+/// but failed to respond.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::time::Duration;
+/// use serenity::http::{HttpBuilder, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(4).base_delay(Duration::from_millis(250));
+/// let http = HttpBuilder::new("token").retry_policy(policy).build();
+/// ```
+///
+/// [`Error::is_retryable`]: crate::Error::is_retryable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy which attempts each request up to `max_attempts` times in total, with a
+    /// base delay of 500 milliseconds and a maximum delay of 30 seconds.
+    pub const fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// Creates a policy which never retries requests.
+    pub const fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay before the first retry.
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts.
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Gets the maximum number of attempts per request. See [`Self::new`] for more info.
+    #[must_use]
+    pub const fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the delay before the given retry, where the first retry is `1`, or `None` if the
+    /// maximum number of attempts has been reached.
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Option<Duration> {
+        if retry == 0 || retry >= self.max_attempts {
+            return None;
+        }
+
+        let factor = 2_u32.saturating_pow(retry - 1);
+        Some(self.base_delay.saturating_mul(factor).min(self.max_delay))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::new(5).base_delay(Duration::from_secs(1));
+        assert_eq!(policy.delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(2), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(4), Some(Duration::from_secs(8)));
+        assert_eq!(policy.delay(5), None);
+
+        let policy = policy.max_delay(Duration::from_secs(3));
+        assert_eq!(policy.delay(3), Some(Duration::from_secs(3)));
+
+        assert_eq!(RetryPolicy::default().delay(1), None);
+    }
+}
@@ -1,18 +1,54 @@
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::mem::Discriminant;
 use std::num::NonZeroU64;
 
+use super::{ApiVersion, LightMethod};
 use crate::model::id::*;
 
 /// Used to group requests together for ratelimiting.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct RatelimitingBucket(Option<(std::mem::Discriminant<Route<'static>>, Option<NonZeroU64>)>);
+pub struct RatelimitingBucket(
+    Option<(std::mem::Discriminant<Route<'static>>, Option<NonZeroU64>, Option<u64>)>,
+);
 
 impl RatelimitingBucket {
     #[must_use]
     pub fn is_none(&self) -> bool {
         self.0.is_none()
     }
+
+    /// The major parameter of the bucket, usually the Id of a channel, guild or webhook, if it is
+    /// ratelimited per major parameter.
+    ///
+    /// Together with the [`Route::name`], this identifies the bucket across processes, except for
+    /// [`Route::Custom`] and [`Route::CustomWithMajorId`], whose buckets also depend on the method
+    /// and path of the request.
+    #[must_use]
+    pub fn major_id(&self) -> Option<NonZeroU64> {
+        self.0.and_then(|(_, id, _)| id)
+    }
+
+    /// Groups requests to a custom route by their method and path, leaving out the Ids in the
+    /// path which are not major parameters, the same way Discord groups requests to its routes.
+    pub(super) fn for_custom_route(self, method: LightMethod, path: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        method.hash(&mut hasher);
+
+        let mut previous = "";
+        for segment in path.split('?').next().unwrap_or_default().split('/') {
+            let is_major = matches!(previous, "channels" | "guilds" | "webhooks");
+            if is_major || !segment.bytes().all(|b| b.is_ascii_digit()) || segment.is_empty() {
+                segment.hash(&mut hasher);
+            } else {
+                "{id}".hash(&mut hasher);
+            }
+            previous = segment;
+        }
+
+        Self(self.0.map(|(discriminant, id, _)| (discriminant, id, Some(hasher.finish()))))
+    }
 }
 
 enum RatelimitingKind {
@@ -23,6 +59,16 @@ enum RatelimitingKind {
     Path,
 }
 
+/// Formats the path of a route relative to the versioned base URL of the API.
+macro_rules! route_path {
+    ($e:expr) => {
+        $e
+    };
+    ($e:expr, $($rest:tt)*) => {
+        format!($e, $($rest)*)
+    };
+}
+
 /// A macro for defining routes as well as the type of ratelimiting they perform. Takes as input a
 /// list of route definitions, and generates a definition for the `Route` enum and implements
 /// methods on it.
@@ -42,8 +88,12 @@ macro_rules! routes {
         }
 
         impl<$lt> Route<$lt> {
+            /// The path of the route relative to the base URL of the API, e.g.
+            /// `"/channels/123/messages"`, or the absolute URL of routes outside of it, such as those
+            /// of the status page.
             #[must_use]
-            pub fn path(self) -> Cow<'static, str> {
+            pub fn relative_path(self) -> Cow<'static, str> {
+                #[allow(unused_variables)]
                 match self {
                     $(
                         Self::$name $({ $($field_name),* })? => $path.into(),
@@ -51,6 +101,34 @@ macro_rules! routes {
                 }
             }
 
+            /// The full URL of the route in the default version of the API.
+            #[must_use]
+            pub fn path(self) -> Cow<'static, str> {
+                self.versioned_path(ApiVersion::default()).into()
+            }
+
+            /// The full URL of the route in the given version of the API. Routes outside of the API,
+            /// such as those of the status page, are not versioned.
+            #[must_use]
+            pub fn versioned_path(self, api_version: ApiVersion) -> String {
+                let path = self.relative_path();
+                if path.starts_with("https://") {
+                    return path.into_owned();
+                }
+
+                format!("https://discord.com/api/v{}{path}", api_version.number())
+            }
+
+            /// The name of the route, e.g. `"ChannelMessage"`.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$name { .. } => stringify!($name),
+                    )+
+                }
+            }
+
             #[must_use]
             pub fn ratelimiting_bucket(&self) -> RatelimitingBucket {
                 #[allow(unused_variables)]
@@ -73,7 +151,7 @@ macro_rules! routes {
                         RatelimitingKind::PathAndId(id) => Some(id),
                         RatelimitingKind::Path => None,
                     };
-                    (discriminant, id)
+                    (discriminant, id, None)
                 }))
             }
 
@@ -83,285 +161,381 @@ macro_rules! routes {
 
 // This macro takes as input a list of route definitions, represented in the following way:
 // 1. The first line defines an enum variant representing an endpoint.
-// 2. The second line provides the url for that endpoint.
+// 2. The second line provides the path of that endpoint, relative to the base URL of the API.
 // 3. The third line indicates what type of ratelimiting the endpoint employs.
 routes! ('a, {
+    AuthLogin,
+    route_path!("/auth/login"),
+    Some(RatelimitingKind::Path);
+
+    AuthMfaTotp,
+    route_path!("/auth/mfa/totp"),
+    Some(RatelimitingKind::Path);
+
+    AuthSessions,
+    route_path!("/auth/sessions"),
+    Some(RatelimitingKind::Path);
+
+    AuthSessionsLogout,
+    route_path!("/auth/sessions/logout"),
+    Some(RatelimitingKind::Path);
+
     Channel { channel_id: ChannelId },
-    api!("/channels/{}", channel_id),
+    route_path!("/channels/{}", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelApplicationCommandIndex { channel_id: ChannelId },
+    route_path!("/channels/{}/application-command-index", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelApplicationCommandsSearch { channel_id: ChannelId },
+    route_path!("/channels/{}/application-commands/search", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelCallRing { channel_id: ChannelId },
+    route_path!("/channels/{}/call/ring", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelCallStopRinging { channel_id: ChannelId },
+    route_path!("/channels/{}/call/stop-ringing", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelInvites { channel_id: ChannelId },
-    api!("/channels/{}/invites", channel_id),
+    route_path!("/channels/{}/invites", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessage { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/messages/{}", channel_id, message_id),
+    route_path!("/channels/{}/messages/{}", channel_id, message_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelMessageAck { channel_id: ChannelId, message_id: MessageId },
+    route_path!("/channels/{}/messages/{}/ack", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageCrosspost { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/messages/{}/crosspost", channel_id, message_id),
+    route_path!("/channels/{}/messages/{}/crosspost", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageReaction { channel_id: ChannelId, message_id: MessageId, user_id: UserId, reaction: &'a str },
-    api!("/channels/{}/messages/{}/reactions/{}/{}", channel_id, message_id, reaction, user_id),
+    route_path!("/channels/{}/messages/{}/reactions/{}/{}", channel_id, message_id, reaction, user_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageReactionMe { channel_id: ChannelId, message_id: MessageId, reaction: &'a str },
-    api!("/channels/{}/messages/{}/reactions/{}/@me", channel_id, message_id, reaction),
+    route_path!("/channels/{}/messages/{}/reactions/{}/@me", channel_id, message_id, reaction),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageReactionEmoji { channel_id: ChannelId, message_id: MessageId, reaction: &'a str },
-    api!("/channels/{}/messages/{}/reactions/{}", channel_id, message_id, reaction),
+    route_path!("/channels/{}/messages/{}/reactions/{}", channel_id, message_id, reaction),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageReactions { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/messages/{}/reactions", channel_id, message_id),
+    route_path!("/channels/{}/messages/{}/reactions", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessages { channel_id: ChannelId },
-    api!("/channels/{}/messages", channel_id),
+    route_path!("/channels/{}/messages", channel_id),
+    Some(RatelimitingKind::PathAndId(channel_id.into()));
+
+    ChannelSendSoundboardSound { channel_id: ChannelId },
+    route_path!("/channels/{}/send-soundboard-sound", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessagesBulkDelete { channel_id: ChannelId },
-    api!("/channels/{}/messages/bulk-delete", channel_id),
+    route_path!("/channels/{}/messages/bulk-delete", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelFollowNews { channel_id: ChannelId },
-    api!("/channels/{}/followers", channel_id),
+    route_path!("/channels/{}/followers", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelPermission { channel_id: ChannelId, target_id: TargetId },
-    api!("/channels/{}/permissions/{}", channel_id, target_id),
+    route_path!("/channels/{}/permissions/{}", channel_id, target_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelPin { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/pins/{}", channel_id, message_id),
+    route_path!("/channels/{}/pins/{}", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelPins { channel_id: ChannelId },
-    api!("/channels/{}/pins", channel_id),
+    route_path!("/channels/{}/pins", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelTyping { channel_id: ChannelId },
-    api!("/channels/{}/typing", channel_id),
+    route_path!("/channels/{}/typing", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelWebhooks { channel_id: ChannelId },
-    api!("/channels/{}/webhooks", channel_id),
+    route_path!("/channels/{}/webhooks", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelMessageThreads { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/messages/{}/threads", channel_id, message_id),
+    route_path!("/channels/{}/messages/{}/threads", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelThreads { channel_id: ChannelId },
-    api!("/channels/{}/threads", channel_id),
+    route_path!("/channels/{}/threads", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelForumPosts { channel_id: ChannelId },
-    api!("/channels/{}/threads", channel_id),
+    route_path!("/channels/{}/threads", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelThreadMember { channel_id: ChannelId, user_id: UserId },
-    api!("/channels/{}/thread-members/{}", channel_id, user_id),
+    route_path!("/channels/{}/thread-members/{}", channel_id, user_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelThreadMemberMe { channel_id: ChannelId },
-    api!("/channels/{}/thread-members/@me", channel_id),
+    route_path!("/channels/{}/thread-members/@me", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelThreadMembers { channel_id: ChannelId },
-    api!("/channels/{}/thread-members", channel_id),
+    route_path!("/channels/{}/thread-members", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelArchivedPublicThreads { channel_id: ChannelId },
-    api!("/channels/{}/threads/archived/public", channel_id),
+    route_path!("/channels/{}/threads/archived/public", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelArchivedPrivateThreads { channel_id: ChannelId },
-    api!("/channels/{}/threads/archived/private", channel_id),
+    route_path!("/channels/{}/threads/archived/private", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelJoinedPrivateThreads { channel_id: ChannelId },
-    api!("/channels/{}/users/@me/threads/archived/private", channel_id),
+    route_path!("/channels/{}/users/@me/threads/archived/private", channel_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelPollGetAnswerVoters { channel_id: ChannelId, message_id: MessageId, answer_id: AnswerId },
-    api!("/channels/{}/polls/{}/answers/{}", channel_id, message_id, answer_id),
+    route_path!("/channels/{}/polls/{}/answers/{}", channel_id, message_id, answer_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     ChannelPollExpire { channel_id: ChannelId, message_id: MessageId },
-    api!("/channels/{}/polls/{}/expire", channel_id, message_id),
+    route_path!("/channels/{}/polls/{}/expire", channel_id, message_id),
     Some(RatelimitingKind::PathAndId(channel_id.into()));
 
     Gateway,
-    api!("/gateway"),
+    route_path!("/gateway"),
     Some(RatelimitingKind::Path);
 
     GatewayBot,
-    api!("/gateway/bot"),
+    route_path!("/gateway/bot"),
     Some(RatelimitingKind::Path);
 
     Guild { guild_id: GuildId },
-    api!("/guilds/{}", guild_id),
+    route_path!("/guilds/{}", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildApplicationCommandIndex { guild_id: GuildId },
+    route_path!("/guilds/{}/application-command-index", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildAuditLogs { guild_id: GuildId },
-    api!("/guilds/{}/audit-logs", guild_id),
+    route_path!("/guilds/{}/audit-logs", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildAutomodRule { guild_id: GuildId, rule_id: RuleId },
-    api!("/guilds/{}/auto-moderation/rules/{}", guild_id, rule_id),
+    route_path!("/guilds/{}/auto-moderation/rules/{}", guild_id, rule_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildAutomodRules { guild_id: GuildId },
-    api!("/guilds/{}/auto-moderation/rules", guild_id),
+    route_path!("/guilds/{}/auto-moderation/rules", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildBan { guild_id: GuildId, user_id: UserId },
-    api!("/guilds/{}/bans/{}", guild_id, user_id),
+    route_path!("/guilds/{}/bans/{}", guild_id, user_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildBulkBan { guild_id: GuildId },
-    api!("/guilds/{}/bulk-ban", guild_id),
+    route_path!("/guilds/{}/bulk-ban", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildBans { guild_id: GuildId },
-    api!("/guilds/{}/bans", guild_id),
+    route_path!("/guilds/{}/bans", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildChannels { guild_id: GuildId },
-    api!("/guilds/{}/channels", guild_id),
+    route_path!("/guilds/{}/channels", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildWidget { guild_id: GuildId },
-    api!("/guilds/{}/widget", guild_id),
+    route_path!("/guilds/{}/widget", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildWidgetJson { guild_id: GuildId },
+    route_path!("/guilds/{}/widget.json", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildPreview { guild_id: GuildId },
-    api!("/guilds/{}/preview", guild_id),
+    route_path!("/guilds/{}/preview", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildEmojis { guild_id: GuildId },
-    api!("/guilds/{}/emojis", guild_id),
+    route_path!("/guilds/{}/emojis", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildEmoji { guild_id: GuildId, emoji_id: EmojiId },
-    api!("/guilds/{}/emojis/{}", guild_id, emoji_id),
+    route_path!("/guilds/{}/emojis/{}", guild_id, emoji_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildIntegration { guild_id: GuildId, integration_id: IntegrationId },
-    api!("/guilds/{}/integrations/{}", guild_id, integration_id),
+    route_path!("/guilds/{}/integrations/{}", guild_id, integration_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildIntegrationSync { guild_id: GuildId, integration_id: IntegrationId },
-    api!("/guilds/{}/integrations/{}/sync", guild_id, integration_id),
+    route_path!("/guilds/{}/integrations/{}/sync", guild_id, integration_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildIntegrations { guild_id: GuildId },
-    api!("/guilds/{}/integrations", guild_id),
+    route_path!("/guilds/{}/integrations", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildInvites { guild_id: GuildId },
-    api!("/guilds/{}/invites", guild_id),
+    route_path!("/guilds/{}/invites", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMember { guild_id: GuildId, user_id: UserId },
-    api!("/guilds/{}/members/{}", guild_id, user_id),
+    route_path!("/guilds/{}/members/{}", guild_id, user_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMemberRole { guild_id: GuildId, user_id: UserId, role_id: RoleId },
-    api!("/guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id),
+    route_path!("/guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMembers { guild_id: GuildId },
-    api!("/guilds/{}/members", guild_id),
+    route_path!("/guilds/{}/members", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMembersSearch { guild_id: GuildId },
-    api!("/guilds/{}/members/search", guild_id),
+    route_path!("/guilds/{}/members/search", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMemberMe { guild_id: GuildId },
-    api!("/guilds/{}/members/@me", guild_id),
+    route_path!("/guilds/{}/members/@me", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildMfa { guild_id: GuildId },
-    api!("/guilds/{}/mfa", guild_id),
+    route_path!("/guilds/{}/mfa", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildPremiumSubscription { guild_id: GuildId, subscription_id: GuildSubscriptionId },
+    route_path!("/guilds/{}/premium/subscriptions/{}", guild_id, subscription_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildPremiumSubscriptions { guild_id: GuildId },
+    route_path!("/guilds/{}/premium/subscriptions", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildPrune { guild_id: GuildId },
-    api!("/guilds/{}/prune", guild_id),
+    route_path!("/guilds/{}/prune", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildRegions { guild_id: GuildId },
-    api!("/guilds/{}/regions", guild_id),
+    route_path!("/guilds/{}/regions", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildRole { guild_id: GuildId, role_id: RoleId },
-    api!("/guilds/{}/roles/{}", guild_id, role_id),
+    route_path!("/guilds/{}/roles/{}", guild_id, role_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildRoles { guild_id: GuildId },
-    api!("/guilds/{}/roles", guild_id),
+    route_path!("/guilds/{}/roles", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildScheduledEvent { guild_id: GuildId, event_id: ScheduledEventId },
-    api!("/guilds/{}/scheduled-events/{}", guild_id, event_id),
+    route_path!("/guilds/{}/scheduled-events/{}", guild_id, event_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildScheduledEvents { guild_id: GuildId },
-    api!("/guilds/{}/scheduled-events", guild_id),
+    route_path!("/guilds/{}/scheduled-events", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildScheduledEventUsers { guild_id: GuildId, event_id: ScheduledEventId },
-    api!("/guilds/{}/scheduled-events/{}/users", guild_id, event_id),
+    route_path!("/guilds/{}/scheduled-events/{}/users", guild_id, event_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildSoundboardSound { guild_id: GuildId, sound_id: SoundId },
+    route_path!("/guilds/{}/soundboard-sounds/{}", guild_id, sound_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildSoundboardSounds { guild_id: GuildId },
+    route_path!("/guilds/{}/soundboard-sounds", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildSticker { guild_id: GuildId, sticker_id: StickerId },
-    api!("/guilds/{}/stickers/{}", guild_id, sticker_id),
+    route_path!("/guilds/{}/stickers/{}", guild_id, sticker_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildStickers { guild_id: GuildId },
-    api!("/guilds/{}/stickers", guild_id),
+    route_path!("/guilds/{}/stickers", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildVanityUrl { guild_id: GuildId },
-    api!("/guilds/{}/vanity-url", guild_id),
+    route_path!("/guilds/{}/vanity-url", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildVoiceStates { guild_id: GuildId, user_id: UserId },
-    api!("/guilds/{}/voice-states/{}", guild_id, user_id),
+    route_path!("/guilds/{}/voice-states/{}", guild_id, user_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildVoiceStateMe { guild_id: GuildId },
-    api!("/guilds/{}/voice-states/@me", guild_id),
+    route_path!("/guilds/{}/voice-states/@me", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildWebhooks { guild_id: GuildId },
-    api!("/guilds/{}/webhooks", guild_id),
+    route_path!("/guilds/{}/webhooks", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildWelcomeScreen { guild_id: GuildId },
-    api!("/guilds/{}/welcome-screen", guild_id),
+    route_path!("/guilds/{}/welcome-screen", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildOnboarding { guild_id: GuildId },
+    route_path!("/guilds/{}/onboarding", guild_id),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
     GuildThreadsActive { guild_id: GuildId },
-    api!("/guilds/{}/threads/active", guild_id),
+    route_path!("/guilds/{}/threads/active", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildTemplate { guild_id: GuildId, code: &'a str },
+    route_path!("/guilds/{}/templates/{}", guild_id, code),
     Some(RatelimitingKind::PathAndId(guild_id.into()));
 
+    GuildTemplates { guild_id: GuildId },
+    route_path!("/guilds/{}/templates", guild_id),
+    Some(RatelimitingKind::PathAndId(guild_id.into()));
+
+    GuildsTemplate { code: &'a str },
+    route_path!("/guilds/templates/{}", code),
+    Some(RatelimitingKind::Path);
+
     Guilds,
-    api!("/guilds"),
+    route_path!("/guilds"),
+    Some(RatelimitingKind::Path);
+
+    HypesquadOnline,
+    route_path!("/hypesquad/online"),
+    Some(RatelimitingKind::Path);
+
+    Interactions,
+    route_path!("/interactions"),
     Some(RatelimitingKind::Path);
 
     Invite { code: &'a str },
-    api!("/invites/{}", code),
+    route_path!("/invites/{}", code),
     Some(RatelimitingKind::Path);
 
     Oauth2ApplicationCurrent,
-    api!("/oauth2/applications/@me"),
+    route_path!("/oauth2/applications/@me"),
     None;
 
+    ReadStatesAckBulk,
+    route_path!("/read-states/ack-bulk"),
+    Some(RatelimitingKind::Path);
+
+    RemoteAuthLogin,
+    route_path!("/users/@me/remote-auth/login"),
+    Some(RatelimitingKind::Path);
+
     StatusIncidentsUnresolved,
     status!("/incidents/unresolved.json"),
     None;
@@ -374,115 +548,192 @@ routes! ('a, {
     status!("/scheduled-maintenances/upcoming.json"),
     None;
 
+    StatusSummary,
+    status!("/summary.json"),
+    None;
+
     Sticker { sticker_id: StickerId },
-    api!("/stickers/{}", sticker_id),
+    route_path!("/stickers/{}", sticker_id),
+    Some(RatelimitingKind::Path);
+
+    StickerPack { sticker_pack_id: StickerPackId },
+    route_path!("/sticker-packs/{}", sticker_pack_id),
     Some(RatelimitingKind::Path);
 
     StickerPacks,
-    api!("/sticker-packs"),
+    route_path!("/sticker-packs"),
+    Some(RatelimitingKind::Path);
+
+    SoundboardDefaultSounds,
+    route_path!("/soundboard-default-sounds"),
     Some(RatelimitingKind::Path);
 
     User { user_id: UserId },
-    api!("/users/{}", user_id),
+    route_path!("/users/{}", user_id),
     Some(RatelimitingKind::Path);
 
     UserMe,
-    api!("/users/@me"),
+    route_path!("/users/@me"),
     Some(RatelimitingKind::Path);
 
     UserMeConnections,
-    api!("/users/@me/connections"),
+    route_path!("/users/@me/connections"),
     Some(RatelimitingKind::Path);
 
     UserMeDmChannels,
-    api!("/users/@me/channels"),
+    route_path!("/users/@me/channels"),
     Some(RatelimitingKind::Path);
 
     UserMeGuild { guild_id: GuildId },
-    api!("/users/@me/guilds/{}", guild_id),
+    route_path!("/users/@me/guilds/{}", guild_id),
+    Some(RatelimitingKind::Path);
+
+    UserMeGuildSubscriptionSlots,
+    route_path!("/users/@me/guilds/premium/subscription-slots"),
     Some(RatelimitingKind::Path);
 
     UserMeGuildMember { guild_id: GuildId },
-    api!("/users/@me/guilds/{}/member", guild_id),
+    route_path!("/users/@me/guilds/{}/member", guild_id),
     Some(RatelimitingKind::Path);
 
     UserMeGuilds,
-    api!("/users/@me/guilds"),
+    route_path!("/users/@me/guilds"),
+    Some(RatelimitingKind::Path);
+
+    UserMeProfile,
+    route_path!("/users/@me/profile"),
+    Some(RatelimitingKind::Path);
+
+    UserMeRelationship { user_id: UserId },
+    route_path!("/users/@me/relationships/{}", user_id),
+    Some(RatelimitingKind::Path);
+
+    UserMeRelationships,
+    route_path!("/users/@me/relationships"),
+    Some(RatelimitingKind::Path);
+
+    UserMeSettings,
+    route_path!("/users/@me/settings"),
     Some(RatelimitingKind::Path);
 
     VoiceRegions,
-    api!("/voice/regions"),
+    route_path!("/voice/regions"),
     Some(RatelimitingKind::Path);
 
     Webhook { webhook_id: WebhookId },
-    api!("/webhooks/{}", webhook_id),
+    route_path!("/webhooks/{}", webhook_id),
     Some(RatelimitingKind::PathAndId(webhook_id.into()));
 
     WebhookWithToken { webhook_id: WebhookId, token: &'a str },
-    api!("/webhooks/{}/{}", webhook_id, token),
+    route_path!("/webhooks/{}/{}", webhook_id, token),
     Some(RatelimitingKind::PathAndId(webhook_id.into()));
 
     WebhookMessage { webhook_id: WebhookId, token: &'a str, message_id: MessageId },
-    api!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id),
+    route_path!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id),
     Some(RatelimitingKind::PathAndId(webhook_id.into()));
 
     WebhookOriginalInteractionResponse { application_id: ApplicationId, token: &'a str },
-    api!("/webhooks/{}/{}/messages/@original", application_id, token),
+    route_path!("/webhooks/{}/{}/messages/@original", application_id, token),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     WebhookFollowupMessage { application_id: ApplicationId, token: &'a str, message_id: MessageId },
-    api!("/webhooks/{}/{}/messages/{}", application_id, token, message_id),
+    route_path!("/webhooks/{}/{}/messages/{}", application_id, token, message_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     WebhookFollowupMessages { application_id: ApplicationId, token: &'a str },
-    api!("/webhooks/{}/{}", application_id, token),
+    route_path!("/webhooks/{}/{}", application_id, token),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     InteractionResponse { interaction_id: InteractionId, token: &'a str },
-    api!("/interactions/{}/{}/callback", interaction_id, token),
+    route_path!("/interactions/{}/{}/callback", interaction_id, token),
     Some(RatelimitingKind::PathAndId(interaction_id.into()));
 
     Command { application_id: ApplicationId, command_id: CommandId },
-    api!("/applications/{}/commands/{}", application_id, command_id),
+    route_path!("/applications/{}/commands/{}", application_id, command_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     Commands { application_id: ApplicationId },
-    api!("/applications/{}/commands", application_id),
+    route_path!("/applications/{}/commands", application_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     GuildCommand { application_id: ApplicationId, guild_id: GuildId, command_id: CommandId },
-    api!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id),
+    route_path!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     GuildCommandPermissions { application_id: ApplicationId, guild_id: GuildId, command_id: CommandId },
-    api!("/applications/{}/guilds/{}/commands/{}/permissions", application_id, guild_id, command_id),
+    route_path!("/applications/{}/guilds/{}/commands/{}/permissions", application_id, guild_id, command_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     GuildCommands { application_id: ApplicationId, guild_id: GuildId },
-    api!("/applications/{}/guilds/{}/commands", application_id, guild_id),
+    route_path!("/applications/{}/guilds/{}/commands", application_id, guild_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     GuildCommandsPermissions { application_id: ApplicationId, guild_id: GuildId },
-    api!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id),
+    route_path!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     Skus { application_id: ApplicationId },
-    api!("/applications/{}/skus", application_id),
+    route_path!("/applications/{}/skus", application_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
+    ApplicationEmoji { application_id: ApplicationId, emoji_id: EmojiId },
+    route_path!("/applications/{}/emojis/{}", application_id, emoji_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
+    ApplicationEmojis { application_id: ApplicationId },
+    route_path!("/applications/{}/emojis", application_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
+    ApplicationMe,
+    route_path!("/applications/@me"),
+    Some(RatelimitingKind::Path);
+
+    RoleConnectionMetadata { application_id: ApplicationId },
+    route_path!("/applications/{}/role-connections/metadata", application_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
+    UserMeApplicationRoleConnection { application_id: ApplicationId },
+    route_path!("/users/@me/applications/{}/role-connection", application_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     Entitlement { application_id: ApplicationId, entitlement_id: EntitlementId },
-    api!("/applications/{}/entitlements/{}", application_id, entitlement_id),
+    route_path!("/applications/{}/entitlements/{}", application_id, entitlement_id),
+    Some(RatelimitingKind::PathAndId(application_id.into()));
+
+    EntitlementConsume { application_id: ApplicationId, entitlement_id: EntitlementId },
+    route_path!("/applications/{}/entitlements/{}/consume", application_id, entitlement_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
     Entitlements { application_id: ApplicationId },
-    api!("/applications/{}/entitlements", application_id),
+    route_path!("/applications/{}/entitlements", application_id),
     Some(RatelimitingKind::PathAndId(application_id.into()));
 
+    SkuSubscription { sku_id: SkuId, subscription_id: SubscriptionId },
+    route_path!("/skus/{}/subscriptions/{}", sku_id, subscription_id),
+    Some(RatelimitingKind::PathAndId(sku_id.into()));
+
+    SkuSubscriptions { sku_id: SkuId },
+    route_path!("/skus/{}/subscriptions", sku_id),
+    Some(RatelimitingKind::PathAndId(sku_id.into()));
+
     StageInstances,
-    api!("/stage-instances"),
+    route_path!("/stage-instances"),
     Some(RatelimitingKind::Path);
 
     StageInstance { channel_id: ChannelId },
-    api!("/stage-instances/{}", channel_id),
+    route_path!("/stage-instances/{}", channel_id),
     Some(RatelimitingKind::Path);
+
+    // An endpoint without a dedicated route, given as its path relative to the API base URL,
+    // such as `/applications/123/role-connections/metadata`. Requests are ratelimited per method
+    // and path, where Ids other than the channel, guild or webhook Id are left out.
+    Custom { path: &'a str },
+    route_path!("{}", path),
+    Some(RatelimitingKind::Path);
+
+    // Like `Custom`, but ratelimited per the given major parameter, such as the channel or guild Id
+    // in the path, in addition to the method and path.
+    CustomWithMajorId { path: &'a str, major_id: NonZeroU64 },
+    route_path!("{}", path),
+    Some(RatelimitingKind::PathAndId(major_id));
 });
@@ -128,6 +128,82 @@ macro_rules! enum_number {
     };
 }
 
+/// The `enum_string!` macro is the counterpart of [`enum_number!`] for enums which Discord sends as
+/// strings. It generates `From` implementations which can be utilized by `serde` with
+/// `#[serde(from = "String", into = "String")]`, as well as an `as_str` method.
+///
+/// Unknown values are kept in the `Unknown` variant, so they are serialized back unchanged.
+///
+/// ```ignore
+/// enum_string! {
+///     /// The `Foo` enum
+///     #[derive(Clone, Deserialize, Serialize)]
+///     #[serde(from = "String", into = "String")]
+///     pub enum Foo {
+///         /// First
+///         Aah = "aah",
+///         /// Second
+///         Bar = "bar",
+///         _ => Unknown(String),
+///     }
+/// }
+/// ```
+macro_rules! enum_string {
+    (
+        $(#[$outer:meta])*
+        $vis:vis enum $Enum:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                $(#[cfg $($cfg:tt)*])?
+                $(#[default $($dummy:tt)?])?
+                $Variant:ident = $value:literal,
+            )*
+            _ => Unknown(String),
+        }
+    ) => {
+        $(#[$outer])*
+        $vis enum $Enum {
+            $(
+                $(#[doc = $doc])*
+                $(#[cfg $($cfg)*])?
+                $(#[default $($dummy:tt)?])?
+                $Variant,
+            )*
+            /// Variant value is unknown.
+            Unknown(String),
+        }
+
+        impl $Enum {
+            /// Returns the string Discord uses for the variant.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($(#[cfg $($cfg)*])? Self::$Variant => $value,)*
+                    Self::Unknown(unknown) => unknown,
+                }
+            }
+        }
+
+        impl From<String> for $Enum {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($(#[cfg $($cfg)*])? $value => Self::$Variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$Enum> for String {
+            fn from(value: $Enum) -> Self {
+                match value {
+                    $Enum::Unknown(unknown) => unknown,
+                    known => known.as_str().to_owned(),
+                }
+            }
+        }
+    };
+}
+
 /// The macro forwards the generation to the `bitflags::bitflags!` macro and implements the default
 /// (de)serialization for Discord's bitmask values.
 ///
@@ -199,4 +275,24 @@ mod tests {
         assert_json(&T::C, json!(3));
         assert_json(&T::Unknown(123), json!(123));
     }
+
+    #[test]
+    fn enum_string() {
+        enum_string! {
+            #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+            #[serde(from = "String", into = "String")]
+            pub enum T {
+                /// AAA
+                A = "a",
+                /// BBB
+                B = "b.b",
+                _ => Unknown(String),
+            }
+        }
+
+        assert_json(&T::A, json!("a"));
+        assert_json(&T::B, json!("b.b"));
+        assert_json(&T::Unknown("c".into()), json!("c"));
+        assert_eq!(T::B.as_str(), "b.b");
+    }
 }
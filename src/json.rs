@@ -44,8 +44,9 @@ pub(crate) async fn decode_resp<T: serde::de::DeserializeOwned>(
 ) -> Result<T> {
     #[cfg(not(feature = "simd_json"))]
     let result = serde_json::from_slice(&resp.bytes().await?)?;
+    // Converting the body into a Vec doesn't copy it if the buffer isn't shared.
     #[cfg(feature = "simd_json")]
-    let result = simd_json::from_slice(&mut resp.bytes().await?.to_vec())?;
+    let result = simd_json::from_slice(&mut Vec::from(resp.bytes().await?))?;
     Ok(result)
 }
 
@@ -169,6 +170,20 @@ where
     Ok(result)
 }
 
+/// Serialize the given data structure as JSON into the I/O stream.
+#[allow(clippy::missing_errors_doc)]
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: ?Sized + Serialize,
+{
+    #[cfg(not(feature = "simd_json"))]
+    serde_json::to_writer(writer, value)?;
+    #[cfg(feature = "simd_json")]
+    simd_json::to_writer(writer, value)?;
+    Ok(())
+}
+
 /// Convert a `T` into a [`Value`] which is an enum that can represent any valid JSON data.
 #[allow(clippy::missing_errors_doc)]
 pub fn to_value<T>(value: T) -> Result<Value>
@@ -94,6 +94,8 @@ pub mod json;
 pub mod model;
 pub mod prelude;
 
+#[cfg(feature = "http")]
+pub mod auth;
 #[cfg(feature = "builder")]
 pub mod builder;
 #[cfg(feature = "cache")]
@@ -110,6 +112,8 @@ pub mod gateway;
 pub mod http;
 #[cfg(feature = "interactions_endpoint")]
 pub mod interactions_endpoint;
+#[cfg(feature = "remote_auth")]
+pub mod remote_auth;
 #[cfg(feature = "utils")]
 pub mod utils;
 
@@ -143,6 +147,9 @@ compile_error!(
 /// - [`crate::framework::standard`]: has many standard_framework-specific items that may collide
 ///   with items from the rest of serenity
 pub mod all {
+    #[cfg(feature = "http")]
+    #[doc(no_inline)]
+    pub use crate::auth::*;
     #[cfg(feature = "builder")]
     #[doc(no_inline)]
     pub use crate::builder::*;
@@ -169,6 +176,9 @@ pub mod all {
     #[cfg(feature = "interactions_endpoint")]
     #[doc(no_inline)]
     pub use crate::interactions_endpoint::*;
+    #[cfg(feature = "remote_auth")]
+    #[doc(no_inline)]
+    pub use crate::remote_auth::*;
     #[cfg(feature = "utils")]
     #[doc(no_inline)]
     pub use crate::utils::{
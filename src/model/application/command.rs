@@ -10,8 +10,10 @@ use crate::builder::{Builder, CreateCommand};
 use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 use crate::model::channel::ChannelType;
+use crate::model::guild::Member;
 use crate::model::id::{
     ApplicationId,
+    ChannelId,
     CommandId,
     CommandPermissionId,
     CommandVersionId,
@@ -19,8 +21,78 @@ use crate::model::id::{
     RoleId,
     UserId,
 };
+use crate::model::misc::ImageHash;
 use crate::model::Permissions;
 
+/// The application commands which can be used in a channel, as found by
+/// [`Http::search_application_commands`].
+///
+/// [`Http::search_application_commands`]: crate::http::Http::search_application_commands
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CommandSearch {
+    /// The applications the found commands belong to.
+    #[serde(default)]
+    pub applications: Vec<CommandSearchApplication>,
+    /// The found commands.
+    #[serde(default)]
+    pub application_commands: Vec<Command>,
+}
+
+/// The application commands which are available in a guild or private channel, as returned by
+/// [`Http::get_guild_application_command_index`] and
+/// [`Http::get_channel_application_command_index`].
+///
+/// [`Http::get_guild_application_command_index`]: crate::http::Http::get_guild_application_command_index
+/// [`Http::get_channel_application_command_index`]: crate::http::Http::get_channel_application_command_index
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CommandIndex {
+    /// The applications the commands belong to.
+    #[serde(default)]
+    pub applications: Vec<CommandSearchApplication>,
+    /// The available commands.
+    #[serde(default)]
+    pub application_commands: Vec<Command>,
+}
+
+impl CommandIndex {
+    /// Returns the commands of the given application.
+    pub fn application_commands(
+        &self,
+        application_id: ApplicationId,
+    ) -> impl Iterator<Item = &Command> {
+        self.application_commands.iter().filter(move |c| c.application_id == application_id)
+    }
+
+    /// Returns the command with the given name and kind, e.g. to invoke it with
+    /// [`CreateInteraction`].
+    ///
+    /// [`CreateInteraction`]: crate::builder::CreateInteraction
+    #[must_use]
+    pub fn command(&self, name: &str, kind: CommandType) -> Option<&Command> {
+        self.application_commands.iter().find(|c| c.name == name && c.kind == kind)
+    }
+}
+
+/// An application found by [`Http::search_application_commands`], or listed in a
+/// [`CommandIndex`].
+///
+/// [`Http::search_application_commands`]: crate::http::Http::search_application_commands
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CommandSearchApplication {
+    /// The Id of the application.
+    pub id: ApplicationId,
+    /// The name of the application.
+    pub name: String,
+    /// The icon hash of the application.
+    pub icon: Option<ImageHash>,
+}
+
 /// The base command model that belongs to an application.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure).
@@ -95,6 +167,8 @@ pub struct Command {
     /// By default, all interaction context types are included.
     #[cfg(feature = "unstable_discord_api")]
     pub contexts: Option<Vec<InteractionContext>>,
+    /// How the command is handled, only for [`CommandType::PrimaryEntryPoint`] commands.
+    pub handler: Option<EntryPointHandlerType>,
     /// An autoincremented version identifier updated during substantial record changes.
     pub version: CommandVersionId,
 }
@@ -233,6 +307,78 @@ impl Command {
     }
 }
 
+impl Command {
+    /// Checks whether the given member can use this command in the given channel.
+    ///
+    /// This combines [`Self::default_member_permissions`] with the command's permission
+    /// overwrites and the application-wide ones, as set by server admins under the integration
+    /// settings, following Discord's rules:
+    ///
+    /// - Members with the [Administrator] permission can always use the command.
+    /// - A channel overwrite denying the command in the channel (or in all channels) hides it.
+    /// - A user overwrite takes precedence over role overwrites, which take precedence over the
+    ///   `@everyone` overwrite. Any allowing role overwrite beats denying ones.
+    /// - Overwrites on the command itself take precedence over application-wide ones.
+    /// - Without any applicable overwrite, the member must have all of the command's default member
+    ///   permissions.
+    ///
+    /// `permissions` are the member's permissions in the channel, as found in
+    /// [`Member::permissions`] for interactions or computed via [`Guild::user_permissions_in`].
+    /// `overwrites` are the guild's command permissions, as returned by
+    /// [`GuildId::get_commands_permissions`].
+    ///
+    /// [Administrator]: Permissions::ADMINISTRATOR
+    /// [`Guild::user_permissions_in`]: crate::model::guild::Guild::user_permissions_in
+    /// [`GuildId::get_commands_permissions`]: crate::model::id::GuildId::get_commands_permissions
+    #[must_use]
+    pub fn is_usable_by(
+        &self,
+        member: &Member,
+        channel_id: ChannelId,
+        permissions: Permissions,
+        overwrites: &[CommandPermissions],
+    ) -> bool {
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            return true;
+        }
+
+        let command = overwrites.iter().find(|o| o.id == self.id);
+        let application = overwrites.iter().find(|o| o.id.get() == self.application_id.get());
+
+        let channel = command
+            .and_then(|o| o.channel_allowed(channel_id))
+            .or_else(|| application.and_then(|o| o.channel_allowed(channel_id)));
+        if channel == Some(false) {
+            return false;
+        }
+
+        command
+            .and_then(|o| o.member_allowed(member))
+            .or_else(|| application.and_then(|o| o.member_allowed(member)))
+            .unwrap_or_else(|| {
+                self.default_member_permissions.map_or(true, |p| permissions.contains(p))
+            })
+    }
+
+    /// Filters the given commands down to the ones the given member can use in the given channel.
+    ///
+    /// This is useful for listing the commands available to a user. Refer to
+    /// [`Self::is_usable_by`] for how each command is checked.
+    #[must_use]
+    pub fn usable_by<'a>(
+        commands: &'a [Command],
+        member: &Member,
+        channel_id: ChannelId,
+        permissions: Permissions,
+        overwrites: &[CommandPermissions],
+    ) -> Vec<&'a Command> {
+        commands
+            .iter()
+            .filter(|command| command.is_usable_by(member, channel_id, permissions, overwrites))
+            .collect()
+    }
+}
+
 enum_number! {
     /// The type of an application command.
     ///
@@ -245,6 +391,28 @@ enum_number! {
         ChatInput = 1,
         User = 2,
         Message = 3,
+        /// The command which launches the application's Activity. Each application can have one.
+        PrimaryEntryPoint = 4,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// How a [`CommandType::PrimaryEntryPoint`] command is handled.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/interactions/application-commands#application-command-object-entry-point-command-handler-types).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum EntryPointHandlerType {
+        /// The application receives an interaction and responds to it, for example with
+        /// [`CreateInteractionResponse::LaunchActivity`].
+        ///
+        /// [`CreateInteractionResponse::LaunchActivity`]: crate::builder::CreateInteractionResponse::LaunchActivity
+        AppHandler = 1,
+        /// Discord launches the Activity and sends a follow-up message, without an interaction.
+        DiscordLaunchActivity = 2,
         _ => Unknown(u8),
     }
 }
@@ -398,6 +566,39 @@ enum_number! {
     }
 }
 
+impl CommandPermissions {
+    fn find(&self, kind: CommandPermissionType, id: u64) -> Option<bool> {
+        self.permissions.iter().find(|p| p.kind == kind && p.id.get() == id).map(|p| p.permission)
+    }
+
+    // Returns whether the overwrites explicitly allow or deny usage in the given channel.
+    fn channel_allowed(&self, channel_id: ChannelId) -> Option<bool> {
+        // The "all channels" overwrite uses the guild ID minus one.
+        let all_channels = self.guild_id.get() - 1;
+
+        self.find(CommandPermissionType::Channel, channel_id.get())
+            .or_else(|| self.find(CommandPermissionType::Channel, all_channels))
+    }
+
+    // Returns whether the overwrites explicitly allow or deny usage by the given member.
+    fn member_allowed(&self, member: &Member) -> Option<bool> {
+        if let Some(allowed) = self.find(CommandPermissionType::User, member.user.id.get()) {
+            return Some(allowed);
+        }
+
+        let mut roles = member
+            .roles
+            .iter()
+            .filter_map(|role| self.find(CommandPermissionType::Role, role.get()));
+        if let Some(first) = roles.next() {
+            return Some(first || roles.any(|allowed| allowed));
+        }
+
+        // The @everyone role shares its ID with the guild.
+        self.find(CommandPermissionType::Role, self.guild_id.get())
+    }
+}
+
 impl CommandPermissionId {
     /// Converts this [`CommandPermissionId`] to [`UserId`].
     #[must_use]
@@ -435,3 +636,118 @@ impl From<CommandPermissionId> for UserId {
         Self::new(id.get())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+    use crate::model::user::User;
+
+    fn command(default_member_permissions: Option<&str>) -> Command {
+        from_value(json!({
+            "id": "10",
+            "type": 1,
+            "application_id": "20",
+            "guild_id": "30",
+            "name": "ban",
+            "description": "Bans a member",
+            "default_member_permissions": default_member_permissions,
+            "version": "1",
+        }))
+        .unwrap()
+    }
+
+    fn overwrites(id: &str, permissions: &[(&str, u8, bool)]) -> CommandPermissions {
+        let permissions: Vec<_> = permissions
+            .iter()
+            .map(|(id, kind, permission)| json!({"id": id, "type": kind, "permission": permission}))
+            .collect();
+
+        from_value(json!({
+            "id": id,
+            "application_id": "20",
+            "guild_id": "30",
+            "permissions": permissions,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn command_usability() {
+        let channel_id = ChannelId::new(40);
+        let member = Member {
+            user: User {
+                id: UserId::new(50),
+                ..Default::default()
+            },
+            roles: vec![RoleId::new(60), RoleId::new(61)],
+            ..Default::default()
+        };
+        let ban_members = Permissions::BAN_MEMBERS;
+
+        // Default member permissions apply without overwrites.
+        let cmd = command(Some("4"));
+        assert!(cmd.is_usable_by(&member, channel_id, ban_members, &[]));
+        assert!(!cmd.is_usable_by(&member, channel_id, Permissions::empty(), &[]));
+        assert!(cmd.is_usable_by(&member, channel_id, Permissions::ADMINISTRATOR, &[]));
+
+        // A role overwrite allows usage despite missing permissions, unless the channel is denied.
+        let allowed = [overwrites("10", &[("60", 1, true), ("61", 1, false)])];
+        assert!(cmd.is_usable_by(&member, channel_id, Permissions::empty(), &allowed));
+        let denied_channel = [overwrites("10", &[("60", 1, true), ("29", 3, false)])];
+        assert!(!cmd.is_usable_by(&member, channel_id, ban_members, &denied_channel));
+
+        // User overwrites beat role overwrites, command overwrites beat application ones.
+        let denied_user = [overwrites("10", &[("60", 1, true), ("50", 2, false)])];
+        assert!(!cmd.is_usable_by(&member, channel_id, ban_members, &denied_user));
+        let mixed = [overwrites("20", &[("30", 1, false)]), overwrites("10", &[("30", 1, true)])];
+        assert!(cmd.is_usable_by(&member, channel_id, Permissions::empty(), &mixed));
+
+        let commands = [command(Some("4")), command(None)];
+        assert_eq!(
+            Command::usable_by(&commands, &member, channel_id, Permissions::empty(), &[]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn command_index() {
+        let index: CommandIndex = from_value(json!({
+            "applications": [{"id": "1", "name": "Bot", "icon": null, "bot_id": "1"}],
+            "application_commands": [{
+                "id": "2",
+                "type": 1,
+                "application_id": "1",
+                "version": "3",
+                "name": "ping",
+                "description": "Pings the bot",
+            }],
+            "version": "4",
+        }))
+        .unwrap();
+
+        assert_eq!(index.applications[0].name, "Bot");
+        assert_eq!(index.application_commands(ApplicationId::new(1)).count(), 1);
+        assert!(index.command("ping", CommandType::ChatInput).is_some());
+        assert!(index.command("ping", CommandType::User).is_none());
+    }
+
+    #[test]
+    fn entry_point_command() {
+        let cmd: Command = from_value(json!({
+            "id": "10",
+            "type": 4,
+            "application_id": "20",
+            "name": "launch",
+            "description": "",
+            "default_member_permissions": null,
+            "handler": 2,
+            "version": "1",
+        }))
+        .unwrap();
+
+        assert_eq!(cmd.kind, CommandType::PrimaryEntryPoint);
+        assert_eq!(cmd.handler, Some(EntryPointHandlerType::DiscordLaunchActivity));
+        assert_eq!(command(None).handler, None);
+    }
+}
@@ -535,6 +535,18 @@ pub struct CommandDataOption {
 }
 
 impl CommandDataOption {
+    /// Creates an option with the given name and value, e.g. to invoke a command with
+    /// [`CreateInteraction`].
+    ///
+    /// [`CreateInteraction`]: crate::builder::CreateInteraction
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: CommandDataOptionValue) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+
     #[must_use]
     pub fn kind(&self) -> CommandOptionType {
         self.value.kind()
@@ -21,6 +21,13 @@ enum_number! {
         RoleSelect = 6,
         MentionableSelect = 7,
         ChannelSelect = 8,
+        Section = 9,
+        TextDisplay = 10,
+        Thumbnail = 11,
+        MediaGallery = 12,
+        File = 13,
+        Separator = 14,
+        Container = 17,
         _ => Unknown(u8),
     }
 }
@@ -67,8 +74,15 @@ impl<'de> Deserialize<'de> for ActionRowComponent {
             | ComponentType::RoleSelect
             | ComponentType::MentionableSelect
             | ComponentType::ChannelSelect => from_value(value).map(ActionRowComponent::SelectMenu),
-            ComponentType::ActionRow => {
-                return Err(DeError::custom("Invalid component type ActionRow"))
+            kind @ (ComponentType::ActionRow
+            | ComponentType::Section
+            | ComponentType::TextDisplay
+            | ComponentType::Thumbnail
+            | ComponentType::MediaGallery
+            | ComponentType::File
+            | ComponentType::Separator
+            | ComponentType::Container) => {
+                return Err(DeError::custom(format_args!("Invalid component type {kind:?}")))
             },
             ComponentType::Unknown(i) => {
                 return Err(DeError::custom(format_args!("Unknown component type {i}")))
@@ -100,6 +114,81 @@ impl From<SelectMenu> for ActionRowComponent {
     }
 }
 
+/// A top-level component of a message.
+///
+/// Messages without the [`MessageFlags::IS_COMPONENTS_V2`] flag only contain action rows.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#component-object-component-types).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Component {
+    ActionRow(ActionRow),
+    Section(Section),
+    TextDisplay(TextDisplay),
+    Thumbnail(Thumbnail),
+    MediaGallery(MediaGallery),
+    File(FileComponent),
+    Separator(Separator),
+    Container(Container),
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::ActionRow => from_value(value).map(Component::ActionRow),
+            ComponentType::Section => from_value(value).map(Component::Section),
+            ComponentType::TextDisplay => from_value(value).map(Component::TextDisplay),
+            ComponentType::Thumbnail => from_value(value).map(Component::Thumbnail),
+            ComponentType::MediaGallery => from_value(value).map(Component::MediaGallery),
+            ComponentType::File => from_value(value).map(Component::File),
+            ComponentType::Separator => from_value(value).map(Component::Separator),
+            ComponentType::Container => from_value(value).map(Component::Container),
+            kind @ (ComponentType::Button
+            | ComponentType::StringSelect
+            | ComponentType::InputText
+            | ComponentType::UserSelect
+            | ComponentType::RoleSelect
+            | ComponentType::MentionableSelect
+            | ComponentType::ChannelSelect) => {
+                return Err(DeError::custom(format_args!(
+                    "Invalid top-level component type {kind:?}"
+                )))
+            },
+            ComponentType::Unknown(i) => {
+                return Err(DeError::custom(format_args!("Unknown component type {i}")))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(c) => c.serialize(serializer),
+            Self::Section(c) => c.serialize(serializer),
+            Self::TextDisplay(c) => c.serialize(serializer),
+            Self::Thumbnail(c) => c.serialize(serializer),
+            Self::MediaGallery(c) => c.serialize(serializer),
+            Self::File(c) => c.serialize(serializer),
+            Self::Separator(c) => c.serialize(serializer),
+            Self::Container(c) => c.serialize(serializer),
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(component: ActionRow) -> Self {
+        Component::ActionRow(component)
+    }
+}
+
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(untagged)]
@@ -205,6 +294,12 @@ pub struct SelectMenu {
     /// List of channel types to include in the [`ComponentType::ChannelSelect`].
     #[serde(default)]
     pub channel_types: Vec<ChannelType>,
+    /// The users, roles or channels selected by default.
+    ///
+    /// Only available for [`ComponentType::UserSelect`], [`ComponentType::RoleSelect`],
+    /// [`ComponentType::MentionableSelect`], and [`ComponentType::ChannelSelect`].
+    #[serde(default)]
+    pub default_values: Vec<SelectMenuDefaultValue>,
     /// The placeholder shown when nothing is selected.
     pub placeholder: Option<String>,
     /// The minimum number of selections allowed.
@@ -216,6 +311,36 @@ pub struct SelectMenu {
     pub disabled: bool,
 }
 
+/// A user, role or channel selected by default in an auto-populated select menu.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SelectMenuDefaultValue {
+    /// The Id of the user, role or channel.
+    pub id: GenericId,
+    /// The kind of the value.
+    #[serde(rename = "type")]
+    pub kind: SelectMenuDefaultValueType,
+}
+
+enum_string! {
+    /// The kind of a [`SelectMenuDefaultValue`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure).
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+    #[serde(from = "String", into = "String")]
+    #[non_exhaustive]
+    pub enum SelectMenuDefaultValueType {
+        User = "user",
+        Role = "role",
+        Channel = "channel",
+        _ => Unknown(String),
+    }
+}
+
 /// A select menu component options.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure).
@@ -294,6 +419,223 @@ enum_number! {
     }
 }
 
+/// A component which shows text next to an accessory.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#section).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Section {
+    /// Always [`ComponentType::Section`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The one to three text displays of the section.
+    pub components: Vec<Component>,
+    /// The button or thumbnail shown next to the text.
+    pub accessory: Box<SectionAccessory>,
+}
+
+/// The component shown next to the text of a [`Section`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#section-section-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SectionAccessory {
+    Button(Button),
+    Thumbnail(Thumbnail),
+}
+
+impl<'de> Deserialize<'de> for SectionAccessory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        let raw_kind = map.get("type").ok_or_else(|| DeError::missing_field("type"))?.clone();
+        let value = Value::from(map);
+
+        match deserialize_val(raw_kind)? {
+            ComponentType::Button => from_value(value).map(SectionAccessory::Button),
+            ComponentType::Thumbnail => from_value(value).map(SectionAccessory::Thumbnail),
+            kind => {
+                return Err(DeError::custom(format_args!(
+                    "Invalid section accessory type {kind:?}"
+                )))
+            },
+        }
+        .map_err(DeError::custom)
+    }
+}
+
+impl Serialize for SectionAccessory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Button(c) => c.serialize(serializer),
+            Self::Thumbnail(c) => c.serialize(serializer),
+        }
+    }
+}
+
+/// A component which shows markdown text.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#text-display).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TextDisplay {
+    /// Always [`ComponentType::TextDisplay`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The markdown text shown.
+    pub content: String,
+}
+
+/// A small image, only usable as the accessory of a [`Section`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#thumbnail).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Thumbnail {
+    /// Always [`ComponentType::Thumbnail`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The image shown.
+    pub media: UnfurledMediaItem,
+    /// The alt text of the image.
+    pub description: Option<String>,
+    /// Whether the image is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A component which shows a grid of images and videos.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGallery {
+    /// Always [`ComponentType::MediaGallery`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The one to ten items of the gallery.
+    pub items: Vec<MediaGalleryItem>,
+}
+
+/// An image or video of a [`MediaGallery`].
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#media-gallery-media-gallery-item-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MediaGalleryItem {
+    /// The image or video shown.
+    pub media: UnfurledMediaItem,
+    /// The alt text of the item.
+    pub description: Option<String>,
+    /// Whether the item is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// A component which shows an attached file.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#file).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FileComponent {
+    /// Always [`ComponentType::File`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The file shown. Only `attachment://<filename>` URLs are supported.
+    pub file: UnfurledMediaItem,
+    /// Whether the file is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+    /// The name of the file.
+    pub name: Option<String>,
+    /// The size of the file in bytes.
+    pub size: Option<u64>,
+}
+
+/// A component which adds vertical padding between other components.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#separator).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Separator {
+    /// Always [`ComponentType::Separator`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// Whether a visual divider line is shown.
+    #[serde(default = "default_true")]
+    pub divider: bool,
+    /// The amount of padding.
+    #[serde(default)]
+    pub spacing: SeparatorSpacing,
+}
+
+enum_number! {
+    /// The amount of padding of a [`Separator`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/components/reference#separator-separator-structure).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum SeparatorSpacing {
+        #[default]
+        Small = 1,
+        Large = 2,
+        _ => Unknown(u8),
+    }
+}
+
+/// A component which groups other components in a box with an optional accent color.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#container).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Container {
+    /// Always [`ComponentType::Container`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The action rows, sections, text displays, media galleries, separators and files inside
+    /// the container.
+    pub components: Vec<Component>,
+    /// The color of the bar on the left side of the container.
+    pub accent_color: Option<Colour>,
+    /// Whether the container is blurred out as a spoiler.
+    #[serde(default)]
+    pub spoiler: bool,
+}
+
+/// An image, video or other file referenced by a component.
+///
+/// [Discord docs](https://discord.com/developers/docs/components/reference#unfurled-media-item-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UnfurledMediaItem {
+    /// The URL of the media. Either an arbitrary URL or an `attachment://<filename>` reference.
+    pub url: String,
+    /// The proxied URL of the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    /// The height of the media, if it is an image or video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// The width of the media, if it is an image or video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// The media type of the media.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,4 +666,61 @@ mod tests {
             json!({"type": 2, "style": 5, "url": "https://google.com", "label": "a", "disabled": false}),
         );
     }
+
+    #[test]
+    fn test_select_menu_default_values() {
+        let menu: SelectMenu = from_value(json!({
+            "type": 8,
+            "custom_id": "channels",
+            "channel_types": [0, 5],
+            "default_values": [{"id": "1", "type": "channel"}],
+        }))
+        .unwrap();
+
+        assert_eq!(menu.kind, ComponentType::ChannelSelect);
+        assert_eq!(menu.channel_types, [ChannelType::Text, ChannelType::News]);
+        assert_eq!(menu.default_values, [SelectMenuDefaultValue {
+            id: GenericId::new(1),
+            kind: SelectMenuDefaultValueType::Channel,
+        }]);
+
+        let unknown = SelectMenuDefaultValue {
+            id: GenericId::new(2),
+            kind: SelectMenuDefaultValueType::Unknown("thread".into()),
+        };
+        assert_json(&unknown, json!({"id": "2", "type": "thread"}));
+    }
+
+    #[test]
+    fn test_layout_components() {
+        let component: Component = from_value(json!({
+            "type": 17,
+            "accent_color": 0xff0000,
+            "components": [{
+                "type": 9,
+                "components": [{"type": 10, "content": "Hello"}],
+                "accessory": {"type": 2, "style": 1, "custom_id": "click", "label": "Click"},
+            }, {
+                "type": 14,
+                "spacing": 2,
+            }, {
+                "type": 12,
+                "items": [{"media": {"url": "https://example.com/a.png", "width": 16, "height": 16}}],
+            }],
+        }))
+        .unwrap();
+
+        let Component::Container(container) = component else {
+            panic!("expected a container, got {component:?}");
+        };
+        assert_eq!(container.accent_color, Some(Colour::new(0xff0000)));
+        assert!(matches!(&container.components[0], Component::Section(section)
+            if matches!(*section.accessory, SectionAccessory::Button(_))));
+        assert!(matches!(&container.components[1], Component::Separator(separator)
+            if separator.divider && separator.spacing == SeparatorSpacing::Large));
+        assert!(matches!(&container.components[2], Component::MediaGallery(gallery)
+            if gallery.items[0].media.width == Some(16)));
+
+        assert!(from_value::<Component>(json!({"type": 2, "style": 1, "custom_id": "a"})).is_err());
+    }
 }
@@ -297,7 +297,15 @@ impl<'de> Deserialize<'de> for ComponentInteractionDataKind {
                 values: parse_values!(),
             },
             ComponentType::Unknown(x) => Self::Unknown(x),
-            x @ (ComponentType::ActionRow | ComponentType::InputText) => {
+            x @ (ComponentType::ActionRow
+            | ComponentType::InputText
+            | ComponentType::Section
+            | ComponentType::TextDisplay
+            | ComponentType::Thumbnail
+            | ComponentType::MediaGallery
+            | ComponentType::File
+            | ComponentType::Separator
+            | ComponentType::Container) => {
                 return Err(D::Error::custom(format_args!(
                     "invalid message component type in this context: {x:?}",
                 )));
@@ -343,4 +351,36 @@ pub struct ComponentInteractionData {
     /// Type and type-specific data of this component interaction.
     #[serde(flatten)]
     pub kind: ComponentInteractionDataKind,
+    /// The users, members, roles and channels chosen in an auto-populated select menu.
+    #[serde(default)]
+    pub resolved: Box<CommandDataResolved>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_menu_resolved() {
+        let data: ComponentInteractionData = json::from_value(json!({
+            "custom_id": "users",
+            "component_type": 5,
+            "values": ["1"],
+            "resolved": {
+                "users": {
+                    "1": {"id": "1", "username": "user", "discriminator": "0", "avatar": null},
+                },
+            },
+        }))
+        .unwrap();
+
+        let ComponentInteractionDataKind::UserSelect {
+            values,
+        } = &data.kind
+        else {
+            panic!("expected a user select, got {:?}", data.kind);
+        };
+        assert_eq!(values, &[UserId::new(1)]);
+        assert_eq!(data.resolved.users[&UserId::new(1)].name, "user");
+    }
 }
@@ -1,9 +1,9 @@
 use serde::de::{Deserialize, Deserializer, Error as DeError};
 use serde::ser::{Serialize, Serializer};
 
-#[cfg(feature = "unstable_discord_api")]
-use super::InstallationContext;
 use super::{CommandInteraction, ComponentInteraction, ModalInteraction, PingInteraction};
+#[cfg(feature = "unstable_discord_api")]
+use super::{InstallationContext, InteractionContext};
 use crate::internal::prelude::*;
 use crate::json::from_value;
 use crate::model::guild::PartialMember;
@@ -108,6 +108,31 @@ impl Interaction {
         }
     }
 
+    /// Gets the owners of the applications that authorized the interaction, such as a guild or
+    /// user.
+    #[cfg(feature = "unstable_discord_api")]
+    #[must_use]
+    pub fn authorizing_integration_owners(&self) -> Option<&AuthorizingIntegrationOwners> {
+        match self {
+            Self::Ping(_) => None,
+            Self::Command(i) | Self::Autocomplete(i) => Some(&i.authorizing_integration_owners),
+            Self::Component(i) => Some(&i.authorizing_integration_owners),
+            Self::Modal(i) => Some(&i.authorizing_integration_owners),
+        }
+    }
+
+    /// Gets the context where the interaction was triggered from, such as a guild or a DM.
+    #[cfg(feature = "unstable_discord_api")]
+    #[must_use]
+    pub fn context(&self) -> Option<InteractionContext> {
+        match self {
+            Self::Ping(_) => None,
+            Self::Command(i) | Self::Autocomplete(i) => i.context,
+            Self::Component(i) => i.context,
+            Self::Modal(i) => i.context,
+        }
+    }
+
     /// Converts this to a [`PingInteraction`]
     #[must_use]
     pub fn ping(self) -> Option<PingInteraction> {
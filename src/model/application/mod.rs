@@ -1,5 +1,7 @@
 //! Models about OAuth2 applications.
 
+use std::collections::HashSet;
+
 mod command;
 pub use command::*;
 mod command_interaction;
@@ -16,6 +18,8 @@ mod oauth;
 pub use oauth::*;
 mod ping_interaction;
 pub use ping_interaction::*;
+mod role_connection;
+pub use role_connection::*;
 
 use super::id::{ApplicationId, GenericId, GuildId, SkuId, UserId};
 use super::misc::ImageHash;
@@ -82,6 +86,30 @@ pub struct CurrentApplicationInfo {
         std::collections::HashMap<InstallationContext, InstallationContextConfig>,
 }
 
+impl CurrentApplicationInfo {
+    /// Returns the Ids of the users who own the application.
+    ///
+    /// If the application belongs to a team, these are the team owner and the members with the
+    /// [`TeamMemberRole::Admin`] role who have accepted their invite. Otherwise, this is the user
+    /// who created the application. Useful for the owners of a framework's configuration.
+    #[must_use]
+    pub fn owner_ids(&self) -> HashSet<UserId> {
+        match &self.team {
+            Some(team) => team
+                .members
+                .iter()
+                .filter(|member| {
+                    member.membership_state == MembershipState::Accepted
+                        && member.role == TeamMemberRole::Admin
+                })
+                .map(|member| member.user.id)
+                .chain(std::iter::once(team.owner_user_id))
+                .collect(),
+            None => self.owner.iter().map(|owner| owner.id).collect(),
+        }
+    }
+}
+
 #[cfg(feature = "unstable_discord_api")]
 enum_number! {
     /// An enum representing the [installation contexts].
@@ -157,6 +185,7 @@ pub struct TeamMember {
     ///
     /// NOTE: Will always be "*" for now.
     #[deprecated = "This field is not sent by the API anymore"]
+    #[serde(default)]
     pub permissions: Vec<String>,
     /// The ID of the team they are a member of.
     pub team_id: GenericId,
@@ -300,3 +329,51 @@ mod team_role_ordering {
         assert_eq!(other("").cmp(&other("")), std::cmp::Ordering::Equal);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn team_owner_ids() {
+        let user =
+            |id: &str| json!({"id": id, "username": "user", "discriminator": "0", "avatar": null});
+        let member = |id: &str, role: &str, state: u8| {
+            json!({
+                "membership_state": state,
+                "team_id": "10",
+                "user": user(id),
+                "role": role,
+            })
+        };
+
+        let info: CurrentApplicationInfo = from_value(json!({
+            "id": "20",
+            "name": "app",
+            "icon": null,
+            "description": "",
+            "bot_public": true,
+            "bot_require_code_grant": false,
+            "owner": user("1"),
+            "verify_key": "key",
+            "team": {
+                "icon": null,
+                "id": "10",
+                "name": "team",
+                "owner_user_id": "1",
+                "members": [
+                    member("1", "admin", 2),
+                    member("2", "admin", 2),
+                    member("3", "developer", 2),
+                    member("4", "admin", 1),
+                ],
+            },
+        }))
+        .unwrap();
+
+        let team = info.team.as_ref().unwrap();
+        assert_eq!(team.members[2].role, TeamMemberRole::Developer);
+        assert_eq!(info.owner_ids(), HashSet::from([UserId::new(1), UserId::new(2)]));
+    }
+}
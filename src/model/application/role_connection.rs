@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "model")]
+use crate::builder::{Builder, CreateRoleConnectionMetadata, EditRoleConnection};
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::id::ApplicationId;
+
+/// A requirement which guilds can configure on a linked role, checked against the
+/// [`ApplicationRoleConnection::metadata`] of a user.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnectionMetadata {
+    /// How the value of the metadata is compared to the one configured by the guild.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// The key of the metadata field, made of `a-z`, `0-9` or `_` characters.
+    pub key: String,
+    /// The name of the metadata field.
+    pub name: String,
+    /// Localizations of the name, with the locale as the key.
+    #[serde(default)]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The description of the metadata field.
+    pub description: String,
+    /// Localizations of the description, with the locale as the key.
+    #[serde(default)]
+    pub description_localizations: Option<HashMap<String, String>>,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationRoleConnectionMetadata {
+    /// Gets the role connection metadata records of the current application.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the application Id of the [`Http`] client is not set.
+    pub async fn get_records(
+        http: impl AsRef<Http>,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        http.as_ref().get_role_connection_metadata_records().await
+    }
+
+    /// Overrides all role connection metadata records of the current application.
+    ///
+    /// **Note**: An application can have at most 5 records.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if invalid data is given, or if the application Id of the
+    /// [`Http`] client is not set.
+    pub async fn set_records(
+        http: impl AsRef<Http>,
+        records: Vec<CreateRoleConnectionMetadata>,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        http.as_ref().edit_role_connection_metadata_records(&records).await
+    }
+}
+
+enum_number! {
+    /// The comparison performed between the metadata value of a user and the one configured by
+    /// the guild.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-type).
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum ApplicationRoleConnectionMetadataType {
+        /// The metadata value (an integer) is less than or equal to the guild's value.
+        #[default]
+        IntegerLessThanOrEqual = 1,
+        /// The metadata value (an integer) is greater than or equal to the guild's value.
+        IntegerGreaterThanOrEqual = 2,
+        /// The metadata value (an integer) is equal to the guild's value.
+        IntegerEqual = 3,
+        /// The metadata value (an integer) is not equal to the guild's value.
+        IntegerNotEqual = 4,
+        /// The metadata value (an ISO8601 timestamp) is less than or equal to the guild's value
+        /// (a number of days before the current date).
+        DatetimeLessThanOrEqual = 5,
+        /// The metadata value (an ISO8601 timestamp) is greater than or equal to the guild's
+        /// value (a number of days before the current date).
+        DatetimeGreaterThanOrEqual = 6,
+        /// The metadata value (an integer of `0` or `1`) is equal to the guild's value.
+        BooleanEqual = 7,
+        /// The metadata value (an integer of `0` or `1`) is not equal to the guild's value.
+        BooleanNotEqual = 8,
+        _ => Unknown(u8),
+    }
+}
+
+/// The role connection of a user for an application, which linked roles are granted from.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#application-role-connection-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnection {
+    /// The vanity name of the platform the application connects to.
+    pub platform_name: Option<String>,
+    /// The username of the user on the platform.
+    pub platform_username: Option<String>,
+    /// The values of the user for the [`ApplicationRoleConnectionMetadata`] keys of the
+    /// application, stringified.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationRoleConnection {
+    /// Gets the role connection of the current user for an application.
+    ///
+    /// **Note**: Requires an OAuth2 access token with the [`Scope::RoleConnectionsWrite`] scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the token lacks the required scope.
+    ///
+    /// [`Scope::RoleConnectionsWrite`]: super::Scope::RoleConnectionsWrite
+    pub async fn get(
+        http: impl AsRef<Http>,
+        application_id: ApplicationId,
+    ) -> Result<ApplicationRoleConnection> {
+        http.as_ref().get_user_application_role_connection(application_id).await
+    }
+
+    /// Updates the role connection of the current user for an application.
+    ///
+    /// **Note**: Requires an OAuth2 access token with the [`Scope::RoleConnectionsWrite`] scope.
+    ///
+    /// # Errors
+    ///
+    /// See [`EditRoleConnection::execute`] for a list of possible errors.
+    ///
+    /// [`Scope::RoleConnectionsWrite`]: super::Scope::RoleConnectionsWrite
+    pub async fn edit(
+        cache_http: impl CacheHttp,
+        application_id: ApplicationId,
+        builder: EditRoleConnection,
+    ) -> Result<ApplicationRoleConnection> {
+        builder.execute(cache_http, application_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn role_connection() {
+        let connection: ApplicationRoleConnection = from_value(json!({
+            "platform_name": "Example",
+            "platform_username": "ferris",
+            "metadata": {"verified": "1"},
+        }))
+        .unwrap();
+        assert_eq!(connection.platform_username.as_deref(), Some("ferris"));
+        assert_eq!(connection.metadata["verified"], "1");
+
+        let value = json!({
+            "type": 7,
+            "key": "verified",
+            "name": "Verified",
+            "name_localizations": null,
+            "description": "Has verified their account",
+            "description_localizations": null,
+        });
+        let metadata: ApplicationRoleConnectionMetadata = from_value(value.clone()).unwrap();
+        assert_eq!(metadata.kind, ApplicationRoleConnectionMetadataType::BooleanEqual);
+        assert_eq!(to_value(&metadata).unwrap(), value);
+    }
+}
@@ -1,7 +1,18 @@
 #[cfg(feature = "model")]
+use std::path::Path;
+
+#[cfg(feature = "model")]
+use bytes::Bytes;
+#[cfg(feature = "model")]
+use futures::{Stream, TryStreamExt};
+#[cfg(feature = "model")]
 use reqwest::Client as ReqwestClient;
 use serde_cow::CowStr;
+#[cfg(feature = "model")]
+use tokio::io::AsyncWriteExt;
 
+#[cfg(feature = "model")]
+use crate::http::Http;
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
@@ -155,4 +166,58 @@ impl Attachment {
         let bytes = reqwest.get(&self.url).send().await?.bytes().await?;
         Ok(bytes.to_vec())
     }
+
+    /// Downloads the attachment as a stream of chunks, so that large files such as videos don't
+    /// have to be held in memory at once. The connection pool of the given [`Http`] is reused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Attachment;
+    /// # async fn run(http: &Http, attachment: &Attachment) -> Result<(), serenity::Error> {
+    /// use serenity::futures::TryStreamExt;
+    ///
+    /// let mut stream = std::pin::pin!(attachment.download_stream(http).await?);
+    /// let mut size = 0;
+    /// while let Some(chunk) = stream.try_next().await? {
+    ///     size += chunk.len();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] when there is a problem retrieving the attachment, or if the
+    /// response is unsuccessful. The stream yields the same error when the download is
+    /// interrupted.
+    pub async fn download_stream(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = http.as_ref().client().get(&self.url).send().await?.error_for_status()?;
+        Ok(response.bytes_stream().map_err(Error::from))
+    }
+
+    /// Downloads the attachment into a file at the given path, creating or truncating it, without
+    /// holding the whole file in memory. Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] when there is a problem retrieving the attachment, or an
+    /// [`Error::Io`] when the file can't be written to.
+    pub async fn download_to(&self, http: impl AsRef<Http>, path: impl AsRef<Path>) -> Result<u64> {
+        let mut stream = std::pin::pin!(self.download_stream(http).await?);
+        let mut file = tokio::fs::File::create(path).await?;
+
+        let mut written = 0;
+        while let Some(chunk) = stream.try_next().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
 }
@@ -66,6 +66,93 @@ impl ChannelId {
         http.as_ref().broadcast_typing(self).await
     }
 
+    /// Marks the channel as read up to and including the given message.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user can't read the channel or the message doesn't
+    /// exist.
+    pub async fn ack_message(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        let map = json!({
+            "token": null,
+        });
+
+        http.as_ref().ack_message(self, message_id.into(), &map).await
+    }
+
+    /// Marks multiple channels as read at once, each up to and including the paired message.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if any of the channels can't be read by the current user.
+    pub async fn ack_messages(
+        http: impl AsRef<Http>,
+        messages: impl IntoIterator<Item = (ChannelId, MessageId)>,
+    ) -> Result<()> {
+        let read_states: Vec<_> = messages
+            .into_iter()
+            .map(|(channel_id, message_id)| {
+                json!({
+                    "channel_id": channel_id,
+                    "message_id": message_id,
+                    "read_state_type": ReadStateType::Channel,
+                })
+            })
+            .collect();
+        let map = json!({
+            "read_states": read_states,
+        });
+
+        http.as_ref().ack_messages(&map).await
+    }
+
+    /// Rings the given recipients of this private channel, starting a call if there is none yet.
+    /// Passing [`None`] rings all recipients.
+    ///
+    /// To take part in the call, the current user must also join the channel's voice connection.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the channel is not a private channel of the current user.
+    pub async fn ring(self, http: impl AsRef<Http>, recipients: Option<&[UserId]>) -> Result<()> {
+        let map = json!({
+            "recipients": recipients,
+        });
+
+        http.as_ref().ring_call(self, &map).await
+    }
+
+    /// Stops ringing the given recipients of the call in this private channel. Passing [`None`]
+    /// stops ringing all recipients, which declines an incoming call when only the current user
+    /// is rung.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if there is no call in the channel.
+    pub async fn stop_ringing(
+        self,
+        http: impl AsRef<Http>,
+        recipients: Option<&[UserId]>,
+    ) -> Result<()> {
+        let map = json!({
+            "recipients": recipients,
+        });
+
+        http.as_ref().stop_ringing_call(self, &map).await
+    }
+
     /// Creates an invite for the given channel.
     ///
     /// **Note**: Requires the [Create Instant Invite] permission.
@@ -163,6 +250,25 @@ impl ChannelId {
         http.as_ref().delete_message(self, message_id.into(), None).await
     }
 
+    /// Deletes a [`Message`] given its Id, attributing the deletion to the given reason in the
+    /// audit log.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::delete_message`] may return an error, may also return
+    /// [`Error::ExceededLimit`] if `reason` is too long.
+    pub async fn delete_message_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        if reason.chars().count() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+        http.as_ref().delete_message(self, message_id.into(), Some(reason)).await
+    }
+
     /// Deletes all messages by Ids from the given vector in the given channel.
     ///
     /// The minimum amount of messages is 2 and the maximum amount is 100.
@@ -183,6 +289,31 @@ impl ChannelId {
         self,
         http: impl AsRef<Http>,
         message_ids: impl IntoIterator<Item = T>,
+    ) -> Result<()> {
+        self._delete_messages(http.as_ref(), message_ids, None).await
+    }
+
+    /// Deletes all messages by Ids from the given vector in the given channel, attributing the
+    /// deletion to the given reason in the audit log.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::delete_messages`] may return an error, may also return
+    /// [`Error::ExceededLimit`] if `reason` is too long.
+    pub async fn delete_messages_with_reason<T: AsRef<MessageId>>(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: impl IntoIterator<Item = T>,
+        reason: &str,
+    ) -> Result<()> {
+        self._delete_messages(http.as_ref(), message_ids, Some(reason)).await
+    }
+
+    async fn _delete_messages<T: AsRef<MessageId>>(
+        self,
+        http: &Http,
+        message_ids: impl IntoIterator<Item = T>,
+        reason: Option<&str>,
     ) -> Result<()> {
         let ids =
             message_ids.into_iter().map(|message_id| *message_id.as_ref()).collect::<Vec<_>>();
@@ -192,13 +323,18 @@ impl ChannelId {
         if len == 0 || len > 100 {
             return Err(Error::Model(ModelError::BulkDeleteAmount));
         }
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
 
         if ids.len() == 1 {
-            self.delete_message(http, ids[0]).await
+            http.delete_message(self, ids[0], reason).await
         } else {
             let map = json!({ "messages": ids });
 
-            http.as_ref().delete_messages(self, &map, None).await
+            http.delete_messages(self, &map, reason).await
         }
     }
 
@@ -742,6 +878,36 @@ impl ChannelId {
         builder.execute(cache_http, (self, None)).await
     }
 
+    /// Plays a soundboard sound in the voice channel.
+    ///
+    /// `source_guild_id` must be given when playing a sound from another guild, and is not
+    /// needed for default sounds or sounds of the channel's own guild.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, as well as [Use External
+    /// Sounds] for sounds from other guilds. The current user must be connected to the channel
+    /// and must not be muted, deafened or suppressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission or is not connected to the
+    /// channel.
+    ///
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    /// [Use External Sounds]: Permissions::USE_EXTERNAL_SOUNDS
+    pub async fn send_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+        source_guild_id: Option<GuildId>,
+    ) -> Result<()> {
+        let map = json!({
+            "sound_id": sound_id.into(),
+            "source_guild_id": source_guild_id,
+        });
+        http.as_ref().send_soundboard_sound(self, &map).await
+    }
+
     /// Starts typing in the channel for an indefinite period of time.
     ///
     /// Returns [`Typing`] that is used to trigger the typing. [`Typing::stop`] must be called on
@@ -940,7 +1106,7 @@ impl ChannelId {
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
-    #[doc(alias = "create_public_thread", alias = "create_private_thread")]
+    #[doc(alias = "create_public_thread")]
     pub async fn create_thread(
         self,
         cache_http: impl CacheHttp,
@@ -949,6 +1115,22 @@ impl ChannelId {
         builder.execute(cache_http, (self, None)).await
     }
 
+    /// Creates a private thread, which is only visible to its members and to members with the
+    /// [Manage Threads] permission. Overrides the [`CreateThread::kind`] of the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    pub async fn create_private_thread(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateThread<'_>,
+    ) -> Result<GuildChannel> {
+        self.create_thread(cache_http, builder.kind(ChannelType::PrivateThread)).await
+    }
+
     /// Creates a post in a forum channel.
     ///
     /// # Errors
@@ -1025,6 +1207,10 @@ impl ChannelId {
 
     /// Gets private archived threads of a channel.
     ///
+    /// Threads are returned newest first, up to `limit` at a time. To fetch the next page, pass
+    /// the archive timestamp of the last returned thread as `before`, for as long as
+    /// [`ThreadsData::has_more`] is `true`.
+    ///
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the bot doesn't have the permission to get it.
@@ -1039,6 +1225,10 @@ impl ChannelId {
 
     /// Gets public archived threads of a channel.
     ///
+    /// Threads are returned newest first, up to `limit` at a time. To fetch the next page, pass
+    /// the archive timestamp of the last returned thread as `before`, for as long as
+    /// [`ThreadsData::has_more`] is `true`.
+    ///
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the bot doesn't have the permission to get it.
@@ -1053,6 +1243,10 @@ impl ChannelId {
 
     /// Gets private archived threads joined by the current user of a channel.
     ///
+    /// Threads are returned newest first, up to `limit` at a time. To fetch the next page, pass
+    /// the id of the last returned thread as `before`, for as long as [`ThreadsData::has_more`]
+    /// is `true`.
+    ///
     /// # Errors
     ///
     /// It may return an [`Error::Http`] if the bot doesn't have the permission to get it.
@@ -625,6 +625,56 @@ impl GuildChannel {
         builder.execute(cache_http, (self.guild_id, self.id, None)).await
     }
 
+    /// Sends or clears a request to speak for the current user in a stage channel.
+    ///
+    /// **Note**: Requires the [Request to Speak] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::edit_own_voice_state`].
+    ///
+    /// [Request to Speak]: Permissions::REQUEST_TO_SPEAK
+    pub async fn request_to_speak(&self, cache_http: impl CacheHttp, request: bool) -> Result<()> {
+        let builder = EditVoiceState::new().request_to_speak(request);
+        self.edit_own_voice_state(cache_http, builder).await
+    }
+
+    /// Invites a user in a stage channel to speak, i.e. unsuppresses them.
+    ///
+    /// **Note**: Requires the [Mute Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::edit_voice_state`].
+    ///
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    pub async fn invite_to_speak(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        let builder = EditVoiceState::new().suppress(false);
+        self.edit_voice_state(cache_http, user_id, builder).await
+    }
+
+    /// Moves a speaker in a stage channel back to the audience, i.e. suppresses them.
+    ///
+    /// **Note**: Requires the [Mute Members] permission, unless moving the current user.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::edit_voice_state`].
+    ///
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    pub async fn move_to_audience(
+        &self,
+        cache_http: impl CacheHttp,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        let builder = EditVoiceState::new().suppress(true);
+        self.edit_voice_state(cache_http, user_id, builder).await
+    }
+
     /// Follows the News Channel
     ///
     /// Requires [Manage Webhook] permissions on the target channel.
@@ -651,6 +701,15 @@ impl GuildChannel {
         cache.as_ref().guild(self.guild_id)
     }
 
+    /// Returns the active threads of this channel from the cache.
+    ///
+    /// See [`Cache::channel_threads`] for which threads are known.
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub fn threads(&self, cache: impl AsRef<Cache>) -> Vec<GuildChannel> {
+        cache.as_ref().channel_threads(self.id)
+    }
+
     /// Gets all of the channel's invites.
     ///
     /// Requires the [Manage Channels] permission.
@@ -1069,9 +1128,9 @@ impl GuildChannel {
         cache_http: impl CacheHttp,
         builder: CreateWebhook<'_>,
     ) -> Result<Webhook> {
-        // forum channels are not text-based, but webhooks can be created in them
+        // forum and media channels are not text-based, but webhooks can be created in them
         // and used to send messages in their posts
-        if !self.is_text_based() && self.kind != ChannelType::Forum {
+        if !self.is_text_based() && !matches!(self.kind, ChannelType::Forum | ChannelType::Media) {
             return Err(Error::Model(ModelError::InvalidChannelType));
         }
 
@@ -1174,6 +1233,24 @@ impl GuildChannel {
         self.id.create_thread(cache_http, builder).await
     }
 
+    /// Creates a private thread, which is only visible to its members and to members with the
+    /// [Manage Threads] permission.
+    ///
+    /// Refer to [`ChannelId::create_private_thread`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    pub async fn create_private_thread(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateThread<'_>,
+    ) -> Result<GuildChannel> {
+        self.id.create_private_thread(cache_http, builder).await
+    }
+
     /// Creates a post in a forum channel.
     ///
     /// # Errors
@@ -12,6 +12,7 @@ use crate::cache::{Cache, GuildRef};
 #[cfg(feature = "collector")]
 use crate::collector::{
     ComponentInteractionCollector,
+    MessageCollector,
     ModalInteractionCollector,
     ReactionCollector,
 };
@@ -102,6 +103,10 @@ pub struct Message {
     pub application_id: Option<ApplicationId>,
     /// Reference data sent with crossposted messages.
     pub message_reference: Option<MessageReference>,
+    /// The contents of the forwarded message, if this message is a forward, i.e. its
+    /// [`Self::message_reference`] is of kind [`MessageReferenceKind::Forward`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_snapshots: Vec<MessageSnapshot>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
@@ -120,7 +125,7 @@ pub struct Message {
     pub thread: Option<GuildChannel>,
     /// The components of this message
     #[serde(default)]
-    pub components: Vec<ActionRow>,
+    pub components: Vec<Component>,
     /// Array of message sticker item objects.
     #[serde(default)]
     pub sticker_items: Vec<StickerItem>,
@@ -232,6 +237,26 @@ impl Message {
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._delete(cache_http, None).await
+    }
+
+    /// Deletes the message, attributing the deletion to the given reason in the audit log.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::delete`] may return an error, may also return
+    /// [`Error::ExceededLimit`] if `reason` is too long.
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._delete(cache_http, Some(reason)).await
+    }
+
+    async fn _delete(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
+
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -245,7 +270,7 @@ impl Message {
             }
         }
 
-        self.channel_id.delete_message(cache_http.http(), self.id).await
+        cache_http.http().delete_message(self.channel_id, self.id, reason).await
     }
 
     /// Deletes all of the [`Reaction`]s associated with the message.
@@ -563,6 +588,27 @@ impl Message {
         self._react(cache_http, reaction_type.into(), true).await
     }
 
+    /// React to the message with a custom [`Emoji`] or unicode character, sending either a normal
+    /// or a super reaction depending on `kind`.
+    ///
+    /// See [`Self::react`] and [`Self::super_react`] for the required permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// does not have the required [permissions].
+    ///
+    /// [permissions]: crate::model::permissions
+    #[inline]
+    pub async fn react_burst(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionTypes,
+    ) -> Result<Reaction> {
+        self._react(cache_http, reaction_type.into(), kind == ReactionTypes::Burst).await
+    }
+
     async fn _react(
         &self,
         cache_http: impl CacheHttp,
@@ -731,6 +777,31 @@ impl Message {
         self.channel_id.send_message(cache_http, builder).await
     }
 
+    /// Forwards the message to the given channel. The contents of the message are copied into the
+    /// forward, see [`Message::message_snapshots`].
+    ///
+    /// **Note**: Requires the [Send Messages] permission in the target channel.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// does not have the required permissions. Otherwise returns [`Error::Http`] if the message
+    /// can't be forwarded, e.g. because it is a system message.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    pub async fn forward(
+        &self,
+        cache_http: impl CacheHttp,
+        target_channel_id: impl Into<ChannelId>,
+    ) -> Result<Message> {
+        let reference = MessageReference {
+            kind: MessageReferenceKind::Forward,
+            ..self.into()
+        };
+        let builder = CreateMessage::new().reference_message(reference);
+        target_channel_id.into().send_message(cache_http, builder).await
+    }
+
     /// Checks whether the message mentions passed [`UserId`].
     #[inline]
     pub fn mentions_user_id(&self, id: impl Into<UserId>) -> bool {
@@ -790,6 +861,21 @@ impl Message {
         cache_http.http().unpin_message(self.channel_id, self.id, None).await
     }
 
+    /// Gets a list of users that voted for the given answer of the [`Poll`] on this message.
+    ///
+    /// # Errors
+    ///
+    /// See [`ChannelId::get_poll_answer_voters`] for more information.
+    pub async fn get_poll_answer_voters(
+        &self,
+        http: impl AsRef<Http>,
+        answer_id: AnswerId,
+        after: Option<UserId>,
+        limit: Option<u8>,
+    ) -> Result<Vec<User>> {
+        self.channel_id.get_poll_answer_voters(http, self.id, answer_id, after, limit).await
+    }
+
     /// Ends the [`Poll`] on this message, if there is one.
     ///
     /// # Errors
@@ -823,8 +909,27 @@ impl Message {
         self.id.link_ensured(cache_http, self.channel_id, self.guild_id).await
     }
 
+    /// Returns a builder which can be awaited to obtain the next message or stream of messages
+    /// sent by the author of this message, in the same channel.
+    ///
+    /// This is useful for interactive commands, which ask the user a question and wait for the
+    /// answer.
+    #[cfg(feature = "collector")]
+    pub fn await_reply(&self, shard_messenger: impl AsRef<ShardMessenger>) -> MessageCollector {
+        MessageCollector::new(shard_messenger).channel_id(self.channel_id).author_id(self.author.id)
+    }
+
+    /// Same as [`Self::await_reply`].
+    #[cfg(feature = "collector")]
+    pub fn await_replies(&self, shard_messenger: impl AsRef<ShardMessenger>) -> MessageCollector {
+        self.await_reply(shard_messenger)
+    }
+
     /// Returns a builder which can be awaited to obtain a reaction or stream of reactions on this
     /// message.
+    ///
+    /// Only added reactions are collected by default, use [`ReactionCollector::removed`] to
+    /// collect removed ones as well.
     #[cfg(feature = "collector")]
     pub fn await_reaction(&self, shard_messenger: impl AsRef<ShardMessenger>) -> ReactionCollector {
         ReactionCollector::new(shard_messenger).message_id(self.id)
@@ -841,6 +946,29 @@ impl Message {
 
     /// Returns a builder which can be awaited to obtain a single component interactions or a
     /// stream of component interactions on this message.
+    ///
+    /// # Examples
+    ///
+    /// Waiting up to a minute for a click on a confirmation button:
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use serenity::builder::CreateInteractionResponse;
+    /// # use serenity::model::prelude::*;
+    /// # use serenity::prelude::*;
+    /// # async fn run(ctx: Context, msg: Message) -> Result<(), serenity::Error> {
+    /// let interaction = msg
+    ///     .await_component_interaction(&ctx)
+    ///     .custom_id("confirm".to_string())
+    ///     .timeout(Duration::from_secs(60))
+    ///     .await;
+    ///
+    /// if let Some(interaction) = interaction {
+    ///     interaction.create_response(&ctx, CreateInteractionResponse::Acknowledge).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     #[cfg(feature = "collector")]
     pub fn await_component_interaction(
         &self,
@@ -946,10 +1074,39 @@ pub struct MessageReaction {
     /// The type of reaction.
     #[serde(rename = "emoji")]
     pub reaction_type: ReactionType,
-    // The colours used for super reactions.
+    /// The colours used for super reactions.
+    #[serde(
+        rename = "burst_colors",
+        default,
+        deserialize_with = "burst_colours",
+        serialize_with = "serialize_burst_colours"
+    )]
     pub burst_colours: Vec<Colour>,
 }
 
+impl MessageReaction {
+    /// Returns the amount of reactions of the given kind.
+    #[must_use]
+    pub fn count_of(&self, kind: ReactionTypes) -> u64 {
+        match kind {
+            ReactionTypes::Normal => self.count_details.normal,
+            ReactionTypes::Burst => self.count_details.burst,
+            _ => 0,
+        }
+    }
+}
+
+fn burst_colours<'de, D: Deserializer<'de>>(deserializer: D) -> StdResult<Vec<Colour>, D::Error> {
+    super::reaction::discord_colours(deserializer).map(Option::unwrap_or_default)
+}
+
+fn serialize_burst_colours<S: serde::Serializer>(
+    colours: &[Colour],
+    serializer: S,
+) -> StdResult<S::Ok, S::Error> {
+    serializer.collect_seq(colours.iter().map(|colour| format!("#{}", colour.hex())))
+}
+
 /// A representation of reaction count details.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#reaction-count-details-object).
@@ -1086,6 +1243,9 @@ pub struct MessageActivity {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
+    /// The kind of the reference.
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceKind,
     /// ID of the originating message.
     pub message_id: Option<MessageId>,
     /// ID of the originating message's channel.
@@ -1100,6 +1260,7 @@ pub struct MessageReference {
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
@@ -1111,6 +1272,7 @@ impl From<&Message> for MessageReference {
 impl From<(ChannelId, MessageId)> for MessageReference {
     fn from(pair: (ChannelId, MessageId)) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
@@ -1119,6 +1281,73 @@ impl From<(ChannelId, MessageId)> for MessageReference {
     }
 }
 
+enum_number! {
+    /// The kind of a [`MessageReference`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/message#message-reference-types).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum MessageReferenceKind {
+        /// A reply to, or crosspost of, the referenced message.
+        #[default]
+        Default = 0,
+        /// A forward of the referenced message, whose contents are in
+        /// [`Message::message_snapshots`].
+        Forward = 1,
+        _ => Unknown(u8),
+    }
+}
+
+/// A copy of a forwarded message, taken when it was forwarded.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/message#message-snapshot-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageSnapshot {
+    /// The forwarded message.
+    pub message: ForwardedMessage,
+}
+
+/// The subset of a [`Message`]'s fields kept in a [`MessageSnapshot`].
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForwardedMessage {
+    /// Indicator of the type of the message.
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// The content of the message.
+    #[serde(default)]
+    pub content: String,
+    /// An vector of embeds in the message.
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// An vector of the files attached to the message.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Initial message creation timestamp, calculated from its Id.
+    pub timestamp: Timestamp,
+    /// The timestamp of the last time the message was updated, if it was.
+    pub edited_timestamp: Option<Timestamp>,
+    /// Bit flags describing extra features of the message.
+    pub flags: Option<MessageFlags>,
+    /// Array of users mentioned in the message.
+    #[serde(default)]
+    pub mentions: Vec<User>,
+    /// Array of [`Role`]s' Ids mentioned in the message.
+    #[serde(default)]
+    pub mention_roles: Vec<RoleId>,
+    /// Array of message sticker item objects.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
+    /// The components of the message.
+    #[serde(default)]
+    pub components: Vec<Component>,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-mention-object).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1175,6 +1404,11 @@ bitflags! {
         /// As of 2023-04-20, bots are currently not able to send voice messages
         /// ([source](https://github.com/discord/discord-api-docs/pull/6082)).
         const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message uses the layout components, such as [`Container`] and [`Section`],
+        /// instead of content and embeds.
+        ///
+        /// Once set, the flag can't be removed from the message.
+        const IS_COMPONENTS_V2 = 1 << 15;
     }
 }
 
@@ -1366,3 +1600,73 @@ pub struct PollAnswerCount {
     pub count: u64,
     pub me_voted: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn forwarded_message() {
+        let message: Message = from_value(json!({
+            "id": "3",
+            "channel_id": "2",
+            "author": {"id": "1", "username": "user", "discriminator": "0", "avatar": null},
+            "content": "",
+            "timestamp": "2024-10-01T00:00:00+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "message_reference": {"type": 1, "channel_id": "4", "message_id": "5"},
+            "message_snapshots": [{
+                "message": {
+                    "type": 0,
+                    "content": "forwarded",
+                    "embeds": [],
+                    "attachments": [],
+                    "timestamp": "2024-09-30T00:00:00+00:00",
+                    "edited_timestamp": null,
+                    "flags": 0,
+                    "mentions": [],
+                    "mention_roles": [],
+                },
+            }],
+        }))
+        .unwrap();
+
+        let reference = message.message_reference.unwrap();
+        assert_eq!(reference.kind, MessageReferenceKind::Forward);
+        assert_eq!(reference.message_id, Some(MessageId::new(5)));
+        assert_eq!(message.message_snapshots[0].message.content, "forwarded");
+    }
+
+    #[test]
+    fn burst_reaction() {
+        let reaction: MessageReaction = from_value(json!({
+            "emoji": {"id": null, "name": "\u{1f525}"},
+            "count": 3,
+            "count_details": {"burst": 1, "normal": 2},
+            "burst_colors": ["#ff4500", "#ffd700"],
+            "me_burst": true,
+            "burst_me": true,
+            "me": false,
+            "burst_count": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(reaction.burst_colours, vec![Colour(0xFF4500), Colour(0xFFD700)]);
+        assert_eq!(reaction.count_of(ReactionTypes::Burst), 1);
+        assert_eq!(reaction.count_of(ReactionTypes::Normal), 2);
+
+        let value = to_value(&reaction).unwrap();
+        assert_eq!(value["burst_colors"], json!(["#FF4500", "#FFD700"]));
+        let reaction: MessageReaction = from_value(value).unwrap();
+        assert_eq!(reaction.burst_colours, vec![Colour(0xFF4500), Colour(0xFFD700)]);
+    }
+}
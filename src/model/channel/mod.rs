@@ -8,6 +8,7 @@ mod message;
 mod partial_channel;
 mod private_channel;
 mod reaction;
+mod read_state;
 
 use std::fmt;
 
@@ -21,6 +22,7 @@ pub use self::message::*;
 pub use self::partial_channel::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
+pub use self::read_state::*;
 #[cfg(feature = "model")]
 use crate::http::CacheHttp;
 use crate::json::*;
@@ -191,7 +193,9 @@ impl<'de> Deserialize<'de> for Channel {
 
         let value = Value::from(map);
         match kind {
-            0 | 2 | 4 | 5 | 10 | 11 | 12 | 13 | 14 | 15 => from_value(value).map(Channel::Guild),
+            0 | 2 | 4 | 5 | 10 | 11 | 12 | 13 | 14 | 15 | 16 => {
+                from_value(value).map(Channel::Guild)
+            },
             1 => from_value(value).map(Channel::Private),
             _ => return Err(DeError::custom("Unknown channel type")),
         }
@@ -252,6 +256,8 @@ enum_number! {
         Directory = 14,
         /// An indicator that the channel is a forum [`GuildChannel`].
         Forum = 15,
+        /// An indicator that the channel is a media [`GuildChannel`].
+        Media = 16,
         _ => Unknown(u8),
     }
 }
@@ -273,6 +279,7 @@ impl ChannelType {
             Self::Stage => "stage",
             Self::Directory => "directory",
             Self::Forum => "forum",
+            Self::Media => "media",
             Self::Unknown(_) => "unknown",
         }
     }
@@ -488,7 +495,7 @@ pub struct ThreadsData {
 /// See [Discord](https://discord.com/developers/docs/resources/channel#default-reaction-object)
 /// [docs]()
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ForumEmoji {
     /// The id of a guild's custom emoji.
@@ -539,7 +546,7 @@ impl<'de> serde::Deserialize<'de> for ForumEmoji {
 ///
 /// See [Discord docs](https://discord.com/developers/docs/resources/channel#forum-tag-object)
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct ForumTag {
     /// The id of the tag.
@@ -583,5 +590,8 @@ bitflags! {
         /// Whether a tag is required to be specified when creating a
         /// thread in a GUILD_FORUM channel. Tags are specified in the applied_tags field.
         const REQUIRE_TAG = 1 << 4;
+        /// Whether the embedded media download options are hidden for the media in a
+        /// GUILD_MEDIA channel.
+        const HIDE_MEDIA_DOWNLOAD_OPTIONS = 1 << 15;
     }
 }
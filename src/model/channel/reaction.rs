@@ -88,7 +88,7 @@ impl Serialize for Reaction {
     }
 }
 
-fn discord_colours<'de, D>(deserializer: D) -> Result<Option<Vec<Colour>>, D::Error>
+pub(super) fn discord_colours<'de, D>(deserializer: D) -> Result<Option<Vec<Colour>>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -305,10 +305,11 @@ impl Reaction {
         }
 
         http.as_ref()
-            .get_reaction_users(
+            .get_reaction_users_by_type(
                 self.channel_id,
                 self.message_id,
                 reaction_type,
+                self.reaction_type,
                 limit,
                 after.map(UserId::get),
             )
@@ -0,0 +1,112 @@
+use crate::model::prelude::*;
+
+/// The read states of the current user, received in [`Ready::read_state`].
+///
+/// Only sent to user accounts.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ReadStates {
+    /// The version of the read states, incremented whenever they change.
+    #[serde(default)]
+    pub version: u64,
+    /// Whether only some of the read states were sent.
+    #[serde(default)]
+    pub partial: bool,
+    /// The read state of each channel the current user has read.
+    #[serde(default)]
+    pub entries: Vec<ReadState>,
+}
+
+impl ReadStates {
+    /// Returns the read state of the given channel, if any.
+    #[must_use]
+    pub fn get(&self, channel_id: ChannelId) -> Option<&ReadState> {
+        self.entries.iter().find(|e| e.kind == ReadStateType::Channel && e.id == channel_id)
+    }
+
+    /// Returns the total amount of unacknowledged mentions across all channels.
+    #[must_use]
+    pub fn mention_count(&self) -> u64 {
+        self.entries.iter().map(|e| e.mention_count).sum()
+    }
+}
+
+/// How far the current user has read a channel.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ReadState {
+    /// The Id of the channel.
+    ///
+    /// For read states that aren't of kind [`ReadStateType::Channel`], this is the Id of the
+    /// related resource instead, e.g. a guild.
+    pub id: ChannelId,
+    /// The kind of the read state.
+    #[serde(rename = "read_state_type", default)]
+    pub kind: ReadStateType,
+    /// The Id of the last acknowledged message.
+    pub last_message_id: Option<MessageId>,
+    /// The amount of mentions of the current user since the last acknowledged message.
+    #[serde(default)]
+    pub mention_count: u64,
+    /// When a message was last pinned in the channel, as seen by the current user.
+    pub last_pin_timestamp: Option<Timestamp>,
+}
+
+impl ReadState {
+    /// Whether the channel has messages newer than the last acknowledged message, given the Id of
+    /// the channel's last message, e.g. [`GuildChannel::last_message_id`].
+    #[must_use]
+    pub fn is_unread(&self, last_message_id: Option<MessageId>) -> bool {
+        match (last_message_id, self.last_message_id) {
+            (Some(last), Some(acked)) => last > acked,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+enum_number! {
+    /// The kind of a [`ReadState`].
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum ReadStateType {
+        #[default]
+        Channel = 0,
+        GuildScheduledEvent = 1,
+        NotificationCenter = 2,
+        GuildHome = 3,
+        GuildOnboardingQuestion = 4,
+        _ => Unknown(u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn unread() {
+        let read_states: ReadStates = from_value(json!({
+            "version": 12,
+            "partial": false,
+            "entries": [
+                {"id": "1", "last_message_id": "10", "mention_count": 2},
+                {"id": "2", "last_message_id": "20", "mention_count": 1, "read_state_type": 3},
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(read_states.mention_count(), 3);
+        assert!(read_states.get(ChannelId::new(2)).is_none());
+
+        let read_state = read_states.get(ChannelId::new(1)).unwrap();
+        assert!(read_state.is_unread(Some(MessageId::new(11))));
+        assert!(!read_state.is_unread(Some(MessageId::new(10))));
+        assert!(!read_state.is_unread(None));
+    }
+}
@@ -24,14 +24,19 @@ pub struct Connection {
     #[serde(default)]
     pub integrations: Vec<Integration>,
     /// Whether this connection has been verified and the user has proven they own the account.
+    #[serde(default)]
     pub verified: bool,
     /// Whether friend sync is enabled for this connection.
+    #[serde(default)]
     pub friend_sync: bool,
     /// Whether activities related to this connection will be shown in presence updates.
+    #[serde(default)]
     pub show_activity: bool,
     /// Whether this connection has a corresponding third party OAuth2 token.
+    #[serde(default)]
     pub two_way_link: bool,
     /// The visibility of this connection.
+    #[serde(default)]
     pub visibility: ConnectionVisibility,
 }
 
@@ -39,14 +44,40 @@ enum_number! {
     /// The visibility of a user connection on a user's profile.
     ///
     /// [Discord docs](https://discord.com/developers/docs/resources/user#connection-object-visibility-types).
-    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
     #[serde(from = "u8", into = "u8")]
     #[non_exhaustive]
     pub enum ConnectionVisibility {
         /// Invisible to everyone except the user themselves
+        #[default]
         None = 0,
         /// Visible to everyone
         Everyone = 1,
         _ => Unknown(u8),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn user_account_connection() {
+        // User accounts don't receive all fields documented for OAuth2 tokens.
+        let connection: Connection = from_value(json!({
+            "type": "spotify",
+            "id": "abc",
+            "name": "someone",
+            "verified": true,
+            "visibility": 1,
+            "show_activity": true,
+        }))
+        .unwrap();
+
+        assert_eq!(connection.kind, "spotify");
+        assert_eq!(connection.visibility, ConnectionVisibility::Everyone);
+        assert!(!connection.friend_sync);
+        assert!(connection.integrations.is_empty());
+    }
+}
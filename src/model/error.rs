@@ -151,6 +151,22 @@ pub enum Error {
     StickerAmount,
     /// When attempting to edit a voice message.
     CannotEditVoiceMessage,
+    /// When attempting to send a voice message which doesn't consist of exactly one voice message
+    /// attachment, or which has content, embeds, stickers or a poll.
+    InvalidVoiceMessage,
+    /// When attempting to enable onboarding with fewer than 7 default channels. The number of
+    /// channels counted is provided.
+    OnboardingDefaultChannelAmount(usize),
+    /// When attempting to send a message with [`MessageFlags::IS_COMPONENTS_V2`] which has
+    /// content, embeds, stickers or a poll.
+    ///
+    /// [`MessageFlags::IS_COMPONENTS_V2`]: super::channel::MessageFlags::IS_COMPONENTS_V2
+    InvalidComponentsV2,
+    /// When attempting to create or edit a scheduled event with a recurrence rule Discord doesn't
+    /// support. See [`ScheduledEventRecurrenceRule`] for the supported rules.
+    ///
+    /// [`ScheduledEventRecurrenceRule`]: super::guild::ScheduledEventRecurrenceRule
+    InvalidRecurrenceRule,
 }
 
 impl Error {
@@ -199,6 +215,16 @@ impl fmt::Display for Error {
             Self::NoStickerFileSet => f.write_str("Sticker file is not set."),
             Self::StickerAmount => f.write_str("Too many stickers in a message."),
             Self::CannotEditVoiceMessage => f.write_str("Cannot edit voice message."),
+            Self::InvalidVoiceMessage => f.write_str("Invalid voice message."),
+            Self::OnboardingDefaultChannelAmount(_) => {
+                f.write_str("Too few default channels to enable onboarding.")
+            },
+            Self::InvalidComponentsV2 => f.write_str(
+                "Messages with layout components can't have content, embeds, stickers or a poll.",
+            ),
+            Self::InvalidRecurrenceRule => {
+                f.write_str("Unsupported scheduled event recurrence rule.")
+            },
         }
     }
 }
@@ -75,6 +75,42 @@ pub struct AutoModActionExecutionEvent {
     pub execution: ActionExecution,
 }
 
+/// Sent to user accounts when a call is started in a private channel.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct CallCreateEvent {
+    pub call: Call,
+}
+
+/// Sent to user accounts when a call changes, e.g. users start or stop being rung.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct CallUpdateEvent {
+    pub call: Call,
+}
+
+/// Sent to user accounts when a call ends.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CallDeleteEvent {
+    /// The Id of the private channel the call took place in.
+    pub channel_id: ChannelId,
+    /// Whether the call only became unavailable due to an outage, rather than ending.
+    #[serde(default)]
+    pub unavailable: bool,
+}
+
 /// Event data for the channel creation event.
 ///
 /// This is fired when:
@@ -261,6 +297,8 @@ pub struct GuildMemberUpdateEvent {
     pub avatar: Option<ImageHash>,
     pub communication_disabled_until: Option<Timestamp>,
     pub unusual_dm_activity_until: Option<Timestamp>,
+    #[serde(default)]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
 }
 
 /// Requires no gateway intents.
@@ -437,6 +475,31 @@ pub struct GuildUpdateEvent {
     pub guild: PartialGuild,
 }
 
+/// Sent to user accounts when a channel is acknowledged, i.e. marked as read up to a message, by
+/// any of the current user's sessions.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageAckEvent {
+    /// The Id of the acknowledged channel.
+    pub channel_id: ChannelId,
+    /// The Id of the last acknowledged message.
+    pub message_id: MessageId,
+    /// The version of the read state, incremented on every acknowledgement.
+    #[serde(default)]
+    pub version: u64,
+    /// Whether the channel was manually marked as unread, in which case [`Self::message_id`] is
+    /// the message before the first unread one.
+    #[serde(default)]
+    pub manual: bool,
+    /// The amount of mentions of the current user remaining after the acknowledged message.
+    ///
+    /// Usually only sent for [manual](Self::manual) acknowledgements.
+    pub mention_count: Option<u64>,
+}
+
 /// Requires [`GatewayIntents::GUILD_MESSAGES`] or [`GatewayIntents::DIRECT_MESSAGES`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-create).
@@ -535,7 +598,7 @@ pub struct MessageUpdateEvent {
     pub interaction_metadata: Option<Option<Box<MessageInteractionMetadata>>>,
     #[serde(default, deserialize_with = "deserialize_some")]
     pub thread: Option<Option<GuildChannel>>,
-    pub components: Option<Vec<ActionRow>>,
+    pub components: Option<Vec<Component>>,
     pub sticker_items: Option<Vec<StickerItem>>,
     pub position: Option<Option<u64>>,
     pub role_subscription_data: Option<Option<RoleSubscriptionData>>,
@@ -713,6 +776,41 @@ pub struct ReadyEvent {
     pub ready: Ready,
 }
 
+/// Sent to user accounts when a friend is added, a user is blocked or a friend request is sent or
+/// received.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct RelationshipAddEvent {
+    pub relationship: Relationship,
+}
+
+/// Sent to user accounts when a relationship is removed, e.g. a friend is removed or a friend
+/// request is declined.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct RelationshipRemoveEvent {
+    pub relationship: Relationship,
+}
+
+/// Sent to user accounts when a relationship changes, e.g. the nickname of a friend is changed.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct RelationshipUpdateEvent {
+    pub relationship: Relationship,
+}
+
 /// Requires no gateway intents.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#resumed).
@@ -763,6 +861,70 @@ pub struct UserUpdateEvent {
     pub current_user: CurrentUser,
 }
 
+/// Sent to user accounts when their settings change, containing only the changed settings.
+///
+/// Requires no gateway intents.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UserSettingsUpdateEvent {
+    pub theme: Option<Theme>,
+    pub locale: Option<String>,
+    pub status: Option<OnlineStatus>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub custom_status: Option<Option<CustomStatus>>,
+    pub default_guilds_restricted: Option<bool>,
+    pub restricted_guilds: Option<Vec<GuildId>>,
+    pub guild_positions: Option<Vec<GuildId>>,
+    pub guild_folders: Option<Vec<GuildFolder>>,
+    pub developer_mode: Option<bool>,
+}
+
+impl UserSettingsUpdateEvent {
+    /// Writes the changed settings in this event into the given [`UserSettings`].
+    pub fn apply_to_settings(&self, settings: &mut UserSettings) {
+        let Self {
+            theme,
+            locale,
+            status,
+            custom_status,
+            default_guilds_restricted,
+            restricted_guilds,
+            guild_positions,
+            guild_folders,
+            developer_mode,
+        } = self;
+
+        if let Some(x) = theme {
+            settings.theme = x.clone();
+        }
+        if let Some(x) = locale {
+            settings.locale.clone_from(x);
+        }
+        if let Some(x) = status {
+            settings.status = *x;
+        }
+        if let Some(x) = custom_status {
+            settings.custom_status.clone_from(x);
+        }
+        if let Some(x) = default_guilds_restricted {
+            settings.default_guilds_restricted = *x;
+        }
+        if let Some(x) = restricted_guilds {
+            settings.restricted_guilds.clone_from(x);
+        }
+        if let Some(x) = guild_positions {
+            settings.guild_positions.clone_from(x);
+        }
+        if let Some(x) = guild_folders {
+            settings.guild_folders.clone_from(x);
+        }
+        if let Some(x) = developer_mode {
+            settings.developer_mode = *x;
+        }
+    }
+}
+
 /// Requires no gateway intents.
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#voice-server-update).
@@ -1035,6 +1197,50 @@ pub struct GuildScheduledEventUserRemoveEvent {
     pub guild_id: GuildId,
 }
 
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-create).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundCreateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundUpdateEvent {
+    pub sound: SoundboardSound,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sound-delete).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundDeleteEvent {
+    pub sound_id: SoundId,
+    pub guild_id: GuildId,
+}
+
+/// Requires [`GatewayIntents::GUILD_EMOJIS_AND_STICKERS`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-soundboard-sounds-update).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSoundboardSoundsUpdateEvent {
+    pub soundboard_sounds: Vec<SoundboardSound>,
+    pub guild_id: GuildId,
+}
+
 /// Requires no gateway intents.
 ///
 /// [Discord docs](https://discord.com/developers/docs/monetization/entitlements#new-entitlement)
@@ -1195,6 +1401,24 @@ pub enum Event {
     /// crate::client::EventHandler::auto_moderation_action_execution
     #[serde(rename = "AUTO_MODERATION_ACTION_EXECUTION")]
     AutoModActionExecution(AutoModActionExecutionEvent),
+    /// A call was started in a private channel.
+    ///
+    /// Fires the [`EventHandler::call_create`] event.
+    ///
+    /// [`EventHandler::call_create`]: crate::client::EventHandler::call_create
+    CallCreate(CallCreateEvent),
+    /// A call in a private channel was updated.
+    ///
+    /// Fires the [`EventHandler::call_update`] event.
+    ///
+    /// [`EventHandler::call_update`]: crate::client::EventHandler::call_update
+    CallUpdate(CallUpdateEvent),
+    /// A call in a private channel ended.
+    ///
+    /// Fires the [`EventHandler::call_delete`] event.
+    ///
+    /// [`EventHandler::call_delete`]: crate::client::EventHandler::call_delete
+    CallDelete(CallDeleteEvent),
     /// A [`Channel`] was created.
     ///
     /// Fires the [`EventHandler::channel_create`] event.
@@ -1249,6 +1473,12 @@ pub enum Event {
     ///
     /// [`EventHandler::invite_delete`]: crate::client::EventHandler::invite_delete
     InviteDelete(InviteDeleteEvent),
+    /// A channel was acknowledged by the current user.
+    ///
+    /// Fires the [`EventHandler::message_ack`] event.
+    ///
+    /// [`EventHandler::message_ack`]: crate::client::EventHandler::message_ack
+    MessageAck(MessageAckEvent),
     MessageCreate(MessageCreateEvent),
     MessageDelete(MessageDeleteEvent),
     MessageDeleteBulk(MessageDeleteBulkEvent),
@@ -1293,10 +1523,30 @@ pub enum Event {
     Ready(ReadyEvent),
     /// The connection has successfully resumed after a disconnect.
     Resumed(ResumedEvent),
+    /// A relationship of the current user was added.
+    ///
+    /// Fires the [`EventHandler::relationship_add`] event.
+    ///
+    /// [`EventHandler::relationship_add`]: crate::client::EventHandler::relationship_add
+    RelationshipAdd(RelationshipAddEvent),
+    /// A relationship of the current user was removed.
+    ///
+    /// Fires the [`EventHandler::relationship_remove`] event.
+    ///
+    /// [`EventHandler::relationship_remove`]: crate::client::EventHandler::relationship_remove
+    RelationshipRemove(RelationshipRemoveEvent),
+    /// A relationship of the current user was updated.
+    ///
+    /// Fires the [`EventHandler::relationship_update`] event.
+    ///
+    /// [`EventHandler::relationship_update`]: crate::client::EventHandler::relationship_update
+    RelationshipUpdate(RelationshipUpdateEvent),
     /// A user is typing; considered to last 5 seconds
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
     UserUpdate(UserUpdateEvent),
+    /// Update to the logged-in user's settings
+    UserSettingsUpdate(UserSettingsUpdateEvent),
     /// A member's voice state has changed
     VoiceStateUpdate(VoiceStateUpdateEvent),
     /// Voice server information is available
@@ -1343,6 +1593,14 @@ pub enum Event {
     GuildScheduledEventUserAdd(GuildScheduledEventUserAddEvent),
     /// A guild member has unsubscribed from a scheduled event.
     GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveEvent),
+    /// A soundboard sound was added to a guild.
+    GuildSoundboardSoundCreate(GuildSoundboardSoundCreateEvent),
+    /// A soundboard sound of a guild was updated.
+    GuildSoundboardSoundUpdate(GuildSoundboardSoundUpdateEvent),
+    /// A soundboard sound was deleted from a guild.
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDeleteEvent),
+    /// Multiple soundboard sounds of a guild were updated.
+    GuildSoundboardSoundsUpdate(GuildSoundboardSoundsUpdateEvent),
     /// A user subscribed to a SKU.
     EntitlementCreate(EntitlementCreateEvent),
     /// A user's entitlement was updated or renewed.
@@ -1370,4 +1628,225 @@ impl Event {
             Some(map.get("t")?.as_str()?.to_string())
         }
     }
+
+    /// Returns the Id of the guild this event happened in, if the event carries one.
+    #[must_use]
+    pub fn guild_id(&self) -> Option<GuildId> {
+        #[allow(deprecated)]
+        match self {
+            Self::CommandPermissionsUpdate(e) => Some(e.permission.guild_id),
+            Self::AutoModRuleCreate(AutoModRuleCreateEvent {
+                rule,
+            })
+            | Self::AutoModRuleUpdate(AutoModRuleUpdateEvent {
+                rule,
+            })
+            | Self::AutoModRuleDelete(AutoModRuleDeleteEvent {
+                rule,
+            }) => Some(rule.guild_id),
+            Self::AutoModActionExecution(e) => Some(e.execution.guild_id),
+            Self::ChannelCreate(ChannelCreateEvent {
+                channel,
+            })
+            | Self::ChannelDelete(ChannelDeleteEvent {
+                channel,
+            })
+            | Self::ChannelUpdate(ChannelUpdateEvent {
+                channel,
+            })
+            | Self::ThreadCreate(ThreadCreateEvent {
+                thread: channel,
+            })
+            | Self::ThreadUpdate(ThreadUpdateEvent {
+                thread: channel,
+            }) => Some(channel.guild_id),
+            Self::GuildAuditLogEntryCreate(e) => Some(e.guild_id),
+            Self::GuildBanAdd(e) => Some(e.guild_id),
+            Self::GuildBanRemove(e) => Some(e.guild_id),
+            Self::GuildCreate(e) => Some(e.guild.id),
+            Self::GuildDelete(e) => Some(e.guild.id),
+            Self::GuildEmojisUpdate(e) => Some(e.guild_id),
+            Self::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+            Self::GuildMemberAdd(e) => Some(e.member.guild_id),
+            Self::GuildMemberRemove(e) => Some(e.guild_id),
+            Self::GuildMemberUpdate(e) => Some(e.guild_id),
+            Self::GuildMembersChunk(e) => Some(e.guild_id),
+            Self::GuildRoleCreate(e) => Some(e.role.guild_id),
+            Self::GuildRoleDelete(e) => Some(e.guild_id),
+            Self::GuildRoleUpdate(e) => Some(e.role.guild_id),
+            Self::GuildStickersUpdate(e) => Some(e.guild_id),
+            Self::GuildUpdate(e) => Some(e.guild.id),
+            Self::VoiceChannelStatusUpdate(e) => Some(e.guild_id),
+            Self::WebhookUpdate(e) => Some(e.guild_id),
+            Self::IntegrationCreate(IntegrationCreateEvent {
+                integration,
+            })
+            | Self::IntegrationUpdate(IntegrationUpdateEvent {
+                integration,
+            }) => integration.guild_id,
+            Self::IntegrationDelete(e) => Some(e.guild_id),
+            Self::StageInstanceCreate(StageInstanceCreateEvent {
+                stage_instance,
+            })
+            | Self::StageInstanceUpdate(StageInstanceUpdateEvent {
+                stage_instance,
+            })
+            | Self::StageInstanceDelete(StageInstanceDeleteEvent {
+                stage_instance,
+            }) => Some(stage_instance.guild_id),
+            Self::ThreadDelete(e) => Some(e.thread.guild_id),
+            Self::ThreadListSync(e) => Some(e.guild_id),
+            Self::ThreadMembersUpdate(e) => Some(e.guild_id),
+            Self::GuildScheduledEventCreate(GuildScheduledEventCreateEvent {
+                event,
+            })
+            | Self::GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent {
+                event,
+            })
+            | Self::GuildScheduledEventDelete(GuildScheduledEventDeleteEvent {
+                event,
+            }) => Some(event.guild_id),
+            Self::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+            Self::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+            Self::GuildSoundboardSoundCreate(GuildSoundboardSoundCreateEvent {
+                sound,
+            })
+            | Self::GuildSoundboardSoundUpdate(GuildSoundboardSoundUpdateEvent {
+                sound,
+            }) => sound.guild_id,
+            Self::GuildSoundboardSoundDelete(e) => Some(e.guild_id),
+            Self::GuildSoundboardSoundsUpdate(e) => Some(e.guild_id),
+            Self::ChannelPinsUpdate(e) => e.guild_id,
+            Self::InviteCreate(e) => e.guild_id,
+            Self::InviteDelete(e) => e.guild_id,
+            Self::MessageCreate(e) => e.message.guild_id,
+            Self::MessageDelete(e) => e.guild_id,
+            Self::MessageDeleteBulk(e) => e.guild_id,
+            Self::MessageUpdate(e) => e.guild_id,
+            Self::PresenceUpdate(e) => e.presence.guild_id,
+            Self::ReactionAdd(ReactionAddEvent {
+                reaction,
+            })
+            | Self::ReactionRemove(ReactionRemoveEvent {
+                reaction,
+            })
+            | Self::ReactionRemoveEmoji(ReactionRemoveEmojiEvent {
+                reaction,
+            }) => reaction.guild_id,
+            Self::ReactionRemoveAll(e) => e.guild_id,
+            Self::TypingStart(e) => e.guild_id,
+            Self::VoiceStateUpdate(e) => e.voice_state.guild_id,
+            Self::VoiceServerUpdate(e) => e.guild_id,
+            Self::InteractionCreate(e) => match &e.interaction {
+                Interaction::Command(i) | Interaction::Autocomplete(i) => i.guild_id,
+                Interaction::Component(i) => i.guild_id,
+                Interaction::Modal(i) => i.guild_id,
+                _ => None,
+            },
+            Self::MessagePollVoteAdd(e) => e.guild_id,
+            Self::MessagePollVoteRemove(e) => e.guild_id,
+            _ => None,
+        }
+    }
+
+    /// Returns the Id of the channel this event happened in, if the event carries one.
+    ///
+    /// For events about a channel or thread itself, this is the Id of that channel.
+    #[must_use]
+    pub fn channel_id(&self) -> Option<ChannelId> {
+        match self {
+            Self::AutoModActionExecution(e) => e.execution.channel_id,
+            Self::ChannelCreate(ChannelCreateEvent {
+                channel,
+            })
+            | Self::ChannelDelete(ChannelDeleteEvent {
+                channel,
+            })
+            | Self::ChannelUpdate(ChannelUpdateEvent {
+                channel,
+            })
+            | Self::ThreadCreate(ThreadCreateEvent {
+                thread: channel,
+            })
+            | Self::ThreadUpdate(ThreadUpdateEvent {
+                thread: channel,
+            }) => Some(channel.id),
+            Self::ThreadDelete(e) => Some(e.thread.id),
+            Self::CallCreate(CallCreateEvent {
+                call,
+            })
+            | Self::CallUpdate(CallUpdateEvent {
+                call,
+            }) => Some(call.channel_id),
+            Self::CallDelete(e) => Some(e.channel_id),
+            Self::ChannelPinsUpdate(e) => Some(e.channel_id),
+            Self::InviteCreate(e) => Some(e.channel_id),
+            Self::InviteDelete(e) => Some(e.channel_id),
+            Self::MessageAck(e) => Some(e.channel_id),
+            Self::MessageCreate(e) => Some(e.message.channel_id),
+            Self::MessageDelete(e) => Some(e.channel_id),
+            Self::MessageDeleteBulk(e) => Some(e.channel_id),
+            Self::MessageUpdate(e) => Some(e.channel_id),
+            Self::ReactionAdd(ReactionAddEvent {
+                reaction,
+            })
+            | Self::ReactionRemove(ReactionRemoveEvent {
+                reaction,
+            })
+            | Self::ReactionRemoveEmoji(ReactionRemoveEmojiEvent {
+                reaction,
+            }) => Some(reaction.channel_id),
+            Self::ReactionRemoveAll(e) => Some(e.channel_id),
+            Self::TypingStart(e) => Some(e.channel_id),
+            Self::VoiceStateUpdate(e) => e.voice_state.channel_id,
+            Self::WebhookUpdate(e) => Some(e.channel_id),
+            Self::StageInstanceCreate(StageInstanceCreateEvent {
+                stage_instance,
+            })
+            | Self::StageInstanceUpdate(StageInstanceUpdateEvent {
+                stage_instance,
+            })
+            | Self::StageInstanceDelete(StageInstanceDeleteEvent {
+                stage_instance,
+            }) => Some(stage_instance.channel_id),
+            Self::GuildScheduledEventCreate(GuildScheduledEventCreateEvent {
+                event,
+            })
+            | Self::GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent {
+                event,
+            })
+            | Self::GuildScheduledEventDelete(GuildScheduledEventDeleteEvent {
+                event,
+            }) => event.channel_id,
+            Self::InteractionCreate(e) => match &e.interaction {
+                Interaction::Command(i) | Interaction::Autocomplete(i) => Some(i.channel_id),
+                Interaction::Component(i) => Some(i.channel_id),
+                Interaction::Modal(i) => Some(i.channel_id),
+                _ => None,
+            },
+            Self::MessagePollVoteAdd(e) => Some(e.channel_id),
+            Self::MessagePollVoteRemove(e) => Some(e.channel_id),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn unknown_event_round_trip() {
+        let payload = json!({"t": "SOME_NEW_EVENT", "d": {"id": "1", "nested": [1, 2]}});
+        let event: Event = from_value(payload.clone()).unwrap();
+
+        let Event::Unknown(unknown) = &event else { panic!("expected an unknown event") };
+        assert_eq!(unknown.kind, "SOME_NEW_EVENT");
+        assert_eq!(to_value(&event).unwrap(), payload);
+
+        let settings: UserSettingsUpdateEvent = from_value(json!({"theme": "midnight"})).unwrap();
+        assert_eq!(settings.theme, Some(Theme::Unknown("midnight".into())));
+        assert_eq!(to_value(&settings).unwrap()["theme"], "midnight");
+    }
 }
@@ -270,6 +270,8 @@ impl PresenceUser {
             id: self.id,
             name: self.name?,
             public_flags: self.public_flags,
+            avatar_decoration_data: None,
+            primary_guild: None,
             banner: None,
             accent_colour: None,
             member: None,
@@ -280,6 +282,7 @@ impl PresenceUser {
             email: self.email,
             flags: self.public_flags.unwrap_or_default(),
             premium_type: PremiumType::None,
+            premium_since: None,
         })
     }
 
@@ -350,6 +353,26 @@ pub struct Ready {
     pub shard: Option<ShardInfo>,
     /// Contains id and flags
     pub application: PartialCurrentApplicationInfo,
+    /// The friends, blocked users and pending friend requests of the user.
+    ///
+    /// Only sent to user accounts.
+    #[serde(default)]
+    pub relationships: Vec<Relationship>,
+    /// How far the user has read each channel.
+    ///
+    /// Only sent to user accounts.
+    #[serde(default)]
+    pub read_state: ReadStates,
+    /// The gateway sessions of the user, including this one.
+    ///
+    /// Only sent to user accounts.
+    #[serde(default)]
+    pub sessions: Vec<GatewaySession>,
+    /// The settings of the user.
+    ///
+    /// Only sent to user accounts.
+    #[serde(default)]
+    pub user_settings: Option<UserSettings>,
 }
 
 /// Information describing how many gateway sessions you can initiate within a ratelimit period.
@@ -494,6 +517,10 @@ bitflags! {
         /// Enables the following gateway events:
         /// - GUILD_EMOJIS_UPDATE
         /// - GUILD_STICKERS_UPDATE
+        /// - GUILD_SOUNDBOARD_SOUND_CREATE
+        /// - GUILD_SOUNDBOARD_SOUND_UPDATE
+        /// - GUILD_SOUNDBOARD_SOUND_DELETE
+        /// - GUILD_SOUNDBOARD_SOUNDS_UPDATE
         const GUILD_EMOJIS_AND_STICKERS = 1 << 3;
         /// Enables the following gateway events:
         /// - GUILD_INTEGRATIONS_UPDATE
@@ -1,15 +1,36 @@
 use crate::json::Value;
-use crate::model::channel::PermissionOverwrite;
+use crate::model::channel::{
+    ForumEmoji,
+    ForumLayoutType,
+    ForumTag,
+    PermissionOverwrite,
+    SortOrder,
+    VideoQualityMode,
+};
 use crate::model::guild::automod::{Action, EventType, TriggerMetadata, TriggerType};
 use crate::model::guild::{
     AfkTimeout,
     DefaultMessageNotificationLevel,
     ExplicitContentFilter,
+    IntegrationExpireBehaviour,
     MfaLevel,
+    OnboardingMode,
+    ScheduledEventStatus,
+    ScheduledEventType,
     SystemChannelFlags,
     VerificationLevel,
 };
-use crate::model::id::{ApplicationId, ChannelId, GenericId, GuildId, RoleId, UserId};
+use crate::model::id::{
+    ApplicationId,
+    ChannelId,
+    EmojiId,
+    ForumTagId,
+    GenericId,
+    GuildId,
+    RoleId,
+    SoundId,
+    UserId,
+};
 use crate::model::misc::ImageHash;
 use crate::model::sticker::StickerFormatType;
 use crate::model::utils::StrOrInt;
@@ -128,6 +149,8 @@ generate_change! {
     "allow" => Allow(Permissions),
     /// Application ID of the added or removed webhook or bot.
     "application_id" => ApplicationId(ApplicationId),
+    /// Tags applied to a forum post were changed.
+    "applied_tags" => AppliedTags(Vec<ForumTagId>),
     /// Thread is now archived/unarchived.
     "archived" => Archived(bool),
     "asset" => Asset(String),
@@ -135,6 +158,8 @@ generate_change! {
     "auto_archive_duration" => AutoArchiveDuration(u16),
     /// Availability of a sticker was changed.
     "available" => Available(bool),
+    /// Tags available in a forum channel were changed.
+    "available_tags" => AvailableTags(Vec<ForumTag>),
     /// User avatar was changed.
     "avatar_hash" => AvatarHash(ImageHash),
     /// Guild banner was changed.
@@ -153,24 +178,38 @@ generate_change! {
     "deaf" => Deaf(bool),
     /// Default auto archive duration for newly created threads was changed.
     "default_auto_archive_duration" => DefaultAutoArchiveDuration(u16),
+    /// Channels members are added to by default during onboarding were changed.
+    "default_channel_ids" => DefaultChannelIds(Vec<ChannelId>),
+    /// Default layout of a forum channel was changed.
+    "default_forum_layout" => DefaultForumLayout(ForumLayoutType),
     /// Default message notification level for a server was changed.
     "default_message_notifications" => DefaultMessageNotifications(DefaultMessageNotificationLevel),
+    /// Default reaction emoji of forum posts was changed.
+    "default_reaction_emoji" => DefaultReactionEmoji(ForumEmoji),
+    /// Default sort order of forum posts was changed.
+    "default_sort_order" => DefaultSortOrder(SortOrder),
+    /// Default ratelimit per user in newly created threads was changed.
+    "default_thread_rate_limit_per_user" => DefaultThreadRateLimitPerUser(u16),
     /// Permission on a text or voice channel was denied for a role.
     "deny" => Deny(Permissions),
     /// Description for guild, sticker, or guild scheduled event was changed.
     "description" => Description(String),
     /// Guild's discovery splash was changed.
     "discovery_splash_hash" => DiscoverySplashHash(ImageHash),
+    /// Emoji of a soundboard sound was changed.
+    "emoji_id" => EmojiId(EmojiId),
+    /// Unicode emoji of a soundboard sound was changed.
+    "emoji_name" => EmojiName(String),
     "enabled" => Enabled(bool),
     /// Integration emoticons was enabled/disabled.
     "enable_emoticons" => EnableEmoticons(bool),
     /// Entity type of guild scheduled event was changed.
-    "entity_type" => EntityType(u64),
+    "entity_type" => EntityType(ScheduledEventType),
     "event_type" => EventType(EventType),
     "exempt_channels" => ExemptChannels(Vec<ChannelId>),
     "exempt_roles" => ExemptRoles(Vec<RoleId>),
     /// Behavior of the expiration of an integration was changed.
-    "expire_behavior" => ExpireBehavior(u64),
+    "expire_behavior" => ExpireBehavior(IntegrationExpireBehaviour),
     /// Grace period of the expiration of an integration was changed.
     "expire_grace_period" => ExpireGracePeriod(u64),
     /// Explicit content filter level of a guild was changed.
@@ -185,10 +224,12 @@ generate_change! {
     "hoist" => Hoist(bool),
     /// Guild icon was changed.
     "icon_hash" => IconHash(ImageHash),
-    /// Guild scheduled event cover image was changed.
-    "id" => Id(GenericId),
     /// ID of the changed entity.
+    "id" => Id(GenericId),
+    /// Guild scheduled event cover image was changed.
     "image_hash" => ImageHash(ImageHash),
+    /// Whether an onboarding prompt is shown during onboarding was changed.
+    "in_onboarding" => InOnboarding(bool),
     /// Private thread's invitable state was changed.
     "invitable" => Invitable(bool),
     /// ID of the user who created the invite.
@@ -205,6 +246,8 @@ generate_change! {
     "mentionable" => Mentionable(bool),
     /// Multi-factor authentication requirement was changed.
     "mfa_level" => MfaLevel(MfaLevel),
+    /// Onboarding mode of a guild was changed.
+    "mode" => Mode(OnboardingMode),
     /// User was server muted/unmuted.
     "mute" => Mute(bool),
     /// Name of an entity was changed.
@@ -221,6 +264,8 @@ generate_change! {
     "permissions" => Permissions(Permissions),
     /// Channel or role position was changed.
     "position" => Position(u32),
+    /// Whether the boost progress bar of a guild is shown was changed.
+    "premium_progress_bar_enabled" => PremiumProgressBarEnabled(bool),
     /// Preferred locale of a guild was changed.
     "preferred_locale" => PreferredLocale(String),
     /// Privacy level of the stage instance was changed.
@@ -233,12 +278,22 @@ generate_change! {
     "rate_limit_per_user" => RateLimitPerUser(u16),
     /// Region of a guild was changed.
     "region" => Region(String),
+    /// Whether an onboarding prompt is required was changed.
+    "required" => Required(bool),
+    /// Voice region of a voice channel was changed.
+    "rtc_region" => RtcRegion(String),
     /// ID of the rules channel was changed.
     "rules_channel_id" => RulesChannelId(ChannelId),
+    /// ID of the safety alerts channel was changed.
+    "safety_alerts_channel_id" => SafetyAlertsChannelId(ChannelId),
+    /// Whether only one option of an onboarding prompt can be selected was changed.
+    "single_select" => SingleSelect(bool),
+    /// ID of a soundboard sound.
+    "sound_id" => SoundId(SoundId),
     /// Invite splash page artwork was changed.
     "splash_hash" => SplashHash(ImageHash),
     /// Status of guild scheduled event was changed.
-    "status" => Status(u64),
+    "status" => Status(ScheduledEventStatus),
     /// System channel settings were changed.
     "system_channel_flags" => SystemChannelFlags(SystemChannelFlags),
     /// ID of the system channel was changed.
@@ -247,6 +302,8 @@ generate_change! {
     "tags" => Tags(String),
     /// Whether an invite is temporary or never expires was changed.
     "temporary" => Temporary(bool),
+    /// Title of an onboarding prompt was changed.
+    "title" => Title(String),
     /// Topic of a text channel or stage instance was changed.
     "topic" => Topic(String),
     "trigger_metadata" => TriggerMetadata(TriggerMetadata),
@@ -255,6 +312,8 @@ generate_change! {
     "type" => Type(EntityType),
     /// Unicode emoji of a role icon was changed.
     "unicode_emoji" => UnicodeEmoji(String),
+    /// ID of the user who created a soundboard sound.
+    "user_id" => UserId(UserId),
     /// Maximum number of users in a voice channel was changed.
     "user_limit" => UserLimit(u64),
     /// Number of uses of an invite was changed.
@@ -263,6 +322,8 @@ generate_change! {
     "vanity_url_code" => VanityUrlCode(String),
     /// Required verification level for new members was changed.
     "verification_level" => VerificationLevel(VerificationLevel),
+    /// Video quality mode of a voice channel was changed.
+    "video_quality_mode" => VideoQualityMode(VideoQualityMode),
     /// Channel of the server widget was changed.
     "widget_channel_id" => WidgetChannelId(ChannelId),
     /// Whether a widget is enabled or not was changed.
@@ -333,4 +394,22 @@ mod tests {
             json!({"key": "system_channel_flags", "old_value": 12, "new_value": 13 }),
         );
     }
+
+    #[test]
+    fn typed_values() {
+        let value = Change::Status {
+            old: Some(ScheduledEventStatus::Scheduled),
+            new: Some(ScheduledEventStatus::Active),
+        };
+        assert_json(&value, json!({"key": "status", "old_value": 1, "new_value": 2}));
+
+        let value = Change::DefaultReactionEmoji {
+            old: None,
+            new: Some(ForumEmoji::Id(EmojiId::new(4))),
+        };
+        assert_json(
+            &value,
+            json!({"key": "default_reaction_emoji", "new_value": {"emoji_id": "4", "emoji_name": null}}),
+        );
+    }
 }
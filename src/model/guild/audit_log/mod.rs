@@ -8,7 +8,7 @@ mod change;
 mod utils;
 
 pub use change::{AffectedRole, Change, EntityType};
-use utils::{optional_string, users, webhooks};
+use utils::{optional_string, optional_trigger_type, users, webhooks};
 
 use crate::model::prelude::*;
 
@@ -33,8 +33,12 @@ pub enum Action {
     Sticker(StickerAction),
     ScheduledEvent(ScheduledEventAction),
     Thread(ThreadAction),
+    ApplicationCommandPermission(ApplicationCommandPermissionAction),
+    SoundboardSound(SoundboardSoundAction),
     AutoMod(AutoModAction),
     CreatorMonetization(CreatorMonetizationAction),
+    Onboarding(OnboardingAction),
+    HomeSettings(HomeSettingsAction),
     VoiceChannelStatus(VoiceChannelStatusAction),
     Unknown(u8),
 }
@@ -57,8 +61,12 @@ impl Action {
             Self::Sticker(x) => x as u8,
             Self::ScheduledEvent(x) => x as u8,
             Self::Thread(x) => x as u8,
+            Self::ApplicationCommandPermission(x) => x as u8,
+            Self::SoundboardSound(x) => x as u8,
             Self::AutoMod(x) => x as u8,
             Self::CreatorMonetization(x) => x as u8,
+            Self::Onboarding(x) => x as u8,
+            Self::HomeSettings(x) => x as u8,
             Self::VoiceChannelStatus(x) => x as u8,
             Self::Unknown(x) => x,
         }
@@ -82,8 +90,12 @@ impl Action {
             90..=92 => Action::Sticker(unsafe { transmute(value) }),
             100..=102 => Action::ScheduledEvent(unsafe { transmute(value) }),
             110..=112 => Action::Thread(unsafe { transmute(value) }),
-            140..=145 => Action::AutoMod(unsafe { transmute(value) }),
+            121 => Action::ApplicationCommandPermission(unsafe { transmute(value) }),
+            130..=132 => Action::SoundboardSound(unsafe { transmute(value) }),
+            140..=146 => Action::AutoMod(unsafe { transmute(value) }),
             150..=151 => Action::CreatorMonetization(unsafe { transmute(value) }),
+            163..=167 => Action::Onboarding(unsafe { transmute(value) }),
+            190..=191 => Action::HomeSettings(unsafe { transmute(value) }),
             192..=193 => Action::VoiceChannelStatus(unsafe { transmute(value) }),
             _ => Action::Unknown(value),
         }
@@ -254,6 +266,26 @@ pub enum ThreadAction {
     Delete = 112,
 }
 
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationCommandPermissionAction {
+    Update = 121,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum SoundboardSoundAction {
+    Create = 130,
+    Update = 131,
+    Delete = 132,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Copy, Clone, Debug)]
@@ -266,6 +298,7 @@ pub enum AutoModAction {
     BlockMessage = 143,
     FlagToChannel = 144,
     UserCommunicationDisabled = 145,
+    QuarantineUser = 146,
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
@@ -278,6 +311,29 @@ pub enum CreatorMonetizationAction {
     TermsAccepted = 151,
 }
 
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum OnboardingAction {
+    PromptCreate = 163,
+    PromptUpdate = 164,
+    PromptDelete = 165,
+    Create = 166,
+    Update = 167,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum HomeSettingsAction {
+    Create = 190,
+    Update = 191,
+}
+
 /// [Incomplete documentation](https://github.com/discord/discord-api-docs/pull/6398)
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Copy, Clone, Debug)]
@@ -363,7 +419,8 @@ pub struct Options {
     /// Name of the Auto Moderation rule that was triggered.
     pub auto_moderation_rule_name: Option<String>,
     /// Trigger type of the Auto Moderation rule that was triggered.
-    pub auto_moderation_rule_trigger_type: Option<String>,
+    #[serde(default, with = "optional_trigger_type")]
+    pub auto_moderation_rule_trigger_type: Option<TriggerType>,
     /// ID of the app whose permissions were targeted.
     pub application_id: Option<ApplicationId>,
     /// Number of days after which inactive members were kicked.
@@ -393,6 +450,9 @@ pub struct Options {
     /// The status of a voice channel when set.
     #[serde(default)]
     pub status: Option<String>,
+    /// Type of the integration which performed the action, such as `"twitch"` or `"discord"`.
+    #[serde(default)]
+    pub integration_type: Option<String>,
 }
 
 #[cfg(test)]
@@ -461,9 +521,24 @@ mod tests {
         assert_action!(Action::AutoMod(AutoModAction::RuleDelete), 142);
         assert_action!(Action::AutoMod(AutoModAction::BlockMessage), 143);
         assert_action!(Action::AutoMod(AutoModAction::FlagToChannel), 144);
+        assert_action!(
+            Action::ApplicationCommandPermission(ApplicationCommandPermissionAction::Update),
+            121
+        );
+        assert_action!(Action::SoundboardSound(SoundboardSoundAction::Create), 130);
+        assert_action!(Action::SoundboardSound(SoundboardSoundAction::Update), 131);
+        assert_action!(Action::SoundboardSound(SoundboardSoundAction::Delete), 132);
         assert_action!(Action::AutoMod(AutoModAction::UserCommunicationDisabled), 145);
+        assert_action!(Action::AutoMod(AutoModAction::QuarantineUser), 146);
         assert_action!(Action::CreatorMonetization(CreatorMonetizationAction::RequestCreated), 150);
         assert_action!(Action::CreatorMonetization(CreatorMonetizationAction::TermsAccepted), 151);
+        assert_action!(Action::Onboarding(OnboardingAction::PromptCreate), 163);
+        assert_action!(Action::Onboarding(OnboardingAction::PromptUpdate), 164);
+        assert_action!(Action::Onboarding(OnboardingAction::PromptDelete), 165);
+        assert_action!(Action::Onboarding(OnboardingAction::Create), 166);
+        assert_action!(Action::Onboarding(OnboardingAction::Update), 167);
+        assert_action!(Action::HomeSettings(HomeSettingsAction::Create), 190);
+        assert_action!(Action::HomeSettings(HomeSettingsAction::Update), 191);
         assert_action!(Action::VoiceChannelStatus(VoiceChannelStatusAction::StatusUpdate), 192);
         assert_action!(Action::VoiceChannelStatus(VoiceChannelStatusAction::StatusDelete), 193);
         assert_action!(Action::Unknown(234), 234);
@@ -487,4 +562,28 @@ mod tests {
 
         assert!(matches!(value.action, Action::Unknown(234)));
     }
+
+    #[test]
+    fn entry_options() {
+        use crate::json::{from_value, json};
+
+        let entry: AuditLogEntry = from_value(json!({
+            "id": "1",
+            "action_type": 143,
+            "target_id": "2",
+            "user_id": "2",
+            "reason": null,
+            "options": {
+                "auto_moderation_rule_name": "No links",
+                "auto_moderation_rule_trigger_type": "1",
+                "channel_id": "3",
+            },
+        }))
+        .unwrap();
+
+        assert!(matches!(entry.action, Action::AutoMod(AutoModAction::BlockMessage)));
+        let options = entry.options.unwrap();
+        assert_eq!(options.auto_moderation_rule_trigger_type, Some(TriggerType::Keyword));
+        assert_eq!(options.channel_id, Some(ChannelId::new(3)));
+    }
 }
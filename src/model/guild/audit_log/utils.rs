@@ -93,10 +93,40 @@ pub mod optional_string {
     }
 }
 
+/// Deserializes an optional string containing an Auto Moderation trigger type as
+/// `Option<TriggerType>`.
+///
+/// Used with `#[serde(with = "optional_trigger_type")]`.
+pub mod optional_trigger_type {
+    use serde::de::{Deserializer, Error};
+    use serde::ser::Serializer;
+
+    use super::optional_string;
+    use crate::model::guild::automod::TriggerType;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<TriggerType>, D::Error> {
+        optional_string::deserialize(deserializer)?
+            .map(|value| u8::try_from(value).map(TriggerType::from).map_err(Error::custom))
+            .transpose()
+    }
+
+    // The signature is dictated by `#[serde(with)]`.
+    #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(
+        value: &Option<TriggerType>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        optional_string::serialize(&value.map(|value| u64::from(u8::from(value))), serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::optional_string;
+    use super::{optional_string, optional_trigger_type};
     use crate::json::{assert_json, json};
+    use crate::model::guild::automod::TriggerType;
 
     #[test]
     fn optional_string_module() {
@@ -112,4 +142,20 @@ mod tests {
 
         assert_json(&value, json!({"opt": "12345"}));
     }
+
+    #[test]
+    fn optional_trigger_type_module() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct T {
+            #[serde(default, with = "optional_trigger_type")]
+            opt: Option<TriggerType>,
+        }
+
+        let value = T {
+            opt: Some(TriggerType::Keyword),
+        };
+
+        assert_json(&value, json!({"opt": "1"}));
+        assert_eq!(crate::json::from_value::<T>(json!({})).unwrap().opt, None);
+    }
 }
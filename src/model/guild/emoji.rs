@@ -13,6 +13,8 @@ use crate::model::user::User;
 use crate::model::utils::default_true;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::model::ModelError;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 /// Represents a custom guild emoji, which can either be created using the API, or via an
 /// integration. Emojis created using the API only work within the guild it was created in.
@@ -180,8 +182,8 @@ impl Emoji {
     #[inline]
     #[must_use]
     pub fn url(&self) -> String {
-        let extension = if self.animated { "gif" } else { "png" };
-        cdn!("/emojis/{}.{}", self.id, extension)
+        let format = if self.animated { ImageFormat::Gif } else { ImageFormat::Png };
+        cdn::emoji(self.id, self.animated, ImageOptions::new().format(format))
     }
 }
 
@@ -6,19 +6,25 @@ use futures::stream::Stream;
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
+    BeginGuildPrune,
     Builder,
     CreateChannel,
     CreateCommand,
+    CreateGuildTemplate,
     CreateScheduledEvent,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditCommandPermissions,
     EditGuild,
+    EditGuildTemplate,
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
+    EditOnboarding,
     EditRole,
     EditScheduledEvent,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -301,6 +307,38 @@ impl GuildId {
         http.as_ref().get_bans(self, target, limit).await
     }
 
+    /// Streams over all the bans in a guild.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::bans`]. A buffer of at most
+    /// 1,000 bans is used to reduce the number of calls necessary.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let ctx: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut bans = guild_id.bans_iter(&ctx).boxed();
+    /// while let Some(ban_result) = bans.next().await {
+    ///     match ban_result {
+    ///         Ok(ban) => println!("{} is banned", ban.user.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub fn bans_iter<H: AsRef<Http>>(self, http: H) -> impl Stream<Item = Result<Ban>> {
+        BansIter::<H>::stream(http, self)
+    }
+
     /// Gets a list of the guild's audit log entries
     ///
     /// **Note**: Requires the [View Audit Log] permission.
@@ -318,9 +356,10 @@ impl GuildId {
         action_type: Option<audit_log::Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
+        after: Option<AuditLogEntryId>,
         limit: Option<u8>,
     ) -> Result<AuditLogs> {
-        http.as_ref().get_audit_logs(self, action_type, user_id, before, limit).await
+        http.as_ref().get_audit_logs(self, action_type, user_id, before, after, limit).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -493,6 +532,25 @@ impl GuildId {
         builder.execute(cache_http, self).await
     }
 
+    /// Creates a new soundboard sound in the guild with the data set, if any.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`] if the current user
+    /// lacks permission. Otherwise returns [`Error::Http`], as well as if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn create_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -612,6 +670,28 @@ impl GuildId {
         http.as_ref().delete_sticker(self, sticker_id.into(), None).await
     }
 
+    /// Deletes a [`SoundboardSound`] by id from the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a sound with that id
+    /// does not exist.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_soundboard_sound(self, sound_id.into(), None).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -839,6 +919,28 @@ impl GuildId {
         builder.execute(cache_http, (self, sticker_id.into())).await
     }
 
+    /// Edits a soundboard sound of the guild.
+    ///
+    /// **Note**: If the sound was created by the current user, requires either the [Create Guild
+    /// Expressions] or the [Manage Guild Expressions] permission. Otherwise, the [Manage Guild
+    /// Expressions] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn edit_soundboard_sound(
+        self,
+        cache_http: impl CacheHttp,
+        sound_id: impl Into<SoundId>,
+        builder: EditSoundboardSound<'_>,
+    ) -> Result<SoundboardSound> {
+        builder.execute(cache_http, (self, sound_id.into())).await
+    }
+
     /// Edit the position of a [`Role`] relative to all others in the [`Guild`].
     ///
     /// **Note**: Requires the [Manage Roles] permission.
@@ -865,6 +967,24 @@ impl GuildId {
         http.as_ref().edit_role_position(self, role_id.into(), position, None).await
     }
 
+    /// Edits the guild's onboarding.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`EditOnboarding::execute`] for a list of possible errors.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        self,
+        cache_http: impl CacheHttp,
+        builder: EditOnboarding<'_>,
+    ) -> Result<Onboarding> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1003,6 +1123,35 @@ impl GuildId {
         http.as_ref().get_guild_sticker(self, sticker_id).await
     }
 
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] or [Manage Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn soundboard_sounds(self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        http.as_ref().get_guild_soundboard_sounds(self).await
+    }
+
+    /// Gets a [`SoundboardSound`] of this guild by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a sound with that Id does not exist.
+    #[inline]
+    pub async fn soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: SoundId,
+    ) -> Result<SoundboardSound> {
+        http.as_ref().get_guild_soundboard_sound(self, sound_id).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1085,11 +1234,61 @@ impl GuildId {
         http.as_ref().leave_guild(self).await
     }
 
+    /// Boosts the guild with the given boost slots of the current user.
+    ///
+    /// Refer to [`CurrentUser::guild_subscription_slots`] for the available slots.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a slot is already used or on cooldown.
+    pub async fn boost(
+        self,
+        http: impl AsRef<Http>,
+        slot_ids: impl IntoIterator<Item = GuildSubscriptionSlotId>,
+    ) -> Result<Vec<GuildSubscription>> {
+        let map = json!({
+            "user_premium_guild_subscription_slot_ids": SerializeIter::new(slot_ids.into_iter()),
+        });
+
+        http.as_ref().create_guild_subscriptions(self, &map).await
+    }
+
+    /// Removes a boost of the current user from the guild.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the boost doesn't belong to the current user.
+    pub async fn remove_boost(
+        self,
+        http: impl AsRef<Http>,
+        subscription_id: GuildSubscriptionId,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_subscription(self, subscription_id).await
+    }
+
+    /// Gets the boosts applied to the guild.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is not in the guild.
+    pub async fn subscriptions(self, http: impl AsRef<Http>) -> Result<Vec<GuildSubscription>> {
+        http.as_ref().get_guild_subscriptions(self).await
+    }
+
     /// Gets a user's [`Member`] for the guild by Id.
     ///
     /// If the cache feature is enabled the cache will be checked first. If not found it will
     /// resort to an http request.
     ///
+    /// **Note**: If the cache is enabled, you might want to enable the `temp_cache` feature to
+    /// cache member data retrieved by this function for a short duration.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the user is not in the guild, or if the guild is otherwise
@@ -1110,10 +1309,26 @@ impl GuildId {
                         return Ok(member.clone());
                     }
                 }
+
+                #[cfg(feature = "temp_cache")]
+                if let Some(member) = cache.temp_members.get(&(self, user_id)) {
+                    return Ok(Member::clone(&*member));
+                }
+            }
+        }
+
+        let member = cache_http.http().get_member(self, user_id).await?;
+
+        #[cfg(all(feature = "cache", feature = "temp_cache"))]
+        {
+            if let Some(cache) = cache_http.cache() {
+                use crate::cache::MaybeOwnedArc;
+
+                cache.temp_members.insert((self, user_id), MaybeOwnedArc::new(member.clone()));
             }
         }
 
-        cache_http.http().get_member(self, user_id).await
+        Ok(member)
     }
 
     /// Gets a list of the guild's members.
@@ -1217,6 +1432,9 @@ impl GuildId {
 
     /// Gets the number of [`Member`]s that would be pruned with the given number of days.
     ///
+    /// By default only members without roles are counted. Members with any of the roles in
+    /// `include_roles` are counted as well.
+    ///
     /// Requires the [Kick Members] permission.
     ///
     /// # Errors
@@ -1225,8 +1443,13 @@ impl GuildId {
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     #[inline]
-    pub async fn prune_count(self, http: impl AsRef<Http>, days: u8) -> Result<GuildPrune> {
-        http.as_ref().get_guild_prune_count(self, days).await
+    pub async fn prune_count(
+        self,
+        http: impl AsRef<Http>,
+        days: u8,
+        include_roles: &[RoleId],
+    ) -> Result<GuildPrune> {
+        http.as_ref().get_guild_prune_count(self, days, include_roles).await
     }
 
     /// Re-orders the channels of the guild.
@@ -1443,6 +1666,27 @@ impl GuildId {
         http.as_ref().start_guild_prune(self, days, None).await
     }
 
+    /// Begins a prune of [`Member`]s, with the options set in the builder.
+    ///
+    /// Returns the number of pruned members, unless [`BeginGuildPrune::compute_prune_count`] was
+    /// set to `false`.
+    ///
+    /// **Note**: Requires the [Kick Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`BeginGuildPrune::execute`] for a list of possible errors.
+    ///
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn begin_prune(
+        self,
+        cache_http: impl CacheHttp,
+        builder: BeginGuildPrune<'_>,
+    ) -> Result<Option<GuildPrune>> {
+        builder.execute(cache_http, self).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1457,6 +1701,25 @@ impl GuildId {
         http.as_ref().remove_ban(self, user_id.into(), None).await
     }
 
+    /// Unbans a [`User`] from the guild, attributing the unban to the given reason in the audit
+    /// log.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::unban`] may return an error, may also return
+    /// [`Error::ExceededLimit`] if `reason` is too long.
+    pub async fn unban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: &str,
+    ) -> Result<()> {
+        if reason.chars().count() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+        http.as_ref().remove_ban(self, user_id.into(), Some(reason)).await
+    }
+
     /// Retrieve's the guild's vanity URL.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1468,7 +1731,7 @@ impl GuildId {
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         http.as_ref().get_guild_vanity_url(self).await
     }
 
@@ -1568,6 +1831,18 @@ impl GuildId {
         http.as_ref().get_guild_commands(self).await
     }
 
+    /// Gets the application commands of every application in the guild which the current user
+    /// can use, see [`Http::get_guild_application_command_index`].
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
+    pub async fn application_command_index(self, http: impl AsRef<Http>) -> Result<CommandIndex> {
+        http.as_ref().get_guild_application_command_index(self).await
+    }
+
     /// Get all guild application commands with localizations.
     ///
     /// # Errors
@@ -1641,16 +1916,106 @@ impl GuildId {
         http.as_ref().get_guild_command_permissions(self, command_id).await
     }
 
+    /// Gets the guild's templates.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self).await
+    }
+
+    /// Creates a template from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreateGuildTemplate::execute`] for a list of possible errors.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_template(
+        self,
+        cache_http: impl CacheHttp,
+        builder: CreateGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        builder.execute(cache_http, self).await
+    }
+
+    /// Syncs the template with the given code to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with the given code.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self, code).await
+    }
+
+    /// Edits the name or description of the template with the given code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`EditGuildTemplate::execute`] for a list of possible errors.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_template(
+        self,
+        cache_http: impl CacheHttp,
+        code: &str,
+        builder: EditGuildTemplate,
+    ) -> Result<GuildTemplate> {
+        builder.execute(cache_http, (self, code)).await
+    }
+
+    /// Deletes the template with the given code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the guild has no
+    /// template with the given code.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_template(
+        self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self, code).await
+    }
+
+    /// Gets the guild's onboarding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn get_onboarding(self, http: impl AsRef<Http>) -> Result<Onboarding> {
+        http.as_ref().get_guild_onboarding(self).await
+    }
+
     /// Get the guild welcome screen.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the guild does not have a welcome screen.
+    #[doc(alias = "welcome_screen")]
     pub async fn get_welcome_screen(self, http: impl AsRef<Http>) -> Result<GuildWelcomeScreen> {
         http.as_ref().get_guild_welcome_screen(self).await
     }
 
-    /// Get the guild preview.
+    /// Gets the guild preview.
     ///
     /// **Note**: The bot need either to be part of the guild or the guild needs to have the
     /// `DISCOVERABLE` feature.
@@ -1658,20 +2023,45 @@ impl GuildId {
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the bot cannot see the guild preview, see the note.
-    pub async fn get_preview(self, http: impl AsRef<Http>) -> Result<GuildPreview> {
+    pub async fn preview(self, http: impl AsRef<Http>) -> Result<GuildPreview> {
         http.as_ref().get_guild_preview(self).await
     }
 
-    /// Get the guild widget.
+    /// Gets the guild preview.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::preview`].
+    #[deprecated = "use `GuildId::preview` instead"]
+    pub async fn get_preview(self, http: impl AsRef<Http>) -> Result<GuildPreview> {
+        self.preview(http).await
+    }
+
+    /// Gets the settings of the guild widget.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the bot does not have `MANAGE_MESSAGES` permission.
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
     pub async fn get_widget(self, http: impl AsRef<Http>) -> Result<GuildWidget> {
         http.as_ref().get_guild_widget(self).await
     }
 
-    /// Get the widget image URL.
+    /// Gets the public data shown by the guild widget, such as the online members.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the widget is disabled.
+    pub async fn widget_info(self, http: impl AsRef<Http>) -> Result<GuildWidgetInfo> {
+        http.as_ref().get_guild_widget_info(self).await
+    }
+
+    /// Gets the URL of the PNG image of the guild widget, in the given style.
+    ///
+    /// **Note**: The image is only available if the widget is enabled.
     #[must_use]
     pub fn widget_image_url(self, style: GuildWidgetStyle) -> String {
         api!("/guilds/{}/widget.png?style={}", self, style)
@@ -1845,13 +2235,113 @@ impl<H: AsRef<Http>> MembersIter<H> {
     }
 }
 
+/// A helper class returned by [`GuildId::bans_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct BansIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    http: H,
+    buffer: Vec<Ban>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> BansIter<H> {
+    fn new(guild_id: GuildId, http: H) -> BansIter<H> {
+        BansIter {
+            guild_id,
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of Bans.
+    ///
+    /// This drops any bans that were currently in the buffer, so it should only be called when
+    /// `self.buffer` is empty.  Additionally, this updates `self.after` so that the next call does
+    /// not return duplicate items.  If there are no more bans to be fetched, then this marks
+    /// `self.after` as None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of bans Discord returns when no limit is given
+        let grab_size: usize = 1000;
+
+        let target = self.after.map(UserPagination::After);
+        self.buffer = self.guild_id.bans(&self.http, target, None).await?;
+
+        // Get the last ban.  If shorter than 1000, there are no more results anyway
+        self.after = self.buffer.get(grab_size - 1).map(|ban| ban.user.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the bans in a guild.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`GuildId::bans`]. A buffer of at
+    /// most 1,000 bans is used to reduce the number of calls necessary. If a call fails, its error
+    /// is yielded and the stream ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::new(1);
+    /// # let ctx: Http = unimplemented!();
+    /// use serenity::futures::StreamExt;
+    /// use serenity::model::guild::BansIter;
+    ///
+    /// let mut bans = BansIter::<Http>::stream(&ctx, guild_id).boxed();
+    /// while let Some(ban_result) = bans.next().await {
+    ///     match ban_result {
+    ///         Ok(ban) => println!("{} is banned", ban.user.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn stream(http: impl AsRef<Http>, guild_id: GuildId) -> impl Stream<Item = Result<Ban>> {
+        let init_state = BansIter::new(guild_id, http);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    // End the stream after the error instead of retrying the same request forever.
+                    state.after = None;
+                    state.tried_fetch = true;
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// The style of a guild widget image, see [`GuildId::widget_image_url`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-widget-image-widget-style-options).
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum GuildWidgetStyle {
+    /// A small shield showing the number of online members.
     Shield,
+    /// A large banner with the guild icon, name and online count, and a "Powered by Discord" logo.
     Banner1,
+    /// A smaller banner with the guild icon, name and online count.
     Banner2,
+    /// A large banner with the guild icon, name and online count, and a "Chat Now" prompt.
     Banner3,
+    /// A large banner with a "Join my server" heading and a "Join Server" button.
     Banner4,
 }
 
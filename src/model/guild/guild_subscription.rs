@@ -0,0 +1,89 @@
+use crate::model::prelude::*;
+
+/// A boost applied to a guild by a user.
+///
+/// **Note**: Only user accounts can boost guilds, using one of their
+/// [`GuildSubscriptionSlot`]s.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSubscription {
+    /// The Id of the boost.
+    pub id: GuildSubscriptionId,
+    /// The Id of the boosted guild.
+    pub guild_id: GuildId,
+    /// The Id of the user who applied the boost.
+    pub user_id: UserId,
+    /// The user who applied the boost.
+    ///
+    /// Only sent when listing the boosts of a guild.
+    #[serde(default)]
+    pub user: Option<User>,
+    /// Whether the boost has ended.
+    #[serde(default)]
+    pub ended: bool,
+    /// When the boost will end, if it was cancelled.
+    pub ends_at: Option<Timestamp>,
+    /// When the boost will be resumed, if it is paused.
+    #[serde(default)]
+    pub pause_ends_at: Option<Timestamp>,
+}
+
+/// A boost slot of the current user, granted by their Nitro subscription, which can be applied
+/// to a guild.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildSubscriptionSlot {
+    /// The Id of the slot.
+    pub id: GuildSubscriptionSlotId,
+    /// The Id of the Nitro subscription granting the slot.
+    pub subscription_id: GenericId,
+    /// Whether the slot will be removed at the end of the current billing period.
+    #[serde(default)]
+    pub canceled: bool,
+    /// When the slot can be applied to another guild, after being removed from one.
+    pub cooldown_ends_at: Option<Timestamp>,
+    /// The boost the slot is currently used for, if any.
+    #[serde(rename = "premium_guild_subscription")]
+    pub guild_subscription: Option<GuildSubscription>,
+}
+
+impl GuildSubscriptionSlot {
+    /// Whether the slot is currently used to boost a guild.
+    #[must_use]
+    pub fn is_used(&self) -> bool {
+        self.guild_subscription.as_ref().is_some_and(|s| !s.ended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn slots() {
+        let slots: Vec<GuildSubscriptionSlot> = from_value(json!([
+            {
+                "id": "1",
+                "subscription_id": "2",
+                "canceled": false,
+                "cooldown_ends_at": null,
+                "premium_guild_subscription": {
+                    "id": "3",
+                    "guild_id": "4",
+                    "user_id": "5",
+                    "ended": false,
+                    "ends_at": null,
+                },
+            },
+            {"id": "6", "subscription_id": "2", "canceled": false, "cooldown_ends_at": null},
+        ]))
+        .unwrap();
+
+        assert!(slots[0].is_used());
+        assert_eq!(slots[0].guild_subscription.as_ref().unwrap().guild_id, GuildId::new(4));
+        assert!(!slots[1].is_used());
+    }
+}
@@ -64,6 +64,9 @@ pub struct Member {
     ///
     /// Will be None or a time in the past if the user is not flagged.
     pub unusual_dm_activity_until: Option<Timestamp>,
+    /// The member's guild-specific avatar decoration, which overrides the one on [`Self::user`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
 }
 
 bitflags! {
@@ -81,6 +84,17 @@ bitflags! {
         const BYPASSES_VERIFICATION = 1 << 2;
         /// Member has started onboarding. Not editable
         const STARTED_ONBOARDING = 1 << 3;
+        /// Member is a guest and can only access the voice channel they were invited to. Not
+        /// editable
+        const IS_GUEST = 1 << 4;
+        /// Member has started the Server Guide new member actions. Not editable
+        const STARTED_HOME_ACTIONS = 1 << 5;
+        /// Member has completed the Server Guide new member actions. Not editable
+        const COMPLETED_HOME_ACTIONS = 1 << 6;
+        /// Member's username, display name, or nickname is blocked by AutoMod. Not editable
+        const AUTOMOD_QUARANTINED_USERNAME = 1 << 7;
+        /// Member has dismissed the DM settings upsell. Not editable
+        const DM_SETTINGS_UPSELL_ACKNOWLEDGED = 1 << 9;
     }
 }
 
@@ -570,6 +584,11 @@ pub struct PartialMember {
     pub pending: bool,
     /// Timestamp representing the date since the member is boosting the guild.
     pub premium_since: Option<Timestamp>,
+    /// When the user's timeout will expire and the user will be able to communicate in the guild
+    /// again.
+    ///
+    /// Will be None or a time in the past if the user is not timed out.
+    pub communication_disabled_until: Option<Timestamp>,
     /// The unique Id of the guild that the member is a part of.
     ///
     /// Manually inserted in [`Reaction::deserialize`].
@@ -603,9 +622,10 @@ impl From<PartialMember> for Member {
             flags: GuildMemberFlags::default(),
             pending: partial.pending,
             permissions: partial.permissions,
-            communication_disabled_until: None,
+            communication_disabled_until: partial.communication_disabled_until,
             guild_id: partial.guild_id.unwrap_or_default(),
             unusual_dm_activity_until: partial.unusual_dm_activity_until,
+            avatar_decoration_data: None,
         }
     }
 }
@@ -620,6 +640,7 @@ impl From<Member> for PartialMember {
             roles: member.roles,
             pending: member.pending,
             premium_since: member.premium_since,
+            communication_disabled_until: member.communication_disabled_until,
             guild_id: Some(member.guild_id),
             user: Some(member.user),
             permissions: member.permissions,
@@ -5,14 +5,18 @@ pub mod automod;
 mod emoji;
 mod guild_id;
 mod guild_preview;
+mod guild_subscription;
 mod integration;
 mod member;
+mod onboarding;
 mod partial_guild;
 mod premium_tier;
 mod role;
 mod scheduled_event;
 mod system_channel;
+mod template;
 mod welcome_screen;
+mod widget;
 
 #[cfg(feature = "model")]
 use std::borrow::Cow;
@@ -23,14 +27,18 @@ use tracing::{error, warn};
 pub use self::emoji::*;
 pub use self::guild_id::*;
 pub use self::guild_preview::*;
+pub use self::guild_subscription::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
 pub use self::system_channel::*;
+pub use self::template::*;
 pub use self::welcome_screen::*;
+pub use self::widget::*;
 #[cfg(feature = "model")]
 use crate::builder::{
     AddMember,
@@ -44,6 +52,7 @@ use crate::builder::{
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
+    EditOnboarding,
     EditRole,
     EditScheduledEvent,
     EditSticker,
@@ -62,6 +71,8 @@ use crate::http::{CacheHttp, Http, UserPagination};
 use crate::json::json;
 use crate::model::prelude::*;
 use crate::model::utils::*;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 /// A representation of a banning of a user.
 ///
@@ -615,9 +626,10 @@ impl Guild {
         action_type: Option<audit_log::Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
+        after: Option<AuditLogEntryId>,
         limit: Option<u8>,
     ) -> Result<AuditLogs> {
-        self.id.audit_logs(http, action_type, user_id, before, limit).await
+        self.id.audit_logs(http, action_type, user_id, before, after, limit).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -1336,6 +1348,24 @@ impl Guild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Edits the guild's onboarding.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::edit_onboarding`] for a list of possible errors.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditOnboarding<'_>,
+    ) -> Result<Onboarding> {
+        self.id.edit_onboarding(cache_http, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1913,6 +1943,7 @@ impl Guild {
             None,
             member.user.id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
@@ -1940,13 +1971,21 @@ impl Guild {
     }
 
     /// Calculate a [`Member`]'s permissions in a given channel in the guild.
+    ///
+    /// Threads use the permission overwrites of their parent channel. Members who are timed out
+    /// only keep the [View Channel] and [Read Message History] permissions, unless they are an
+    /// administrator or the guild owner.
+    ///
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
     #[inline]
     #[must_use]
     pub fn user_permissions_in(&self, channel: &GuildChannel, member: &Member) -> Permissions {
         Self::_user_permissions_in(
-            Some(channel),
+            Some(self.overwrites_channel(channel)),
             member.user.id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
@@ -1970,20 +2009,31 @@ impl Guild {
         }
 
         Self::_user_permissions_in(
-            Some(channel),
+            Some(self.overwrites_channel(channel)),
             member_id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
         )
     }
 
+    /// Threads have no permission overwrites of their own and inherit those of their parent.
+    fn overwrites_channel<'a>(&'a self, channel: &'a GuildChannel) -> &'a GuildChannel {
+        if channel.thread_metadata.is_none() {
+            return channel;
+        }
+
+        channel.parent_id.and_then(|parent_id| self.channels.get(&parent_id)).unwrap_or(channel)
+    }
+
     /// Helper function that can also be used from [`PartialGuild`].
     pub(crate) fn _user_permissions_in(
         channel: Option<&GuildChannel>,
         member_user_id: UserId,
         member_roles: &[RoleId],
+        member_communication_disabled_until: Option<Timestamp>,
         guild_id: GuildId,
         guild_roles: &HashMap<RoleId, Role>,
         guild_owner_id: UserId,
@@ -2019,6 +2069,8 @@ impl Guild {
 
         calculate_permissions(CalculatePermissions {
             is_guild_owner: member_user_id == guild_owner_id,
+            is_timed_out: member_communication_disabled_until
+                .is_some_and(|until| until > Timestamp::now()),
             everyone_permissions: if let Some(role) = guild_roles.get(&RoleId::new(guild_id.get()))
             {
                 role.permissions
@@ -2110,7 +2162,12 @@ impl Guild {
     /// [Kick Members]: Permissions::KICK_MEMBERS
     /// [`Error::Http`]: crate::error::Error::Http
     /// [`Error::Json`]: crate::error::Error::Json
-    pub async fn prune_count(&self, cache_http: impl CacheHttp, days: u8) -> Result<GuildPrune> {
+    pub async fn prune_count(
+        &self,
+        cache_http: impl CacheHttp,
+        days: u8,
+        include_roles: &[RoleId],
+    ) -> Result<GuildPrune> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -2118,7 +2175,7 @@ impl Guild {
             }
         }
 
-        self.id.prune_count(cache_http.http(), days).await
+        self.id.prune_count(cache_http.http(), days, include_roles).await
     }
 
     pub(crate) fn remove_unusable_permissions(permissions: &mut Permissions) {
@@ -2328,7 +2385,8 @@ impl Guild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        let options = ImageOptions::new().format(ImageFormat::Webp).size(4096);
+        self.splash.as_ref().map(|splash| cdn::guild_splash(self.id, splash, options))
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -2418,7 +2476,7 @@ impl Guild {
     /// Will return [`Error::Http`] if the current user is lacking permissions. Can also return an
     /// [`Error::Json`] if there is an error deserializing the API response.
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(http).await
     }
 
@@ -2513,6 +2571,8 @@ impl Guild {
 struct CalculatePermissions {
     /// Whether the guild member is the guild owner
     pub is_guild_owner: bool,
+    /// Whether the guild member is currently timed out
+    pub is_timed_out: bool,
     /// Base permissions given to @everyone (guild level)
     pub everyone_permissions: Permissions,
     /// Permissions allowed to a user by their roles (guild level)
@@ -2536,6 +2596,7 @@ impl Default for CalculatePermissions {
     fn default() -> Self {
         Self {
             is_guild_owner: false,
+            is_timed_out: false,
             everyone_permissions: Permissions::empty(),
             user_roles_permissions: Vec::new(),
             everyone_allow_overwrites: Permissions::empty(),
@@ -2592,6 +2653,11 @@ fn calculate_permissions(data: CalculatePermissions) -> Permissions {
     // 8. Member-specific overwrites that allow permissions are applied at a channel level
     permissions |= data.member_allow_overwrites;
 
+    // Timed out members can only read, unless they are an administrator (handled above)
+    if data.is_timed_out {
+        permissions &= Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+    }
+
     permissions
 }
 
@@ -2624,7 +2690,9 @@ fn closest_to_origin(origin: &str, word_a: &str, word_b: &str) -> std::cmp::Orde
     value_a.cmp(&value_b)
 }
 
-/// A [`Guild`] widget.
+/// The settings of a [`Guild`]'s widget.
+///
+/// See [`GuildWidgetInfo`] for the public data shown by the widget.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-settings-object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -2647,6 +2715,28 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// The vanity invite of a guild with the `VANITY_URL` feature.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildVanityUrl {
+    /// The code of the vanity invite, if one is set.
+    pub code: Option<String>,
+    /// The number of times the vanity invite has been used.
+    pub uses: u64,
+}
+
+#[cfg(feature = "model")]
+impl GuildVanityUrl {
+    /// Returns the URL of the vanity invite, if one is set.
+    #[must_use]
+    pub fn url(&self) -> Option<String> {
+        self.code.as_ref().map(|code| format!("https://discord.gg/{code}"))
+    }
+}
+
 /// Variant of [`Guild`] returned from [`Http::get_guilds`].
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object),
@@ -2689,7 +2779,8 @@ impl InviteGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        let options = ImageOptions::new().format(ImageFormat::Webp).size(4096);
+        self.splash.as_ref().map(|splash| cdn::guild_splash(self.id, splash, options))
     }
 }
 
@@ -2873,5 +2964,132 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        fn permissions_guild() -> Guild {
+            let role = |id: u64, permissions: Permissions| Role {
+                id: RoleId::new(id),
+                permissions,
+                ..Default::default()
+            };
+
+            let parent = GuildChannel {
+                id: ChannelId::new(10),
+                permission_overwrites: vec![
+                    PermissionOverwrite {
+                        allow: Permissions::empty(),
+                        deny: Permissions::SEND_MESSAGES,
+                        kind: PermissionOverwriteType::Role(RoleId::new(1)),
+                    },
+                    PermissionOverwrite {
+                        allow: Permissions::SEND_MESSAGES,
+                        deny: Permissions::empty(),
+                        kind: PermissionOverwriteType::Role(RoleId::new(2)),
+                    },
+                    PermissionOverwrite {
+                        allow: Permissions::empty(),
+                        deny: Permissions::SEND_MESSAGES,
+                        kind: PermissionOverwriteType::Member(UserId::new(20)),
+                    },
+                ],
+                ..Default::default()
+            };
+
+            Guild {
+                id: GuildId::new(1),
+                owner_id: UserId::new(99),
+                roles: HashMap::from([
+                    (
+                        RoleId::new(1),
+                        role(1, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+                    ),
+                    (RoleId::new(2), role(2, Permissions::empty())),
+                    (RoleId::new(3), role(3, Permissions::ADMINISTRATOR)),
+                ]),
+                channels: HashMap::from([(parent.id, parent)]),
+                ..Default::default()
+            }
+        }
+
+        fn permissions_member(id: u64, roles: &[u64]) -> Member {
+            Member {
+                user: User {
+                    id: UserId::new(id),
+                    ..Default::default()
+                },
+                roles: roles.iter().copied().map(RoleId::new).collect(),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn permission_overwrites() {
+            let guild = permissions_guild();
+            let channel = &guild.channels[&ChannelId::new(10)];
+            let send = Permissions::SEND_MESSAGES;
+
+            // The @everyone deny is applied, then overridden by a role allow, then by a member
+            // deny.
+            let everyone = permissions_member(10, &[]);
+            assert!(!guild.user_permissions_in(channel, &everyone).contains(send));
+            #[cfg(feature = "cache")]
+            assert!(guild.member_permissions(&everyone).contains(send));
+            let with_role = permissions_member(10, &[2]);
+            assert!(guild.user_permissions_in(channel, &with_role).contains(send));
+            let denied_member = permissions_member(20, &[2]);
+            assert!(!guild.user_permissions_in(channel, &denied_member).contains(send));
+
+            // Administrators and the owner bypass all overwrites.
+            let admin = permissions_member(20, &[3]);
+            assert_eq!(guild.user_permissions_in(channel, &admin), Permissions::all());
+            let owner = permissions_member(99, &[]);
+            assert_eq!(guild.user_permissions_in(channel, &owner), Permissions::all());
+        }
+
+        #[test]
+        fn permissions_in_thread() {
+            let guild = permissions_guild();
+            let thread = GuildChannel {
+                id: ChannelId::new(11),
+                parent_id: Some(ChannelId::new(10)),
+                thread_metadata: Some(
+                    crate::json::from_value(crate::json::json!({
+                        "archived": false,
+                        "auto_archive_duration": 60,
+                        "locked": false,
+                    }))
+                    .unwrap(),
+                ),
+                ..Default::default()
+            };
+
+            let member = permissions_member(10, &[]);
+            let permissions = guild.user_permissions_in(&thread, &member);
+            assert!(permissions.contains(Permissions::VIEW_CHANNEL));
+            assert!(!permissions.contains(Permissions::SEND_MESSAGES));
+        }
+
+        #[test]
+        fn permissions_timed_out() {
+            let guild = permissions_guild();
+            let channel = &guild.channels[&ChannelId::new(10)];
+            let read = Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+
+            let mut member = permissions_member(10, &[2]);
+            member.communication_disabled_until = Some(Timestamp::from_unix_timestamp(1).unwrap());
+            assert!(guild
+                .user_permissions_in(channel, &member)
+                .contains(Permissions::SEND_MESSAGES));
+
+            member.communication_disabled_until =
+                Some(Timestamp::from_unix_timestamp(i32::MAX.into()).unwrap());
+            assert!(!guild
+                .user_permissions_in(channel, &member)
+                .contains(Permissions::SEND_MESSAGES));
+            #[cfg(feature = "cache")]
+            assert!(read.contains(guild.member_permissions(&member)));
+
+            member.roles.push(RoleId::new(3));
+            assert_eq!(guild.user_permissions_in(channel, &member), Permissions::all());
+        }
     }
 }
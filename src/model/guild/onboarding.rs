@@ -0,0 +1,184 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::model::channel::ReactionType;
+use crate::model::id::{
+    ChannelId,
+    EmojiId,
+    GuildId,
+    OnboardingPromptId,
+    OnboardingPromptOptionId,
+    RoleId,
+};
+
+/// The onboarding flow of a guild, shown to new members when they join.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Onboarding {
+    /// The Id of the guild the onboarding is part of.
+    pub guild_id: GuildId,
+    /// The prompts shown during onboarding and in the customize community tab.
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The channels which members get opted into automatically.
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether onboarding is enabled in the guild.
+    pub enabled: bool,
+    /// The criteria used to check if onboarding is set up correctly.
+    pub mode: OnboardingMode,
+}
+
+/// A question shown during the onboarding of a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    pub id: OnboardingPromptId,
+    /// How the options of the prompt are displayed.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options which can be chosen.
+    pub options: Vec<OnboardingPromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether members are limited to choosing one option.
+    pub single_select: bool,
+    /// Whether the prompt must be answered to complete onboarding.
+    pub required: bool,
+    /// Whether the prompt is shown during onboarding, as opposed to only in the customize
+    /// community tab.
+    pub in_onboarding: bool,
+}
+
+/// An option of an [`OnboardingPrompt`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPromptOption {
+    /// The Id of the option.
+    pub id: OnboardingPromptOptionId,
+    /// The channels a member is added to when choosing the option.
+    pub channel_ids: Vec<ChannelId>,
+    /// The roles a member is given when choosing the option.
+    pub role_ids: Vec<RoleId>,
+    /// The emoji shown next to the option, if there is one.
+    #[serde(default, deserialize_with = "deserialize_option_emoji")]
+    pub emoji: Option<ReactionType>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option.
+    pub description: Option<String>,
+}
+
+/// Options without an emoji are sent with an emoji object where all fields are `null`.
+fn deserialize_option_emoji<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ReactionType>, D::Error> {
+    #[derive(Deserialize)]
+    struct PartialEmoji {
+        id: Option<EmojiId>,
+        name: Option<String>,
+        #[serde(default)]
+        animated: bool,
+    }
+
+    Ok(Option::<PartialEmoji>::deserialize(deserializer)?.and_then(|emoji| {
+        match (emoji.id, emoji.name) {
+            (Some(id), name) => Some(ReactionType::Custom {
+                animated: emoji.animated,
+                id,
+                name,
+            }),
+            (None, Some(name)) => Some(ReactionType::Unicode(name)),
+            (None, None) => None,
+        }
+    }))
+}
+
+enum_number! {
+    /// The criteria used to check if the onboarding of a guild is set up correctly.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode).
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingMode {
+        /// Only default channels count towards the constraints.
+        #[default]
+        Default = 0,
+        /// Default channels and the channels of prompt options count towards the constraints.
+        Advanced = 1,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// How the options of an [`OnboardingPrompt`] are displayed.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types).
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum OnboardingPromptType {
+        /// The options are shown as buttons.
+        #[default]
+        MultipleChoice = 0,
+        /// The options are shown in a dropdown menu.
+        Dropdown = 1,
+        _ => Unknown(u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn onboarding() {
+        let onboarding: Onboarding = from_value(json!({
+            "guild_id": "1",
+            "prompts": [{
+                "id": "2",
+                "type": 1,
+                "options": [{
+                    "id": "3",
+                    "channel_ids": ["4"],
+                    "role_ids": [],
+                    "emoji": {"id": null, "name": null, "animated": false},
+                    "title": "Rust",
+                    "description": null,
+                }, {
+                    "id": "5",
+                    "channel_ids": [],
+                    "role_ids": ["6"],
+                    "emoji": {"id": null, "name": "\u{1f980}", "animated": false},
+                    "title": "Crabs",
+                    "description": "Likes crabs",
+                }],
+                "title": "Interests",
+                "single_select": false,
+                "required": true,
+                "in_onboarding": true,
+            }],
+            "default_channel_ids": ["4"],
+            "enabled": true,
+            "mode": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(onboarding.mode, OnboardingMode::Advanced);
+        let prompt = &onboarding.prompts[0];
+        assert_eq!(prompt.kind, OnboardingPromptType::Dropdown);
+        assert_eq!(prompt.options[0].emoji, None);
+        assert_eq!(prompt.options[1].emoji, Some(ReactionType::Unicode("\u{1f980}".into())));
+    }
+}
@@ -11,6 +11,7 @@ use crate::builder::{
     EditGuildWelcomeScreen,
     EditGuildWidget,
     EditMember,
+    EditOnboarding,
     EditRole,
     EditSticker,
 };
@@ -26,6 +27,8 @@ use crate::model::prelude::*;
 #[cfg(feature = "model")]
 use crate::model::utils::icon_url;
 use crate::model::utils::{emojis, roles, stickers};
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 /// Partial information about a [`Guild`]. This does not include information like member data.
 ///
@@ -357,9 +360,10 @@ impl PartialGuild {
         action_type: Option<audit_log::Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
+        after: Option<AuditLogEntryId>,
         limit: Option<u8>,
     ) -> Result<AuditLogs> {
-        self.id.audit_logs(http, action_type, user_id, before, limit).await
+        self.id.audit_logs(http, action_type, user_id, before, after, limit).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -958,6 +962,24 @@ impl PartialGuild {
         self.id.edit_sticker(cache_http, sticker_id, builder).await
     }
 
+    /// Edits the guild's onboarding.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`GuildId::edit_onboarding`] for a list of possible errors.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: EditOnboarding<'_>,
+    ) -> Result<Onboarding> {
+        self.id.edit_onboarding(cache_http, builder).await
+    }
+
     /// Edits the guild's welcome screen.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1041,6 +1063,7 @@ impl PartialGuild {
             None,
             member.user.id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
@@ -1067,6 +1090,7 @@ impl PartialGuild {
             Some(channel),
             member_id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
@@ -1309,6 +1333,9 @@ impl PartialGuild {
     }
 
     /// Calculate a [`Member`]'s permissions in a given channel in the guild.
+    ///
+    /// **Note**: The channels of a partial guild are unknown, so for threads the parent channel
+    /// must be passed instead. See [`Guild::user_permissions_in`] for more information.
     #[inline]
     #[must_use]
     pub fn user_permissions_in(&self, channel: &GuildChannel, member: &Member) -> Permissions {
@@ -1316,6 +1343,7 @@ impl PartialGuild {
             Some(channel),
             member.user.id,
             &member.roles,
+            member.communication_disabled_until,
             self.id,
             &self.roles,
             self.owner_id,
@@ -1346,8 +1374,13 @@ impl PartialGuild {
     /// [Kick Members]: Permissions::KICK_MEMBERS
     /// [`Guild::prune_count`]: crate::model::guild::Guild::prune_count
     #[inline]
-    pub async fn prune_count(&self, http: impl AsRef<Http>, days: u8) -> Result<GuildPrune> {
-        self.id.prune_count(http, days).await
+    pub async fn prune_count(
+        &self,
+        http: impl AsRef<Http>,
+        days: u8,
+        include_roles: &[RoleId],
+    ) -> Result<GuildPrune> {
+        self.id.prune_count(http, days, include_roles).await
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -1394,7 +1427,8 @@ impl PartialGuild {
     #[inline]
     #[must_use]
     pub fn splash_url(&self) -> Option<String> {
-        self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
+        let options = ImageOptions::new().format(ImageFormat::Webp).size(4096);
+        self.splash.as_ref().map(|splash| cdn::guild_splash(self.id, splash, options))
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -1442,7 +1476,7 @@ impl PartialGuild {
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     /// [`Guild::vanity_url`]: crate::model::guild::Guild::vanity_url
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(http).await
     }
 
@@ -11,6 +11,8 @@ use crate::http::Http;
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::is_false;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageOptions};
 
 /// Information about a role within a guild. A role represents a set of permissions, and can be
 /// attached to one or multiple users. A role has various miscellaneous configurations, such as
@@ -64,9 +66,11 @@ pub struct Role {
     /// [`Integration`]: super::Integration
     #[serde(default)]
     pub tags: RoleTags,
-    /// Role icon image hash.
+    /// The hash of the role's custom icon image, if it has one.
+    ///
+    /// **Note**: Role icons require the guild to have the `ROLE_ICONS` feature.
     pub icon: Option<ImageHash>,
-    /// Role unicoded image.
+    /// The unicode emoji used as the role's icon, if it has one.
     pub unicode_emoji: Option<String>,
 }
 
@@ -105,6 +109,12 @@ impl Role {
         Ok(())
     }
 
+    /// Returns the URL of the role's custom icon, if it has one.
+    #[must_use]
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon.as_ref().map(|icon| cdn::role_icon(self.id, icon, ImageOptions::new()))
+    }
+
     /// Check that the role has the given permission.
     #[inline]
     #[must_use]
@@ -294,4 +304,26 @@ mod tests {
             json!({"bot_id": null, "integration_id": null, "subscription_listing_id": null}),
         );
     }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn role_icon_url() {
+        let role: super::Role = crate::json::from_value(json!({
+            "id": "1",
+            "name": "Booster",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "permissions": "0",
+            "position": 1,
+            "icon": "fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05",
+            "unicode_emoji": null,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            role.icon_url().unwrap(),
+            "https://cdn.discordapp.com/role-icons/1/fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.webp"
+        );
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
 /// Information about a guild scheduled event.
@@ -51,6 +53,9 @@ pub struct ScheduledEvent {
     pub user_count: Option<u64>,
     /// The hash of the event's cover image, if present.
     pub image: Option<ImageHash>,
+    /// The rule by which the event repeats, if it is a recurring event.
+    #[serde(default)]
+    pub recurrence_rule: Option<ScheduledEventRecurrenceRule>,
 }
 
 enum_number! {
@@ -91,6 +96,245 @@ pub struct ScheduledEventMetadata {
     pub location: Option<String>,
 }
 
+/// The rule by which a recurring [`ScheduledEvent`] repeats.
+///
+/// Discord only accepts a subset of all possible rules, e.g. `by_weekday` may only be combined
+/// with a daily or weekly frequency. See the Discord docs for the supported combinations.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ScheduledEventRecurrenceRule {
+    /// The start of the interval within which the event recurs.
+    pub start: Timestamp,
+    /// The end of the interval within which the event recurs. Set by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<Timestamp>,
+    /// How often the event occurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// The spacing between events, e.g. an interval of 2 with a weekly frequency means every other
+    /// week.
+    pub interval: u16,
+    /// The days of the week the event recurs on.
+    #[serde(default)]
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// The specific days within a specific week, e.g. the first Monday, the event recurs on.
+    #[serde(default)]
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// The months the event recurs in.
+    #[serde(default)]
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// The dates within a month the event recurs on, from 1 to 31.
+    #[serde(default)]
+    pub by_month_day: Option<Vec<u8>>,
+    /// The days within a year the event recurs on, from 1 to 364. Set by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_year_day: Option<Vec<u16>>,
+    /// The total amount of times the event is allowed to recur before stopping. Set by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+impl ScheduledEventRecurrenceRule {
+    /// Creates a rule recurring with the given frequency and interval from `start` on.
+    ///
+    /// Set the `by_*` fields to further restrict when the event recurs.
+    #[must_use]
+    pub fn new(
+        start: impl Into<Timestamp>,
+        frequency: RecurrenceRuleFrequency,
+        interval: u16,
+    ) -> Self {
+        Self {
+            start: start.into(),
+            end: None,
+            frequency,
+            interval,
+            by_weekday: None,
+            by_n_weekday: None,
+            by_month: None,
+            by_month_day: None,
+            by_year_day: None,
+            count: None,
+        }
+    }
+
+    /// Clears the fields which are set by Discord, as they are not accepted in requests.
+    #[cfg(feature = "builder")]
+    pub(crate) fn into_request(self) -> Self {
+        Self {
+            end: None,
+            by_year_day: None,
+            count: None,
+            ..self
+        }
+    }
+
+    /// Checks the rule against the combinations Discord supports:
+    ///
+    /// - a daily rule may recur on one of a few sets of weekdays, such as Monday to Friday;
+    /// - a weekly rule must recur on exactly one weekday, every week or every other week;
+    /// - a monthly rule must recur on exactly one weekday within a specific week;
+    /// - a yearly rule must recur on exactly one date, given by both a month and a day of the
+    ///   month.
+    ///
+    /// Apart from the biweekly case, the interval must be 1.
+    #[cfg(feature = "http")]
+    pub(crate) fn check(&self) -> StdResult<(), ModelError> {
+        use RecurrenceRuleWeekday::{
+            Friday,
+            Monday,
+            Saturday,
+            Sunday,
+            Thursday,
+            Tuesday,
+            Wednesday,
+        };
+
+        const DAILY_WEEKDAYS: [&[RecurrenceRuleWeekday]; 6] = [
+            &[Monday, Tuesday, Wednesday, Thursday, Friday],
+            &[Tuesday, Wednesday, Thursday, Friday, Saturday],
+            &[Monday, Tuesday, Wednesday, Thursday, Sunday],
+            &[Friday, Saturday],
+            &[Saturday, Sunday],
+            &[Monday, Sunday],
+        ];
+
+        let mut weekdays = self.by_weekday.clone().unwrap_or_default();
+        weekdays.sort_unstable();
+        let n_weekdays = self.by_n_weekday.as_deref().unwrap_or_default();
+        let months = self.by_month.as_deref().unwrap_or_default();
+        let month_days = self.by_month_day.as_deref().unwrap_or_default();
+
+        let valid = match self.frequency {
+            RecurrenceRuleFrequency::Daily => {
+                self.interval == 1
+                    && (weekdays.is_empty() || DAILY_WEEKDAYS.contains(&weekdays.as_slice()))
+                    && n_weekdays.is_empty()
+                    && months.is_empty()
+                    && month_days.is_empty()
+            },
+            RecurrenceRuleFrequency::Weekly => {
+                matches!(self.interval, 1 | 2)
+                    && weekdays.len() == 1
+                    && n_weekdays.is_empty()
+                    && months.is_empty()
+                    && month_days.is_empty()
+            },
+            RecurrenceRuleFrequency::Monthly => {
+                self.interval == 1
+                    && weekdays.is_empty()
+                    && n_weekdays.len() == 1
+                    && n_weekdays.iter().all(|n_weekday| (1..=5).contains(&n_weekday.n))
+                    && months.is_empty()
+                    && month_days.is_empty()
+            },
+            RecurrenceRuleFrequency::Yearly => {
+                self.interval == 1
+                    && weekdays.is_empty()
+                    && n_weekdays.is_empty()
+                    && months.len() == 1
+                    && month_days.len() == 1
+                    && month_days.iter().all(|day| (1..=31).contains(day))
+            },
+            RecurrenceRuleFrequency::Unknown(_) => true,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ModelError::InvalidRecurrenceRule)
+        }
+    }
+}
+
+enum_number! {
+    /// See [`ScheduledEventRecurrenceRule::frequency`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-frequency).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum RecurrenceRuleFrequency {
+        Yearly = 0,
+        Monthly = 1,
+        Weekly = 2,
+        Daily = 3,
+        _ => Unknown(u8),
+    }
+}
+
+enum_number! {
+    /// A day of the week, see [`ScheduledEventRecurrenceRule::by_weekday`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-weekday).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum RecurrenceRuleWeekday {
+        Monday = 0,
+        Tuesday = 1,
+        Wednesday = 2,
+        Thursday = 3,
+        Friday = 4,
+        Saturday = 5,
+        Sunday = 6,
+        _ => Unknown(u8),
+    }
+}
+
+/// A specific day within a specific week, see [`ScheduledEventRecurrenceRule::by_n_weekday`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-nweekday-structure).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct RecurrenceRuleNWeekday {
+    /// The week of the month, from 1 to 5.
+    pub n: u8,
+    /// The day within the week.
+    pub day: RecurrenceRuleWeekday,
+}
+
+impl RecurrenceRuleNWeekday {
+    /// Creates the `n`th given weekday of a month.
+    #[must_use]
+    pub fn new(n: u8, day: RecurrenceRuleWeekday) -> Self {
+        Self {
+            n,
+            day,
+        }
+    }
+}
+
+enum_number! {
+    /// A month, see [`ScheduledEventRecurrenceRule::by_month`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-month).
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum RecurrenceRuleMonth {
+        January = 1,
+        February = 2,
+        March = 3,
+        April = 4,
+        May = 5,
+        June = 6,
+        July = 7,
+        August = 8,
+        September = 9,
+        October = 10,
+        November = 11,
+        December = 12,
+        _ => Unknown(u8),
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-user-object).
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -115,3 +359,91 @@ enum_number! {
         _ => Unknown(u8),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{json, to_value};
+
+    #[test]
+    fn recurrence_rule() {
+        let mut rule = ScheduledEventRecurrenceRule::new(
+            Timestamp::from_unix_timestamp(0).unwrap(),
+            RecurrenceRuleFrequency::Monthly,
+            1,
+        );
+        rule.by_n_weekday =
+            Some(vec![RecurrenceRuleNWeekday::new(1, RecurrenceRuleWeekday::Friday)]);
+        rule.count = Some(3);
+
+        let value = json!({
+            "start": "1970-01-01T00:00:00Z",
+            "frequency": 1,
+            "interval": 1,
+            "by_weekday": null,
+            "by_n_weekday": [{"n": 1, "day": 4}],
+            "by_month": null,
+            "by_month_day": null,
+        });
+        let mut with_count = value.clone();
+        with_count["count"] = json!(3);
+        assert_eq!(to_value(&rule).unwrap(), with_count);
+
+        // Fields set by Discord are not sent back.
+        #[cfg(feature = "builder")]
+        assert_eq!(to_value(rule.into_request()).unwrap(), value);
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn recurrence_rule_check() {
+        use RecurrenceRuleWeekday::{
+            Friday,
+            Monday,
+            Saturday,
+            Sunday,
+            Thursday,
+            Tuesday,
+            Wednesday,
+        };
+
+        let rule = |frequency, interval| {
+            ScheduledEventRecurrenceRule::new(
+                Timestamp::from_unix_timestamp(0).unwrap(),
+                frequency,
+                interval,
+            )
+        };
+
+        let mut daily = rule(RecurrenceRuleFrequency::Daily, 1);
+        assert!(daily.check().is_ok());
+        daily.by_weekday = Some(vec![Sunday, Monday, Tuesday, Wednesday, Thursday]);
+        assert!(daily.check().is_ok());
+        daily.by_weekday = Some(vec![Monday, Friday]);
+        assert!(daily.check().is_err());
+
+        let mut weekly = rule(RecurrenceRuleFrequency::Weekly, 2);
+        assert!(weekly.check().is_err());
+        weekly.by_weekday = Some(vec![Wednesday]);
+        assert!(weekly.check().is_ok());
+        weekly.by_weekday = Some(vec![Wednesday, Saturday]);
+        assert!(weekly.check().is_err());
+        assert!(rule(RecurrenceRuleFrequency::Weekly, 3).check().is_err());
+
+        let mut monthly = rule(RecurrenceRuleFrequency::Monthly, 1);
+        assert!(monthly.check().is_err());
+        monthly.by_n_weekday = Some(vec![]);
+        assert!(monthly.check().is_err());
+        monthly.by_n_weekday = Some(vec![RecurrenceRuleNWeekday::new(2, Friday)]);
+        assert!(monthly.check().is_ok());
+        monthly.by_n_weekday = Some(vec![RecurrenceRuleNWeekday::new(6, Friday)]);
+        assert!(monthly.check().is_err());
+
+        let mut yearly = rule(RecurrenceRuleFrequency::Yearly, 1);
+        assert!(yearly.check().is_err());
+        yearly.by_month = Some(vec![RecurrenceRuleMonth::July]);
+        assert!(yearly.check().is_err());
+        yearly.by_month_day = Some(vec![4]);
+        assert!(yearly.check().is_ok());
+    }
+}
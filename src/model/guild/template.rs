@@ -0,0 +1,149 @@
+#[cfg(feature = "model")]
+use crate::builder::{Builder, CreateGuildFromTemplate, EditGuildTemplate};
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::guild::PartialGuild;
+use crate::model::id::{GuildId, UserId};
+use crate::model::user::User;
+use crate::model::Timestamp;
+
+/// A snapshot of the channels, roles and settings of a guild, which new guilds can be created
+/// from.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The unique code of the template.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of times the template has been used.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced to its source guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the source guild.
+    ///
+    /// **Note**: The Ids in the snapshot are placeholders local to the template, so it is kept as
+    /// raw JSON.
+    pub serialized_source_guild: Value,
+    /// Whether the source guild has changed since the template was last synced.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(feature = "model")]
+impl GuildTemplate {
+    /// Gets a template by its code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if there is no template with the given code.
+    pub async fn get(http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().get_guild_template(code).await
+    }
+
+    /// Creates a new guild from the template.
+    ///
+    /// **Note**: This endpoint is usually only available for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreateGuildFromTemplate::execute`] for a list of possible errors.
+    pub async fn create_guild(
+        &self,
+        cache_http: impl CacheHttp,
+        builder: CreateGuildFromTemplate,
+    ) -> Result<PartialGuild> {
+        builder.execute(cache_http, &self.code).await
+    }
+
+    /// Syncs the template to the current state of its source guild.
+    ///
+    /// Refer to [`GuildId::sync_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn sync(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.sync_template(http, &self.code).await
+    }
+
+    /// Edits the name or description of the template.
+    ///
+    /// Refer to [`GuildId::edit_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`EditGuildTemplate::execute`] for a list of possible errors.
+    pub async fn edit(
+        &mut self,
+        cache_http: impl CacheHttp,
+        builder: EditGuildTemplate,
+    ) -> Result<()> {
+        *self = self.source_guild_id.edit_template(cache_http, &self.code, builder).await?;
+        Ok(())
+    }
+
+    /// Deletes the template.
+    ///
+    /// Refer to [`GuildId::delete_template`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.delete_template(http, &self.code).await
+    }
+
+    /// Returns the link which can be shared to create a guild from the template.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("https://discord.new/{}", self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn guild_template() {
+        let template: GuildTemplate = from_value(json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49605,
+            "creator_id": "1",
+            "creator": {"id": "1", "username": "user", "discriminator": "0", "avatar": null},
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "2",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "roles": [{"id": 0, "name": "@everyone", "permissions": "104189505"}],
+                "channels": [{"id": 1, "name": "general", "type": 0, "parent_id": null}],
+            },
+            "is_dirty": null,
+        }))
+        .unwrap();
+
+        assert_eq!(template.source_guild_id, GuildId::new(2));
+        assert_eq!(template.serialized_source_guild["channels"][0]["name"], "general");
+        #[cfg(feature = "model")]
+        assert_eq!(template.url(), "https://discord.new/hgM48av5Q69A");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::model::id::{ChannelId, GuildId};
+use crate::model::user::OnlineStatus;
+
+/// The public data of a guild's widget, as shown by its embeddable HTML widget.
+///
+/// Only available for guilds which have the widget enabled.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetInfo {
+    /// The guild Id.
+    pub id: GuildId,
+    /// The guild name.
+    pub name: String,
+    /// An invite URL to the widget channel, if one is set.
+    pub instant_invite: Option<String>,
+    /// The voice channels which can be joined through the widget.
+    pub channels: Vec<GuildWidgetChannel>,
+    /// The online members of the guild, limited to 100.
+    pub members: Vec<GuildWidgetMember>,
+    /// The number of online members in the guild.
+    pub presence_count: u64,
+}
+
+/// A voice channel listed on a guild's widget.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetChannel {
+    /// The channel Id.
+    pub id: ChannelId,
+    /// The channel name.
+    pub name: String,
+    /// The sorting position of the channel.
+    pub position: u16,
+}
+
+/// An online member listed on a guild's widget.
+///
+/// **Note**: To protect the privacy of members, the Id and discriminator are anonymised, so the
+/// member can't be related to a [`User`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-widget-object-example-guild-widget).
+///
+/// [`User`]: crate::model::user::User
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetMember {
+    /// The index of the member in the widget, as a string.
+    pub id: String,
+    /// The name of the member.
+    pub username: String,
+    /// The placeholder discriminator of the member.
+    pub discriminator: String,
+    /// Always [`None`].
+    pub avatar: Option<String>,
+    /// The online status of the member.
+    pub status: OnlineStatus,
+    /// The URL of the avatar of the member.
+    pub avatar_url: String,
+    /// The activity of the member, if any.
+    pub activity: Option<GuildWidgetActivity>,
+    /// The voice channel the member is connected to, if it is listed in
+    /// [`GuildWidgetInfo::channels`].
+    pub channel_id: Option<ChannelId>,
+}
+
+/// The activity of a [`GuildWidgetMember`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidgetActivity {
+    /// The name of the activity.
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn guild_widget_info() {
+        let widget: GuildWidgetInfo = from_value(json!({
+            "id": "290926798626357999",
+            "name": "Test Server",
+            "instant_invite": "https://discord.com/invite/abcdefg",
+            "channels": [{"id": "705216630279993882", "name": "elephant", "position": 2}],
+            "members": [{
+                "id": "0",
+                "username": "1234",
+                "discriminator": "0000",
+                "avatar": null,
+                "status": "online",
+                "avatar_url": "https://cdn.discordapp.com/widget-avatars/FfvURgcr3Za92K3JtoCppqnYMppMDc5B-Rll74YrGCU/C-1DyBZPQ6t5q5RuATFuMFgq0_uEMZVzd_6LbOIoNs4",
+                "activity": {"name": "Cyberpunk"},
+            }],
+            "presence_count": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(widget.channels[0].id, ChannelId::new(705216630279993882));
+        assert_eq!(widget.members[0].status, OnlineStatus::Online);
+        assert_eq!(widget.members[0].activity.as_ref().unwrap().name, "Cyberpunk");
+        assert_eq!(widget.members[0].channel_id, None);
+        #[cfg(feature = "model")]
+        assert_eq!(
+            widget.id.widget_image_url(crate::model::guild::GuildWidgetStyle::Banner2),
+            "https://discord.com/api/v10/guilds/290926798626357999/widget.png?style=banner2",
+        );
+    }
+}
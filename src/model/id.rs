@@ -258,10 +258,34 @@ pub struct StageInstanceId(#[serde(with = "snowflake")] NonZeroU64);
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct ForumTagId(#[serde(with = "snowflake")] NonZeroU64);
 
+/// An identifier for a soundboard sound.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct SoundId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for a prompt of a guild's onboarding.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct OnboardingPromptId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for an option of an onboarding prompt.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct OnboardingPromptOptionId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for a boost applied to a guild.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct GuildSubscriptionId(#[serde(with = "snowflake")] NonZeroU64);
+
+/// An identifier for a boost slot of the current user.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct GuildSubscriptionSlotId(#[serde(with = "snowflake")] NonZeroU64);
+
 /// An identifier for an entitlement.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct EntitlementId(#[serde(with = "snowflake")] pub NonZeroU64);
 
+/// An identifier for a subscription of a user to an application's SKU.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct SubscriptionId(#[serde(with = "snowflake")] NonZeroU64);
+
 id_u64! {
     AttachmentId;
     ApplicationId;
@@ -289,6 +313,12 @@ id_u64! {
     RuleId;
     ForumTagId;
     EntitlementId;
+    GuildSubscriptionId;
+    GuildSubscriptionSlotId;
+    OnboardingPromptId;
+    OnboardingPromptOptionId;
+    SoundId;
+    SubscriptionId;
 }
 
 /// An identifier for a Shard.
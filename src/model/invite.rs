@@ -6,7 +6,7 @@ use crate::builder::CreateInvite;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http};
+use crate::http::{CacheHttp, CaptchaSolution, Http};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 
@@ -63,6 +63,60 @@ pub struct Invite {
 
 #[cfg(feature = "model")]
 impl Invite {
+    /// Joins the guild of the invite as the current user, returning the updated invite.
+    ///
+    /// **Note**: Only user accounts can accept invites.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Captcha`] if Discord requires a captcha to be solved first, which can
+    /// be passed to [`Self::accept_with_captcha`] once solved. Otherwise returns an
+    /// [`Error::Http`] if the invite is invalid or the current user is banned from the guild.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::invite::Invite;
+    /// # fn solve(sitekey: &str, rqdata: Option<&str>) -> String { unimplemented!() }
+    /// # async fn run(http: &Http, invite: Invite) -> Result<(), serenity::Error> {
+    /// use serenity::Error;
+    ///
+    /// if let Err(why) = invite.accept(http).await {
+    ///     let Error::Captcha {
+    ///         sitekey,
+    ///         rqdata,
+    ///         ..
+    ///     } = &why
+    ///     else {
+    ///         return Err(why);
+    ///     };
+    ///
+    ///     let solution = why.captcha_solution(solve(sitekey, rqdata.as_deref())).unwrap();
+    ///     invite.accept_with_captcha(http, &solution).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept(&self, http: impl AsRef<Http>) -> Result<Invite> {
+        http.as_ref().join_guild_by_invite(&self.code).await
+    }
+
+    /// Joins the guild of the invite as the current user, attaching a solved captcha to retry an
+    /// [`Self::accept`] which failed with an [`Error::Captcha`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Captcha`] again if the solution was rejected, and otherwise the same
+    /// errors as [`Self::accept`].
+    pub async fn accept_with_captcha(
+        &self,
+        http: impl AsRef<Http>,
+        solution: &CaptchaSolution,
+    ) -> Result<Invite> {
+        http.as_ref().clone_with_captcha(solution)?.join_guild_by_invite(&self.code).await
+    }
+
     /// Creates an invite for the given channel.
     ///
     /// **Note**: Requires the [Create Instant Invite] permission.
@@ -13,6 +13,8 @@ use arrayvec::ArrayString;
 use super::prelude::*;
 #[cfg(all(feature = "model", any(feature = "cache", feature = "utils")))]
 use crate::utils;
+#[cfg(all(feature = "model", feature = "utils"))]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 /// Hides the implementation detail of ImageHash as an enum.
 #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
@@ -178,9 +180,8 @@ impl EmojiIdentifier {
     /// Generates a URL to the emoji's image.
     #[must_use]
     pub fn url(&self) -> String {
-        let ext = if self.animated { "gif" } else { "png" };
-
-        cdn!("/emojis/{}.{}", self.id, ext)
+        let format = if self.animated { ImageFormat::Gif } else { ImageFormat::Png };
+        cdn::emoji(self.id, self.animated, ImageOptions::new().format(format))
     }
 }
 
@@ -288,8 +289,33 @@ pub struct Maintenance {
     pub updated_at: String,
 }
 
+/// The overall status of Discord, as summarized by the status page.
+///
+/// [Discord docs](https://discordstatus.com/api) (see "Summary" example)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StatusSummary {
+    pub status: StatusIndicator,
+    #[serde(default)]
+    pub incidents: Vec<Incident>,
+    #[serde(default)]
+    pub scheduled_maintenances: Vec<Maintenance>,
+}
+
+/// The severity of Discord's current status, such as `"minor"`, with a human readable
+/// description, such as `"Partial System Outage"`.
+///
+/// [Discord docs](https://discordstatus.com/api) (see "Status" example)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StatusIndicator {
+    pub indicator: String,
+    pub description: String,
+}
+
 #[cfg(test)]
 mod test {
+    use crate::json::{from_value, json};
     use crate::model::prelude::*;
 
     #[test]
@@ -300,4 +326,43 @@ mod test {
         assert_eq!(RoleId::new(4).to_string(), "4");
         assert_eq!(UserId::new(5).to_string(), "5");
     }
+
+    #[test]
+    fn deserialize_status_summary() {
+        let summary = json!({
+            "page": {"id": "srhpyqt94yxb", "name": "Discord"},
+            "status": {"indicator": "minor", "description": "Partial System Outage"},
+            "components": [],
+            "incidents": [{
+                "id": "abc123",
+                "name": "Elevated API errors",
+                "status": "identified",
+                "impact": "minor",
+                "shortlink": "https://stspg.io/abc123",
+                "page_id": "srhpyqt94yxb",
+                "created_at": "2024-05-01T10:00:00.000-07:00",
+                "updated_at": "2024-05-01T10:30:00.000-07:00",
+                "monitoring_at": null,
+                "resolved_at": null,
+                "incident_updates": [{
+                    "id": "def456",
+                    "incident_id": "abc123",
+                    "status": "identified",
+                    "body": "We have identified the issue.",
+                    "created_at": "2024-05-01T10:30:00.000-07:00",
+                    "display_at": "2024-05-01T10:30:00.000-07:00",
+                    "updated_at": "2024-05-01T10:30:00.000-07:00",
+                }],
+            }],
+            "scheduled_maintenances": [],
+        });
+
+        let summary: StatusSummary = from_value(summary).unwrap();
+        assert_eq!(summary.status.indicator, "minor");
+        assert!(summary.scheduled_maintenances.is_empty());
+
+        let incident = &summary.incidents[0];
+        assert_eq!(incident.status, "identified");
+        assert_eq!(incident.incident_updates.len(), 1);
+    }
 }
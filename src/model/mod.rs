@@ -34,6 +34,8 @@ pub mod mention;
 pub mod misc;
 pub mod monetization;
 pub mod permissions;
+pub mod session;
+pub mod soundboard;
 pub mod sticker;
 pub mod timestamp;
 pub mod user;
@@ -93,6 +95,8 @@ pub mod prelude {
         misc::*,
         monetization::*,
         permissions::*,
+        session::*,
+        soundboard::*,
         sticker::*,
         user::*,
         voice::*,
@@ -1,3 +1,7 @@
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
 /// A premium offering that can be made available to an application's users and guilds.
@@ -28,6 +32,10 @@ enum_number! {
     #[serde(from = "u8", into = "u8")]
     #[non_exhaustive]
     pub enum SkuKind {
+        /// A durable one-time purchase.
+        Durable = 2,
+        /// A consumable one-time purchase, which can be bought again once consumed.
+        Consumable = 3,
         /// Represents a recurring subscription.
         Subscription = 5,
         /// A system-generated group for each SKU created of type [`SkuKind::Subscription`].
@@ -80,6 +88,24 @@ pub struct Entitlement {
     pub ends_at: Option<Timestamp>,
     /// The ID of the guild that is granted access to the SKU.
     pub guild_id: Option<GuildId>,
+    /// For consumable SKUs, whether the entitlement has been consumed.
+    pub consumed: Option<bool>,
+}
+
+#[cfg(feature = "model")]
+impl Entitlement {
+    /// Marks the entitlement as consumed, so that the SKU can be purchased again.
+    ///
+    /// Only entitlements for [consumable] SKUs can be consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the entitlement is not consumable or was already consumed.
+    ///
+    /// [consumable]: SkuKind::Consumable
+    pub async fn consume(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().consume_entitlement(self.id).await
+    }
 }
 
 enum_number! {
@@ -91,13 +117,108 @@ enum_number! {
     #[serde(from = "u8", into = "u8")]
     #[non_exhaustive]
     pub enum EntitlementKind {
+        /// Entitlement was purchased by a user.
+        Purchase = 1,
+        /// Entitlement for a Discord Nitro subscription.
+        PremiumSubscription = 2,
+        /// Entitlement was gifted by a developer.
+        DeveloperGift = 3,
+        /// Entitlement was purchased by a developer in application test mode.
+        TestModePurchase = 4,
+        /// Entitlement was granted when the SKU was free.
+        FreePurchase = 5,
+        /// Entitlement was gifted by another user.
+        UserGift = 6,
+        /// Entitlement was claimed by a user for free as a Nitro subscriber.
+        PremiumPurchase = 7,
         /// Entitlement was purchased as an app subscription.
         ApplicationSubscription = 8,
         _ => Unknown(u8),
     }
 }
 
+/// The guild or user a test entitlement is created for.
+///
+/// Used with [`Http::create_test_entitlement`].
+///
+/// [`Http::create_test_entitlement`]: crate::http::Http::create_test_entitlement
 pub enum EntitlementOwner {
     Guild(GuildId),
     User(UserId),
 }
+
+/// A recurring payment of a user for one of the application's SKUs.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/subscription#subscription-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Subscription {
+    /// The ID of the subscription.
+    pub id: SubscriptionId,
+    /// The ID of the user who is subscribed.
+    pub user_id: UserId,
+    /// The SKUs the user is subscribed to.
+    pub sku_ids: Vec<SkuId>,
+    /// The entitlements granted by the subscription.
+    pub entitlement_ids: Vec<EntitlementId>,
+    /// The SKUs the user will be subscribed to at renewal, if they differ from [`Self::sku_ids`].
+    pub renewal_sku_ids: Option<Vec<SkuId>>,
+    /// Start of the current billing period.
+    pub current_period_start: Timestamp,
+    /// End of the current billing period.
+    pub current_period_end: Timestamp,
+    /// The current status of the subscription.
+    pub status: SubscriptionStatus,
+    /// When the subscription was canceled.
+    pub canceled_at: Option<Timestamp>,
+    /// The ISO 3166-1 alpha-2 country code of the payment source.
+    ///
+    /// **Note**: Only present when fetched with an OAuth2 token carrying the private scopes.
+    pub country: Option<String>,
+}
+
+enum_number! {
+    /// The status of a [`Subscription`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/subscription#subscription-statuses).
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum SubscriptionStatus {
+        /// The subscription is active and scheduled to renew.
+        Active = 0,
+        /// The subscription is active but will not renew.
+        Ending = 1,
+        /// The subscription is inactive and not being charged.
+        Inactive = 2,
+        _ => Unknown(u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn subscription() {
+        let subscription: Subscription = from_value(json!({
+            "id": "1",
+            "user_id": "2",
+            "sku_ids": ["3"],
+            "entitlement_ids": ["4"],
+            "renewal_sku_ids": null,
+            "current_period_start": "2024-08-27T19:48:44.406602+00:00",
+            "current_period_end": "2024-09-27T19:48:44.406602+00:00",
+            "status": 1,
+            "canceled_at": null,
+        }))
+        .unwrap();
+
+        assert_eq!(subscription.sku_ids, [SkuId::new(3)]);
+        assert_eq!(subscription.status, SubscriptionStatus::Ending);
+        assert_eq!(subscription.country, None);
+    }
+}
@@ -0,0 +1,99 @@
+//! Models for the sessions of the current user.
+
+use super::prelude::*;
+
+/// A device logged in to the current user's account, as listed in the `Devices` settings.
+///
+/// **Note**: Only available to user accounts, see [`Http::get_auth_sessions`].
+///
+/// [`Http::get_auth_sessions`]: crate::http::Http::get_auth_sessions
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AuthSession {
+    /// The hashed Id of the session, used to log it out.
+    pub id_hash: String,
+    /// When the session was last used, accurate to about a day.
+    #[serde(rename = "approx_last_used_time")]
+    pub last_used: Timestamp,
+    /// Information about the device of the session.
+    pub client_info: AuthSessionClientInfo,
+}
+
+/// Information about the device of an [`AuthSession`].
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AuthSessionClientInfo {
+    /// The operating system of the device, e.g. `Windows`.
+    pub os: Option<String>,
+    /// The client used, e.g. `Discord Client` or the name of a browser.
+    pub platform: Option<String>,
+    /// The approximate location of the device, based on its IP address.
+    pub location: Option<String>,
+}
+
+/// A gateway session of the current user, i.e. a connected client.
+///
+/// Received in [`Ready::sessions`] and stored in the cache, see [`Cache::sessions`].
+///
+/// [`Cache::sessions`]: crate::cache::Cache::sessions
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GatewaySession {
+    /// The Id of the session, or `all` for the combined presence of all sessions.
+    pub session_id: String,
+    /// Information about the client of the session.
+    pub client_info: GatewaySessionClientInfo,
+    /// The status of the session.
+    pub status: OnlineStatus,
+    /// The activities of the session.
+    #[serde(default)]
+    pub activities: Vec<Activity>,
+    /// Whether the session is the one currently determining the user's presence.
+    #[serde(default)]
+    pub active: bool,
+}
+
+/// Information about the client of a [`GatewaySession`].
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GatewaySessionClientInfo {
+    /// The kind of client, e.g. `desktop`, `web` or `mobile`.
+    pub client: String,
+    /// The operating system of the client, e.g. `windows`.
+    pub os: String,
+    /// The version of the client.
+    #[serde(default)]
+    pub version: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn sessions() {
+        let session: AuthSession = from_value(json!({
+            "id_hash": "pXjy7kWkvfK6ZeWn8eUyIaLpNvZN4jXSmNtOY5p0U4E",
+            "approx_last_used_time": "2024-03-01T00:00:00+00:00",
+            "client_info": {"os": "Windows", "platform": "Discord Client", "location": "Berlin, DE"},
+        }))
+        .unwrap();
+        assert_eq!(session.client_info.location.as_deref(), Some("Berlin, DE"));
+
+        let session: GatewaySession = from_value(json!({
+            "session_id": "5f0f2d6f6b0e4f8e9a1c2b3d4e5f6a7b",
+            "client_info": {"client": "desktop", "os": "windows", "version": 0},
+            "status": "dnd",
+            "activities": [],
+            "active": true,
+        }))
+        .unwrap();
+        assert_eq!(session.status, OnlineStatus::DoNotDisturb);
+        assert_eq!(session.client_info.client, "desktop");
+    }
+}
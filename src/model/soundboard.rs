@@ -0,0 +1,84 @@
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+use crate::model::id::{EmojiId, GuildId, SoundId};
+use crate::model::user::User;
+#[cfg(feature = "model")]
+use crate::utils::cdn;
+
+/// A sound which can be played in voice channels, either one of Discord's default sounds or one
+/// uploaded to a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SoundboardSound {
+    /// The Id of the sound.
+    pub sound_id: SoundId,
+    /// The name of the sound.
+    pub name: String,
+    /// The volume of the sound, from 0 to 1.
+    pub volume: f64,
+    /// The Id of the custom emoji shown for the sound, if any.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode emoji shown for the sound, if any.
+    pub emoji_name: Option<String>,
+    /// The Id of the guild the sound was uploaded to, or [`None`] for default sounds.
+    pub guild_id: Option<GuildId>,
+    /// Whether the sound can be used. This may be false when the guild loses boosts, reducing the
+    /// soundboard slots.
+    pub available: bool,
+    /// The user who uploaded the sound.
+    ///
+    /// **Note**: Only included when fetched with the [Create Guild Expressions] or [Manage Guild
+    /// Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: crate::model::Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: crate::model::Permissions::MANAGE_GUILD_EXPRESSIONS
+    pub user: Option<User>,
+}
+
+#[cfg(feature = "model")]
+impl SoundboardSound {
+    /// Gets the default sounds which can be played in every guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the request fails.
+    pub async fn default_sounds(http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        http.as_ref().get_default_soundboard_sounds().await
+    }
+
+    /// Returns the URL of the sound's audio file.
+    #[must_use]
+    pub fn url(&self) -> String {
+        cdn::soundboard_sound(self.sound_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn soundboard_sound() {
+        let sound: SoundboardSound = from_value(json!({
+            "name": "quack",
+            "sound_id": "1",
+            "volume": 1.0,
+            "emoji_id": null,
+            "emoji_name": "\u{1f986}",
+            "available": true,
+        }))
+        .unwrap();
+
+        assert_eq!(sound.sound_id, SoundId::new(1));
+        assert_eq!(sound.guild_id, None);
+        assert_eq!(sound.emoji_name.as_deref(), Some("\u{1f986}"));
+        #[cfg(feature = "model")]
+        assert_eq!(sound.url(), "https://cdn.discordapp.com/soundboard-sounds/1");
+    }
+}
@@ -6,6 +6,8 @@ use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::comma_separated_string;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 #[cfg(feature = "model")]
 impl StickerId {
@@ -137,7 +139,8 @@ impl StickerPack {
 
 #[cfg(feature = "model")]
 fn banner_url(banner_asset_id: StickerPackBannerId) -> String {
-    cdn!("/app-assets/710982414301790216/store/{}.webp?size=1024", banner_asset_id)
+    let options = ImageOptions::new().format(ImageFormat::Webp).size(1024);
+    cdn::sticker_pack_banner(banner_asset_id, options)
 }
 
 /// A sticker sent with a message.
@@ -297,12 +300,5 @@ enum_number! {
 
 #[cfg(feature = "model")]
 fn sticker_url(sticker_id: StickerId, sticker_format_type: StickerFormatType) -> Option<String> {
-    let ext = match sticker_format_type {
-        StickerFormatType::Png | StickerFormatType::Apng => "png",
-        StickerFormatType::Lottie => "json",
-        StickerFormatType::Gif => "gif",
-        StickerFormatType::Unknown(_) => return None,
-    };
-
-    Some(cdn!("/stickers/{}.{}", sticker_id, ext))
+    cdn::sticker(sticker_id, sticker_format_type)
 }
@@ -18,13 +18,15 @@ use crate::collector::{MessageCollector, ReactionCollector};
 #[cfg(feature = "collector")]
 use crate::gateway::ShardMessenger;
 #[cfg(feature = "model")]
-use crate::http::CacheHttp;
+use crate::http::{CacheHttp, Http};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::json::json;
 #[cfg(feature = "model")]
 use crate::model::utils::avatar_url;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageFormat, ImageOptions};
 
 /// Used with `#[serde(with|deserialize_with|serialize_with)]`
 ///
@@ -183,6 +185,33 @@ impl CurrentUser {
         *self = builder.execute(cache_http, ()).await?;
         Ok(())
     }
+
+    /// Gets the third party accounts linked to the current user, such as Steam, Spotify or Twitch
+    /// accounts.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is a bot.
+    pub async fn connections(&self, http: impl AsRef<Http>) -> Result<Vec<Connection>> {
+        http.as_ref().get_user_connections().await
+    }
+
+    /// Gets the boost slots of the current user, granted by their Nitro subscription, which can
+    /// be applied to a guild with [`GuildId::boost`].
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is a bot.
+    pub async fn guild_subscription_slots(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<Vec<GuildSubscriptionSlot>> {
+        http.as_ref().get_guild_subscription_slots().await
+    }
 }
 
 /// The representation of a user's status.
@@ -282,8 +311,19 @@ pub struct User {
     /// The type of Nitro subscription on a user's account
     #[serde(default)]
     pub premium_type: PremiumType,
+    /// When the user's Nitro subscription started.
+    ///
+    /// **Note**: Only present for the [`CurrentUser`] of a user account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub premium_since: Option<Timestamp>,
     /// The public flags on a user's account
     pub public_flags: Option<UserPublicFlags>,
+    /// The user's avatar decoration, if they have one equipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+    /// The guild tag the user displays next to their name, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_guild: Option<PrimaryGuild>,
     /// Only included in [`Message::mentions`] for messages from the gateway.
     ///
     /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-create-message-create-extra-fields).
@@ -291,6 +331,56 @@ pub struct User {
     pub member: Option<Box<PartialMember>>,
 }
 
+/// The decoration shown around the avatar of a user or member.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#avatar-decoration-data-object).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AvatarDecorationData {
+    /// The hash of the decoration's image.
+    pub asset: ImageHash,
+    /// The Id of the SKU the decoration belongs to.
+    pub sku_id: SkuId,
+}
+
+#[cfg(feature = "model")]
+impl AvatarDecorationData {
+    /// Returns the URL of the decoration's image.
+    #[must_use]
+    pub fn url(&self) -> String {
+        cdn::avatar_decoration(&self.asset)
+    }
+}
+
+/// The guild tag a user has chosen to display, also known as a clan badge.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#user-object-user-primary-guild).
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PrimaryGuild {
+    /// The Id of the guild the tag belongs to.
+    pub identity_guild_id: Option<GuildId>,
+    /// Whether the user is displaying the tag. [`None`] if the tag was recently cleared by the
+    /// guild.
+    pub identity_enabled: Option<bool>,
+    /// The text of the tag, up to 4 characters long.
+    pub tag: Option<String>,
+    /// The hash of the tag's badge.
+    pub badge: Option<ImageHash>,
+}
+
+#[cfg(feature = "model")]
+impl PrimaryGuild {
+    /// Returns the URL of the tag's badge, if it has one.
+    #[must_use]
+    pub fn badge_url(&self) -> Option<String> {
+        let guild_id = self.identity_guild_id?;
+        self.badge.as_ref().map(|badge| cdn::guild_tag_badge(guild_id, badge))
+    }
+}
+
 enum_number! {
     /// Premium types denote the level of premium a user has. Visit the [Nitro](https://discord.com/nitro)
     /// page to learn more about the premium plans Discord currently offers.
@@ -310,6 +400,233 @@ enum_number! {
     }
 }
 
+enum_number! {
+    /// A HypeSquad house the current user can join with [`Http::change_hypesquad_house`].
+    ///
+    /// The house the user is in is shown by the [`UserPublicFlags::HOUSE_BRAVERY`],
+    /// [`UserPublicFlags::HOUSE_BRILLIANCE`] and [`UserPublicFlags::HOUSE_BALANCE`] flags.
+    ///
+    /// [`Http::change_hypesquad_house`]: crate::http::Http::change_hypesquad_house
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum HypeSquadHouse {
+        Bravery = 1,
+        Brilliance = 2,
+        Balance = 3,
+        _ => Unknown(u8),
+    }
+}
+
+/// A relationship of the current user with another user, such as a friend or a blocked user.
+///
+/// Only user accounts have relationships. They are received in [`Ready::relationships`] and kept
+/// up to date by the `RELATIONSHIP_ADD`, `RELATIONSHIP_UPDATE` and `RELATIONSHIP_REMOVE` events.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Relationship {
+    /// The Id of the other user.
+    pub id: UserId,
+    /// The kind of the relationship.
+    #[serde(rename = "type")]
+    pub kind: RelationshipType,
+    /// The nickname the current user gave the other user, if any.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// The other user.
+    ///
+    /// Not sent when a relationship is updated or removed.
+    #[serde(default)]
+    pub user: Option<User>,
+    /// When the relationship was created.
+    #[serde(default)]
+    pub since: Option<Timestamp>,
+}
+
+enum_number! {
+    /// The kind of a [`Relationship`].
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[serde(from = "u8", into = "u8")]
+    #[non_exhaustive]
+    pub enum RelationshipType {
+        #[default]
+        None = 0,
+        /// The other user is a friend.
+        Friend = 1,
+        /// The current user blocked the other user.
+        Blocked = 2,
+        /// The other user sent a friend request to the current user.
+        IncomingRequest = 3,
+        /// The current user sent a friend request to the other user.
+        OutgoingRequest = 4,
+        /// The users interact often, without being friends.
+        Implicit = 5,
+        _ => Unknown(u8),
+    }
+}
+
+/// The settings of a user account, received in [`Ready::user_settings`].
+///
+/// Only user accounts have settings. Use [`EditUserSettings`] to change them.
+///
+/// [`EditUserSettings`]: crate::builder::EditUserSettings
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct UserSettings {
+    /// The theme of the client.
+    #[serde(default)]
+    pub theme: Theme,
+    /// The language of the client, e.g. `"en-US"`.
+    #[serde(default)]
+    pub locale: String,
+    /// The status the user set, which is shown to other users.
+    #[serde(default)]
+    pub status: OnlineStatus,
+    /// The custom status the user set, if any.
+    #[serde(default)]
+    pub custom_status: Option<CustomStatus>,
+    /// Whether members of newly joined guilds are restricted from sending direct messages.
+    #[serde(default)]
+    pub default_guilds_restricted: bool,
+    /// The guilds whose members are restricted from sending direct messages.
+    #[serde(default)]
+    pub restricted_guilds: Vec<GuildId>,
+    /// The order of the guilds in the guild list.
+    #[serde(default)]
+    pub guild_positions: Vec<GuildId>,
+    /// The folders of the guild list, in order. Guilds outside of a folder are in a folder without
+    /// an Id.
+    #[serde(default)]
+    pub guild_folders: Vec<GuildFolder>,
+    /// Whether developer mode, which allows to copy Ids, is enabled.
+    #[serde(default)]
+    pub developer_mode: bool,
+}
+
+/// A folder of guilds in the guild list.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildFolder {
+    /// The Id of the folder, or [`None`] for a single guild outside of a folder.
+    pub id: Option<u64>,
+    /// The name of the folder.
+    pub name: Option<String>,
+    /// The colour of the folder.
+    #[serde(rename = "color")]
+    pub colour: Option<Colour>,
+    /// The guilds in the folder, in order.
+    pub guild_ids: Vec<GuildId>,
+}
+
+impl GuildFolder {
+    /// Creates a folder with the given Id, containing the given guilds.
+    ///
+    /// The Id can be chosen freely, but must be unique among the folders of the user.
+    #[must_use]
+    pub fn new(id: u64, guild_ids: impl IntoIterator<Item = GuildId>) -> Self {
+        Self {
+            id: Some(id),
+            guild_ids: guild_ids.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an entry for a single guild outside of a folder.
+    #[must_use]
+    pub fn single(guild_id: GuildId) -> Self {
+        Self {
+            guild_ids: vec![guild_id],
+            ..Default::default()
+        }
+    }
+
+    /// Sets the name of the folder.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the colour of the folder.
+    #[must_use]
+    pub fn colour<C: Into<Colour>>(mut self, colour: C) -> Self {
+        self.colour = Some(colour.into());
+        self
+    }
+}
+
+enum_string! {
+    /// The theme of the client.
+    #[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+    #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+    #[serde(from = "String", into = "String")]
+    #[non_exhaustive]
+    pub enum Theme {
+        #[default]
+        Dark = "dark",
+        Light = "light",
+        _ => Unknown(String),
+    }
+}
+
+/// A custom status of a user, shown below their name.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CustomStatus {
+    /// The text of the status.
+    pub text: Option<String>,
+    /// The Id of the custom emoji of the status.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the custom emoji, or the unicode emoji, of the status.
+    pub emoji_name: Option<String>,
+    /// When the status is cleared automatically.
+    pub expires_at: Option<Timestamp>,
+}
+
+impl CustomStatus {
+    /// Creates a custom status with the given text.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the emoji of the status.
+    #[must_use]
+    pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+        match emoji.into() {
+            ReactionType::Custom {
+                id,
+                name,
+                ..
+            } => {
+                self.emoji_id = Some(id);
+                self.emoji_name = name;
+            },
+            ReactionType::Unicode(name) => {
+                self.emoji_id = None;
+                self.emoji_name = Some(name);
+            },
+        }
+        self
+    }
+
+    /// Sets when the status is cleared automatically.
+    #[must_use]
+    pub fn expires_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
+
 bitflags! {
     /// User's public flags
     ///
@@ -756,6 +1073,78 @@ impl UserId {
 
         Ok(user)
     }
+
+    /// Sends a friend request to the user.
+    ///
+    /// To send a friend request by username instead, use [`Http::send_friend_request`].
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the user doesn't accept friend requests from the current user.
+    pub async fn send_friend_request(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().add_relationship(self, &json!({})).await
+    }
+
+    /// Accepts an incoming friend request from the user.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the request could not be accepted.
+    pub async fn accept_friend_request(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().add_relationship(self, &json!({})).await
+    }
+
+    /// Ignores an incoming friend request from the user.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if there is no friend request from the user.
+    pub async fn ignore_friend_request(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().remove_relationship(self).await
+    }
+
+    /// Removes the user from the current user's friends.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the user is not a friend.
+    pub async fn remove_friend(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().remove_relationship(self).await
+    }
+
+    /// Blocks the user, removing them from the current user's friends if necessary.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the user could not be blocked.
+    pub async fn block(self, http: impl AsRef<Http>) -> Result<()> {
+        let map = json!({
+            "type": RelationshipType::Blocked,
+        });
+
+        http.as_ref().add_relationship(self, &map).await
+    }
+
+    /// Unblocks the user.
+    ///
+    /// **Note**: Only works for user accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the user is not blocked.
+    pub async fn unblock(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().remove_relationship(self).await
+    }
 }
 
 impl From<Member> for UserId {
@@ -788,26 +1177,18 @@ impl<'a> From<&'a User> for UserId {
 
 #[cfg(feature = "model")]
 fn default_avatar_url(user: &User) -> String {
-    let avatar_id = if let Some(discriminator) = user.discriminator {
-        discriminator.get() % 5 // Legacy username system
-    } else {
-        ((user.id.get() >> 22) % 6) as u16 // New username system
-    };
-
-    cdn!("/embed/avatars/{}.png", avatar_id)
+    cdn::default_avatar(user.id, user.discriminator.map(NonZeroU16::get))
 }
 
 #[cfg(feature = "model")]
 fn static_avatar_url(user_id: UserId, hash: Option<&ImageHash>) -> Option<String> {
-    hash.map(|hash| cdn!("/avatars/{}/{}.webp?size=1024", user_id, hash))
+    let options = ImageOptions::new().format(ImageFormat::Webp).size(1024);
+    hash.map(|hash| cdn::user_avatar(user_id, hash, options))
 }
 
 #[cfg(feature = "model")]
 fn banner_url(user_id: UserId, hash: Option<&ImageHash>) -> Option<String> {
-    hash.map(|hash| {
-        let ext = if hash.is_animated() { "gif" } else { "webp" };
-        cdn!("/banners/{}/{}.{}?size=1024", user_id, hash, ext)
-    })
+    hash.map(|hash| cdn::user_banner(user_id, hash, ImageOptions::new().size(1024)))
 }
 
 #[cfg(feature = "model")]
@@ -909,4 +1290,89 @@ mod test {
             assert!(user.default_avatar_url().ends_with("4.png"));
         }
     }
+
+    #[test]
+    fn user_settings_update() {
+        use crate::json::{from_value, json};
+        use crate::model::prelude::*;
+
+        let mut settings: UserSettings = from_value(json!({
+            "theme": "dark",
+            "locale": "en-US",
+            "status": "online",
+            "custom_status": {"text": "hi", "emoji_id": null, "emoji_name": null, "expires_at": null},
+            "guild_positions": ["1", "2"],
+        }))
+        .unwrap();
+        assert_eq!(settings.custom_status.as_ref().unwrap().text.as_deref(), Some("hi"));
+
+        let event: UserSettingsUpdateEvent = from_value(json!({
+            "theme": "light",
+            "custom_status": null,
+        }))
+        .unwrap();
+        event.apply_to_settings(&mut settings);
+
+        assert_eq!(settings.theme, Theme::Light);
+        assert_eq!(settings.locale, "en-US");
+        assert!(settings.custom_status.is_none());
+        assert_eq!(settings.guild_positions, [GuildId::new(1), GuildId::new(2)]);
+
+        let event: UserSettingsUpdateEvent = from_value(json!({
+            "guild_folders": [
+                {"id": null, "name": null, "color": null, "guild_ids": ["2"]},
+                {"id": 5, "name": "Friends", "color": 3447003, "guild_ids": ["1", "3"]},
+            ],
+        }))
+        .unwrap();
+        event.apply_to_settings(&mut settings);
+
+        let folder = &settings.guild_folders[1];
+        assert_eq!(folder.name.as_deref(), Some("Friends"));
+        assert_eq!(folder.colour, Some(Colour::BLUE));
+        assert_eq!(folder.guild_ids, [GuildId::new(1), GuildId::new(3)]);
+        assert!(settings.guild_folders[0].id.is_none());
+    }
+
+    #[test]
+    fn avatar_decoration_and_primary_guild() {
+        use crate::json::{from_value, json};
+        use crate::model::prelude::*;
+
+        let user: User = from_value(json!({
+            "id": "1",
+            "username": "user",
+            "discriminator": "0",
+            "global_name": null,
+            "avatar": null,
+            "avatar_decoration_data": {
+                "asset": "a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05",
+                "sku_id": "2",
+            },
+            "primary_guild": {
+                "identity_guild_id": "3",
+                "identity_enabled": true,
+                "tag": "TAG",
+                "badge": "fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05",
+            },
+        }))
+        .unwrap();
+
+        let decoration = user.avatar_decoration_data.unwrap();
+        assert_eq!(decoration.sku_id, SkuId::new(2));
+        let primary_guild = user.primary_guild.unwrap();
+        assert_eq!(primary_guild.tag.as_deref(), Some("TAG"));
+
+        #[cfg(feature = "model")]
+        {
+            assert_eq!(
+                decoration.url(),
+                "https://cdn.discordapp.com/avatar-decoration-presets/a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.png",
+            );
+            assert_eq!(
+                primary_guild.badge_url().unwrap(),
+                "https://cdn.discordapp.com/guild-tag-badges/3/fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.png",
+            );
+        }
+    }
 }
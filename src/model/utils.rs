@@ -7,6 +7,8 @@ use std::num::NonZeroU64;
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 
 use super::prelude::*;
+#[cfg(feature = "model")]
+use crate::utils::cdn::{self, ImageOptions};
 
 pub fn default_true() -> bool {
     true
@@ -24,24 +26,16 @@ pub(super) fn avatar_url(
     user_id: UserId,
     hash: Option<&ImageHash>,
 ) -> Option<String> {
-    hash.map(|hash| {
-        let ext = if hash.is_animated() { "gif" } else { "webp" };
-
-        if let Some(guild_id) = guild_id {
-            cdn!("/guilds/{}/users/{}/avatars/{}.{}?size=1024", guild_id, user_id, hash, ext)
-        } else {
-            cdn!("/avatars/{}/{}.{}?size=1024", user_id, hash, ext)
-        }
+    let options = ImageOptions::new().size(1024);
+    hash.map(|hash| match guild_id {
+        Some(guild_id) => cdn::member_avatar(guild_id, user_id, hash, options),
+        None => cdn::user_avatar(user_id, hash, options),
     })
 }
 
 #[cfg(feature = "model")]
 pub(super) fn icon_url(id: GuildId, icon: Option<&ImageHash>) -> Option<String> {
-    icon.map(|icon| {
-        let ext = if icon.is_animated() { "gif" } else { "webp" };
-
-        cdn!("/icons/{}/{}.{}", id, icon, ext)
-    })
+    icon.map(|icon| cdn::guild_icon(id, icon, ImageOptions::new()))
 }
 
 pub fn deserialize_val<T, E>(val: Value) -> StdResult<T, E>
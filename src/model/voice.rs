@@ -4,7 +4,7 @@ use serde::de::{Deserialize, Deserializer};
 use serde::Serialize;
 
 use crate::model::guild::Member;
-use crate::model::id::{ChannelId, GuildId, UserId};
+use crate::model::id::{ChannelId, GuildId, MessageId, UserId};
 use crate::model::Timestamp;
 
 /// Information about an available voice region.
@@ -69,3 +69,22 @@ impl Serialize for VoiceState {
         Self::serialize(self, serializer)
     }
 }
+
+/// A call in a private channel, which only user accounts can take part in.
+#[cfg_attr(feature = "typesize", derive(typesize::derive::TypeSize))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Call {
+    /// The Id of the private channel the call takes place in.
+    pub channel_id: ChannelId,
+    /// The Id of the message announcing the call.
+    pub message_id: MessageId,
+    /// The voice region of the call.
+    pub region: String,
+    /// The users whose clients are currently ringing.
+    #[serde(default)]
+    pub ringing: Vec<UserId>,
+    /// The voice states of the users that joined the call.
+    #[serde(default)]
+    pub voice_states: Vec<VoiceState>,
+}
@@ -265,26 +265,35 @@ impl Webhook {
         http.as_ref().get_webhook_with_token(webhook_id.into(), token).await
     }
 
-    /// Retrieves a webhook given its url.
+    /// Retrieves a webhook given its url. See [`utils::parse_webhook`] for the accepted formats.
     ///
-    /// This method does _not_ require authentication.
+    /// This method does _not_ require authentication, so the webhook can be retrieved and
+    /// executed with an [`Http`] which has no token.
     ///
     /// # Examples
     ///
-    /// Retrieve a webhook by url:
+    /// Retrieve a webhook by url, and post a message in one of the threads of its channel:
     ///
     /// ```rust,no_run
     /// # use serenity::http::Http;
     /// # use serenity::model::webhook::Webhook;
     /// #
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let http: Http = unimplemented!();
+    /// use serenity::builder::ExecuteWebhook;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let http = Http::new("");
     /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
     /// let webhook = Webhook::from_url(&http, url).await?;
+    ///
+    /// let builder = ExecuteWebhook::new().content("hello").in_thread(ChannelId::new(1));
+    /// let message = webhook.execute(&http, true, builder).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// [`utils::parse_webhook`]: crate::utils::parse_webhook
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the url is malformed, or otherwise if the webhook does not
@@ -0,0 +1,401 @@
+//! Logging in to a user account by scanning a QR code with the mobile app, using Discord's
+//! remote auth protocol.
+//!
+//! The flow is driven by [`RemoteAuth`]:
+//! 1. [`RemoteAuth::connect`] connects to the remote auth gateway and exchanges an RSA key with it,
+//!    after which a QR code can be shown with [`RemoteAuth::qr_code`].
+//! 2. [`RemoteAuth::wait_for_user`] resolves once the QR code was scanned, with some information
+//!    about the user who scanned it.
+//! 3. [`RemoteAuth::wait_for_token`] resolves with the user's token once they confirmed the login
+//!    in the app.
+//!
+//! ```rust,no_run
+//! use serenity::http::Http;
+//! use serenity::remote_auth::RemoteAuth;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut remote_auth = RemoteAuth::connect().await?;
+//! println!("{}", remote_auth.qr_code());
+//!
+//! let user = remote_auth.wait_for_user().await?;
+//! println!("Scanned by {}, waiting for confirmation", user.username);
+//!
+//! let token = remote_auth.wait_for_token(&Http::new("")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::num::NonZeroU16;
+use std::time::Duration;
+
+use base64::prelude::{Engine as _, BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
+use futures::{SinkExt, StreamExt};
+use qrcode::render::unicode::Dense1x2;
+use qrcode::QrCode;
+use rsa::pkcs8::EncodePublicKey;
+use rsa::{Oaep, RsaPrivateKey};
+use sha2::{Digest, Sha256};
+use tokio::net::TcpStream;
+use tokio::time::{interval_at, Instant, Interval};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::header::{HeaderValue, ORIGIN};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tracing::{debug, trace, warn};
+
+use crate::http::{Http, LightMethod, Request, Route};
+use crate::json::{from_str, json, to_string, to_vec};
+use crate::model::id::UserId;
+
+/// The URL of the remote auth gateway.
+const GATEWAY_URL: &str = "wss://remote-auth-gateway.discord.gg/?v=2";
+
+/// The URL encoded in the QR code, followed by the fingerprint.
+const QR_CODE_URL: &str = "https://discord.com/ra/";
+
+/// An error that occurred during a remote auth login.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RemoteAuthError {
+    /// An error from the WebSocket connection, the HTTP request or while decoding a message.
+    Serenity(crate::Error),
+    /// An error while generating the key or decrypting a message.
+    Crypto(rsa::Error),
+    /// The user cancelled the login in the app.
+    Cancelled,
+    /// The remote auth gateway closed the connection, e.g. because the QR code expired.
+    Closed,
+    /// The remote auth gateway sent an unexpected message.
+    UnexpectedMessage(&'static str),
+}
+
+impl fmt::Display for RemoteAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serenity(inner) => fmt::Display::fmt(inner, f),
+            Self::Crypto(inner) => write!(f, "remote auth crypto error: {inner}"),
+            Self::Cancelled => f.write_str("the remote auth login was cancelled"),
+            Self::Closed => f.write_str("the remote auth gateway closed the connection"),
+            Self::UnexpectedMessage(op) => write!(f, "unexpected remote auth message: {op}"),
+        }
+    }
+}
+
+impl std::error::Error for RemoteAuthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Serenity(inner) => Some(inner),
+            Self::Crypto(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Into<crate::Error>> From<E> for RemoteAuthError {
+    fn from(e: E) -> Self {
+        Self::Serenity(e.into())
+    }
+}
+
+type Result<T> = std::result::Result<T, RemoteAuthError>;
+
+/// The user who scanned the QR code, received from [`RemoteAuth::wait_for_user`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RemoteAuthUser {
+    /// The Id of the user.
+    pub id: UserId,
+    /// The discriminator of the user, if they haven't migrated to a unique username.
+    pub discriminator: Option<NonZeroU16>,
+    /// The avatar hash of the user.
+    pub avatar: Option<String>,
+    /// The username of the user.
+    pub username: String,
+}
+
+impl RemoteAuthUser {
+    /// Parses the decrypted user payload, formatted as `id:discriminator:avatar:username`.
+    fn parse(payload: &str) -> Option<Self> {
+        let mut parts = payload.splitn(4, ':');
+        let id = parts.next()?.parse().ok()?;
+        let discriminator = parts.next()?.parse().ok().and_then(NonZeroU16::new);
+        let avatar = Some(parts.next()?).filter(|a| !a.is_empty() && *a != "0");
+        let username = parts.next()?;
+
+        Some(Self {
+            id: UserId::new(id),
+            discriminator,
+            avatar: avatar.map(String::from),
+            username: username.into(),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ServerMessage {
+    Hello {
+        heartbeat_interval: u64,
+    },
+    HeartbeatAck,
+    NonceProof {
+        encrypted_nonce: String,
+    },
+    PendingRemoteInit {
+        fingerprint: String,
+    },
+    PendingTicket {
+        encrypted_user_payload: String,
+    },
+    PendingLogin {
+        ticket: String,
+    },
+    Cancel,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    encrypted_token: String,
+}
+
+/// A connection to the remote auth gateway, for logging in to a user account by QR code.
+///
+/// See the [module-level documentation](self) for an example.
+pub struct RemoteAuth {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    key: RsaPrivateKey,
+    heartbeat: Interval,
+    fingerprint: String,
+}
+
+impl RemoteAuth {
+    /// Connects to the remote auth gateway and performs the key exchange, after which the QR code
+    /// can be shown.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RemoteAuthError`] if the connection or the key exchange fails.
+    pub async fn connect() -> Result<Self> {
+        let mut request = GATEWAY_URL.into_client_request()?;
+        request.headers_mut().insert(ORIGIN, HeaderValue::from_static("https://discord.com"));
+        let (stream, _) = connect_async(request).await?;
+
+        // Generating the key takes a while, so keep it off the async worker threads.
+        let key = tokio::task::spawn_blocking(|| RsaPrivateKey::new(&mut rand::thread_rng(), 2048))
+            .await
+            .expect("generating the key doesn't panic")
+            .map_err(RemoteAuthError::Crypto)?;
+
+        let mut remote_auth = Self {
+            stream,
+            key,
+            // Replaced once the interval is known from the hello message.
+            heartbeat: interval_at(Instant::now() + Duration::from_secs(3600), Duration::MAX),
+            fingerprint: String::new(),
+        };
+
+        let ServerMessage::Hello {
+            heartbeat_interval,
+        } = remote_auth.recv().await?
+        else {
+            return Err(RemoteAuthError::UnexpectedMessage("expected hello"));
+        };
+        let period = Duration::from_millis(heartbeat_interval);
+        remote_auth.heartbeat = interval_at(Instant::now() + period, period);
+
+        let public_key =
+            remote_auth.key.to_public_key().to_public_key_der().map_err(|e| {
+                RemoteAuthError::Crypto(rsa::Error::from(rsa::pkcs8::Error::from(e)))
+            })?;
+        remote_auth
+            .send(json!({
+                "op": "init",
+                "encoded_public_key": BASE64_STANDARD.encode(public_key.as_bytes()),
+            }))
+            .await?;
+
+        let ServerMessage::NonceProof {
+            encrypted_nonce,
+        } = remote_auth.recv().await?
+        else {
+            return Err(RemoteAuthError::UnexpectedMessage("expected nonce_proof"));
+        };
+        let nonce =
+            Self::decrypt(&remote_auth.key, &encrypted_nonce).map_err(RemoteAuthError::Crypto)?;
+        remote_auth
+            .send(json!({
+                "op": "nonce_proof",
+                "proof": BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(nonce)),
+            }))
+            .await?;
+
+        let ServerMessage::PendingRemoteInit {
+            fingerprint,
+        } = remote_auth.recv().await?
+        else {
+            return Err(RemoteAuthError::UnexpectedMessage("expected pending_remote_init"));
+        };
+        remote_auth.fingerprint = fingerprint;
+
+        Ok(remote_auth)
+    }
+
+    /// The fingerprint of the public key, identifying this login attempt.
+    #[must_use]
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// The URL to encode in a QR code, which is scanned with the mobile app.
+    #[must_use]
+    pub fn qr_code_url(&self) -> String {
+        format!("{QR_CODE_URL}{}", self.fingerprint)
+    }
+
+    /// Renders the QR code as text with unicode block characters, e.g. to print it to a terminal.
+    #[must_use]
+    pub fn qr_code(&self) -> String {
+        QrCode::new(self.qr_code_url())
+            .expect("the URL always fits into a QR code")
+            .render::<Dense1x2>()
+            .quiet_zone(true)
+            .build()
+    }
+
+    /// Waits until the QR code was scanned, returning the user who scanned it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RemoteAuthError::Cancelled`] if the user cancelled the login, or
+    /// [`RemoteAuthError::Closed`] if the QR code expired.
+    pub async fn wait_for_user(&mut self) -> Result<RemoteAuthUser> {
+        let ServerMessage::PendingTicket {
+            encrypted_user_payload,
+        } = self.recv().await?
+        else {
+            return Err(RemoteAuthError::UnexpectedMessage("expected pending_ticket"));
+        };
+
+        let payload =
+            Self::decrypt(&self.key, &encrypted_user_payload).map_err(RemoteAuthError::Crypto)?;
+        String::from_utf8(payload)
+            .ok()
+            .and_then(|payload| RemoteAuthUser::parse(&payload))
+            .ok_or(RemoteAuthError::UnexpectedMessage("invalid user payload"))
+    }
+
+    /// Waits until the user confirmed the login in the app, returning their token.
+    ///
+    /// The given [`Http`] is used to exchange the login ticket for the token, and doesn't need a
+    /// token itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RemoteAuthError::Cancelled`] if the user cancelled the login, or an error if the
+    /// ticket couldn't be exchanged for a token.
+    pub async fn wait_for_token(mut self, http: &Http) -> Result<String> {
+        let ServerMessage::PendingLogin {
+            ticket,
+        } = self.recv().await?
+        else {
+            return Err(RemoteAuthError::UnexpectedMessage("expected pending_login"));
+        };
+        if let Err(why) = self.stream.close(None).await {
+            debug!("Failed to close the remote auth connection: {why:?}");
+        }
+
+        let body = to_vec(&json!({
+            "ticket": ticket,
+        }))?;
+        let request =
+            Request::new(Route::RemoteAuthLogin, LightMethod::Post).body(Some(body)).headers(None);
+        let response: LoginResponse = http.fire(request).await?;
+
+        let token =
+            Self::decrypt(&self.key, &response.encrypted_token).map_err(RemoteAuthError::Crypto)?;
+        String::from_utf8(token).map_err(|_| RemoteAuthError::UnexpectedMessage("invalid token"))
+    }
+
+    /// Decrypts a base64 encoded message of the remote auth gateway.
+    fn decrypt(key: &RsaPrivateKey, data: &str) -> std::result::Result<Vec<u8>, rsa::Error> {
+        let data = BASE64_STANDARD.decode(data).map_err(|_| rsa::Error::Decryption)?;
+        key.decrypt(Oaep::new::<Sha256>(), &data)
+    }
+
+    async fn send(&mut self, value: crate::json::Value) -> Result<()> {
+        self.stream.send(Message::Text(to_string(&value)?)).await?;
+        Ok(())
+    }
+
+    /// Receives the next message, sending heartbeats in the meantime.
+    async fn recv(&mut self) -> Result<ServerMessage> {
+        loop {
+            tokio::select! {
+                _ = self.heartbeat.tick() => {
+                    self.send(json!({"op": "heartbeat"})).await?;
+                },
+                message = self.stream.next() => {
+                    let text = match message.ok_or(RemoteAuthError::Closed)?? {
+                        Message::Text(text) => text,
+                        Message::Close(frame) => {
+                            debug!("Remote auth gateway closed the connection: {frame:?}");
+                            return Err(RemoteAuthError::Closed);
+                        },
+                        _ => continue,
+                    };
+                    trace!("Remote auth message: {text}");
+
+                    match from_str(&*text)? {
+                        ServerMessage::HeartbeatAck => {},
+                        ServerMessage::Unknown => warn!("Unknown remote auth message: {text}"),
+                        ServerMessage::Cancel => return Err(RemoteAuthError::Cancelled),
+                        message => return Ok(message),
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl fmt::Debug for RemoteAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteAuth").field("fingerprint", &self.fingerprint).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_payload() {
+        let user = RemoteAuthUser::parse("1234:0:a_abc:some:name").unwrap();
+        assert_eq!(user.id, UserId::new(1234));
+        assert_eq!(user.discriminator, None);
+        assert_eq!(user.avatar.as_deref(), Some("a_abc"));
+        assert_eq!(user.username, "some:name");
+
+        let user = RemoteAuthUser::parse("1234:0042::legacy").unwrap();
+        assert_eq!(user.discriminator, NonZeroU16::new(42));
+        assert!(user.avatar.is_none());
+
+        assert!(RemoteAuthUser::parse("not an id").is_none());
+    }
+
+    #[test]
+    fn decrypt() {
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let encrypted = key
+            .to_public_key()
+            .encrypt(&mut rand::thread_rng(), Oaep::new::<Sha256>(), b"nonce")
+            .unwrap();
+
+        let decrypted = RemoteAuth::decrypt(&key, &BASE64_STANDARD.encode(encrypted)).unwrap();
+        assert_eq!(decrypted, b"nonce");
+
+        assert!(RemoteAuth::decrypt(&key, "not base64!").is_err());
+    }
+}
@@ -0,0 +1,298 @@
+//! Builders for the URLs of images and other assets hosted on Discord's CDN.
+//!
+//! Model methods such as [`User::avatar_url`] cover the common cases. The functions in this module
+//! additionally allow choosing the format and size of the image.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serenity::model::id::UserId;
+//! use serenity::model::misc::ImageHash;
+//! use serenity::utils::cdn::{self, ImageFormat, ImageOptions};
+//!
+//! let hash: ImageHash = "a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05".parse().unwrap();
+//! let options = ImageOptions::new().format(ImageFormat::Png).size(256);
+//!
+//! assert_eq!(
+//!     cdn::user_avatar(UserId::new(1), &hash, options),
+//!     "https://cdn.discordapp.com/avatars/1/a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.png?size=256",
+//! );
+//! ```
+//!
+//! [`User::avatar_url`]: crate::model::user::User::avatar_url
+
+use std::fmt;
+
+use crate::model::id::{
+    ApplicationId,
+    AttachmentId,
+    ChannelId,
+    EmojiId,
+    GuildId,
+    RoleId,
+    SoundId,
+    StickerId,
+    StickerPackBannerId,
+    UserId,
+};
+use crate::model::misc::ImageHash;
+use crate::model::sticker::StickerFormatType;
+
+/// The format of an image on the CDN.
+///
+/// [Discord docs](https://discord.com/developers/docs/reference#image-formatting).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+    Gif,
+}
+
+impl ImageFormat {
+    /// The file extension of the format.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+            Self::Gif => "gif",
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+/// The format and size to request an image in.
+///
+/// By default, animated images are requested as GIF and all other images as WEBP, in the size
+/// chosen by Discord.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[must_use]
+pub struct ImageOptions {
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+}
+
+impl ImageOptions {
+    /// Creates options which use the default format and size.
+    pub const fn new() -> Self {
+        Self {
+            format: None,
+            size: None,
+        }
+    }
+
+    /// Sets the format of the image. Requesting an image which is not animated as
+    /// [`ImageFormat::Gif`] results in a still image.
+    pub const fn format(mut self, format: ImageFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the size of the image, in pixels along the longest side.
+    ///
+    /// Discord only accepts powers of two between 16 and 4096, so the size is rounded up to the
+    /// next power of two and clamped to that range.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size.clamp(16, 4096).next_power_of_two());
+        self
+    }
+
+    fn extension(self, animated: bool) -> &'static str {
+        let default = if animated { ImageFormat::Gif } else { ImageFormat::Webp };
+        self.format.unwrap_or(default).extension()
+    }
+
+    fn query(self) -> String {
+        self.size.map(|size| format!("?size={size}")).unwrap_or_default()
+    }
+}
+
+fn image(path: fmt::Arguments<'_>, hash: &ImageHash, options: ImageOptions) -> String {
+    let ext = options.extension(hash.is_animated());
+    format!("{}{path}/{hash}.{ext}{}", cdn!(""), options.query())
+}
+
+/// The URL of a user's avatar.
+#[must_use]
+pub fn user_avatar(user_id: UserId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/avatars/{user_id}"), hash, options)
+}
+
+/// The URL of a member's guild-specific avatar.
+#[must_use]
+pub fn member_avatar(
+    guild_id: GuildId,
+    user_id: UserId,
+    hash: &ImageHash,
+    options: ImageOptions,
+) -> String {
+    image(format_args!("/guilds/{guild_id}/users/{user_id}/avatars"), hash, options)
+}
+
+/// The URL of the default avatar of a user without a custom avatar.
+///
+/// `discriminator` must be given for users which have not migrated to the new username system.
+/// Default avatars are only available as PNG.
+#[must_use]
+pub fn default_avatar(user_id: UserId, discriminator: Option<u16>) -> String {
+    let index = match discriminator {
+        Some(discriminator) => u64::from(discriminator % 5),
+        None => (user_id.get() >> 22) % 6,
+    };
+
+    cdn!("/embed/avatars/{}.png", index)
+}
+
+/// The URL of a user's profile banner.
+#[must_use]
+pub fn user_banner(user_id: UserId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/banners/{user_id}"), hash, options)
+}
+
+/// The URL of a guild's icon.
+#[must_use]
+pub fn guild_icon(guild_id: GuildId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/icons/{guild_id}"), hash, options)
+}
+
+/// The URL of a guild's invite splash.
+#[must_use]
+pub fn guild_splash(guild_id: GuildId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/splashes/{guild_id}"), hash, options)
+}
+
+/// The URL of a guild's discovery splash.
+#[must_use]
+pub fn guild_discovery_splash(
+    guild_id: GuildId,
+    hash: &ImageHash,
+    options: ImageOptions,
+) -> String {
+    image(format_args!("/discovery-splashes/{guild_id}"), hash, options)
+}
+
+/// The URL of a guild's banner.
+#[must_use]
+pub fn guild_banner(guild_id: GuildId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/banners/{guild_id}"), hash, options)
+}
+
+/// The URL of a role's icon.
+#[must_use]
+pub fn role_icon(role_id: RoleId, hash: &ImageHash, options: ImageOptions) -> String {
+    image(format_args!("/role-icons/{role_id}"), hash, options)
+}
+
+/// The URL of an avatar decoration.
+///
+/// Avatar decorations are only available as PNG.
+#[must_use]
+pub fn avatar_decoration(asset: &ImageHash) -> String {
+    cdn!("/avatar-decoration-presets/{}.png", asset)
+}
+
+/// The URL of the badge of a guild tag.
+///
+/// Guild tag badges are only available as PNG.
+#[must_use]
+pub fn guild_tag_badge(guild_id: GuildId, badge: &ImageHash) -> String {
+    cdn!("/guild-tag-badges/{}/{}.png", guild_id, badge)
+}
+
+/// The URL of an application's icon.
+#[must_use]
+pub fn application_icon(
+    application_id: ApplicationId,
+    hash: &ImageHash,
+    options: ImageOptions,
+) -> String {
+    image(format_args!("/app-icons/{application_id}"), hash, options)
+}
+
+/// The URL of a custom emoji.
+#[must_use]
+pub fn emoji(emoji_id: EmojiId, animated: bool, options: ImageOptions) -> String {
+    let ext = options.extension(animated);
+    format!("{}/{emoji_id}.{ext}{}", cdn!("/emojis"), options.query())
+}
+
+/// The URL of a sticker, or `None` if the format of the sticker is unknown.
+///
+/// The format of a sticker can't be chosen: PNG and APNG stickers are served as PNG, Lottie
+/// stickers as JSON and GIF stickers as GIF.
+#[must_use]
+pub fn sticker(sticker_id: StickerId, format_type: StickerFormatType) -> Option<String> {
+    let ext = match format_type {
+        StickerFormatType::Png | StickerFormatType::Apng => "png",
+        StickerFormatType::Lottie => "json",
+        StickerFormatType::Gif => "gif",
+        StickerFormatType::Unknown(_) => return None,
+    };
+
+    Some(cdn!("/stickers/{}.{}", sticker_id, ext))
+}
+
+/// The URL of a sticker pack's banner.
+#[must_use]
+pub fn sticker_pack_banner(banner_asset_id: StickerPackBannerId, options: ImageOptions) -> String {
+    let ext = options.extension(false);
+    format!(
+        "{}/{banner_asset_id}.{ext}{}",
+        cdn!("/app-assets/710982414301790216/store"),
+        options.query()
+    )
+}
+
+/// The URL of a soundboard sound's audio file.
+#[must_use]
+pub fn soundboard_sound(sound_id: SoundId) -> String {
+    cdn!("/soundboard-sounds/{}", sound_id)
+}
+
+/// The URL of a message attachment.
+///
+/// **Note**: Attachment URLs must be signed. Prefer the [`url`] of the attachment, which includes
+/// the signature; unsigned URLs only work when requested with a bot token.
+///
+/// [`url`]: crate::model::channel::Attachment::url
+#[must_use]
+pub fn attachment(channel_id: ChannelId, attachment_id: AttachmentId, filename: &str) -> String {
+    cdn!("/attachments/{}/{}/{}", channel_id, attachment_id, filename)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_urls() {
+        let still: ImageHash = "fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05".parse().unwrap();
+        let animated: ImageHash = "a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05".parse().unwrap();
+
+        assert_eq!(
+            guild_icon(GuildId::new(1), &still, ImageOptions::new()),
+            "https://cdn.discordapp.com/icons/1/fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.webp",
+        );
+        assert_eq!(
+            member_avatar(GuildId::new(1), UserId::new(2), &animated, ImageOptions::new().size(100)),
+            "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_fba3ee4ad3ea9fc9e3c4b7cb8c2d9d05.gif?size=128",
+        );
+        assert_eq!(
+            emoji(EmojiId::new(3), false, ImageOptions::new().format(ImageFormat::Png)),
+            "https://cdn.discordapp.com/emojis/3.png",
+        );
+        assert_eq!(
+            default_avatar(UserId::new(1 << 22), None),
+            "https://cdn.discordapp.com/embed/avatars/1.png",
+        );
+        assert_eq!(ImageOptions::new().size(10_000).size, Some(4096));
+    }
+}
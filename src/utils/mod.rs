@@ -11,6 +11,7 @@ mod message_builder;
 #[cfg(feature = "collector")]
 mod quick_modal;
 
+pub mod cdn;
 pub mod token;
 
 use std::num::NonZeroU16;
@@ -395,6 +396,9 @@ const DOMAINS: &[&str] = &[
 
 /// Parses the id and token from a webhook url. Expects a [`url::Url`] rather than a [`&str`].
 ///
+/// The url may contain an API version, such as `/api/v10/webhooks/...`, and segments after the
+/// token, such as the `/slack` of Slack-compatible webhook urls.
+///
 /// # Examples
 ///
 /// ```rust
@@ -409,7 +413,18 @@ const DOMAINS: &[&str] = &[
 /// ```
 #[must_use]
 pub fn parse_webhook(url: &Url) -> Option<(WebhookId, &str)> {
-    let (webhook_id, token) = url.path().strip_prefix("/api/webhooks/")?.split_once('/')?;
+    let path = url.path().strip_prefix("/api/")?;
+    let path = match path.split_once('/') {
+        Some((version, rest))
+            if version.strip_prefix('v').is_some_and(|v| v.parse::<u8>().is_ok()) =>
+        {
+            rest
+        },
+        _ => path,
+    };
+
+    let mut segments = path.strip_prefix("webhooks/")?.split('/');
+    let (webhook_id, token) = (segments.next()?, segments.next()?);
     if !["http", "https"].contains(&url.scheme())
         || !DOMAINS.contains(&url.domain()?)
         || !(17..=20).contains(&webhook_id.len())
@@ -556,5 +571,17 @@ mod test {
                 "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV"
             );
         }
+
+        let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+        for path in [
+            format!("/api/v10/webhooks/245037420704169985/{token}"),
+            format!("/api/webhooks/245037420704169985/{token}/slack"),
+        ] {
+            let url = format!("https://discord.com{path}").parse().unwrap();
+            assert_eq!(parse_webhook(&url), Some((WebhookId::new(245037420704169985), token)));
+        }
+
+        let url = format!("https://discord.com/api/v10/users/245037420704169985/{token}");
+        assert_eq!(parse_webhook(&url.parse().unwrap()), None);
     }
 }
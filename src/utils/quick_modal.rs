@@ -9,9 +9,16 @@ use crate::client::Context;
 use crate::collector::ModalInteractionCollector;
 use crate::model::prelude::*;
 
+/// The submission of a modal sent via [`CreateQuickModal`].
 #[cfg(feature = "collector")]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct QuickModalResponse {
+    /// The modal submit interaction, which still has to be responded to.
     pub interaction: ModalInteraction,
+    /// The submitted values, in the order the fields were added to the [`CreateQuickModal`].
+    ///
+    /// Fields which were left empty are represented by an empty string.
     pub inputs: Vec<String>,
 }
 
@@ -41,6 +48,7 @@ pub struct CreateQuickModal {
 
 #[cfg(feature = "collector")]
 impl CreateQuickModal {
+    /// Creates a new quick modal with the given title and no fields.
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
@@ -81,9 +89,15 @@ impl CreateQuickModal {
         self.field(CreateInputText::new(InputTextStyle::Paragraph, label, ""))
     }
 
+    /// Responds to the interaction with the modal, then waits for the user to submit it.
+    ///
+    /// Returns `None` if the [timeout] elapsed before the modal was submitted.
+    ///
     /// # Errors
     ///
     /// See [`CreateInteractionResponse::execute()`].
+    ///
+    /// [timeout]: Self::timeout
     pub async fn execute(
         self,
         ctx: &Context,
@@ -120,22 +134,15 @@ impl CreateQuickModal {
             .data
             .components
             .iter()
-            .filter_map(|row| match row.components.first() {
-                Some(ActionRowComponent::InputText(text)) => {
-                    if let Some(value) = &text.value {
-                        Some(value.clone())
-                    } else {
-                        tracing::warn!("input text value was empty in modal response");
-                        None
-                    }
-                },
+            .map(|row| match row.components.first() {
+                Some(ActionRowComponent::InputText(text)) => text.value.clone().unwrap_or_default(),
                 Some(other) => {
                     tracing::warn!("expected input text in modal response, got {:?}", other);
-                    None
+                    String::new()
                 },
                 None => {
                     tracing::warn!("empty action row");
-                    None
+                    String::new()
                 },
             })
             .collect();